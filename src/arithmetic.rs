@@ -4,7 +4,41 @@ use core::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
-use crate::TwoFloat;
+use hexf::hexf64;
+
+use crate::{no_overlap, TwoFloat};
+
+// Allowance for the rounding of the adjusted low word when widening a result
+const WIDENING_SLACK: f64 = hexf64!("0x1p-105");
+
+// Factor applied to absolute error bounds to cover the difference between
+// the exact and rounded results and the rounding of the bound itself
+const BOUND_SCALE: f64 = 1.0 + hexf64!("0x1p-50");
+
+// Allowance for the absolute error of results whose low word is subnormal
+const UNDERFLOW_ERROR: f64 = hexf64!("0x1p-1070");
+
+// Relative error of a result computed from the high words alone
+const ESTIMATE_ERROR: f64 = hexf64!("0x1p-50");
+
+// Relative error bound of `sqrt` stated in its documentation, which has been
+// observed in testing but not proved. It is used as the initial margin of the
+// directed square roots, whose results are then verified.
+const SQRT_ERROR_BOUND: f64 = hexf64!("0x1p-102");
+
+// Number of times the margin of the directed square roots is doubled before
+// falling back to a bound computed from the high word alone
+const SQRT_WIDENING_STEPS: usize = 16;
+
+// Operands of divisions and square roots outside this range are scaled by a
+// power of two, so that the intermediate values neither overflow nor lose
+// precision through underflow
+const SCALE_MIN: f64 = hexf64!("0x1p-900");
+const SCALE_MAX: f64 = hexf64!("0x1p+900");
+
+// Products below this magnitude may have an error which is not exactly
+// representable
+const PRODUCT_UNDERFLOW: f64 = hexf64!("0x1p-968");
 
 // MinGW FMA seems to be inaccurate, use libm even if std is enabled.
 #[cfg(all(feature = "std", not(all(windows, target_env = "gnu"))))]
@@ -24,9 +58,9 @@ fn fma(x: f64, y: f64, z: f64) -> f64 {
 /// first.
 #[inline]
 pub fn renorm3(a: f64, b: f64, c: f64) -> TwoFloat {
-    let u = fast_two_sum(a, b);
-    let v = fast_two_sum(c, u.hi);
-    fast_two_sum(v.hi, u.lo + v.lo)
+    let u = fast_two_sum(b, c);
+    let v = fast_two_sum(a, u.hi);
+    fast_two_sum(v.hi, v.lo + u.lo)
 }
 
 pub(crate) fn fast_two_sum(a: f64, b: f64) -> TwoFloat {
@@ -36,11 +70,174 @@ pub(crate) fn fast_two_sum(a: f64, b: f64) -> TwoFloat {
     TwoFloat { hi: s, lo: b - z }
 }
 
+// Multiplies a `TwoFloat` by an `f64` using Joldes et al. (2017) Algorithm 7,
+// which is the product used in the proof of the error bound of Algorithm 17
+fn mul_fp1(x: TwoFloat, y: f64) -> TwoFloat {
+    let (ch, cl1) = TwoFloat::new_mul(x.hi, y).into();
+    let cl2 = x.lo * y;
+    let (th, tl1) = fast_two_sum(ch, cl2).into();
+    let tl2 = tl1 + cl1;
+    fast_two_sum(th, tl2)
+}
+
+/// Checks whether a finite nonzero value is close enough to the limits of
+/// the range of `f64` that it should be scaled before a division or square
+/// root.
+#[inline]
+pub(crate) fn needs_scaling(x: f64) -> bool {
+    let magnitude = libm::fabs(x);
+    x != 0.0 && x.is_finite() && !(SCALE_MIN..=SCALE_MAX).contains(&magnitude)
+}
+
+/// Multiplies a value by 2<sup>n</sup>. This is exact unless the result
+/// overflows or is close to underflow, in which case the rounding error of
+/// the high word is carried into the low word.
+pub(crate) fn scale_pow2(value: TwoFloat, n: i32) -> TwoFloat {
+    let hi = libm::scalbn(value.hi, n);
+    let error = value.hi - libm::scalbn(hi, -n);
+    fast_two_sum(hi, libm::scalbn(error + value.lo, n))
+}
+
+// Returns the powers of two by which the dividend and divisor of a division
+// are scaled if either is close to the limits of the range of `f64`
+fn division_scales(dividend: f64, divisor: f64) -> Option<(i32, i32)> {
+    if needs_scaling(dividend) || needs_scaling(divisor) {
+        let scale = |x: f64| if x == 0.0 { 0 } else { -libm::ilogb(x) };
+        Some((scale(dividend), scale(divisor)))
+    } else {
+        None
+    }
+}
+
+// Checks that both words of a value are finite, without checking that they
+// do not overlap
+fn finite(value: &TwoFloat) -> bool {
+    value.hi.is_finite() && value.lo.is_finite()
+}
+
+/// Gives the canonical representation of a value which has no low word, so
+/// that infinities have a zero low word and `NAN` has both words `NAN`.
+#[inline]
+pub(crate) fn special_value(value: f64) -> TwoFloat {
+    if value.is_nan() {
+        TwoFloat::NAN
+    } else {
+        TwoFloat { hi: value, lo: 0.0 }
+    }
+}
+
+// Gives the result of an operation following IEEE 754, given an estimate of
+// the result computed from the high words of the operands. Where the
+// operands are not finite the estimate is the result. Otherwise the result
+// is computed, and if it overflows or is zero it takes the sign of the
+// estimate.
+#[inline(always)]
+fn ieee_result(finite: bool, estimate: f64, compute: impl FnOnce() -> TwoFloat) -> TwoFloat {
+    if !finite {
+        return special_value(estimate);
+    }
+
+    let result = compute();
+    if !result.hi.is_finite() || !result.lo.is_finite() {
+        special_value(libm::copysign(f64::INFINITY, estimate))
+    } else if result.hi == 0.0 {
+        // A zero estimate has the sign given by IEEE 754, while cancellation
+        // to an exact zero gives a positive zero
+        special_value(if estimate == 0.0 { estimate } else { 0.0 })
+    } else {
+        result
+    }
+}
+
+// Exponent difference up to which the quotient of the remainder is finite
+const MAX_QUOTIENT_EXPONENT: i32 = 1000;
+
+// Computes the remainder of truncated division, which is `NAN` where the
+// dividend is infinite or the divisor is zero, the dividend where the
+// divisor is infinite, and otherwise has the sign of the dividend.
+fn remainder(dividend: TwoFloat, divisor: TwoFloat) -> TwoFloat {
+    if !finite(&dividend) || divisor.hi.is_nan() || divisor.hi == 0.0 {
+        TwoFloat::NAN
+    } else if divisor.hi.is_infinite() {
+        dividend
+    } else {
+        // Where the quotient would overflow, the dividend is first reduced
+        // by a multiple of the divisor scaled by a power of two
+        let sign = dividend.hi;
+        let mut dividend = dividend;
+        while dividend.hi != 0.0 {
+            let gap = libm::ilogb(dividend.hi) - libm::ilogb(divisor.hi);
+            if gap <= MAX_QUOTIENT_EXPONENT {
+                break;
+            }
+
+            let scale = gap - MAX_QUOTIENT_EXPONENT;
+            let scaled = TwoFloat {
+                hi: libm::scalbn(divisor.hi, scale),
+                lo: libm::scalbn(divisor.lo, scale),
+            };
+            dividend -= (dividend / scaled).trunc() * scaled;
+        }
+
+        let quotient = (dividend / divisor).trunc();
+        let result = dividend - quotient * divisor;
+        if result.hi == 0.0 {
+            special_value(libm::copysign(0.0, sign))
+        } else {
+            result
+        }
+    }
+}
+
+// Number of exactly representable terms in the expansion of `a * b + c`
+const FMA_TERMS: usize = 10;
+
+// Sums the terms exactly as a non-overlapping expansion using the
+// Grow-Expansion algorithm with zero elimination from Shewchuk (1997), then
+// rounds the largest components of the expansion to a `TwoFloat`. Also
+// returns an approximation of the rounding error.
+fn expansion_sum(terms: &[f64; FMA_TERMS]) -> (TwoFloat, f64) {
+    let mut expansion = [0.0; FMA_TERMS];
+    let mut length = 0;
+    for &term in terms {
+        let mut q = term;
+        let mut k = 0;
+        for i in 0..length {
+            let (sh, sl) = TwoFloat::new_add(q, expansion[i]).into();
+            q = sh;
+            if sl != 0.0 {
+                expansion[k] = sl;
+                k += 1;
+            }
+        }
+        if q != 0.0 {
+            expansion[k] = q;
+            k += 1;
+        }
+        length = k;
+    }
+
+    match length {
+        0 => (TwoFloat::from(0.0), 0.0),
+        1 => (TwoFloat::from(expansion[0]), 0.0),
+        _ => {
+            let (top, second) = (expansion[length - 1], expansion[length - 2]);
+            let rest = expansion[..length - 2].iter().sum();
+            let result = renorm3(top, second, rest);
+            let error = (top - result.hi) + (second - result.lo) + rest;
+            (result, error)
+        }
+    }
+}
+
 impl TwoFloat {
     /// Creates a new `TwoFloat` by adding two `f64` values using Algorithm 2
     /// from Joldes et al. (2017).
     pub fn new_add(a: f64, b: f64) -> Self {
         let s = a + b;
+        if !s.is_finite() {
+            return special_value(s);
+        }
         let aa = s - b;
         let bb = s - aa;
         let da = a - aa;
@@ -53,6 +250,9 @@ impl TwoFloat {
     /// side.
     pub fn new_sub(a: f64, b: f64) -> Self {
         let s = a - b;
+        if !s.is_finite() {
+            return special_value(s);
+        }
         let aa = s + b;
         let bb = s - aa;
         let da = a - aa;
@@ -64,9 +264,17 @@ impl TwoFloat {
     /// Algorithm 3 from Joldes et al. (2017).
     pub fn new_mul(a: f64, b: f64) -> Self {
         let p = a * b;
-        Self {
-            hi: p,
-            lo: fma(a, b, -p),
+        if !p.is_finite() {
+            return special_value(p);
+        }
+
+        let lo = fma(a, b, -p);
+        if libm::fabs(p) < PRODUCT_UNDERFLOW && !no_overlap(p, lo) {
+            // The error has been rounded to a subnormal value as large as
+            // half an ulp of the product, so the sum is renormalized
+            fast_two_sum(p, lo)
+        } else {
+            Self { hi: p, lo }
         }
     }
 
@@ -75,6 +283,12 @@ impl TwoFloat {
     /// zero value in the low word.
     pub fn new_div(a: f64, b: f64) -> Self {
         let th = a / b;
+        if !th.is_finite() || th == 0.0 || !b.is_finite() || b == 0.0 {
+            return special_value(th);
+        } else if let Some((m, n)) = division_scales(a, b) {
+            let quotient = Self::new_div(libm::scalbn(a, m), libm::scalbn(b, n));
+            return ieee_result(true, th, || scale_pow2(quotient, n - m));
+        }
         let (ph, pl) = Self::new_mul(th, b).into();
         let dh = a - ph;
         let d = dh - pl;
@@ -85,9 +299,11 @@ impl TwoFloat {
 
 unary_ops! {
     fn Neg::neg(self: &TwoFloat) -> TwoFloat {
+        // Subtracting from +0.0 leaves a zero low word positive, so that
+        // negated zeros keep the canonical form of `TwoFloat::from(-0.0)`
         Self::Output {
             hi: -self.hi,
-            lo: -self.lo,
+            lo: 0.0 - self.lo,
         }
     }
 }
@@ -96,131 +312,172 @@ binary_ops! {
     /// Implements addition of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4.
     fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_add(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        fast_two_sum(sh, v)
+        ieee_result(finite(self) && rhs.is_finite(), self.hi + rhs, || {
+            let (sh, sl) = TwoFloat::new_add(self.hi, *rhs).into();
+            let v = self.lo + sl;
+            fast_two_sum(sh, v)
+        })
     }
 
     /// Implements addition of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4.
     fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_add(rhs.hi, *self).into();
-        let v = rhs.lo + sl;
-        fast_two_sum(sh, v)
+        ieee_result(self.is_finite() && finite(rhs), self + rhs.hi, || {
+            let (sh, sl) = TwoFloat::new_add(rhs.hi, *self).into();
+            let v = rhs.lo + sl;
+            fast_two_sum(sh, v)
+        })
     }
 
     /// Implements addition of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6.
     fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_add(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_add(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        fast_two_sum(vh, w)
+        ieee_result(finite(self) && finite(rhs), self.hi + rhs.hi, || {
+            let (sh, sl) = TwoFloat::new_add(self.hi, rhs.hi).into();
+            let (th, tl) = TwoFloat::new_add(self.lo, rhs.lo).into();
+            let c = sl + th;
+            let (vh, vl) = fast_two_sum(sh, c).into();
+            let w = tl + vl;
+            fast_two_sum(vh, w)
+        })
     }
 
     /// Implements subtraction of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4 modified for negative right-hand side.
     fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        fast_two_sum(sh, v)
+        ieee_result(finite(self) && rhs.is_finite(), self.hi - rhs, || {
+            let (sh, sl) = TwoFloat::new_sub(self.hi, *rhs).into();
+            let v = self.lo + sl;
+            fast_two_sum(sh, v)
+        })
     }
 
     /// Implements subtraction of `f64` and `TwoFloat` using Joldes et al.
     /// (2017) Algorithm 4 modified for negative left-hand side.
     fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_sub(*self, rhs.hi).into();
-        let v = sl - rhs.lo;
-        fast_two_sum(sh, v)
+        ieee_result(self.is_finite() && finite(rhs), self - rhs.hi, || {
+            let (sh, sl) = TwoFloat::new_sub(*self, rhs.hi).into();
+            let v = sl - rhs.lo;
+            fast_two_sum(sh, v)
+        })
     }
 
     /// Implements subtraction of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6 modified for a negative right-hand side.
     fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_sub(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        fast_two_sum(vh, w)
+        ieee_result(finite(self) && finite(rhs), self.hi - rhs.hi, || {
+            let (sh, sl) = TwoFloat::new_sub(self.hi, rhs.hi).into();
+            let (th, tl) = TwoFloat::new_sub(self.lo, rhs.lo).into();
+            let c = sl + th;
+            let (vh, vl) = fast_two_sum(sh, c).into();
+            let w = tl + vl;
+            fast_two_sum(vh, w)
+        })
     }
 
     /// Implements multiplication of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 9.
     fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, *rhs).into();
-        let cl3 = fma(self.lo, *rhs, cl1);
-        fast_two_sum(ch, cl3)
+        ieee_result(finite(self) && rhs.is_finite(), self.hi * rhs, || {
+            let (ch, cl1) = TwoFloat::new_mul(self.hi, *rhs).into();
+            let cl3 = fma(self.lo, *rhs, cl1);
+            fast_two_sum(ch, cl3)
+        })
     }
 
     /// Implements multiplication of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 9.
     fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let (ch, cl1) = TwoFloat::new_mul(rhs.hi, *self).into();
-        let cl3 = fma(rhs.lo, *self, cl1);
-        fast_two_sum(ch, cl3)
+        ieee_result(self.is_finite() && finite(rhs), self * rhs.hi, || {
+            let (ch, cl1) = TwoFloat::new_mul(rhs.hi, *self).into();
+            let cl3 = fma(rhs.lo, *self, cl1);
+            fast_two_sum(ch, cl3)
+        })
     }
 
     /// Implements multiplication of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 12.
     fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, rhs.hi).into();
-        let tl0 = self.lo * rhs.lo;
-        let tl1 = fma(self.hi, rhs.lo, tl0);
-        let cl2 = fma(self.lo, rhs.hi, tl1);
-        let cl3 = cl1 + cl2;
-        fast_two_sum(ch, cl3)
+        ieee_result(finite(self) && finite(rhs), self.hi * rhs.hi, || {
+            let (ch, cl1) = TwoFloat::new_mul(self.hi, rhs.hi).into();
+            let tl0 = self.lo * rhs.lo;
+            let tl1 = fma(self.hi, rhs.lo, tl0);
+            let cl2 = fma(self.lo, rhs.hi, tl1);
+            let cl3 = cl1 + cl2;
+            fast_two_sum(ch, cl3)
+        })
     }
 
     /// Implements division of `TwoFloat` and `f64` using Joldes et al. (2017)
     /// Algorithm 15
     fn Div::div<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let th = self.hi / rhs;
-        let (ph, pl) = TwoFloat::new_mul(th, *rhs).into();
-        let dh = self.hi - ph;
-        let dt = dh - pl;
-        let d = dt + self.lo;
-        let tl = d / rhs;
-        fast_two_sum(th, tl)
+        let divisible = finite(self) && rhs.is_finite() && *rhs != 0.0;
+        ieee_result(divisible, self.hi / rhs, || {
+            if let Some((m, n)) = division_scales(self.hi, *rhs) {
+                let quotient = scale_pow2(*self, m) / libm::scalbn(*rhs, n);
+                return scale_pow2(quotient, n - m);
+            }
+
+            let th = self.hi / rhs;
+            let (ph, pl) = TwoFloat::new_mul(th, *rhs).into();
+            let dh = self.hi - ph;
+            let dt = dh - pl;
+            let d = dt + self.lo;
+            let tl = d / rhs;
+            fast_two_sum(th, tl)
+        })
     }
 
-    /// Former implements division from Joldes et al. (2017) Algorithm 18
-    /// Now taken from qd crate using long division
+    /// Implements division of `f64` and `TwoFloat` using Joldes et al.
+    /// (2017) Algorithm 17 modified for the left-hand side having a zero
+    /// value in the low word.
     fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-                let q1 = self / rhs.hi;
-                let mut r = self - (rhs* q1);
-                let q2 = r.hi / rhs.hi;
-                r -=rhs* q2;
-                let q3 = r.hi / rhs.hi;
-                renorm3(q1, q2, q3)
+        let divisible = self.is_finite() && finite(rhs) && rhs.hi != 0.0;
+        ieee_result(divisible, self / rhs.hi, || {
+            if let Some((m, n)) = division_scales(*self, rhs.hi) {
+                let quotient = libm::scalbn(*self, m) / scale_pow2(*rhs, n);
+                return scale_pow2(quotient, n - m);
+            }
+
+            let th = self / rhs.hi;
+            let (rh, rl) = mul_fp1(*rhs, th).into();
+            let ph = self - rh;
+            let d = ph - rl;
+            let tl = d / rhs.hi;
+            fast_two_sum(th, tl)
+        })
     }
 
-    /// Former implements division from Joldes et al. (2017) Algorithm 18
-    /// Now taken from qd crate using long division
+    /// Implements division of two `TwoFloat` values using Joldes et al.
+    /// (2017) Algorithm 17.
     fn Div::div<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-                let q1 = self.hi / rhs.hi;
-                let mut r = self - (rhs* q1);
-                let q2 = r.hi / rhs.hi;
-                r -=rhs* q2;
-                let q3 = r.hi / rhs.hi;
-                renorm3(q1, q2, q3)
+        let divisible = finite(self) && finite(rhs) && rhs.hi != 0.0;
+        ieee_result(divisible, self.hi / rhs.hi, || {
+            if let Some((m, n)) = division_scales(self.hi, rhs.hi) {
+                let quotient = scale_pow2(*self, m) / scale_pow2(*rhs, n);
+                return scale_pow2(quotient, n - m);
+            }
+
+            let th = self.hi / rhs.hi;
+            let (rh, rl) = mul_fp1(*rhs, th).into();
+            let ph = self.hi - rh;
+            let dl = self.lo - rl;
+            let d = ph + dl;
+            let tl = d / rhs.hi;
+            fast_two_sum(th, tl)
+        })
     }
 
     fn Rem::rem<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let quotient = (self / rhs).trunc();
-        self - quotient * rhs
+        remainder(*self, TwoFloat::from(*rhs))
     }
 
     fn Rem::rem<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let quotient = (self / rhs).trunc();
-        self - quotient * rhs
+        remainder(TwoFloat::from(*self), *rhs)
     }
 
     fn Rem::rem<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let quotient = (self / rhs).trunc();
-        self - quotient * rhs
+        remainder(*self, *rhs)
     }
 }
 
@@ -230,91 +487,57 @@ assign_ops! {
     /// Implements addition of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4.
     fn AddAssign::add_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let (sh, sl) = TwoFloat::new_add(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        *self = fast_two_sum(sh, v);
+        *self = *self + rhs;
     }
 
     /// Implements addition of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6.
     fn AddAssign::add_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let (sh, sl) = TwoFloat::new_add(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_add(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        *self = fast_two_sum(vh, w)
+        *self = *self + rhs;
     }
 
     /// Implements subtraction of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4 modified for negative right-hand side.
     fn SubAssign::sub_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        *self = fast_two_sum(sh, v);
+        *self = *self - rhs;
     }
 
     /// Implements subtraction of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6 modified for a negative right-hand side.
     fn SubAssign::sub_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_sub(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        *self = fast_two_sum(vh, w)
+        *self = *self - rhs;
     }
 
     /// Implements multiplication of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 9.
     fn MulAssign::mul_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, *rhs).into();
-        let cl3 = fma(self.lo, *rhs, cl1);
-        *self = fast_two_sum(ch, cl3);
+        *self = *self * rhs;
     }
 
     /// Implements multiplication of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 12.
     fn MulAssign::mul_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, rhs.hi).into();
-        let tl0 = self.lo * rhs.lo;
-        let tl1 = fma(self.hi, rhs.lo, tl0);
-        let cl2 = fma(self.lo, rhs.hi, tl1);
-        let cl3 = cl1 + cl2;
-        *self = fast_two_sum(ch, cl3)
+        *self = *self * rhs;
     }
 
     /// Implements division of `TwoFloat` and `f64` using Joldes et al. (2017)
     /// Algorithm 15
     fn DivAssign::div_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let th = self.hi / rhs;
-        let (ph, pl) = TwoFloat::new_mul(th, *rhs).into();
-        let dh = self.hi - ph;
-        let dt = dh - pl;
-        let d = dt + self.lo;
-        let tl = d / rhs;
-        *self = fast_two_sum(th, tl)
+        *self = *self / rhs;
     }
 
-    /// Former implements division from Joldes et al. (2017) Algorithm 18
-    /// Now taken from qd crate using long division
+    /// Implements division of two `TwoFloat` values using Joldes et al.
+    /// (2017) Algorithm 17.
     fn DivAssign::div_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let q1 = self.hi / rhs.hi;
-        let mut r = *self - (rhs* q1);
-        let q2 = r.hi / rhs.hi;
-        r -=rhs* q2;
-        let q3 = r.hi / rhs.hi;
-        *self = renorm3(q1, q2, q3)
+        *self = *self / rhs;
     }
 
     fn RemAssign::rem_assign<'b>(self: &mut TwoFloat, rhs: &'b f64) {
-        let quotient = (*self / rhs).trunc();
-        *self -= quotient * rhs;
+        *self = *self % rhs;
     }
 
     fn RemAssign::rem_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let quotient = (*self / rhs).trunc();
-        *self -= quotient * rhs;
+        *self = *self % rhs;
     }
 }
 
@@ -335,7 +558,7 @@ impl TwoFloat {
     /// ```
     pub fn div_euclid(self, rhs: Self) -> Self {
         let quotient = (self / rhs).trunc();
-        if (self - quotient * rhs) < 0.0 {
+        if self % rhs < 0.0 {
             if rhs > 0.0 {
                 quotient - 1.0
             } else {
@@ -372,12 +595,552 @@ impl TwoFloat {
             remainder
         }
     }
+
+    /// Computes `(self * a) + b` with a single rounding.
+    ///
+    /// The product is expanded into eight partial products, each of which is
+    /// represented exactly by a pair of `f64` values. These are summed
+    /// exactly together with the words of `b`, and the result is rounded to
+    /// a `TwoFloat` using `renorm3`, so the result is accurate even when the
+    /// sum cancels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_add(1.0, 2f64.powi(-60));
+    /// let b = TwoFloat::new_add(-1.0, -2f64.powi(-59));
+    ///
+    /// assert_eq!(a.mul_add(a, b), 2f64.powi(-120));
+    /// assert_eq!(a * a + b, 0.0);
+    /// ```
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add_with_error(a, b).0
+    }
+
+    /// Computes `(self * a) + b` with a single rounding, together with an
+    /// approximation of the rounding error.
+    pub(crate) fn mul_add_with_error(self, a: Self, b: Self) -> (Self, f64) {
+        let operands_finite = finite(&self) && finite(&a) && finite(&b);
+        let mut error = 0.0;
+        let result = ieee_result(operands_finite, fma(self.hi, a.hi, b.hi), || {
+            let p00 = Self::new_mul(self.hi, a.hi);
+            let p01 = Self::new_mul(self.hi, a.lo);
+            let p10 = Self::new_mul(self.lo, a.hi);
+            let p11 = Self::new_mul(self.lo, a.lo);
+            let terms = [
+                p00.hi, p00.lo, p01.hi, p01.lo, p10.hi, p10.lo, p11.hi, p11.lo, b.hi, b.lo,
+            ];
+            let (result, result_error) = expansion_sum(&terms);
+            error = result_error;
+            result
+        });
+
+        if result.hi.is_finite() {
+            (result, error)
+        } else {
+            (result, 0.0)
+        }
+    }
+}
+
+/// Converts a bound on the relative error of a value to a bound on its
+/// absolute error, including the error of results whose low word is
+/// subnormal.
+pub(crate) fn absolute_bound(value: TwoFloat, bound: f64) -> f64 {
+    if value.hi.is_finite() && value.lo.is_finite() {
+        libm::fabs(value.hi) * bound * BOUND_SCALE + UNDERFLOW_ERROR
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Moves a value rounded to nearest with a relative error of at most `bound`
+/// so that it is no greater (or no less if `up` is set) than the exact
+/// result. The adjusted low word is renormalized exactly using
+/// `fast_two_sum`.
+pub(crate) fn widen(value: TwoFloat, bound: f64, up: bool) -> TwoFloat {
+    let margin = absolute_bound(value, bound) + libm::fabs(value.hi) * WIDENING_SLACK;
+    let lo = if up {
+        value.lo + margin
+    } else {
+        value.lo - margin
+    };
+    fast_two_sum(value.hi, lo)
+}
+
+// Bounds a result which overflows using an estimate computed from the high
+// words of the operands
+fn overflow_bound(estimate: f64, up: bool) -> TwoFloat {
+    let limit = if estimate.is_finite() {
+        estimate
+    } else {
+        libm::copysign(f64::MAX, estimate)
+    };
+
+    let margin = libm::fabs(limit) * ESTIMATE_ERROR;
+    let bound = match (up, estimate) {
+        (true, f64::INFINITY) | (false, f64::NEG_INFINITY) => estimate,
+        (true, _) => limit + margin,
+        (false, _) => limit - margin,
+    };
+
+    special_value(bound)
+}
+
+// Rounds the result of an operation in the given direction. Where the
+// operands are not finite the result is calculated from the high words.
+fn round_directed(
+    nearest: TwoFloat,
+    estimate: f64,
+    finite: bool,
+    bound: f64,
+    up: bool,
+) -> TwoFloat {
+    if !finite || estimate.is_nan() {
+        special_value(estimate)
+    } else if nearest.hi.is_finite() && nearest.lo.is_finite() {
+        widen(nearest, bound, up)
+    } else {
+        overflow_bound(estimate, up)
+    }
+}
+
+// Widens the square root rounded to nearest, then checks the result `r`
+// against the quotient `value / r` rounded in the opposite direction. As the
+// directed division is proved, `value / r >= r` shows that `r` is no greater
+// than the exact square root, and `value / r <= r` that it is no less. The
+// margin is doubled until the check succeeds, up to a limited number of
+// times after which the result is bounded using the high word alone.
+fn sqrt_directed(value: TwoFloat, up: bool) -> TwoFloat {
+    if value.hi == f64::INFINITY {
+        TwoFloat::INFINITY
+    } else if value.hi.is_nan() || value.hi <= 0.0 {
+        value.sqrt()
+    } else {
+        let root = value.sqrt();
+        let mut bound = SQRT_ERROR_BOUND;
+        for _ in 0..SQRT_WIDENING_STEPS {
+            let result = widen(root, bound, up);
+            let verified = if up {
+                value.div_up(result) <= result
+            } else {
+                value.div_down(result) >= result
+            };
+
+            if verified {
+                return result;
+            }
+            bound *= 2.0;
+        }
+
+        overflow_bound(libm::sqrt(value.hi), up)
+    }
+}
+
+/// Rounding direction of the arithmetic operations with an explicit
+/// rounding mode, such as [`TwoFloat::add_rounded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Round {
+    /// Round to nearest, giving the same result as the arithmetic operators.
+    Nearest,
+    /// Round towards negative infinity, giving a result which is less than or
+    /// equal to the exact value.
+    Down,
+    /// Round towards positive infinity, giving a result which is greater
+    /// than or equal to the exact value.
+    Up,
+}
+
+impl TwoFloat {
+    /// Bound on the relative error of the addition or subtraction of a
+    /// `TwoFloat` and an `f64`, 2u²/(1 - 2u) where u = 2<sup>-53</sup>, as
+    /// proved for Algorithm 4 in Joldes et al. (2017). The value is rounded
+    /// up.
+    pub const ERROR_BOUND_ADD_F64: f64 = hexf64!("0x1.0000000000002p-105");
+
+    /// Bound on the relative error of the addition or subtraction of two
+    /// `TwoFloat` values, 3u²/(1 - 4u) where u = 2<sup>-53</sup>, as proved
+    /// for Algorithm 6 in Joldes et al. (2017). The value is rounded up.
+    pub const ERROR_BOUND_ADD: f64 = hexf64!("0x1.8000000000004p-105");
+
+    /// Bound on the relative error of the multiplication of a `TwoFloat` and
+    /// an `f64`, 2u² where u = 2<sup>-53</sup>, as proved for Algorithm 9 in
+    /// Joldes et al. (2017).
+    pub const ERROR_BOUND_MUL_F64: f64 = hexf64!("0x1p-105");
+
+    /// Bound on the relative error of the multiplication of two `TwoFloat`
+    /// values, 5u² where u = 2<sup>-53</sup>, as proved for Algorithm 12 in
+    /// Joldes et al. (2017).
+    pub const ERROR_BOUND_MUL: f64 = hexf64!("0x1.4p-104");
+
+    /// Bound on the relative error of the division of a `TwoFloat` by an
+    /// `f64` and of `new_div`, 3u² where u = 2<sup>-53</sup>, as proved for
+    /// Algorithm 15 in Joldes et al. (2017).
+    pub const ERROR_BOUND_DIV_F64: f64 = hexf64!("0x1.8p-105");
+
+    /// Bound on the relative error of the division of an `f64` or `TwoFloat`
+    /// by a `TwoFloat`, 15u² + 56u³ where u = 2<sup>-53</sup>, as proved for
+    /// Algorithm 17 in Joldes et al. (2017). The value is rounded up.
+    pub const ERROR_BOUND_DIV: f64 = hexf64!("0x1.e000000000004p-103");
+
+    /// Adds two values, returning the result together with a bound on its
+    /// absolute error.
+    ///
+    /// The bound is derived from [`ERROR_BOUND_ADD`], with an allowance for
+    /// results whose low word is subnormal. If the result is not finite the
+    /// bound is infinite.
+    ///
+    /// [`ERROR_BOUND_ADD`]: TwoFloat::ERROR_BOUND_ADD
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_div(1.0, 3.0);
+    /// let b = TwoFloat::new_div(2.0, 3.0);
+    /// let (sum, bound) = a.add_with_bound(b);
+    ///
+    /// assert!((sum - 1.0).abs() <= bound);
+    /// assert!(bound < 1e-31);
+    /// ```
+    pub fn add_with_bound(self, rhs: Self) -> (Self, f64) {
+        let result = self + rhs;
+        (result, absolute_bound(result, Self::ERROR_BOUND_ADD))
+    }
+
+    /// Subtracts two values, returning the result together with a bound on
+    /// its absolute error. See [`add_with_bound`] for details.
+    ///
+    /// [`add_with_bound`]: TwoFloat::add_with_bound
+    pub fn sub_with_bound(self, rhs: Self) -> (Self, f64) {
+        let result = self - rhs;
+        (result, absolute_bound(result, Self::ERROR_BOUND_ADD))
+    }
+
+    /// Multiplies two values, returning the result together with a bound on
+    /// its absolute error derived from [`ERROR_BOUND_MUL`].
+    ///
+    /// [`ERROR_BOUND_MUL`]: TwoFloat::ERROR_BOUND_MUL
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_div(1.0, 3.0);
+    /// let (product, bound) = a.mul_with_bound(TwoFloat::from(3.0));
+    ///
+    /// assert!((product - 1.0).abs() <= bound);
+    /// ```
+    pub fn mul_with_bound(self, rhs: Self) -> (Self, f64) {
+        let result = self * rhs;
+        (result, absolute_bound(result, Self::ERROR_BOUND_MUL))
+    }
+
+    /// Divides two values, returning the result together with a bound on its
+    /// absolute error derived from [`ERROR_BOUND_DIV`].
+    ///
+    /// [`ERROR_BOUND_DIV`]: TwoFloat::ERROR_BOUND_DIV
+    pub fn div_with_bound(self, rhs: Self) -> (Self, f64) {
+        let result = self / rhs;
+        (result, absolute_bound(result, Self::ERROR_BOUND_DIV))
+    }
+
+    /// Adds two values, returning a result which is less than or equal to
+    /// the exact sum.
+    ///
+    /// The result is obtained by widening the sum rounded to nearest by
+    /// [`ERROR_BOUND_ADD`].
+    ///
+    /// [`ERROR_BOUND_ADD`]: TwoFloat::ERROR_BOUND_ADD
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0);
+    /// let b = TwoFloat::from(1e-40);
+    ///
+    /// assert!(a.add_down(b) <= a + b);
+    /// assert!(a.add_down(b) < a.add_up(b));
+    /// ```
+    pub fn add_down(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite();
+        round_directed(
+            self + rhs,
+            self.hi + rhs.hi,
+            finite,
+            Self::ERROR_BOUND_ADD,
+            false,
+        )
+    }
+
+    /// Adds two values, returning a result which is greater than or equal to
+    /// the exact sum. See [`add_down`] for details of the bound.
+    ///
+    /// [`add_down`]: TwoFloat::add_down
+    pub fn add_up(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite();
+        round_directed(
+            self + rhs,
+            self.hi + rhs.hi,
+            finite,
+            Self::ERROR_BOUND_ADD,
+            true,
+        )
+    }
+
+    /// Subtracts two values, returning a result which is less than or equal
+    /// to the exact difference. The error bound is the same as for
+    /// [`add_down`].
+    ///
+    /// [`add_down`]: TwoFloat::add_down
+    pub fn sub_down(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite();
+        round_directed(
+            self - rhs,
+            self.hi - rhs.hi,
+            finite,
+            Self::ERROR_BOUND_ADD,
+            false,
+        )
+    }
+
+    /// Subtracts two values, returning a result which is greater than or
+    /// equal to the exact difference. The error bound is the same as for
+    /// [`add_down`].
+    ///
+    /// [`add_down`]: TwoFloat::add_down
+    pub fn sub_up(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite();
+        round_directed(
+            self - rhs,
+            self.hi - rhs.hi,
+            finite,
+            Self::ERROR_BOUND_ADD,
+            true,
+        )
+    }
+
+    /// Multiplies two values, returning a result which is less than or equal
+    /// to the exact product.
+    ///
+    /// The result is obtained by widening the product rounded to nearest by
+    /// [`ERROR_BOUND_MUL`].
+    ///
+    /// [`ERROR_BOUND_MUL`]: TwoFloat::ERROR_BOUND_MUL
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_div(1.0, 3.0);
+    /// let b = TwoFloat::from(3.0);
+    ///
+    /// assert!(a.mul_down(b) < 1.0);
+    /// assert!(a.mul_up(b) > a.mul_down(b));
+    /// ```
+    pub fn mul_down(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite();
+        round_directed(
+            self * rhs,
+            self.hi * rhs.hi,
+            finite,
+            Self::ERROR_BOUND_MUL,
+            false,
+        )
+    }
+
+    /// Multiplies two values, returning a result which is greater than or
+    /// equal to the exact product. See [`mul_down`] for details of the bound.
+    ///
+    /// [`mul_down`]: TwoFloat::mul_down
+    pub fn mul_up(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite();
+        round_directed(
+            self * rhs,
+            self.hi * rhs.hi,
+            finite,
+            Self::ERROR_BOUND_MUL,
+            true,
+        )
+    }
+
+    /// Divides two values, returning a result which is less than or equal to
+    /// the exact quotient.
+    ///
+    /// The result is obtained by widening the quotient rounded to nearest by
+    /// [`ERROR_BOUND_DIV`]. Division by zero returns an infinite or NaN
+    /// value as for `f64`.
+    ///
+    /// [`ERROR_BOUND_DIV`]: TwoFloat::ERROR_BOUND_DIV
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0);
+    /// let b = TwoFloat::from(3.0);
+    ///
+    /// assert!(a.div_down(b) * 3.0 <= 1.0);
+    /// assert!(a.div_up(b) * 3.0 >= 1.0);
+    /// ```
+    pub fn div_down(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite() && rhs.hi != 0.0;
+        round_directed(
+            self / rhs,
+            self.hi / rhs.hi,
+            finite,
+            Self::ERROR_BOUND_DIV,
+            false,
+        )
+    }
+
+    /// Divides two values, returning a result which is greater than or equal
+    /// to the exact quotient. See [`div_down`] for details of the bound.
+    ///
+    /// [`div_down`]: TwoFloat::div_down
+    pub fn div_up(self, rhs: Self) -> Self {
+        let finite = self.hi.is_finite() && rhs.hi.is_finite() && rhs.hi != 0.0;
+        round_directed(
+            self / rhs,
+            self.hi / rhs.hi,
+            finite,
+            Self::ERROR_BOUND_DIV,
+            true,
+        )
+    }
+
+    /// Returns the square root of the number, rounded to a result which is
+    /// less than or equal to the exact value.
+    ///
+    /// The square root rounded to nearest is widened by the error bound
+    /// given in the documentation of [`sqrt`], which has not been proved.
+    /// The result `r` is therefore checked by comparing it with the quotient
+    /// of the value by `r` computed using [`div_down`], and the margin is
+    /// increased until the quotient is at least `r`, which shows that `r`
+    /// is no greater than the exact square root.
+    ///
+    /// [`sqrt`]: TwoFloat::sqrt
+    /// [`div_down`]: TwoFloat::div_down
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.0);
+    ///
+    /// assert!(a.sqrt_down() * a.sqrt_down() < 2.0);
+    /// assert!(a.sqrt_up() * a.sqrt_up() > 2.0);
+    /// ```
+    pub fn sqrt_down(self) -> Self {
+        sqrt_directed(self, false)
+    }
+
+    /// Returns the square root of the number, rounded to a result which is
+    /// greater than or equal to the exact value. See [`sqrt_down`] for
+    /// details of the bound, which is checked here using [`div_up`].
+    ///
+    /// [`sqrt_down`]: TwoFloat::sqrt_down
+    /// [`div_up`]: TwoFloat::div_up
+    pub fn sqrt_up(self) -> Self {
+        sqrt_directed(self, true)
+    }
+
+    /// Adds two values using the given rounding direction.
+    ///
+    /// With [`Round::Down`] or [`Round::Up`] this is equivalent to
+    /// [`add_down`] or [`add_up`], and with [`Round::Nearest`] to the `+`
+    /// operator.
+    ///
+    /// [`add_down`]: TwoFloat::add_down
+    /// [`add_up`]: TwoFloat::add_up
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Round, TwoFloat};
+    /// let a = TwoFloat::from(1.0);
+    /// let b = TwoFloat::new_div(1.0, 3.0);
+    ///
+    /// let down = TwoFloat::add_rounded(a, b, Round::Down);
+    /// let up = TwoFloat::add_rounded(a, b, Round::Up);
+    /// assert!(down < a + b && a + b < up);
+    /// assert_eq!(a.add_rounded(b, Round::Nearest), a + b);
+    /// ```
+    pub fn add_rounded(self, rhs: Self, round: Round) -> Self {
+        match round {
+            Round::Nearest => self + rhs,
+            Round::Down => self.add_down(rhs),
+            Round::Up => self.add_up(rhs),
+        }
+    }
+
+    /// Subtracts two values using the given rounding direction. See
+    /// [`add_rounded`] for details.
+    ///
+    /// [`add_rounded`]: TwoFloat::add_rounded
+    pub fn sub_rounded(self, rhs: Self, round: Round) -> Self {
+        match round {
+            Round::Nearest => self - rhs,
+            Round::Down => self.sub_down(rhs),
+            Round::Up => self.sub_up(rhs),
+        }
+    }
+
+    /// Multiplies two values using the given rounding direction. See
+    /// [`add_rounded`] for details.
+    ///
+    /// [`add_rounded`]: TwoFloat::add_rounded
+    pub fn mul_rounded(self, rhs: Self, round: Round) -> Self {
+        match round {
+            Round::Nearest => self * rhs,
+            Round::Down => self.mul_down(rhs),
+            Round::Up => self.mul_up(rhs),
+        }
+    }
+
+    /// Divides two values using the given rounding direction. See
+    /// [`add_rounded`] for details.
+    ///
+    /// [`add_rounded`]: TwoFloat::add_rounded
+    pub fn div_rounded(self, rhs: Self, round: Round) -> Self {
+        match round {
+            Round::Nearest => self / rhs,
+            Round::Down => self.div_down(rhs),
+            Round::Up => self.div_up(rhs),
+        }
+    }
+
+    /// Returns the square root of the number using the given rounding
+    /// direction, equivalent to [`sqrt`], [`sqrt_down`] or [`sqrt_up`].
+    ///
+    /// [`sqrt`]: TwoFloat::sqrt
+    /// [`sqrt_down`]: TwoFloat::sqrt_down
+    /// [`sqrt_up`]: TwoFloat::sqrt_up
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Round, TwoFloat};
+    /// let a = TwoFloat::from(3.0);
+    ///
+    /// assert!(a.sqrt_rounded(Round::Down) <= a.sqrt_rounded(Round::Up));
+    /// ```
+    pub fn sqrt_rounded(self, round: Round) -> Self {
+        match round {
+            Round::Nearest => self.sqrt(),
+            Round::Down => self.sqrt_down(),
+            Round::Up => self.sqrt_up(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::fast_two_sum;
+    use super::{fast_two_sum, widen};
     use crate::test_util::{get_valid_pair, repeated_test};
+    use crate::TwoFloat;
 
     #[test]
     fn fast_two_sum_test() {
@@ -405,4 +1168,17 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn widen_test() {
+        let value = TwoFloat::new_div(1.0, 3.0);
+        let down = widen(value, 0.0, false);
+        let up = widen(value, 0.0, true);
+        assert!(down < value && value < up);
+        assert!(down.is_valid() && up.is_valid());
+
+        assert!(widen(TwoFloat::from(0.0), 0.0, false) < 0.0);
+        assert!(widen(TwoFloat::from(0.0), 0.0, true) > 0.0);
+        assert!(widen(TwoFloat::MAX, 0.0, true).hi().is_infinite());
+    }
 }
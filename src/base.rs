@@ -9,7 +9,7 @@ const DEG_PER_RAD: TwoFloat = TwoFloat {
     lo: hexf64!("-0x1.1e7ab456405f9p-49"),
 };
 
-const RAD_PER_DEG: TwoFloat = TwoFloat {
+pub(crate) const RAD_PER_DEG: TwoFloat = TwoFloat {
     hi: hexf64!("0x1.1df46a2529d39p-6"),
     lo: hexf64!("0x1.5c1d8becdd291p-62"),
 };
@@ -94,19 +94,20 @@ impl TwoFloat {
         lo: 0.0,
     };
 
-    /// A positive infinite value
+    /// A positive infinite value, which has a zero low word.
     pub const INFINITY: Self = Self {
         hi: f64::INFINITY,
-        lo: f64::INFINITY,
+        lo: 0.0,
     };
 
-    /// A negative infinite value
+    /// A negative infinite value, which has a zero low word.
     pub const NEG_INFINITY: Self = Self {
         hi: f64::NEG_INFINITY,
-        lo: f64::NEG_INFINITY,
+        lo: 0.0,
     };
 
-    /// Creates a new TwoFloat from a constant `f64` value.
+    /// Creates a new TwoFloat from a constant `f64` value. A `NAN` value
+    /// gives `TwoFloat::NAN`.
     ///
     /// # Examples
     ///
@@ -116,7 +117,20 @@ impl TwoFloat {
     /// assert_eq!(value.hi(), 1.0);
     /// ```
     pub const fn from_f64(value: f64) -> Self {
-        TwoFloat { hi: value, lo: 0.0 }
+        if value.is_nan() {
+            Self::NAN
+        } else {
+            TwoFloat { hi: value, lo: 0.0 }
+        }
+    }
+
+    // Used by the `tf!` macro, which computes the words at compile time.
+    #[doc(hidden)]
+    pub const fn __from_bits(hi: u64, lo: u64) -> Self {
+        TwoFloat {
+            hi: f64::from_bits(hi),
+            lo: f64::from_bits(lo),
+        }
     }
 
     /// Returns the high word of `self`.
@@ -175,9 +189,9 @@ impl TwoFloat {
     /// assert_eq!(a.min(b), b);
     /// ```
     pub fn min(self, other: Self) -> Self {
-        if !self.is_valid() {
+        if self.hi.is_nan() {
             other
-        } else if !other.is_valid() || self <= other {
+        } else if other.hi.is_nan() || self <= other {
             self
         } else {
             other
@@ -197,15 +211,62 @@ impl TwoFloat {
     /// assert_eq!(a.max(b), a);
     /// ```
     pub fn max(self, other: Self) -> Self {
-        if !self.is_valid() {
+        if self.hi.is_nan() {
             other
-        } else if !other.is_valid() || self >= other {
+        } else if other.hi.is_nan() || self >= other {
             self
         } else {
             other
         }
     }
 
+    /// Returns the ordering between `self` and `other`, matching the
+    /// semantics of `f64::total_cmp`.
+    ///
+    /// The values are first put into canonical form, in which the words do
+    /// not overlap, a zero low word is positive and infinities and `NAN`
+    /// values have a zero low word. The high and low words are then compared
+    /// in turn using `f64::total_cmp`, so that negative zero is less than
+    /// positive zero and `NAN` values are ordered by their sign and payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_add(1.0, 1e-20);
+    /// let b = TwoFloat::from(-0.0);
+    ///
+    /// assert_eq!(a.total_cmp(&TwoFloat::from(1.0)), Ordering::Greater);
+    /// assert_eq!(b.total_cmp(&TwoFloat::from(0.0)), Ordering::Less);
+    /// assert_eq!(TwoFloat::NAN.total_cmp(&TwoFloat::INFINITY), Ordering::Greater);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        let (a_hi, a_lo) = self.canonical_words();
+        let (b_hi, b_lo) = other.canonical_words();
+        a_hi.total_cmp(&b_hi).then_with(|| a_lo.total_cmp(&b_lo))
+    }
+
+    // Returns the words of the canonical representation of the value. A
+    // `NAN` in either word gives a `NAN` high word. Words which are not
+    // normalized, including a zero high word with a nonzero low word, are
+    // summed exactly.
+    pub(crate) fn canonical_words(&self) -> (f64, f64) {
+        if self.hi.is_nan() {
+            (self.hi, 0.0)
+        } else if self.lo.is_nan() {
+            (self.lo, 0.0)
+        } else if self.hi.is_infinite() || self.lo == 0.0 {
+            (self.hi, 0.0)
+        } else if no_overlap(self.hi, self.lo) {
+            (self.hi, self.lo)
+        } else {
+            // Adding zero makes a zero low word positive
+            let value = Self::new_add(self.hi, self.lo);
+            (value.hi, value.lo + 0.0)
+        }
+    }
+
     /// Converts degrees to radians.
     ///
     /// # Examples
@@ -251,7 +312,8 @@ impl TwoFloat {
         1.0 / self
     }
 
-    /// Raises the number to an integer power. Returns a NAN value for 0^0.
+    /// Raises the number to an integer power. As for `f64`, any value
+    /// raised to the power of zero is one, including zero and `NAN`.
     ///
     /// # Examples
     ///
@@ -261,17 +323,11 @@ impl TwoFloat {
     /// let b = TwoFloat::from(0.0).powi(0);
     ///
     /// assert!(a - TwoFloat::from(8.0) <= 1e-16);
-    /// assert!(!b.is_valid());
+    /// assert_eq!(b, 1.0);
     /// ```
     pub fn powi(self, n: i32) -> Self {
         match n {
-            0 => {
-                if self.hi == 0.0 && self.lo == 0.0 {
-                    Self::NAN
-                } else {
-                    Self::from(1.0)
-                }
-            }
+            0 => Self::from(1.0),
             1 => self,
             -1 => self.recip(),
             _ => {
@@ -295,82 +351,55 @@ impl TwoFloat {
     }
 }
 
+// Infinite values are equal regardless of their low words, so that values
+// which are not in canonical form still compare as IEEE 754 infinities.
+
 impl PartialEq<f64> for TwoFloat {
     fn eq(&self, other: &f64) -> bool {
-        self.hi.eq(other) && self.lo == 0.0
+        self.hi.eq(other) && (self.lo == 0.0 || self.hi.is_infinite())
     }
 }
 
 impl PartialEq<TwoFloat> for f64 {
     fn eq(&self, other: &TwoFloat) -> bool {
-        self.eq(&other.hi) && other.lo == 0.0
+        other.eq(self)
     }
 }
 
 impl PartialEq<TwoFloat> for TwoFloat {
     fn eq(&self, other: &TwoFloat) -> bool {
-        if self.is_valid() != other.is_valid()
-            || self.hi.is_nan()
-            || self.lo.is_nan()
-            || other.hi.is_nan()
-            || self.lo.is_nan()
-        {
-            false
-        } else if self.is_valid() {
-            self.hi == other.hi && self.lo == other.lo
-        } else {
-            // all infinities compare equal
-            true
-        }
+        self.hi == other.hi && (self.lo == other.lo || self.hi.is_infinite())
     }
 }
 
 impl PartialOrd<f64> for TwoFloat {
     fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
-        let hi_cmp = self.hi.partial_cmp(other);
-        if hi_cmp == Some(Ordering::Equal) {
-            self.lo.partial_cmp(&0.0)
-        } else {
-            hi_cmp
+        match self.hi.partial_cmp(other) {
+            Some(Ordering::Equal) if self.hi.is_finite() => self.lo.partial_cmp(&0.0),
+            hi_cmp => hi_cmp,
         }
     }
 }
 
 impl PartialOrd<TwoFloat> for f64 {
     fn partial_cmp(&self, other: &TwoFloat) -> Option<Ordering> {
-        let hi_cmp = self.partial_cmp(&other.hi);
-        if hi_cmp == Some(Ordering::Equal) {
-            0.0.partial_cmp(&other.lo)
-        } else {
-            hi_cmp
-        }
+        other.partial_cmp(self).map(Ordering::reverse)
     }
 }
 
 impl PartialOrd<TwoFloat> for TwoFloat {
     fn partial_cmp(&self, other: &TwoFloat) -> Option<Ordering> {
-        if self.hi.is_nan() || self.lo.is_nan() || other.hi.is_nan() || other.lo.is_nan() {
-            return None;
-        }
-
-        match (self.is_valid(), other.is_valid()) {
-            (true, true) => {
-                let hi_cmp = self.hi.partial_cmp(&other.hi);
-                if matches!(hi_cmp, Some(Ordering::Equal)) {
-                    self.lo.partial_cmp(&other.lo)
-                } else {
-                    hi_cmp
-                }
-            }
-            (true, false) => Some(Ordering::Less),
-            (false, true) => Some(Ordering::Greater),
-            (false, false) => Some(Ordering::Equal),
+        match self.hi.partial_cmp(&other.hi) {
+            Some(Ordering::Equal) if self.hi.is_finite() => self.lo.partial_cmp(&other.lo),
+            hi_cmp => hi_cmp,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use core::cmp::Ordering;
+
     use hexf::hexf64;
 
     use super::{no_overlap, TwoFloat};
@@ -429,6 +458,33 @@ mod tests {
         assert!(no_overlap(0.0, 0.0));
     }
 
+    #[test]
+    fn canonical_words_test() {
+        let cases = [
+            ((0.0, 1.5), (1.5, 0.0)),
+            ((-0.0, -1e-300), (-1e-300, 0.0)),
+            ((1.0, 1.0), (2.0, 0.0)),
+            ((hexf64!("0x1p-60"), 1.0), (1.0, hexf64!("0x1p-60"))),
+            ((1.0, f64::INFINITY), (f64::INFINITY, 0.0)),
+            ((-0.0, 0.0), (-0.0, 0.0)),
+        ];
+
+        for &((hi, lo), expected) in cases.iter() {
+            let (a, b) = TwoFloat { hi, lo }.canonical_words();
+            assert_eq!(
+                (a.to_bits(), b.to_bits()),
+                (expected.0.to_bits(), expected.1.to_bits()),
+                "canonical_words({:?}, {:?})",
+                hi,
+                lo
+            );
+        }
+
+        let value = TwoFloat { hi: 0.0, lo: 1.5 };
+        assert_eq!(value.total_cmp(&TwoFloat::from(1.5)), Ordering::Equal);
+        assert_eq!(value.total_cmp(&TwoFloat::from(0.0)), Ordering::Greater);
+    }
+
     #[test]
     fn default_test() {
         let value: TwoFloat = Default::default();
@@ -570,4 +626,33 @@ mod tests {
             mid_values.iter().for_each(|&b| assert!(a > b));
         });
     }
+
+    #[test]
+    fn total_cmp_test() {
+        let overlapping = TwoFloat {
+            hi: ONE,
+            lo: UPPER_MID_DIFF_NEXT,
+        };
+        let canonical = TwoFloat::new_add(ONE, UPPER_MID_DIFF_NEXT);
+        assert_eq!(overlapping.total_cmp(&canonical), Ordering::Equal);
+
+        let infinity = TwoFloat {
+            hi: f64::INFINITY,
+            lo: 1.0,
+        };
+        assert_eq!(infinity.total_cmp(&TwoFloat::INFINITY), Ordering::Equal);
+
+        let negative_lo = TwoFloat { hi: ONE, lo: -0.0 };
+        assert_eq!(negative_lo.total_cmp(&TwoFloat::from(ONE)), Ordering::Equal);
+
+        let nan_lo = TwoFloat {
+            hi: ONE,
+            lo: f64::NAN,
+        };
+        assert_eq!(nan_lo.total_cmp(&TwoFloat::NAN), Ordering::Equal);
+        assert_eq!(
+            TwoFloat::from(-0.0).total_cmp(&TwoFloat::from(0.0)),
+            Ordering::Less
+        );
+    }
 }
@@ -0,0 +1,253 @@
+use crate::{TwoFloat, TwoFloatError};
+
+// Classifies the result of an operation following the IEEE 754 exceptions.
+// An infinite result from finite operands is a division by zero if the
+// operation has a pole at the operands, and an overflow otherwise.
+pub(crate) fn check(
+    operands: &[TwoFloat],
+    result: TwoFloat,
+    pole: bool,
+) -> Result<TwoFloat, TwoFloatError> {
+    if operands.iter().any(|x| x.hi.is_nan()) {
+        Err(TwoFloatError::NaNInput)
+    } else if result.hi.is_nan() {
+        Err(TwoFloatError::DomainError)
+    } else if result.hi.is_infinite() && operands.iter().all(|x| x.hi.is_finite()) {
+        if pole {
+            Err(TwoFloatError::DivisionByZero)
+        } else {
+            Err(TwoFloatError::Overflow)
+        }
+    } else {
+        Ok(result)
+    }
+}
+
+impl TwoFloat {
+    /// Adds two values, returning an error if the result is not valid.
+    ///
+    /// The checked operations return:
+    ///
+    /// * [`NaNInput`] if any of the operands is `NAN`,
+    /// * [`DomainError`] if the result is `NAN`, for example the square root
+    ///   of a negative number or the difference of two equal infinities,
+    /// * [`DivisionByZero`] if a finite value is divided by zero, or a
+    ///   function with a pole such as `ln` is evaluated at zero,
+    /// * [`Overflow`] if the result is infinite but the operands are finite.
+    ///
+    /// Infinite operands are accepted, and give the same result as the
+    /// unchecked operation.
+    ///
+    /// [`NaNInput`]: TwoFloatError::NaNInput
+    /// [`DomainError`]: TwoFloatError::DomainError
+    /// [`DivisionByZero`]: TwoFloatError::DivisionByZero
+    /// [`Overflow`]: TwoFloatError::Overflow
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1e300);
+    ///
+    /// assert_eq!(a.checked_add(a), Ok(a + a));
+    /// assert_eq!(TwoFloat::MAX.checked_add(a), Err(TwoFloatError::Overflow));
+    /// assert_eq!(
+    ///     TwoFloat::NAN.checked_add(a),
+    ///     Err(TwoFloatError::NaNInput)
+    /// );
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check(&[self, rhs], self + rhs, false)
+    }
+
+    /// Subtracts two values, returning an error if the result is not valid.
+    /// See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check(&[self, rhs], self - rhs, false)
+    }
+
+    /// Multiplies two values, returning an error if the result is not
+    /// valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1e300);
+    ///
+    /// assert_eq!(a.checked_mul(a), Err(TwoFloatError::Overflow));
+    /// assert_eq!(
+    ///     TwoFloat::INFINITY.checked_mul(TwoFloat::from(0.0)),
+    ///     Err(TwoFloatError::DomainError)
+    /// );
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check(&[self, rhs], self * rhs, false)
+    }
+
+    /// Divides two values, returning an error if the result is not valid.
+    /// See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let zero = TwoFloat::from(0.0);
+    ///
+    /// assert_eq!(
+    ///     TwoFloat::from(1.0).checked_div(zero),
+    ///     Err(TwoFloatError::DivisionByZero)
+    /// );
+    /// assert_eq!(zero.checked_div(zero), Err(TwoFloatError::DomainError));
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check(&[self, rhs], self / rhs, rhs.hi == 0.0)
+    }
+
+    /// Returns the remainder of the division of two values, or an error if
+    /// the result is not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check(&[self, rhs], self % rhs, false)
+    }
+
+    /// Returns the square root of the value, or an error if the result is
+    /// not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// assert_eq!(TwoFloat::from(4.0).checked_sqrt(), Ok(TwoFloat::from(2.0)));
+    /// assert_eq!(
+    ///     TwoFloat::from(-4.0).checked_sqrt(),
+    ///     Err(TwoFloatError::DomainError)
+    /// );
+    /// ```
+    pub fn checked_sqrt(self) -> Result<Self, TwoFloatError> {
+        check(&[self], self.sqrt(), false)
+    }
+
+    /// Returns the exponential of the value, or an error if the result is
+    /// not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    pub fn checked_exp(self) -> Result<Self, TwoFloatError> {
+        check(&[self], self.exp(), false)
+    }
+
+    /// Returns 2 raised to the power of the value, or an error if the result
+    /// is not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    pub fn checked_exp2(self) -> Result<Self, TwoFloatError> {
+        check(&[self], self.exp2(), false)
+    }
+
+    /// Returns the natural logarithm of the value, or an error if the result
+    /// is not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// assert_eq!(
+    ///     TwoFloat::from(0.0).checked_ln(),
+    ///     Err(TwoFloatError::DivisionByZero)
+    /// );
+    /// assert_eq!(
+    ///     TwoFloat::from(-1.0).checked_ln(),
+    ///     Err(TwoFloatError::DomainError)
+    /// );
+    /// ```
+    pub fn checked_ln(self) -> Result<Self, TwoFloatError> {
+        check(&[self], self.ln(), true)
+    }
+
+    /// Returns the base 2 logarithm of the value, or an error if the result
+    /// is not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    pub fn checked_log2(self) -> Result<Self, TwoFloatError> {
+        check(&[self], self.log2(), true)
+    }
+
+    /// Returns the base 10 logarithm of the value, or an error if the result
+    /// is not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    pub fn checked_log10(self) -> Result<Self, TwoFloatError> {
+        check(&[self], self.log10(), true)
+    }
+
+    /// Raises the value to an integer power, returning an error if the
+    /// result is not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    pub fn checked_powi(self, n: i32) -> Result<Self, TwoFloatError> {
+        check(&[self], self.powi(n), self.hi == 0.0)
+    }
+
+    /// Raises the value to a `TwoFloat` power, returning an error if the
+    /// result is not valid. See [`checked_add`] for details.
+    ///
+    /// [`checked_add`]: TwoFloat::checked_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(-8.0);
+    ///
+    /// assert_eq!(
+    ///     a.checked_powf(TwoFloat::new_div(1.0, 3.0)),
+    ///     Err(TwoFloatError::DomainError)
+    /// );
+    /// ```
+    pub fn checked_powf(self, y: Self) -> Result<Self, TwoFloatError> {
+        check(&[self, y], self.powf(y), self.hi == 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check;
+    use crate::{TwoFloat, TwoFloatError};
+
+    #[test]
+    fn check_test() {
+        let one = TwoFloat::from(1.0);
+        assert_eq!(check(&[one], one, true), Ok(one));
+        assert_eq!(
+            check(&[one, TwoFloat::NAN], TwoFloat::NAN, false),
+            Err(TwoFloatError::NaNInput)
+        );
+        assert_eq!(
+            check(&[one], TwoFloat::NAN, true),
+            Err(TwoFloatError::DomainError)
+        );
+        assert_eq!(
+            check(&[one], TwoFloat::INFINITY, true),
+            Err(TwoFloatError::DivisionByZero)
+        );
+        assert_eq!(
+            check(&[one], TwoFloat::NEG_INFINITY, false),
+            Err(TwoFloatError::Overflow)
+        );
+        assert_eq!(
+            check(&[TwoFloat::INFINITY, one], TwoFloat::INFINITY, false),
+            Ok(TwoFloat::INFINITY)
+        );
+    }
+}
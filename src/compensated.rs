@@ -0,0 +1,210 @@
+use crate::TwoFloat;
+
+// Number of partial sums used to accumulate the terms of a `TwoFloat` dot
+// product
+const DOT_LEVELS: usize = 3;
+
+// Accumulates terms in a cascade of partial sums, where the rounding error
+// of each sum is added to the next, following the vertical variant of SumK
+// from Ogita, Rump and Oishi (2005)
+struct Cascade<const K: usize> {
+    sums: [f64; K],
+}
+
+impl<const K: usize> Cascade<K> {
+    fn new() -> Self {
+        const { assert!(K > 0, "at least one partial sum is required") };
+        Self { sums: [0.0; K] }
+    }
+
+    fn add(&mut self, term: f64) {
+        let mut q = term;
+        for sum in self.sums[..K - 1].iter_mut() {
+            let s = TwoFloat::new_add(*sum, q);
+            *sum = s.hi;
+            q = s.lo;
+        }
+        self.sums[K - 1] += q;
+    }
+
+    fn result(&self) -> TwoFloat {
+        // The first partial sum is the naive sum, so overflow and non-finite
+        // terms are reported in the same way
+        if !self.sums[0].is_finite() {
+            return TwoFloat::from(self.sums[0]);
+        }
+
+        // The partial sums may cancel, so they are distilled before the
+        // largest is rounded together with the sum of the others
+        let mut sums = self.sums;
+        for _ in 0..K {
+            for i in 1..K {
+                let s = TwoFloat::new_add(sums[i], sums[i - 1]);
+                sums[i] = s.hi;
+                sums[i - 1] = s.lo;
+            }
+        }
+
+        let (&last, rest) = sums.split_last().unwrap();
+        TwoFloat::new_add(last, rest.iter().sum())
+    }
+}
+
+/// Types whose slices have an accurate dot product.
+pub trait DotProduct: Sized {
+    /// Computes the dot product of two slices of equal length.
+    fn dot(x: &[Self], y: &[Self]) -> TwoFloat;
+}
+
+impl DotProduct for f64 {
+    /// Computes the dot product using the Dot2 algorithm from Ogita, Rump and
+    /// Oishi (2005), giving a result as accurate as if it had been computed
+    /// in twice the precision of `f64`.
+    fn dot(x: &[Self], y: &[Self]) -> TwoFloat {
+        assert_eq!(x.len(), y.len(), "slices must have the same length");
+        let (mut p, mut s) = (0.0, 0.0);
+        for (&a, &b) in x.iter().zip(y) {
+            let h = TwoFloat::new_mul(a, b);
+            let q = TwoFloat::new_add(p, h.hi);
+            p = q.hi;
+            s += q.lo + h.lo;
+        }
+
+        if p.is_finite() {
+            TwoFloat::new_add(p, s)
+        } else {
+            TwoFloat::from(p)
+        }
+    }
+}
+
+impl DotProduct for TwoFloat {
+    /// Computes the dot product by accumulating the partial products of the
+    /// words, giving a result as accurate as if it had been computed in
+    /// three times the precision of `f64`.
+    fn dot(x: &[Self], y: &[Self]) -> TwoFloat {
+        assert_eq!(x.len(), y.len(), "slices must have the same length");
+        let mut cascade = Cascade::<DOT_LEVELS>::new();
+        for (&a, &b) in x.iter().zip(y) {
+            let p0 = TwoFloat::new_mul(a.hi, b.hi);
+            let p1 = TwoFloat::new_mul(a.hi, b.lo);
+            let p2 = TwoFloat::new_mul(a.lo, b.hi);
+            for &term in [p0.hi, p0.lo, p1.hi, p1.lo, p2.hi, p2.lo, a.lo * b.lo].iter() {
+                cascade.add(term);
+            }
+        }
+        cascade.result()
+    }
+}
+
+/// Computes the dot product of two slices of `f64` or `TwoFloat` values.
+///
+/// The products and their rounding errors are accumulated separately, so the
+/// result is accurate even when the sum cancels. For `f64` values this is the
+/// Dot2 algorithm from Ogita, Rump and Oishi (2005).
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::TwoFloat;
+/// let x = [1e20, 1.0, -1e20];
+/// let y = [1.0, 1.0, 1.0];
+///
+/// assert_eq!(twofloat::dot(&x, &y), 1.0);
+///
+/// let a = [TwoFloat::from(2f64.powi(100)), TwoFloat::new_div(1.0, 3.0)];
+/// let b = [TwoFloat::from(2f64.powi(-100)), TwoFloat::from(3.0)];
+/// assert!((twofloat::dot(&a, &b) - 2.0).abs() < 1e-30);
+/// ```
+pub fn dot<T: DotProduct>(x: &[T], y: &[T]) -> TwoFloat {
+    T::dot(x, y)
+}
+
+/// Computes the sum of a slice of `f64` values using `K`-fold compensated
+/// summation.
+///
+/// This is the vertical variant of the SumK algorithm from Ogita, Rump and
+/// Oishi (2005), which gives a result as accurate as if it had been computed
+/// in `K` times the precision of `f64` and then rounded to a `TwoFloat`. It
+/// uses a fixed amount of storage for the partial sums.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::TwoFloat;
+/// let values = [1e40, 1.0, 1e-40, -1e40];
+///
+/// assert_eq!(twofloat::sum_k::<2>(&values), 1.0);
+/// assert_eq!(twofloat::sum_k::<3>(&values), TwoFloat::new_add(1.0, 1e-40));
+/// ```
+pub fn sum_k<const K: usize>(values: &[f64]) -> TwoFloat {
+    let mut cascade = Cascade::<K>::new();
+    for &value in values {
+        cascade.add(value);
+    }
+    cascade.result()
+}
+
+/// Evaluates the polynomial with the given coefficients at `x` using the
+/// compensated Horner scheme.
+///
+/// The coefficients are given in order of increasing degree. Each step of
+/// the Horner scheme is computed with a single rounding using
+/// `TwoFloat::mul_add`, and the rounding errors are accumulated in a
+/// correction term. The result is as accurate as if it had been computed
+/// in three times the precision of `f64`, so it remains accurate close to
+/// the roots of the polynomial. The polynomial with no coefficients
+/// evaluates to zero.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::TwoFloat;
+/// // (x - 1)^3
+/// let coefficients = [-1.0, 3.0, -3.0, 1.0].map(TwoFloat::from);
+/// let x = TwoFloat::new_add(1.0, 1e-10);
+/// let exact = (x - 1.0).powi(3);
+///
+/// let result = twofloat::eval_poly(&coefficients, x);
+/// assert!(((result - exact) / exact).abs() < 1e-15);
+/// ```
+pub fn eval_poly(coefficients: &[TwoFloat], x: TwoFloat) -> TwoFloat {
+    let mut iter = coefficients.iter().rev();
+    let mut sum = match iter.next() {
+        Some(&leading) => leading,
+        None => return TwoFloat::from(0.0),
+    };
+
+    let mut correction = TwoFloat::from(0.0);
+    for &coefficient in iter {
+        let (next, error) = sum.mul_add_with_error(x, coefficient);
+        sum = next;
+        correction = correction * x + error;
+    }
+
+    sum + correction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cascade_test() {
+        let mut cascade = Cascade::<2>::new();
+        for &term in [1.0, 1e-20, -1.0, 1e-40].iter() {
+            cascade.add(term);
+        }
+        assert_eq!(cascade.sums, [1e-40, 1e-20]);
+        assert_eq!(cascade.result(), TwoFloat::new_add(1e-20, 1e-40));
+
+        let mut overflow = Cascade::<3>::new();
+        overflow.add(f64::MAX);
+        overflow.add(f64::MAX);
+        assert_eq!(overflow.result().hi(), f64::INFINITY);
+    }
+}
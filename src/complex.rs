@@ -0,0 +1,539 @@
+#![allow(clippy::extra_unused_lifetimes)]
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_complex::{Complex, Complex64};
+
+use crate::{DotProduct, TwoFloat};
+
+/// Represents a complex number with `TwoFloat` real and imaginary parts.
+///
+/// Unlike `num_complex::Complex<TwoFloat>`, products, quotients and the
+/// logarithm avoid the cancellation of the textbook formulas by evaluating
+/// expressions of the form `a*b + c*d` from their exact partial products.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TwoComplex {
+    /// Real part of the complex number
+    pub re: TwoFloat,
+    /// Imaginary part of the complex number
+    pub im: TwoFloat,
+}
+
+// Computes a*b + c*d + offset without cancellation, using the compensated
+// dot product of the partial products of the words
+fn sum_of_products(a: TwoFloat, b: TwoFloat, c: TwoFloat, d: TwoFloat, offset: f64) -> TwoFloat {
+    let one = TwoFloat::from(1.0);
+    TwoFloat::dot(&[a, c, TwoFloat::from(offset)], &[b, d, one])
+}
+
+// Returns the power of two which brings the larger part of z close to 1
+fn scale_factor(z: &TwoComplex) -> f64 {
+    let max = libm::fmax(z.re.hi.abs(), z.im.hi.abs());
+    if max == 0.0 || !max.is_finite() {
+        1.0
+    } else {
+        libm::ldexp(1.0, -libm::ilogb(max).clamp(-1022, 1023))
+    }
+}
+
+impl TwoComplex {
+    /// The imaginary unit.
+    pub const I: Self = Self::new(TwoFloat::from_f64(0.0), TwoFloat::from_f64(1.0));
+
+    /// Creates a new complex number from its real and imaginary parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(2.0));
+    ///
+    /// assert_eq!(z.re, 1.0);
+    /// assert_eq!(z.im, 2.0);
+    /// ```
+    pub const fn new(re: TwoFloat, im: TwoFloat) -> Self {
+        Self { re, im }
+    }
+
+    /// Creates a complex number from its polar form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::FRAC_PI_2, TwoComplex, TwoFloat};
+    /// let z = TwoComplex::from_polar(TwoFloat::from(2.0), FRAC_PI_2);
+    ///
+    /// assert!(z.re.abs() < 1e-30);
+    /// assert!((z.im - 2.0).abs() < 1e-30);
+    /// ```
+    pub fn from_polar(r: TwoFloat, theta: TwoFloat) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self::new(r * cos, r * sin)
+    }
+
+    /// Returns the magnitude and argument of the complex number.
+    pub fn to_polar(self) -> (TwoFloat, TwoFloat) {
+        (self.norm(), self.arg())
+    }
+
+    /// Returns `true` if both parts are valid `TwoFloat` values.
+    pub fn is_valid(&self) -> bool {
+        self.re.is_valid() && self.im.is_valid()
+    }
+
+    /// Returns the complex conjugate.
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Returns the magnitude of the complex number, computed without
+    /// intermediate overflow or underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let big = TwoFloat::from(2f64.powi(700));
+    /// let z = TwoComplex::new(big * 3.0, big * -4.0);
+    ///
+    /// assert_eq!(z.norm(), big * 5.0);
+    /// ```
+    pub fn norm(self) -> TwoFloat {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the absolute value of the complex number, which is its
+    /// magnitude as returned by [`norm`](Self::norm).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(-5.0), TwoFloat::from(12.0));
+    ///
+    /// assert_eq!(z.abs(), 13.0);
+    /// ```
+    pub fn abs(self) -> TwoFloat {
+        self.norm()
+    }
+
+    /// Returns the squared magnitude of the complex number.
+    pub fn norm_sqr(self) -> TwoFloat {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the argument of the complex number in the range `[-pi, pi]`.
+    pub fn arg(self) -> TwoFloat {
+        let theta = self.im.atan2(self.re);
+        if !self.is_valid() || self.re == 0.0 || self.im == 0.0 {
+            return theta;
+        }
+
+        // The polynomial approximation of atan does not reach full
+        // precision, so the result is refined by a Newton step
+        let (s, c) = theta.sin_cos();
+        theta + (self.im * c - self.re * s) / (self.re * c + self.im * s)
+    }
+
+    /// Returns the reciprocal of the complex number.
+    pub fn recip(self) -> Self {
+        Self::from(1.0) / self
+    }
+
+    /// Multiplies both parts by a real number.
+    pub fn scale(self, t: TwoFloat) -> Self {
+        Self::new(self.re * t, self.im * t)
+    }
+
+    /// Divides both parts by a real number.
+    pub fn unscale(self, t: TwoFloat) -> Self {
+        Self::new(self.re / t, self.im / t)
+    }
+
+    /// Returns the exponential of the complex number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::PI, TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(0.0), PI).exp();
+    ///
+    /// assert!((z.re + 1.0).abs() < 1e-30);
+    /// assert!(z.im.abs() < 1e-30);
+    /// ```
+    pub fn exp(self) -> Self {
+        let magnitude = self.re.exp();
+        if self.im == 0.0 {
+            Self::new(magnitude, self.im)
+        } else {
+            let (sin, cos) = self.im.sin_cos();
+            Self::new(magnitude * cos, magnitude * sin)
+        }
+    }
+
+    /// Returns the principal value of the natural logarithm of the complex
+    /// number, with imaginary part in the range `[-pi, pi]`.
+    ///
+    /// Close to the unit circle, the real part is computed as
+    /// `ln_1p(re^2 + im^2 - 1)/2`, where `re^2 + im^2 - 1` is evaluated
+    /// without cancellation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::FRAC_PI_2, TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(0.0), TwoFloat::from(-1.0)).ln();
+    ///
+    /// assert_eq!(z.re, 0.0);
+    /// assert!((z.im + FRAC_PI_2).abs() < 1e-30);
+    /// ```
+    pub fn ln(self) -> Self {
+        let r2 = self.norm_sqr();
+        let re = if r2.hi > 0.5 && r2.hi < 2.0 {
+            sum_of_products(self.re, self.re, self.im, self.im, -1.0).ln_1p() * 0.5
+        } else {
+            self.norm().ln()
+        };
+
+        Self::new(re, self.arg())
+    }
+
+    /// Returns the principal square root of the complex number, with
+    /// non-negative real part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(-4.0), TwoFloat::from(0.0)).sqrt();
+    ///
+    /// assert_eq!(z, TwoComplex::new(TwoFloat::from(0.0), TwoFloat::from(2.0)));
+    /// ```
+    pub fn sqrt(self) -> Self {
+        if self.re.hi == 0.0 && self.im.hi == 0.0 {
+            return Self::new(TwoFloat::from(0.0), self.im);
+        }
+
+        // Computing t = sqrt((|z| + |re|)/2) avoids cancellation, with the
+        // other part given by im/(2t)
+        let t = (self.norm() * 0.5 + self.re.abs() * 0.5).sqrt();
+        let other = self.im / (t * 2.0);
+        if self.re.is_sign_positive() {
+            Self::new(t, other)
+        } else {
+            Self::new(other.abs(), t.copysign(&self.im))
+        }
+    }
+
+    /// Returns the sine of the complex number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(0.0), TwoFloat::from(1.0));
+    ///
+    /// assert!((z.sin().im - TwoFloat::from(1.0).sinh()).abs() < 1e-30);
+    /// ```
+    pub fn sin(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        Self::new(sin * self.im.cosh(), cos * self.im.sinh())
+    }
+
+    /// Returns the cosine of the complex number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(0.0), TwoFloat::from(1.0));
+    ///
+    /// assert!((z.cos().re - TwoFloat::from(1.0).cosh()).abs() < 1e-30);
+    /// ```
+    pub fn cos(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        Self::new(cos * self.im.cosh(), -sin * self.im.sinh())
+    }
+
+    /// Raises the complex number to an integer power using repeated
+    /// multiplication.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(1.0));
+    ///
+    /// assert_eq!(z.powi(4), TwoComplex::from(-4.0));
+    /// ```
+    pub fn powi(self, n: i32) -> Self {
+        let mut result = Self::from(1.0);
+        let mut value = self;
+        let mut n_pos = n.unsigned_abs();
+        while n_pos > 0 {
+            if (n_pos & 1) != 0 {
+                result *= value;
+            }
+            value *= value;
+            n_pos >>= 1;
+        }
+
+        if n >= 0 {
+            result
+        } else {
+            result.recip()
+        }
+    }
+
+    /// Raises the complex number to a real power, using the principal value
+    /// of the logarithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let z = TwoComplex::new(TwoFloat::from(-4.0), TwoFloat::from(0.0));
+    /// let w = z.powf(TwoFloat::from(0.5));
+    ///
+    /// assert!(w.re.abs() < 1e-30);
+    /// assert!((w.im - 2.0).abs() < 1e-30);
+    /// ```
+    pub fn powf(self, exponent: TwoFloat) -> Self {
+        if self.re.hi == 0.0 && self.im.hi == 0.0 {
+            if exponent > 0.0 {
+                Self::from(0.0)
+            } else {
+                Self::from(TwoFloat::NAN)
+            }
+        } else {
+            self.ln().scale(exponent).exp()
+        }
+    }
+
+    /// Raises the complex number to a complex power, using the principal
+    /// value of the logarithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::FRAC_PI_2, TwoComplex};
+    /// // i^i = exp(-pi/2)
+    /// let z = TwoComplex::I.powc(TwoComplex::I);
+    ///
+    /// assert!((z.re - (-FRAC_PI_2).exp()).abs() < 1e-30);
+    /// assert!(z.im.abs() < 1e-30);
+    /// ```
+    pub fn powc(self, exponent: Self) -> Self {
+        if self.re.hi == 0.0 && self.im.hi == 0.0 {
+            if exponent.re > 0.0 {
+                Self::from(0.0)
+            } else {
+                Self::from(TwoFloat::NAN)
+            }
+        } else {
+            (self.ln() * exponent).exp()
+        }
+    }
+}
+
+impl From<TwoFloat> for TwoComplex {
+    fn from(re: TwoFloat) -> Self {
+        Self::new(re, TwoFloat::from(0.0))
+    }
+}
+
+impl From<f64> for TwoComplex {
+    fn from(re: f64) -> Self {
+        Self::from(TwoFloat::from(re))
+    }
+}
+
+impl From<Complex64> for TwoComplex {
+    fn from(z: Complex64) -> Self {
+        Self::new(TwoFloat::from(z.re), TwoFloat::from(z.im))
+    }
+}
+
+impl From<TwoComplex> for Complex64 {
+    fn from(z: TwoComplex) -> Self {
+        Self::new(f64::from(z.re), f64::from(z.im))
+    }
+}
+
+impl From<Complex<TwoFloat>> for TwoComplex {
+    fn from(z: Complex<TwoFloat>) -> Self {
+        Self::new(z.re, z.im)
+    }
+}
+
+impl From<TwoComplex> for Complex<TwoFloat> {
+    fn from(z: TwoComplex) -> Self {
+        Self::new(z.re, z.im)
+    }
+}
+
+unary_ops! {
+    fn Neg::neg(self: &TwoComplex) -> TwoComplex {
+        TwoComplex::new(-self.re, -self.im)
+    }
+}
+
+binary_ops! {
+    fn Add::add<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+
+    fn Add::add<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        TwoComplex::new(self.re + rhs, self.im)
+    }
+
+    fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self + rhs.re, rhs.im)
+    }
+
+    fn Add::add<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        TwoComplex::new(self.re + rhs, self.im)
+    }
+
+    fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self + rhs.re, rhs.im)
+    }
+
+    fn Sub::sub<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+
+    fn Sub::sub<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        TwoComplex::new(self.re - rhs, self.im)
+    }
+
+    fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self - rhs.re, -rhs.im)
+    }
+
+    fn Sub::sub<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        TwoComplex::new(self.re - rhs, self.im)
+    }
+
+    fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self - rhs.re, -rhs.im)
+    }
+
+    /// Implements multiplication of two `TwoComplex` values, evaluating each
+    /// part from the exact partial products to avoid cancellation.
+    fn Mul::mul<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(
+            sum_of_products(self.re, rhs.re, -self.im, rhs.im, 0.0),
+            sum_of_products(self.re, rhs.im, self.im, rhs.re, 0.0),
+        )
+    }
+
+    fn Mul::mul<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        TwoComplex::new(self.re * rhs, self.im * rhs)
+    }
+
+    fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self * rhs.re, self * rhs.im)
+    }
+
+    fn Mul::mul<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        TwoComplex::new(self.re * rhs, self.im * rhs)
+    }
+
+    fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::new(self * rhs.re, self * rhs.im)
+    }
+
+    /// Implements division of two `TwoComplex` values. The divisor is scaled
+    /// by a power of two to avoid overflow and underflow, and the numerator is
+    /// evaluated from the exact partial products to avoid cancellation.
+    fn Div::div<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        let scale = scale_factor(rhs);
+        let (c, d) = (rhs.re * scale, rhs.im * scale);
+        let denominator = c * c + d * d;
+        TwoComplex::new(
+            sum_of_products(self.re, c, self.im, d, 0.0) / denominator * scale,
+            sum_of_products(self.im, c, -self.re, d, 0.0) / denominator * scale,
+        )
+    }
+
+    fn Div::div<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        TwoComplex::new(self.re / rhs, self.im / rhs)
+    }
+
+    fn Div::div<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::from(*self) / rhs
+    }
+
+    fn Div::div<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        TwoComplex::new(self.re / rhs, self.im / rhs)
+    }
+
+    fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex::from(*self) / rhs
+    }
+}
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        self.re += rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        self.re += rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        self.re -= rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        self.re -= rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_of_products_cancellation_test() {
+        // (1 + 2^-60)(1 - 2^-60) - 1 = -2^-120 exactly
+        let a = TwoFloat::new_add(1.0, libm::ldexp(1.0, -60));
+        let b = TwoFloat::new_add(1.0, -libm::ldexp(1.0, -60));
+        let result = sum_of_products(a, b, TwoFloat::from(0.0), TwoFloat::from(0.0), -1.0);
+        assert_eq!(result, -libm::ldexp(1.0, -120));
+    }
+}
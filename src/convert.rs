@@ -65,10 +65,7 @@ macro_rules! float_convert {
     ($type:tt) => {
         impl From<$type> for TwoFloat {
             fn from(value: $type) -> Self {
-                Self {
-                    hi: value as f64,
-                    lo: 0.0,
-                }
+                Self::from_f64(value as f64)
             }
         }
 
@@ -83,10 +80,7 @@ macro_rules! int_convert {
     ($type:tt) => {
         impl From<$type> for TwoFloat {
             fn from(value: $type) -> Self {
-                Self {
-                    hi: value as f64,
-                    lo: 0.0,
-                }
+                Self::from_f64(value as f64)
             }
         }
 
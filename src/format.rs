@@ -1,10 +1,664 @@
-use core::fmt;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Write},
+};
 
-use crate::TwoFloat;
+use twofloat_decimal::{Decimal, WordFormat};
+
+use crate::{hex::BitBuffer, QuadFloat, TwoF32, TwoFloat};
+
+/// Adapter for formatting the high and low words of a `TwoFloat` separately,
+/// created by [`TwoFloat::display_parts`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayParts(TwoFloat);
+
+impl TwoFloat {
+    /// Returns an object that formats the high and low words separately, in
+    /// the form `hi + lo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_add(1.0, -0.3e-16);
+    /// assert_eq!(format!("{}", a.display_parts()), "1 - 0.00000000000000003");
+    /// assert_eq!(format!("{:e}", a.display_parts()), "1e0 - 3e-17");
+    /// ```
+    pub fn display_parts(&self) -> DisplayParts {
+        DisplayParts(*self)
+    }
+}
+
+/// Counts the number of bytes written, used for calculating padding.
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+fn write_repeated(w: &mut dyn Write, c: char, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        w.write_char(c)?;
+    }
+    Ok(())
+}
+
+/// Writes the sign and body of a number, applying the width, fill and
+/// alignment options in the same way as for `f64`.
+fn write_padded<F>(f: &mut fmt::Formatter<'_>, sign: &str, body: F) -> fmt::Result
+where
+    F: Fn(&mut dyn Write) -> fmt::Result,
+{
+    let width = match f.width() {
+        Some(width) => width,
+        None => {
+            f.write_str(sign)?;
+            return body(f);
+        }
+    };
+
+    let mut counter = Counter(sign.len());
+    body(&mut counter)?;
+    if counter.0 >= width {
+        f.write_str(sign)?;
+        return body(f);
+    }
+
+    let padding = width - counter.0;
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        write_repeated(f, '0', padding)?;
+        return body(f);
+    }
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let fill = f.fill();
+    write_repeated(f, fill, before)?;
+    f.write_str(sign)?;
+    body(f)?;
+    write_repeated(f, fill, after)
+}
+
+fn sign_str(negative: bool, f: &fmt::Formatter<'_>) -> &'static str {
+    if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    }
+}
+
+/// Writes the digits of a decimal in positional notation, with the given
+/// number of digits after the decimal point.
+fn write_fixed(w: &mut dyn Write, d: &Decimal, precision: usize) -> fmt::Result {
+    let digit = |index: i64| {
+        if index >= 0 && index < d.num_digits as i64 {
+            (b'0' + d.digits[index as usize]) as char
+        } else {
+            '0'
+        }
+    };
+
+    if d.decimal_point <= 0 {
+        w.write_char('0')?;
+    } else {
+        for i in 0..d.decimal_point as i64 {
+            w.write_char(digit(i))?;
+        }
+    }
+
+    if precision > 0 {
+        w.write_char('.')?;
+        for i in 0..precision as i64 {
+            w.write_char(digit(d.decimal_point as i64 + i))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The words of a multi-word value widened to `f64`, most significant first,
+/// together with the format of each word.
+#[derive(Clone, Copy)]
+struct Words {
+    words: [f64; 4],
+    len: usize,
+    valid: bool,
+    format: &'static WordFormat,
+}
+
+impl Words {
+    fn as_slice(&self) -> &[f64] {
+        &self.words[..self.len]
+    }
+
+    fn hi(&self) -> f64 {
+        self.words[0]
+    }
+}
+
+impl From<&TwoFloat> for Words {
+    fn from(value: &TwoFloat) -> Self {
+        Self {
+            words: [value.hi, value.lo, 0.0, 0.0],
+            len: 2,
+            valid: value.is_valid(),
+            format: &WordFormat::F64,
+        }
+    }
+}
+
+impl From<&TwoF32> for Words {
+    fn from(value: &TwoF32) -> Self {
+        Self {
+            words: [value.hi as f64, value.lo as f64, 0.0, 0.0],
+            len: 2,
+            valid: value.is_valid(),
+            format: &WordFormat::F32,
+        }
+    }
+}
+
+impl From<&QuadFloat> for Words {
+    fn from(value: &QuadFloat) -> Self {
+        Self {
+            words: value.words,
+            len: 4,
+            valid: value.is_valid(),
+            format: &WordFormat::F64,
+        }
+    }
+}
+
+/// Adds a word to a decimal magnitude with the given sign.
+fn add_signed((negative, mut sum): (bool, Decimal), x: f64) -> (bool, Decimal) {
+    let term = Decimal::from_f64(x);
+    if x.is_sign_negative() == negative {
+        sum.add(&term);
+        (negative, sum)
+    } else if term.compare(&sum) != Ordering::Greater {
+        sum.sub(&term);
+        (negative, sum)
+    } else {
+        let mut term = term;
+        term.sub(&sum);
+        (!negative, term)
+    }
+}
+
+/// Returns the sign and exact decimal magnitude of a finite value.
+fn exact_decimal(value: &Words) -> (bool, Decimal) {
+    let words = value.as_slice();
+    let first = (words[0].is_sign_negative(), Decimal::from_f64(words[0]));
+    words[1..].iter().fold(first, |sum, &x| add_signed(sum, x))
+}
+
+/// Returns the exponent of the least significant bit of a finite value in
+/// the given format.
+fn lsb_exponent(x: f64, format: &WordFormat) -> i32 {
+    if x == 0.0 {
+        format.min_exponent
+    } else {
+        (libm::ilogb(x) + 1 - format.mantissa_digits).max(format.min_exponent)
+    }
+}
+
+/// Returns the exponents of the gaps between a finite value and the adjacent
+/// values of the given format below and above it.
+fn gap_exponents(x: f64, format: &WordFormat) -> (i32, i32) {
+    let outer = lsb_exponent(x, format);
+    // The gap is halved below a power of two, unless it is already the
+    // subnormal spacing
+    let is_power_of_two = x != 0.0 && x.to_bits() & ((1 << 52) - 1) == 0;
+    let inner = if is_power_of_two && outer > format.min_exponent {
+        outer - 1
+    } else {
+        outer
+    };
+
+    if x.is_sign_negative() {
+        (outer, inner)
+    } else {
+        (inner, outer)
+    }
+}
+
+fn is_even(x: f64, format: &WordFormat) -> bool {
+    let mantissa = libm::scalbn(libm::fabs(x), -lsb_exponent(x, format));
+    (mantissa as u64) & 1 == 0
+}
+
+/// Selects the rounding boundary closer to the value, where `closer` gives
+/// the required ordering of the first boundary relative to the second. Equal
+/// boundaries are only inclusive if both are.
+fn closer_bound(a: (Decimal, bool), b: (Decimal, bool), closer: Ordering) -> (Decimal, bool) {
+    match a.0.compare(&b.0) {
+        Ordering::Equal => (a.0, a.1 && b.1),
+        ordering if ordering == closer => a,
+        _ => b,
+    }
+}
+
+/// Returns the sign and the decimal magnitude with the fewest significant
+/// digits that parses to the same value, using the algorithm from the Go
+/// standard library's `strconv` package.
+///
+/// Parsing gives the nearest word as the high word, then rounds the remaining
+/// error to give each following word. The decimal must therefore lie within
+/// the rounding interval of each word about the sum of the words up to it,
+/// so the tightest of these bounds are used. Non-normalized values are
+/// formatted exactly.
+fn shortest_decimal(value: &Words) -> (bool, Decimal) {
+    if !value.valid || value.hi() == 0.0 {
+        return exact_decimal(value);
+    }
+
+    // Returns the bounds of the rounding interval of a word about a partial
+    // sum, and whether they are inclusive
+    let word_bounds = |sum: &Decimal, x: f64| {
+        let (below, above) = gap_exponents(x, value.format);
+        let inclusive = is_even(x, value.format);
+        let mut lower = sum.clone();
+        lower.sub(&Decimal::from_dyadic(1, below - 1));
+        let mut upper = sum.clone();
+        upper.add(&Decimal::from_dyadic(1, above - 1));
+        ((lower, inclusive), (upper, inclusive))
+    };
+
+    let negative = value.hi().is_sign_negative();
+    let magnitude = |x: f64| if negative { -x } else { x };
+    let words = value.as_slice();
+    let mut d = Decimal::from_f64(words[0]);
+    let (mut lower, mut upper) = word_bounds(&d, magnitude(words[0]));
+    for &word in &words[1..] {
+        let word = magnitude(word);
+        if word.is_sign_negative() {
+            d.sub(&Decimal::from_f64(word));
+        } else {
+            d.add(&Decimal::from_f64(word));
+        }
+
+        let (word_lower, word_upper) = word_bounds(&d, word);
+        lower = closer_bound(word_lower, lower, Ordering::Greater);
+        upper = closer_bound(word_upper, upper, Ordering::Less);
+    }
+    let ((lower, lower_inclusive), (upper, upper_inclusive)) = (lower, upper);
+
+    // Walk along the digits until the value is distinguished from the bounds.
+    // The upper bound has the most digits before the decimal point, so index
+    // the digits of the others relative to it.
+    //
+    // upper_delta is 0 while the digits of the value and upper bound agree, 1
+    // if they differed by one in an earlier digit followed only by nines in
+    // the value and zeros in the upper bound, and 2 once rounding up is known
+    // to stay below the upper bound.
+    let digit = |d: &Decimal, index: i64| {
+        if index >= 0 && index < d.num_digits as i64 {
+            d.digits[index as usize]
+        } else {
+            0
+        }
+    };
+    let mut upper_delta = 0;
+    let mut ui = 0i64;
+    loop {
+        let mi = ui - upper.decimal_point as i64 + d.decimal_point as i64;
+        if mi >= d.num_digits as i64 {
+            break;
+        }
+        let li = ui - upper.decimal_point as i64 + lower.decimal_point as i64;
+        let l = digit(&lower, li);
+        let m = digit(&d, mi);
+        let u = digit(&upper, ui);
+
+        // Truncation is possible if the lower bound has a different digit, or
+        // if the lower bound is inclusive and is exactly the truncated value.
+        let ok_down = l != m || (lower_inclusive && li + 1 == lower.num_digits as i64);
+
+        if upper_delta == 0 && m + 1 < u {
+            upper_delta = 2;
+        } else if upper_delta == 0 && m != u {
+            upper_delta = 1;
+        } else if upper_delta == 1 && (m != 9 || u != 0) {
+            upper_delta = 2;
+        }
+
+        // Rounding up is possible if the upper bound has a different digit and
+        // is either inclusive or greater than the rounded up value.
+        let ok_up = upper_delta > 0
+            && (upper_inclusive || upper_delta > 1 || ui + 1 < upper.num_digits as i64);
+
+        let n = (mi + 1) as usize;
+        match (ok_down, ok_up) {
+            (true, true) => d.round(n),
+            (true, false) => d.round_down(n),
+            (false, true) => d.round_up(n),
+            (false, false) => {
+                ui += 1;
+                continue;
+            }
+        }
+        break;
+    }
+
+    (negative, d)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    Fixed,
+    Exponent { upper: bool },
+}
+
+/// Writes the digits of a decimal in scientific notation, with the given
+/// number of digits after the decimal point.
+fn write_scientific(w: &mut dyn Write, d: &Decimal, precision: usize, upper: bool) -> fmt::Result {
+    let digit = |index: usize| {
+        if index < d.num_digits {
+            (b'0' + d.digits[index]) as char
+        } else {
+            '0'
+        }
+    };
+
+    w.write_char(digit(0))?;
+    if precision > 0 {
+        w.write_char('.')?;
+        for i in 1..=precision {
+            w.write_char(digit(i))?;
+        }
+    }
+
+    let exponent = if d.is_zero() { 0 } else { d.decimal_point - 1 };
+    write!(w, "{}{}", if upper { 'E' } else { 'e' }, exponent)
+}
+
+/// Formats a value as a single decimal number, rounded to the precision if
+/// one is given and otherwise using the shortest representation.
+fn format_decimal(value: &Words, f: &mut fmt::Formatter<'_>, notation: Notation) -> fmt::Result {
+    if value.as_slice().iter().any(|x| x.is_nan()) {
+        return write_padded(f, "", |w| w.write_str("NaN"));
+    }
+
+    if value.hi().is_infinite() {
+        let sign = sign_str(value.hi().is_sign_negative(), f);
+        return write_padded(f, sign, |w| w.write_str("inf"));
+    }
+
+    let (negative, d, precision) = match f.precision() {
+        Some(precision) => {
+            let (negative, mut d) = exact_decimal(value);
+            let n = match notation {
+                Notation::Fixed => d.decimal_point as i64 + precision as i64,
+                Notation::Exponent { .. } => precision as i64 + 1,
+            };
+            if n < 0 {
+                d = Decimal::default();
+            } else {
+                d.round(n as usize);
+            }
+            (negative, d, precision)
+        }
+        None => {
+            let (negative, d) = shortest_decimal(value);
+            let precision = match notation {
+                Notation::Fixed => d.num_digits as i64 - d.decimal_point as i64,
+                Notation::Exponent { .. } => d.num_digits as i64 - 1,
+            };
+            (negative, d, precision.max(0) as usize)
+        }
+    };
+
+    let sign = sign_str(negative, f);
+    match notation {
+        Notation::Fixed => write_padded(f, sign, |w| write_fixed(w, &d, precision)),
+        Notation::Exponent { upper } => {
+            write_padded(f, sign, |w| write_scientific(w, &d, precision, upper))
+        }
+    }
+}
 
 impl fmt::Display for TwoFloat {
+    /// Formats the value as a single decimal number.
+    ///
+    /// If no precision is specified, the output is the shortest decimal that
+    /// parses back to the same value. Otherwise the exact value is rounded to
+    /// the given number of decimal places, with ties rounding to even. The
+    /// width, fill, alignment and sign options behave as they do for `f64`.
+    ///
+    /// Note that a value converted from an `f64` such as `0.1` is displayed
+    /// with many more digits than the `f64` itself, as the shortest decimal
+    /// must also reproduce the zero low word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a: TwoFloat = "0.1".parse().unwrap();
+    /// assert_eq!(format!("{}", a), "0.1");
+    ///
+    /// let a = TwoFloat::from(1.0) / 3.0;
+    /// assert_eq!(format!("{:+.5}", a), "+0.33333");
+    /// assert_eq!(format!("{:>8.2}", -a), "   -0.33");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_decimal(&self.into(), f, Notation::Fixed)
+    }
+}
+
+impl fmt::LowerExp for TwoFloat {
+    /// Formats the value in scientific notation with a single significand.
+    ///
+    /// If no precision is specified, the significand has the fewest digits
+    /// that parse back to the same value. Otherwise the exact value is rounded
+    /// to the given number of digits after the decimal point, with ties
+    /// rounding to even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a: TwoFloat = "1.23e-5".parse().unwrap();
+    /// assert_eq!(format!("{:e}", a), "1.23e-5");
+    /// assert_eq!(format!("{:.30e}", twofloat::consts::PI), "3.141592653589793238462643383280e0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_decimal(&self.into(), f, Notation::Exponent { upper: false })
+    }
+}
+
+impl fmt::UpperExp for TwoFloat {
+    /// Formats the value in scientific notation with a single significand,
+    /// as for `LowerExp` but using an upper-case `E`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_decimal(&self.into(), f, Notation::Exponent { upper: true })
+    }
+}
+
+impl fmt::Display for TwoF32 {
+    /// Formats the value as a single decimal number, in the same way as for
+    /// `TwoFloat`. Without a precision, the output is the shortest decimal
+    /// that parses back to the same `TwoF32` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a: TwoF32 = "0.1".parse().unwrap();
+    /// assert_eq!(format!("{}", a), "0.1");
+    ///
+    /// let a = TwoF32::new_div(1.0, 3.0);
+    /// assert_eq!(format!("{:.5}", a), "0.33333");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_decimal(&self.into(), f, Notation::Fixed)
+    }
+}
+
+impl fmt::LowerExp for TwoF32 {
+    /// Formats the value in scientific notation with a single significand,
+    /// in the same way as for `TwoFloat`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_decimal(&self.into(), f, Notation::Exponent { upper: false })
+    }
+}
+
+impl fmt::UpperExp for TwoF32 {
+    /// Formats the value in scientific notation with a single significand,
+    /// as for `LowerExp` but using an upper-case `E`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_decimal(&self.into(), f, Notation::Exponent { upper: true })
+    }
+}
+
+impl fmt::Display for QuadFloat {
+    /// Formats the value as a single decimal number, in the same way as for
+    /// `TwoFloat`. Without a precision, the output is the shortest decimal
+    /// that parses back to the same `QuadFloat` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a: QuadFloat = "0.1".parse().unwrap();
+    /// assert_eq!(format!("{}", a), "0.1");
+    ///
+    /// let a = QuadFloat::from(2.0) / 3.0;
+    /// assert_eq!(format!("{:.60}", a), format!("0.{}7", "6".repeat(59)));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_decimal(&self.into(), f, Notation::Fixed)
+    }
+}
+
+/// Splits a finite `f64` magnitude into an integer mantissa and the exponent
+/// of its least significant bit.
+fn dyadic_parts(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    }
+}
+
+/// Computes the exact binary expansion of a finite value, returning the sign,
+/// the digits and the exponent of the digit at index zero.
+fn exact_binary(value: &TwoFloat) -> (bool, BitBuffer, i32) {
+    let (big, small) = if libm::fabs(value.lo) > libm::fabs(value.hi) {
+        (value.lo, value.hi)
+    } else {
+        (value.hi, value.lo)
+    };
+
+    let (big_mantissa, big_exponent) = dyadic_parts(big);
+    let (small_mantissa, small_exponent) = dyadic_parts(small);
+    if big_mantissa == 0 {
+        return (value.hi.is_sign_negative(), BitBuffer::new(), 0);
+    }
+
+    // Leave a spare digit at the top for any carry from the addition
+    let top = big_exponent + 64 - big_mantissa.leading_zeros() as i32;
+    let bottom = if small_mantissa == 0 {
+        big_exponent
+    } else {
+        big_exponent.min(small_exponent)
+    };
+
+    let mut digits = BitBuffer::zeros((top - bottom + 1) as usize);
+    digits.add(big_mantissa, (top - big_exponent) as usize);
+    if small.is_sign_negative() == big.is_sign_negative() {
+        digits.add(small_mantissa, (top - small_exponent) as usize);
+    } else {
+        digits.sub(small_mantissa, (top - small_exponent) as usize);
+    }
+
+    (big.is_sign_negative(), digits, top)
+}
+
+fn write_hex(w: &mut dyn Write, digits: &BitBuffer, top: i32, upper: bool) -> fmt::Result {
+    let (prefix, marker) = if upper { ("0X", 'P') } else { ("0x", 'p') };
+    let lead = digits.first_one(0);
+    if lead >= digits.len {
+        return write!(w, "{}0{}+0", prefix, marker);
+    }
+
+    w.write_str(prefix)?;
+    w.write_char('1')?;
+    let end = digits.end();
+    if end > lead + 1 {
+        w.write_char('.')?;
+        let mut index = lead + 1;
+        while index < end {
+            let mut nibble = 0;
+            for offset in 0..4 {
+                nibble = (nibble << 1) | digits.get(index + offset) as u32;
+            }
+            let c = char::from_digit(nibble, 16).unwrap();
+            w.write_char(if upper { c.to_ascii_uppercase() } else { c })?;
+            index += 4;
+        }
+    }
+
+    write!(w, "{}{:+}", marker, top as i64 - lead as i64)
+}
+
+fn format_hex(value: &TwoFloat, f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+    if value.hi.is_nan() || value.lo.is_nan() {
+        return write_padded(f, "", |w| w.write_str("NaN"));
+    }
+
+    if value.hi.is_infinite() {
+        let sign = sign_str(value.hi.is_sign_negative(), f);
+        return write_padded(f, sign, |w| w.write_str("inf"));
+    }
+
+    let (negative, digits, top) = exact_binary(value);
+    let sign = sign_str(negative, f);
+    write_padded(f, sign, |w| write_hex(w, &digits, top, upper))
+}
+
+impl fmt::LowerHex for TwoFloat {
+    /// Formats the exact value as a single hexadecimal significand with a
+    /// binary exponent, in the form accepted by [`TwoFloat::from_hex_str`].
+    /// Nonzero values are normalized to have a leading digit of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(
+    ///     format!("{:x}", twofloat::consts::PI),
+    ///     "0x1.921fb54442d18469898cc51701cp+1"
+    /// );
+    /// assert_eq!(format!("{:x}", TwoFloat::from(-0.75)), "-0x1.8p-1");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let sign_char = if self.lo().is_sign_positive() {
+        format_hex(self, f, false)
+    }
+}
+
+impl fmt::UpperHex for TwoFloat {
+    /// Formats the exact value as a single hexadecimal significand, as for
+    /// `LowerHex` but using upper-case digits and exponent marker.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_hex(self, f, true)
+    }
+}
+
+impl fmt::Display for DisplayParts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign_char = if self.0.lo().is_sign_positive() {
             '+'
         } else {
             '-'
@@ -15,12 +669,12 @@ impl fmt::Display for TwoFloat {
                     f,
                     "{:+.*} {} {:.*}",
                     p,
-                    self.hi,
+                    self.0.hi,
                     sign_char,
                     p,
-                    libm::fabs(self.lo)
+                    libm::fabs(self.0.lo)
                 ),
-                None => write!(f, "{:+} {} {}", self.hi, sign_char, libm::fabs(self.lo)),
+                None => write!(f, "{:+} {} {}", self.0.hi, sign_char, libm::fabs(self.0.lo)),
             }
         } else {
             match f.precision() {
@@ -28,20 +682,20 @@ impl fmt::Display for TwoFloat {
                     f,
                     "{:.*} {} {:.*}",
                     p,
-                    self.hi,
+                    self.0.hi,
                     sign_char,
                     p,
-                    libm::fabs(self.lo)
+                    libm::fabs(self.0.lo)
                 ),
-                None => write!(f, "{} {} {}", self.hi, sign_char, libm::fabs(self.lo)),
+                None => write!(f, "{} {} {}", self.0.hi, sign_char, libm::fabs(self.0.lo)),
             }
         }
     }
 }
 
-impl fmt::LowerExp for TwoFloat {
+impl fmt::LowerExp for DisplayParts {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let sign_char = if self.lo().is_sign_positive() {
+        let sign_char = if self.0.lo().is_sign_positive() {
             '+'
         } else {
             '-'
@@ -52,12 +706,18 @@ impl fmt::LowerExp for TwoFloat {
                     f,
                     "{:+.*e} {} {:.*e}",
                     p,
-                    self.hi,
+                    self.0.hi,
                     sign_char,
                     p,
-                    libm::fabs(self.lo)
+                    libm::fabs(self.0.lo)
+                ),
+                None => write!(
+                    f,
+                    "{:+e} {} {:e}",
+                    self.0.hi,
+                    sign_char,
+                    libm::fabs(self.0.lo)
                 ),
-                None => write!(f, "{:+e} {} {:e}", self.hi, sign_char, libm::fabs(self.lo)),
             }
         } else {
             match f.precision() {
@@ -65,20 +725,26 @@ impl fmt::LowerExp for TwoFloat {
                     f,
                     "{:.*e} {} {:.*e}",
                     p,
-                    self.hi,
+                    self.0.hi,
                     sign_char,
                     p,
-                    libm::fabs(self.lo)
+                    libm::fabs(self.0.lo)
+                ),
+                None => write!(
+                    f,
+                    "{:e} {} {:e}",
+                    self.0.hi,
+                    sign_char,
+                    libm::fabs(self.0.lo)
                 ),
-                None => write!(f, "{:e} {} {:e}", self.hi, sign_char, libm::fabs(self.lo)),
             }
         }
     }
 }
 
-impl fmt::UpperExp for TwoFloat {
+impl fmt::UpperExp for DisplayParts {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let sign_char = if self.lo().is_sign_positive() {
+        let sign_char = if self.0.lo().is_sign_positive() {
             '+'
         } else {
             '-'
@@ -89,12 +755,18 @@ impl fmt::UpperExp for TwoFloat {
                     f,
                     "{:+.*E} {} {:.*E}",
                     p,
-                    self.hi,
+                    self.0.hi,
                     sign_char,
                     p,
-                    libm::fabs(self.lo)
+                    libm::fabs(self.0.lo)
+                ),
+                None => write!(
+                    f,
+                    "{:+E} {} {:E}",
+                    self.0.hi,
+                    sign_char,
+                    libm::fabs(self.0.lo)
                 ),
-                None => write!(f, "{:+E} {} {:E}", self.hi, sign_char, libm::fabs(self.lo)),
             }
         } else {
             match f.precision() {
@@ -102,12 +774,18 @@ impl fmt::UpperExp for TwoFloat {
                     f,
                     "{:.*E} {} {:.*E}",
                     p,
-                    self.hi,
+                    self.0.hi,
                     sign_char,
                     p,
-                    libm::fabs(self.lo)
+                    libm::fabs(self.0.lo)
+                ),
+                None => write!(
+                    f,
+                    "{:E} {} {:E}",
+                    self.0.hi,
+                    sign_char,
+                    libm::fabs(self.0.lo)
                 ),
-                None => write!(f, "{:E} {} {:E}", self.hi, sign_char, libm::fabs(self.lo)),
             }
         }
     }
@@ -119,34 +797,129 @@ mod test {
 
     #[test]
     fn display_test() {
+        let value = TwoFloat {
+            hi: 1.0,
+            lo: 0.25e-16,
+        };
+        assert_eq!(format!("{value}"), "1.000000000000000025");
+        assert_eq!(format!("{}", -value), "-1.000000000000000025");
+        assert_eq!(format!("{value:+}"), "+1.000000000000000025");
+        assert_eq!(format!("{value:.2}"), "1.00");
+        assert_eq!(format!("{:.2}", -value), "-1.00");
+        assert_eq!(format!("{value:+.2}"), "+1.00");
+        assert_eq!(format!("{value:.17}"), "1.00000000000000002");
+        assert_eq!(format!("{value:.18}"), "1.000000000000000025");
+        assert_eq!(format!("{value:.20}"), "1.00000000000000002500");
+    }
+
+    #[test]
+    fn display_special_test() {
+        assert_eq!(format!("{}", TwoFloat::from(0.0)), "0");
+        assert_eq!(format!("{}", TwoFloat::from(-0.0)), "-0");
+        assert_eq!(format!("{:.1}", TwoFloat::from(-0.01)), "-0.0");
+        assert_eq!(format!("{}", TwoFloat::from(1e20)), "100000000000000000000");
+        let value: TwoFloat = "0.000005".parse().unwrap();
+        assert_eq!(format!("{value}"), "0.000005");
+        assert_eq!(
+            format!("{}", TwoFloat::from(0.5e-5)),
+            "0.0000050000000000000004090152695701565477293115691281855106353759765625"
+        );
+        assert_eq!(format!("{}", TwoFloat::INFINITY), "inf");
+        assert_eq!(format!("{:+}", TwoFloat::NEG_INFINITY), "-inf");
+        assert_eq!(format!("{:+}", TwoFloat::NAN), "NaN");
+        assert_eq!(format!("{:.0}", TwoFloat::from(2.5)), "2");
+        assert_eq!(format!("{:.0}", TwoFloat::from(3.5)), "4");
+        assert_eq!(format!("{:.0}", TwoFloat::new_add(2.5, 1e-20)), "3");
+    }
+
+    #[test]
+    fn display_padding_test() {
+        let value = TwoFloat::from(-1.5);
+        assert_eq!(format!("{value:8}"), "    -1.5");
+        assert_eq!(format!("{value:<8}|"), "-1.5    |");
+        assert_eq!(format!("{value:*^9}"), "**-1.5***");
+        assert_eq!(format!("{value:08.2}"), "-0001.50");
+        assert_eq!(format!("{:+08}", -value), "+00001.5");
+        assert_eq!(format!("{value:2}"), "-1.5");
+        assert_eq!(format!("{:05}", TwoFloat::INFINITY), "00inf");
+        assert_eq!(format!("{:>5}", TwoFloat::NAN), "  NaN");
+    }
+
+    #[test]
+    fn display_parts_test() {
         let value = TwoFloat { hi: 1.0, lo: 0.3 };
-        assert_eq!(format!("{value}"), "1 + 0.3");
-        assert_eq!(format!("{}", -value), "-1 - 0.3");
-        assert_eq!(format!("{value:+}"), "+1 + 0.3");
-        assert_eq!(format!("{value:.2}"), "1.00 + 0.30");
-        assert_eq!(format!("{:.2}", -value), "-1.00 - 0.30");
-        assert_eq!(format!("{value:+.2}"), "+1.00 + 0.30");
+        let parts = value.display_parts();
+        assert_eq!(format!("{parts}"), "1 + 0.3");
+        assert_eq!(format!("{}", (-value).display_parts()), "-1 - 0.3");
+        assert_eq!(format!("{parts:+}"), "+1 + 0.3");
+        assert_eq!(format!("{parts:.2}"), "1.00 + 0.30");
+        assert_eq!(format!("{:.2}", (-value).display_parts()), "-1.00 - 0.30");
+        assert_eq!(format!("{parts:+.2}"), "+1.00 + 0.30");
     }
 
     #[test]
     fn lowerexp_test() {
-        let value = TwoFloat { hi: 1.0, lo: -0.3 };
-        assert_eq!(format!("{value:e}"), "1e0 - 3e-1");
-        assert_eq!(format!("{:e}", -value), "-1e0 + 3e-1");
-        assert_eq!(format!("{value:+e}"), "+1e0 - 3e-1");
-        assert_eq!(format!("{value:.2e}"), "1.00e0 - 3.00e-1");
-        assert_eq!(format!("{:.2e}", -value), "-1.00e0 + 3.00e-1");
-        assert_eq!(format!("{value:+.2e}"), "+1.00e0 - 3.00e-1");
+        let value = TwoFloat::new_add(1.0, -0.3e-16);
+        assert_eq!(format!("{value:e}"), "9.9999999999999997e-1");
+        assert_eq!(format!("{:e}", -value), "-9.9999999999999997e-1");
+        assert_eq!(format!("{value:+e}"), "+9.9999999999999997e-1");
+        assert_eq!(format!("{value:.2e}"), "1.00e0");
+        assert_eq!(format!("{:.2e}", -value), "-1.00e0");
+        assert_eq!(format!("{value:+.2e}"), "+1.00e0");
+        assert_eq!(format!("{value:.20e}"), "9.99999999999999970000e-1");
+        assert_eq!(format!("{value:12.3e}"), "     1.000e0");
     }
 
     #[test]
     fn upperexp_test() {
-        let value = TwoFloat { hi: 1.0, lo: 0.3 };
-        assert_eq!(format!("{value:E}"), "1E0 + 3E-1");
-        assert_eq!(format!("{:E}", -value), "-1E0 - 3E-1");
-        assert_eq!(format!("{value:+E}"), "+1E0 + 3E-1");
-        assert_eq!(format!("{value:.2E}"), "1.00E0 + 3.00E-1");
-        assert_eq!(format!("{:.2E}", -value), "-1.00E0 - 3.00E-1");
-        assert_eq!(format!("{value:+.2E}"), "+1.00E0 + 3.00E-1");
+        let value: TwoFloat = "1.00000000000000003e100".parse().unwrap();
+        assert_eq!(format!("{value:E}"), "1.00000000000000003E100");
+        assert_eq!(format!("{:E}", -value), "-1.00000000000000003E100");
+        assert_eq!(format!("{value:+E}"), "+1.00000000000000003E100");
+        assert_eq!(format!("{value:.2E}"), "1.00E100");
+        assert_eq!(format!("{:.2E}", -value), "-1.00E100");
+        assert_eq!(format!("{value:+.2E}"), "+1.00E100");
+    }
+
+    #[test]
+    fn exp_special_test() {
+        assert_eq!(format!("{:e}", TwoFloat::from(0.0)), "0e0");
+        assert_eq!(format!("{:.2e}", TwoFloat::from(-0.0)), "-0.00e0");
+        assert_eq!(format!("{:e}", TwoFloat::INFINITY), "inf");
+        assert_eq!(format!("{:E}", TwoFloat::NAN), "NaN");
+        assert_eq!(format!("{:.0e}", TwoFloat::from(2.5)), "2e0");
+        assert_eq!(format!("{:.0e}", TwoFloat::from(9.5)), "1e1");
+        assert_eq!(format!("{:010.1e}", TwoFloat::from(-1.25)), "-00001.2e0");
+    }
+
+    #[test]
+    fn parts_exp_test() {
+        let value = TwoFloat { hi: 1.0, lo: -0.3 };
+        let parts = value.display_parts();
+        assert_eq!(format!("{parts:e}"), "1e0 - 3e-1");
+        assert_eq!(format!("{:e}", (-value).display_parts()), "-1e0 + 3e-1");
+        assert_eq!(format!("{parts:+e}"), "+1e0 - 3e-1");
+        assert_eq!(format!("{parts:.2e}"), "1.00e0 - 3.00e-1");
+        assert_eq!(format!("{parts:+.2E}"), "+1.00E0 - 3.00E-1");
+    }
+
+    #[test]
+    fn hex_test() {
+        let value = TwoFloat::new_add(1.0, -0.3e-16);
+        assert_eq!(format!("{value:x}"), "0x1.fffffffffffffbad3202d2b823cp-1");
+        assert_eq!(
+            format!("{:X}", -value),
+            "-0X1.FFFFFFFFFFFFFBAD3202D2B823CP-1"
+        );
+        assert_eq!(format!("{value:+x}"), "+0x1.fffffffffffffbad3202d2b823cp-1");
+        assert_eq!(format!("{:x}", TwoFloat::from(0.0)), "0x0p+0");
+        assert_eq!(format!("{:x}", TwoFloat::from(-0.0)), "-0x0p+0");
+        assert_eq!(
+            format!("{:x}", TwoFloat::from(f64::MIN_POSITIVE)),
+            "0x1p-1022"
+        );
+        assert_eq!(format!("{:x}", TwoFloat::INFINITY), "inf");
+        assert_eq!(format!("{:X}", TwoFloat::NAN), "NaN");
+        assert_eq!(format!("{:>10x}", TwoFloat::from(2.0)), "    0x1p+1");
     }
 }
@@ -4,7 +4,13 @@ pub mod sign;
 #[macro_use]
 mod function_utils;
 
+pub mod bessel;
+pub mod elliptic;
 pub mod explog;
 pub mod hyperbolic;
+pub mod lambert;
 pub mod power;
+pub(crate) mod reduction;
+pub mod special;
 pub mod trigonometry;
+pub mod zeta;
@@ -0,0 +1,1426 @@
+use hexf::hexf64;
+
+use super::{reduction::rem_frac_pi_2, special::split_square};
+use crate::{
+    consts::{FRAC_2_PI, FRAC_PI_4, LN_2},
+    TwoFloat,
+};
+
+// Below this value the power series are used
+const SERIES_LIMIT: f64 = 1.0;
+
+// Above this value the asymptotic expansions are used
+const ASYMPTOTIC_LIMIT: f64 = 45.0;
+
+// Maximum distance from a zero at which the expansion about the zero is
+// used, which covers the interval between consecutive zeros
+const ZERO_WIDTH: f64 = 2.0;
+
+// Maximum number of terms of the expansions about the zeros
+const ZERO_TERMS: usize = 200;
+
+// Step size of the trapezoidal rule for k0(x) and k1(x)
+const K_STEP: f64 = 0.0625;
+
+// Terms smaller than this relative to the sum are neglected
+const TERM_EPSILON: f64 = hexf64!("0x1.0p-110");
+
+// Orders above this limit are not computed using the recurrences, whose
+// length is proportional to the order
+const RECURRENCE_ORDER_LIMIT: u32 = 1 << 16;
+
+// Values in the backward recurrence are rescaled when they exceed this limit
+const RECURRENCE_LIMIT: f64 = hexf64!("0x1.0p500");
+const RECURRENCE_SCALE: f64 = hexf64!("0x1.0p-500");
+
+// Zero of a Bessel function, split into three parts, together with the
+// derivative of the function at the zero
+struct BesselZero {
+    hi: TwoFloat,
+    lo: f64,
+    slope: TwoFloat,
+}
+
+// Euler-Mascheroni constant
+const EULER_GAMMA: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.2788cfc6fb619p-1"),
+    lo: hexf64!("-0x1.6cb90701fbfabp-58"),
+};
+
+// sqrt(2/pi)
+const SQRT_FRAC_2_PI: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.9884533d43651p-1"),
+    lo: hexf64!("-0x1.cbc0d30ebfd15p-55"),
+};
+
+// 1/sqrt(2*pi)
+const FRAC_1_SQRT_2PI: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.9884533d43651p-2"),
+    lo: hexf64!("-0x1.cbc0d30ebfd15p-56"),
+};
+
+// sqrt(pi/2)
+const SQRT_FRAC_PI_2: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.40d931ff62706p+0"),
+    lo: hexf64!("-0x1.a6a0d6f814637p-54"),
+};
+
+// Zeros of j0(x) below the asymptotic limit together with the derivative
+// -j1(x) at each zero
+const J0_ZEROS: [BesselZero; 14] = [
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.33d152e971b40p+1"),
+            lo: hexf64!("-0x1.0f539d7da258ep-53"),
+        },
+        lo: hexf64!("-0x1.646effa90e9e4p-107"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.09cdb36551280p-1"),
+            lo: hexf64!("-0x1.ac8cc3d6bafa5p-55"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.6148f5b2c2e45p+2"),
+            lo: hexf64!("0x1.75054cd60a517p-54"),
+        },
+        lo: hexf64!("-0x1.2c78a130dfed1p-112"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.5c6e60a097823p-2"),
+            lo: hexf64!("-0x1.af17f78e58353p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.14eb56cccdecap+3"),
+            lo: hexf64!("-0x1.51970714c7c25p-52"),
+        },
+        lo: hexf64!("-0x1.3d1debae8c3cfp-107"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.15f7977a772d4p-2"),
+            lo: hexf64!("0x1.0b85158068ef8p-56"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.79544008272b6p+3"),
+            lo: hexf64!("0x1.444fd5821d5b1p-52"),
+        },
+        lo: hexf64!("-0x1.fea27c5f7d5e2p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.dc13e66ac2e77p-3"),
+            lo: hexf64!("-0x1.6d72d40e790b3p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.ddca13ef271d2p+3"),
+            lo: hexf64!("-0x1.9796609364e85p-51"),
+        },
+        lo: hexf64!("0x1.92f3fbe39942bp-106"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.a701d0f967500p-3"),
+            lo: hexf64!("0x1.2010996eec734p-60"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.212313f8a19f6p+4"),
+            lo: hexf64!("-0x1.165fd108f46ffp-50"),
+        },
+        lo: hexf64!("0x1.838a1ec94e4d5p-105"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.8077f56c9b782p-3"),
+            lo: hexf64!("0x1.a4f96a2520badp-59"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.5362dd173f792p+4"),
+            lo: hexf64!("0x1.1d2dfa1c3b5a8p-51"),
+        },
+        lo: hexf64!("0x1.f31f030a8fd2dp-106"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.62d93aa9d05bbp-3"),
+            lo: hexf64!("-0x1.444d3d89ac00fp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.85a3b930156ddp+4"),
+            lo: hexf64!("0x1.0847c620015e0p-50"),
+        },
+        lo: hexf64!("-0x1.51158cd6818fbp-105"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.4b2a2ebf61ecep-3"),
+            lo: hexf64!("-0x1.e5d93454f99e3p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.b7e54a5fd5f11p+4"),
+            lo: hexf64!("0x1.d2b3714972b28p-50"),
+        },
+        lo: hexf64!("0x1.53c8753c8e8c7p-105"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.37aac8c1aeabbp-3"),
+            lo: hexf64!("0x1.948539688f9cfp-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.ea27591cbbed2p+4"),
+            lo: hexf64!("-0x1.36bbabc1c9f31p-51"),
+        },
+        lo: hexf64!("-0x1.8875f801a4f68p-112"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.27407dfadee6dp-3"),
+            lo: hexf64!("-0x1.89c717cff1ebap-60"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.0e34e13a66fe6p+5"),
+            lo: hexf64!("0x1.a326cf4307839p-50"),
+        },
+        lo: hexf64!("0x1.6cc100aaeb559p-104"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.192f23ce3e051p-3"),
+            lo: hexf64!("0x1.f5f4b08a76fd4p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.275637a9619ecp+5"),
+            lo: hexf64!("-0x1.0b6068f861c6fp-50"),
+        },
+        lo: hexf64!("-0x1.78a15f1ffc666p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.0cf3ed059c573p-3"),
+            lo: hexf64!("0x1.3f099a5f56db3p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.4077a7ed6293ap+5"),
+            lo: hexf64!("-0x1.34c86f4e27936p-52"),
+        },
+        lo: hexf64!("-0x1.169cf6441fcdbp-107"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.0230b9797a7b3p-3"),
+            lo: hexf64!("0x1.65439df5bb54cp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.59992c65d0d8dp+5"),
+            lo: hexf64!("0x1.ed48fe99f45efp-51"),
+        },
+        lo: hexf64!("0x1.4d78f0fbc4f3bp-105"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.f13faf32c8e0ap-4"),
+            lo: hexf64!("0x1.81bdf89b0a8b1p-58"),
+        },
+    },
+];
+
+// Positive zeros of j1(x) below the asymptotic limit together with the
+// derivative j0(x) at each zero
+const J1_ZEROS: [BesselZero; 14] = [
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.ea75575af6f09p+1"),
+            lo: hexf64!("-0x1.60155a9d1b256p-53"),
+        },
+        lo: hexf64!("-0x1.fb72b16a8f55ep-108"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.9c6cf582cbf7fp-2"),
+            lo: hexf64!("0x1.2de1143765a96p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.c0ff5f3b47250p+2"),
+            lo: hexf64!("-0x1.b226d9d243827p-54"),
+        },
+        lo: hexf64!("-0x1.3ceb4a3a76e2cp-109"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.33518b3874e8ap-2"),
+            lo: hexf64!("0x1.af22d033ee0a4p-56"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.458d0d0bdfc29p+3"),
+            lo: hexf64!("0x1.02610a51562b6p-51"),
+        },
+        lo: hexf64!("0x1.2b2309fae859bp-105"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.ff654544ebcd1p-3"),
+            lo: hexf64!("-0x1.052a3a2541c57p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.aa5baf310e5a2p+3"),
+            lo: hexf64!("0x1.2bce7fd18e693p-52"),
+        },
+        lo: hexf64!("0x1.1cf438a4cb2a5p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.bf3337873a7d8p-3"),
+            lo: hexf64!("0x1.c8c66d2e42062p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.0787b360508c5p+4"),
+            lo: hexf64!("-0x1.d2a68e88ab317p-50"),
+        },
+        lo: hexf64!("-0x1.439271688c74bp-104"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.925c6fca08f55p-3"),
+            lo: hexf64!("0x1.e9557ccd1703fp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.39da8e7416ca4p+4"),
+            lo: hexf64!("-0x1.21830197e9e86p-50"),
+        },
+        lo: hexf64!("0x1.9e2c22f2a4746p-107"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.70c511227d5aap-3"),
+            lo: hexf64!("0x1.2da0057f84d3cp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.6c294e3d4d8acp+4"),
+            lo: hexf64!("-0x1.1bf33afef88f1p-51"),
+        },
+        lo: hexf64!("-0x1.ad010dfc461bbp-107"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.5664e13b70622p-3"),
+            lo: hexf64!("0x1.a47ab4241a9f5p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.9e7570dcea106p+4"),
+            lo: hexf64!("0x1.1a2686480d882p-51"),
+        },
+        lo: hexf64!("0x1.f4590c173c5bdp-109"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.40f90793605bbp-3"),
+            lo: hexf64!("0x1.316f8ffd294bcp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.d0bfcf471fcccp+4"),
+            lo: hexf64!("-0x1.42ce39ec976fbp-52"),
+        },
+        lo: hexf64!("0x1.36c2ced5f1616p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.2f2072e638cf4p-3"),
+            lo: hexf64!("0x1.89d1f48185c7ep-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.018476e6b2bf0p+5"),
+            lo: hexf64!("-0x1.be3a1cd066b66p-50"),
+        },
+        lo: hexf64!("-0x1.3a4e4f34767f8p-105"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.1ff5eec6a01cdp-3"),
+            lo: hexf64!("0x1.1f9b16832f362p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.1aa890dc5e97cp+5"),
+            lo: hexf64!("-0x1.d5fbbff045068p-49"),
+        },
+        lo: hexf64!("0x1.d57b4bc69d984p-107"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.12dd57bf18adap-3"),
+            lo: hexf64!("0x1.e71c482be67bdp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.33cc523d5cb69p+5"),
+            lo: hexf64!("0x1.9eafeca0ca4fdp-51"),
+        },
+        lo: hexf64!("-0x1.97a6541467645p-105"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.076826cc2c191p-3"),
+            lo: hexf64!("0x1.1a13e2fee5687p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.4cefcf1734b62p+5"),
+            lo: hexf64!("0x1.489bd556e510ap-51"),
+        },
+        lo: hexf64!("-0x1.8e28e36ab8d96p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.fa8b41711c83ap-4"),
+            lo: hexf64!("0x1.d7cc4171715a0p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.661315d6b133fp+5"),
+            lo: hexf64!("0x1.4f716f3179d90p-49"),
+        },
+        lo: hexf64!("0x1.0730ba0b6447fp-104"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.e8727daa3daedp-4"),
+            lo: hexf64!("0x1.020b4016594acp-63"),
+        },
+    },
+];
+
+// Zeros of y0(x) below the asymptotic limit together with the derivative
+// -y1(x) at each zero
+const Y0_ZEROS: [BesselZero; 15] = [
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.c982eb8d417eap-1"),
+            lo: hexf64!("0x1.ea9d270347f83p-56"),
+        },
+        lo: hexf64!("-0x1.2c2f4d6e99a1fp-112"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.c24371844b88ap-1"),
+            lo: hexf64!("-0x1.225c9ba2f376cp-56"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.fa9534d98569cp+1"),
+            lo: hexf64!("-0x1.f06ae7804384ep-54"),
+        },
+        lo: hexf64!("0x1.a02d2e779d6a4p-109"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.9c34256a12a0cp-2"),
+            lo: hexf64!("-0x1.a4026e436c4d3p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.c581dc4e72103p+2"),
+            lo: hexf64!("-0x1.9774a495f56cfp-54"),
+        },
+        lo: hexf64!("0x1.acc2b9cf914d1p-113"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.334cca0697a5bp-2"),
+            lo: hexf64!("-0x1.8d4484b7cd2a6p-56"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.471d735a47d58p+3"),
+            lo: hexf64!("-0x1.cb49ff791c495p-51"),
+        },
+        lo: hexf64!("0x1.ed5a6b6512a7bp-105"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.ff635cc72b9f1p-3"),
+            lo: hexf64!("0x1.1e8f568f8c6b9p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.ab8e1c4a1e74ap+3"),
+            lo: hexf64!("-0x1.7df81de86f24dp-51"),
+        },
+        lo: hexf64!("-0x1.a7b72d7171b65p-105"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.bf32a27594007p-3"),
+            lo: hexf64!("0x1.1398cacaa32d4p-59"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.0803c74003214p+4"),
+            lo: hexf64!("0x1.25a237d12159bp-50"),
+        },
+        lo: hexf64!("-0x1.127f27b040becp-104"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.925c35988ee29p-3"),
+            lo: hexf64!("-0x1.5b9c39e42719ep-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.3a42cdf5febd7p+4"),
+            lo: hexf64!("-0x1.8bf92d51fbaebp-50"),
+        },
+        lo: hexf64!("0x1.a0f4fe0d26f69p-104"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.70c4f66cab47fp-3"),
+            lo: hexf64!("0x1.4fef53f4893e5p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.6c832fd77ac07p+4"),
+            lo: hexf64!("0x1.ca75080cf53a8p-50"),
+        },
+        lo: hexf64!("0x1.c4842d83249d9p-105"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.5664d37c37d7bp-3"),
+            lo: hexf64!("0x1.fe2103f7148bbp-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.9ec46f3e80146p+4"),
+            lo: hexf64!("-0x1.03e052bd9c0afp-52"),
+        },
+        lo: hexf64!("0x1.45efea056332cp-107"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.40f8ffdf09a5fp-3"),
+            lo: hexf64!("0x1.f3474ffad3fd7p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.d106449616c4fp+4"),
+            lo: hexf64!("0x1.0aab17eca74b9p-50"),
+        },
+        lo: hexf64!("-0x1.5904a3b5988fdp-104"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.2f206e49909c7p-3"),
+            lo: hexf64!("-0x1.69479644686c0p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.01a4420e4abeep+5"),
+            lo: hexf64!("0x1.d2f18aa8a8f2fp-49"),
+        },
+        lo: hexf64!("-0x1.db474a885680ep-104"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.1ff5ebddd3c3ap-3"),
+            lo: hexf64!("-0x1.be2029a752b31p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.1ac588c944279p+5"),
+            lo: hexf64!("-0x1.9dd1578036d11p-53"),
+        },
+        lo: hexf64!("0x1.7f484eb36170ap-107"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.12dd55d4be2b3p-3"),
+            lo: hexf64!("-0x1.e7aa4db2a788ap-59"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.33e6ecf5cb221p+5"),
+            lo: hexf64!("-0x1.9c3dd43e59158p-49"),
+        },
+        lo: hexf64!("0x1.41373c61d155ap-103"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.0768257dad56ap-3"),
+            lo: hexf64!("-0x1.4353fd6c42f1dp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.4d0867ec213f3p+5"),
+            lo: hexf64!("-0x1.753b7fcd5250cp-49"),
+        },
+        lo: hexf64!("0x1.d340d65509798p-107"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.fa8b3f9ae4375p-4"),
+            lo: hexf64!("0x1.0417847765c19p-62"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.6629f4e1e0321p+5"),
+            lo: hexf64!("0x1.c2eb6ee3e4c70p-49"),
+        },
+        lo: hexf64!("0x1.0b05b3fde1c5bp-104"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.e8727c572a2c2p-4"),
+            lo: hexf64!("0x1.6a7c2ed8fa844p-58"),
+        },
+    },
+];
+
+// Zeros of y1(x) below the asymptotic limit together with the derivative
+// y0(x) at each zero
+const Y1_ZEROS: [BesselZero; 14] = [
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.193bed4dff243p+1"),
+            lo: hexf64!("-0x1.bd1e50d219bfdp-55"),
+        },
+        lo: hexf64!("-0x1.eaabce933bf64p-114"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.0aa48442f014bp-1"),
+            lo: hexf64!("-0x1.49367c4c05aaap-56"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.5b7fe4e87b02ep+2"),
+            lo: hexf64!("0x1.dfe7bac228e8cp-52"),
+        },
+        lo: hexf64!("-0x1.11f731cc08f30p-108"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.5c7c556f0c19ap-2"),
+            lo: hexf64!("0x1.b8d2a1c496808p-56"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.13127ae6169b4p+3"),
+            lo: hexf64!("0x1.479cc068d9046p-52"),
+        },
+        lo: hexf64!("0x1.b7d6c9367e7b0p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.15f993fceab5cp-2"),
+            lo: hexf64!("0x1.1dc672a53c590p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.77f9138d43206p+3"),
+            lo: hexf64!("0x1.0fc786ce06080p-55"),
+        },
+        lo: hexf64!("0x1.35abbfaecbf17p-110"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.dc14ea14e89f9p-3"),
+            lo: hexf64!("0x1.4d14c77bc1691p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.dcb7d88de848bp+3"),
+            lo: hexf64!("-0x1.5e091a50f8e05p-51"),
+        },
+        lo: hexf64!("0x1.c30291e6af093p-108"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.a7022be084d99p-3"),
+            lo: hexf64!("0x1.7ba12cd0fc91fp-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.20b1c695f1e3bp+4"),
+            lo: hexf64!("-0x1.a1ee4c5487edep-50"),
+        },
+        lo: hexf64!("0x1.d168996eb7494p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.80781c32422e7p-3"),
+            lo: hexf64!("-0x1.3db68c567283bp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.53025492188cdp+4"),
+            lo: hexf64!("0x1.391b14410528fp-50"),
+        },
+        lo: hexf64!("-0x1.1b2b43e52da62p-104"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.62d94d97e859cp-3"),
+            lo: hexf64!("-0x1.d2f0105f3ce7cp-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.854fa303820cap+4"),
+            lo: hexf64!("0x1.52f75f025b205p-52"),
+        },
+        lo: hexf64!("0x1.9f22ca387f225p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.4b2a38f1ab9b4p-3"),
+            lo: hexf64!("-0x1.e9088e9ff2519p-58"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.b79acee8cfb7dp+4"),
+            lo: hexf64!("-0x1.cf130fbea3b24p-52"),
+        },
+        lo: hexf64!("-0x1.705e22ce5abfbp-106"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.37aaceac987b9p-3"),
+            lo: hexf64!("0x1.997782859a00dp-59"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.e9e480605283cp+4"),
+            lo: hexf64!("-0x1.e7a77047d6166p-54"),
+        },
+        lo: hexf64!("0x1.e1171e4759ab9p-109"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.2740819f1caaap-3"),
+            lo: hexf64!("0x1.b7326e3fbaa70p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.0e16907f8fb56p+5"),
+            lo: hexf64!("-0x1.96beabef7ecf4p-49"),
+        },
+        lo: hexf64!("-0x1.85f6251a311f6p-107"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.192f2627a74e3p-3"),
+            lo: hexf64!("-0x1.081c2a50ad27bp-59"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.273a7b35a7affp+5"),
+            lo: hexf64!("0x1.2481e87adfe57p-50"),
+        },
+        lo: hexf64!("0x1.41b03ec15e37ap-105"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.0cf3ee98f769bp-3"),
+            lo: hexf64!("0x1.0c06e2860e868p-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.405e18393afb5p+5"),
+            lo: hexf64!("0x1.a8ffacaac8461p-50"),
+        },
+        lo: hexf64!("-0x1.fe4b430a6db49p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("0x1.0230ba90f2871p-3"),
+            lo: hexf64!("0x1.1166b7995967ap-57"),
+        },
+    },
+    BesselZero {
+        hi: TwoFloat {
+            hi: hexf64!("0x1.5981787d668dbp+5"),
+            lo: hexf64!("0x1.fe463face2c1cp-52"),
+        },
+        lo: hexf64!("-0x1.81e163a44db36p-106"),
+        slope: TwoFloat {
+            hi: hexf64!("-0x1.f13fb0c0e6fcdp-4"),
+            lo: hexf64!("0x1.0db2c50623ec0p-58"),
+        },
+    },
+];
+
+// Computes the sum of t^k n!/(k! (k + n)!) where t = x^2/4, or -x^2/4 if
+// `negate` is set. The number of terms is found using f64 arithmetic, and
+// the series is then evaluated in nested form, which avoids accumulating
+// rounding errors in the terms. The square is kept in two parts since the
+// relative error in t is multiplied by the number of significant terms.
+fn power_series(x: TwoFloat, n: u32, negate: bool) -> TwoFloat {
+    let (mut t_hi, mut t_lo) = split_square(x);
+    let scale = if negate { -0.25 } else { 0.25 };
+    t_hi *= scale;
+    t_lo *= scale;
+
+    let mut terms = 0;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    while libm::fabs(term) > libm::fabs(sum) * TERM_EPSILON {
+        terms += 1;
+        term *= t_hi.hi / (terms * (terms + n)) as f64;
+        sum += term;
+    }
+
+    let mut result = TwoFloat::from(1.0);
+    for k in (1..=terms).rev() {
+        result = (result * t_hi + result * t_lo) / (k * (k + n)) as f64 + 1.0;
+    }
+    result
+}
+
+// Computes the sum of (H(k) + H(k + n)) t^k n!/(k! (k + n)!) for n = 0 or 1,
+// where H(k) is the kth harmonic number and t is as in `power_series`
+fn harmonic_series(x: TwoFloat, n: u32, negate: bool) -> TwoFloat {
+    let t = if negate { x * x * -0.25 } else { x * x * 0.25 };
+    let mut term = TwoFloat::from(1.0);
+    let mut harmonic = TwoFloat::from(0.0);
+    let mut harmonic_n = TwoFloat::from(n as f64);
+    let mut sum = harmonic_n;
+    for k in 1.. {
+        term = term * t / ((k * (k + n)) as f64);
+        harmonic += TwoFloat::from(k as f64).recip();
+        harmonic_n += TwoFloat::from((k + n) as f64).recip();
+        let weighted = term * (harmonic + harmonic_n);
+        sum += weighted;
+        if weighted.abs() <= sum.abs() * TERM_EPSILON {
+            break;
+        }
+    }
+    sum
+}
+
+// Computes ln(x/2) + gamma, which appears in the expansions of the
+// functions of the second kind about zero
+fn log_term(x: TwoFloat) -> TwoFloat {
+    x.ln() - LN_2 + EULER_GAMMA
+}
+
+// Computes j0(x), j1(x), y0(x) and y1(x) for x >= 1 using Miller's backward
+// recurrence normalized by j0(x) + 2 sum j2k(x) = 1, with the functions of
+// the second kind obtained from their Neumann series
+fn miller(x: TwoFloat) -> (TwoFloat, TwoFloat, TwoFloat, TwoFloat) {
+    let start = 2 * (libm::ceil(0.5 * (x.hi + 30.0 + 12.0 * libm::cbrt(x.hi))) as u32);
+
+    // Unnormalized values of j(k+1), j(k) and the sums over the even
+    // orders, including sum (-1)^(m+1) j2m/m and
+    // sum (-1)^(m+1) (j(2m-1) - j(2m+1))/m
+    let mut next = TwoFloat::from(0.0);
+    let mut current = TwoFloat::from(1.0);
+    let mut norm = TwoFloat::from(0.0);
+    let mut sum0 = TwoFloat::from(0.0);
+    let mut sum1 = TwoFloat::from(0.0);
+    let mut sign = if start % 4 == 0 { -1.0 } else { 1.0 };
+    for k in (1..=start).rev() {
+        let previous = current * (2 * k) as f64 / x - next;
+        if k % 2 == 0 {
+            let m = (k / 2) as f64;
+            norm += current * 2.0;
+            sum0 += current * sign / m;
+            sum1 += (previous - next) * sign / m;
+            sign = -sign;
+        }
+
+        next = current;
+        current = previous;
+        if current.hi.abs() > RECURRENCE_LIMIT {
+            next *= RECURRENCE_SCALE;
+            current *= RECURRENCE_SCALE;
+            norm *= RECURRENCE_SCALE;
+            sum0 *= RECURRENCE_SCALE;
+            sum1 *= RECURRENCE_SCALE;
+        }
+    }
+
+    norm += current;
+    let j0 = current / norm;
+    let j1 = next / norm;
+    let l = log_term(x);
+    let y0 = (l * j0 + sum0 * 2.0 / norm) * FRAC_2_PI;
+    let y1 = (l * j1 - j0 / x - sum1 / norm) * FRAC_2_PI;
+    (j0, j1, y0, y1)
+}
+
+// Computes jn(x) for n >= x >= 1 using Miller's backward recurrence
+fn jn_backward(x: TwoFloat, n: u32) -> TwoFloat {
+    let start = 2 * (libm::ceil(0.5 * (n as f64 + 30.0 + 12.0 * libm::cbrt(x.hi))) as u32);
+
+    let mut next = TwoFloat::from(0.0);
+    let mut current = TwoFloat::from(1.0);
+    let mut norm = TwoFloat::from(0.0);
+    let mut result = TwoFloat::from(0.0);
+    for k in (1..=start).rev() {
+        if k == n {
+            result = current;
+        }
+        if k % 2 == 0 {
+            norm += current * 2.0;
+        }
+
+        let previous = current * (2 * k) as f64 / x - next;
+        next = current;
+        current = previous;
+        if current.hi.abs() > RECURRENCE_LIMIT {
+            next *= RECURRENCE_SCALE;
+            current *= RECURRENCE_SCALE;
+            norm *= RECURRENCE_SCALE;
+            result *= RECURRENCE_SCALE;
+        }
+    }
+
+    norm += current;
+    result / norm
+}
+
+// Returns the tabulated zero close to x, if any. The width of the interval
+// is limited for small zeros of the functions of the second kind, whose
+// expansions converge more slowly due to the singularity at zero.
+fn nearby_zero(x: TwoFloat, zeros: &[BesselZero]) -> Option<&BesselZero> {
+    zeros
+        .iter()
+        .find(|zero| libm::fabs(x.hi - zero.hi.hi) < libm::fmin(ZERO_WIDTH, 0.25 * zero.hi.hi))
+}
+
+// Evaluates a solution of the Bessel equation of order nu close to one of
+// its zeros using the Taylor series about the zero, where the coefficients
+// are obtained from the differential equation
+//   x^2 y'' + x y' + (x^2 - nu^2) y = 0
+fn zero_expansion(x: TwoFloat, zero: &BesselZero, nu: f64) -> TwoFloat {
+    let z = zero.hi;
+    let h = (x - z) - zero.lo;
+    let h2 = h * h;
+    let zh = z * h;
+    let zh3 = zh * h2 * 2.0;
+    let h4 = h2 * h2;
+    let z2 = z * z;
+    let c = z2 - nu * nu;
+
+    // Terms a(k) h^k for k = m - 2, ..., m + 1
+    let mut terms = [
+        TwoFloat::from(0.0),
+        TwoFloat::from(0.0),
+        TwoFloat::from(0.0),
+        zero.slope * h,
+    ];
+    let mut sum = terms[3];
+    for m in 0..ZERO_TERMS {
+        let m1 = (m + 1) as f64;
+        let mf = m as f64;
+        let next = -(zh * terms[3] * (m1 * (2.0 * mf + 1.0))
+            + (c + mf * mf) * h2 * terms[2]
+            + zh3 * terms[1]
+            + h4 * terms[0])
+            / (z2 * (m1 * (mf + 2.0)));
+        sum += next;
+        terms = [terms[1], terms[2], terms[3], next];
+        if next.abs() <= sum.abs() * TERM_EPSILON && terms[2].abs() <= sum.abs() * TERM_EPSILON {
+            break;
+        }
+    }
+    sum
+}
+
+// Computes the even and odd parts of the asymptotic series sum a(k)/x^k,
+// where a(k) = (mu - 1)(mu - 9)...(mu - (2k - 1)^2)/(k! 8^k) and mu = 4n^2.
+// If `alternate` is set, the signs of the terms are changed so that the even
+// and odd parts are the P and Q functions of the Hankel expansion.
+fn asymptotic_series(x: TwoFloat, n: u32, alternate: bool) -> (TwoFloat, TwoFloat) {
+    // mu is exact, as it may exceed 2^53 for large orders
+    let mu = TwoFloat::new_mul(n as f64, n as f64) * 4.0;
+    let mut even = TwoFloat::from(1.0);
+    let mut odd = TwoFloat::from(0.0);
+    let mut term = TwoFloat::from(1.0);
+    for k in 1.. {
+        let odd_square = ((2 * k - 1) * (2 * k - 1)) as f64;
+        let next = term * (mu - odd_square) / (8 * k) as f64 / x;
+        if next.abs() >= term.abs() {
+            break;
+        }
+
+        term = if alternate && k % 2 == 0 { -next } else { next };
+        if k % 2 == 0 {
+            even += term;
+        } else {
+            odd += term;
+        }
+
+        if term.abs() <= even.abs() * TERM_EPSILON {
+            break;
+        }
+    }
+    (even, odd)
+}
+
+// Computes jn(x) and yn(x) for x large compared to n using the Hankel
+// asymptotic expansions
+fn hankel(x: TwoFloat, order: u32) -> (TwoFloat, TwoFloat) {
+    let (p, q) = asymptotic_series(x, order, true);
+
+    // The phase x - (2 order + 1) pi/4 is reduced to r - pi/4 and a
+    // number of quarter periods
+    let (r, quadrant) = rem_frac_pi_2(x);
+    let (s, c) = (r - FRAC_PI_4).sin_cos();
+    let (s, c) = match (quadrant as u32).wrapping_sub(order) & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+
+    let scale = SQRT_FRAC_2_PI / x.sqrt();
+    (scale * (p * c - q * s), scale * (p * s + q * c))
+}
+
+// Computes exp(x) k0(x) and exp(x) k1(x) for x >= 1 by applying the
+// trapezoidal rule to the integral of exp(-x (cosh(t) - 1)) cosh(nu t) for
+// t from 0 to infinity
+fn k_trapezoid(x: TwoFloat) -> (TwoFloat, TwoFloat) {
+    let mut sum0 = TwoFloat::from(0.5);
+    let mut sum1 = TwoFloat::from(0.5);
+    for j in 1.. {
+        let s = TwoFloat::from(0.5 * K_STEP * j as f64).sinh();
+        let c = s * s * 2.0;
+        let term = (-x * c).exp();
+        let term1 = term * (c + 1.0);
+        sum0 += term;
+        sum1 += term1;
+        if term1.hi <= sum1.hi * TERM_EPSILON {
+            break;
+        }
+    }
+    (sum0 * K_STEP, sum1 * K_STEP)
+}
+
+// Computes i0(x) or i1(x) for x >= 0
+fn i_positive(x: TwoFloat, order: u32) -> TwoFloat {
+    if x.hi < ASYMPTOTIC_LIMIT {
+        let s = power_series(x, order, false);
+        if order == 0 {
+            s
+        } else {
+            s * x * 0.5
+        }
+    } else {
+        // exp(x) is split to avoid overflow before the division
+        let (even, odd) = asymptotic_series(x, order, false);
+        let e = (x * 0.5).exp();
+        let result = e * (e * FRAC_1_SQRT_2PI / x.sqrt()) * (even - odd);
+        if result.hi.is_finite() {
+            result
+        } else {
+            TwoFloat::INFINITY
+        }
+    }
+}
+
+// Computes k0(x) or k1(x) for x > 0
+fn k_positive(x: TwoFloat, order: u32) -> TwoFloat {
+    if x.hi <= SERIES_LIMIT {
+        let l = log_term(x);
+        let t = harmonic_series(x, order, false);
+        if order == 0 {
+            t * 0.5 - l * power_series(x, 0, false)
+        } else {
+            let r = x.recip();
+            if !r.hi.is_finite() {
+                return TwoFloat::INFINITY;
+            }
+            let i1 = power_series(x, 1, false) * x * 0.5;
+            r + l * i1 - t * x * 0.25
+        }
+    } else if x.hi < ASYMPTOTIC_LIMIT {
+        let (k0, k1) = k_trapezoid(x);
+        (if order == 0 { k0 } else { k1 }) * (-x).exp()
+    } else {
+        let (even, odd) = asymptotic_series(x, order, false);
+        SQRT_FRAC_PI_2 / x.sqrt() * (-x).exp() * (even + odd)
+    }
+}
+
+// Computes y0(x) or y1(x) for x > 0
+fn y_positive(x: TwoFloat, order: u8) -> TwoFloat {
+    let (zeros, nu) = if order == 0 {
+        (&Y0_ZEROS[..], 0.0)
+    } else {
+        (&Y1_ZEROS[..], 1.0)
+    };
+
+    if x.hi < ASYMPTOTIC_LIMIT {
+        if let Some(zero) = nearby_zero(x, zeros) {
+            return zero_expansion(x, zero, nu);
+        }
+    }
+
+    if x.hi < SERIES_LIMIT {
+        let l = log_term(x);
+        let t = harmonic_series(x, order as u32, true);
+        let result = if order == 0 {
+            l * power_series(x, 0, true) - t * 0.5
+        } else {
+            let r = x.recip();
+            if !r.hi.is_finite() {
+                return TwoFloat::NEG_INFINITY;
+            }
+            let j1 = power_series(x, 1, true) * x * 0.5;
+            l * j1 - r - t * x * 0.25
+        };
+        result * FRAC_2_PI
+    } else if x.hi < ASYMPTOTIC_LIMIT {
+        let (_, _, y0, y1) = miller(x);
+        if order == 0 {
+            y0
+        } else {
+            y1
+        }
+    } else {
+        hankel(x, order as u32).1
+    }
+}
+
+// Computes j0(x) or j1(x) for x >= 0
+fn j_positive(x: TwoFloat, order: u8) -> TwoFloat {
+    let (zeros, nu) = if order == 0 {
+        (&J0_ZEROS[..], 0.0)
+    } else {
+        (&J1_ZEROS[..], 1.0)
+    };
+
+    if x.hi < SERIES_LIMIT {
+        let s = power_series(x, order as u32, true);
+        if order == 0 {
+            s
+        } else {
+            s * x * 0.5
+        }
+    } else if x.hi < ASYMPTOTIC_LIMIT {
+        if let Some(zero) = nearby_zero(x, zeros) {
+            zero_expansion(x, zero, nu)
+        } else {
+            let (j0, j1, _, _) = miller(x);
+            if order == 0 {
+                j0
+            } else {
+                j1
+            }
+        }
+    } else {
+        hankel(x, order as u32).0
+    }
+}
+
+// Checks whether the Hankel expansions are accurate for order n, which
+// holds for x >= n^2 as well as above the limit for orders zero and one
+fn hankel_applies(x: TwoFloat, n: u32) -> bool {
+    x.hi >= ASYMPTOTIC_LIMIT && x.hi >= n as f64 * n as f64
+}
+
+// Computes jn(x) for n >= 2 and x > 0
+fn jn_positive(x: TwoFloat, n: u32) -> TwoFloat {
+    if x.hi < SERIES_LIMIT {
+        // (x/2)^n/n!, stopping early if it underflows
+        let half = x * 0.5;
+        let mut prefactor = TwoFloat::from(1.0);
+        for k in 1..=n {
+            prefactor = prefactor * half / k as f64;
+            if prefactor.hi == 0.0 {
+                return prefactor;
+            }
+        }
+        prefactor * power_series(x, n, true)
+    } else if hankel_applies(x, n) {
+        hankel(x, n).0
+    } else if (n as f64) < x.hi {
+        if n > RECURRENCE_ORDER_LIMIT {
+            return TwoFloat::NAN;
+        }
+
+        let mut previous = j_positive(x, 0);
+        let mut current = j_positive(x, 1);
+        for k in 1..n {
+            let next = current * (2 * k) as f64 / x - previous;
+            previous = current;
+            current = next;
+        }
+        current
+    } else if n as f64 * libm::log(0.5 * core::f64::consts::E * x.hi / n as f64) < -800.0 {
+        // jn(x) < (e x/2n)^n underflows
+        TwoFloat::from(0.0)
+    } else if n > RECURRENCE_ORDER_LIMIT {
+        TwoFloat::NAN
+    } else {
+        jn_backward(x, n)
+    }
+}
+
+// Computes yn(x) for n >= 2 and x > 0
+fn yn_positive(x: TwoFloat, n: u32) -> TwoFloat {
+    if hankel_applies(x, n) {
+        return hankel(x, n).1;
+    } else if n as f64 * libm::log(2.0 * n as f64 / (core::f64::consts::E * x.hi)) > 800.0 {
+        // |yn(x)| is close to (2n/e x)^n sqrt(2/pi n), which overflows
+        return TwoFloat::NEG_INFINITY;
+    } else if n > RECURRENCE_ORDER_LIMIT {
+        return TwoFloat::NAN;
+    }
+
+    let mut previous = y_positive(x, 0);
+    let mut current = y_positive(x, 1);
+    for k in 1..n {
+        let next = current * (2 * k) as f64 / x - previous;
+        if !next.hi.is_finite() {
+            return TwoFloat::NEG_INFINITY;
+        }
+        previous = current;
+        current = next;
+    }
+    current
+}
+
+impl TwoFloat {
+    /// Computes the Bessel function of the first kind of order zero.
+    ///
+    /// Small arguments use the power series and large arguments use the
+    /// Hankel asymptotic expansion. Between these, the Taylor series about
+    /// the nearest zero is used so that the relative error remains small
+    /// close to the zeros. For arguments above 45 the error is instead
+    /// bounded relative to the amplitude of the oscillations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.5).j0();
+    /// let b = TwoFloat::new_add(-0.048383776468198, 1.272741446544498e-18);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn j0(self) -> Self {
+        if self.hi.is_nan() {
+            Self::NAN
+        } else if self.hi.is_infinite() {
+            Self::from(0.0)
+        } else {
+            j_positive(self.abs(), 0)
+        }
+    }
+
+    /// Computes the Bessel function of the first kind of order one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.5).j1();
+    /// let b = TwoFloat::new_add(0.49709410246427405, -7.772027537603235e-18);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn j1(self) -> Self {
+        if self.hi.is_nan() {
+            return Self::NAN;
+        }
+
+        let result = if self.hi.is_infinite() {
+            Self::from(0.0)
+        } else {
+            j_positive(self.abs(), 1)
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Computes the Bessel function of the first kind of integer order `n`.
+    ///
+    /// Arguments of at least 45 and the square of the order use the Hankel
+    /// asymptotic expansion. Otherwise, orders below the argument are
+    /// computed using the forward recurrence from `j0` and `j1`, and higher
+    /// orders using Miller's backward recurrence. The recurrences are
+    /// limited to orders up to 65536, and NaN is returned for higher orders
+    /// unless the result underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(3.0).jn(5);
+    /// let b = TwoFloat::new_add(0.043028434877047585, -7.585736105302113e-19);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn jn(self, n: i32) -> Self {
+        let order = n.unsigned_abs();
+        let result = match order {
+            0 => return self.j0(),
+            1 => self.abs().j1(),
+            _ if self.hi.is_nan() => return Self::NAN,
+            _ if self.hi.is_infinite() || self.hi == 0.0 => Self::from(0.0),
+            _ => jn_positive(self.abs(), order),
+        };
+
+        // jn(-x) = (-1)^n jn(x) and j(-n)(x) = (-1)^n jn(x)
+        if order % 2 == 1 && (n < 0) != self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Computes the Bessel function of the second kind of order zero.
+    /// Returns NaN for negative arguments.
+    ///
+    /// The method is the same as for `j0`, except that Miller's backward
+    /// recurrence together with the Neumann series is used for small
+    /// arguments which are not close to a zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.5).y0();
+    /// let b = TwoFloat::new_add(0.4980703596152319, 4.32860753262344e-18);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn y0(self) -> Self {
+        if self.hi.is_nan() || self.hi < 0.0 {
+            Self::NAN
+        } else if self.hi == 0.0 {
+            Self::NEG_INFINITY
+        } else if self.hi.is_infinite() {
+            Self::from(0.0)
+        } else {
+            y_positive(self, 0)
+        }
+    }
+
+    /// Computes the Bessel function of the second kind of order one.
+    /// Returns NaN for negative arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.5).y1();
+    /// let b = TwoFloat::new_add(0.1459181379667858, 5.254335313012183e-18);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn y1(self) -> Self {
+        if self.hi.is_nan() || self.hi < 0.0 {
+            Self::NAN
+        } else if self.hi == 0.0 {
+            Self::NEG_INFINITY
+        } else if self.hi.is_infinite() {
+            Self::from(0.0)
+        } else {
+            y_positive(self, 1)
+        }
+    }
+
+    /// Computes the Bessel function of the second kind of integer order `n`
+    /// using the forward recurrence from `y0` and `y1`, or the Hankel
+    /// asymptotic expansion for arguments of at least 45 and the square of
+    /// the order. Returns NaN for negative arguments, and for orders above
+    /// 65536 when neither the expansion applies nor the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(10.0).yn(3);
+    /// let b = TwoFloat::new_add(-0.2513626571838373, -6.185781995343952e-18);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn yn(self, n: i32) -> Self {
+        let order = n.unsigned_abs();
+        let result = match order {
+            0 => return self.y0(),
+            1 => self.y1(),
+            _ if self.hi.is_nan() || self.hi < 0.0 => return Self::NAN,
+            _ if self.hi == 0.0 => Self::NEG_INFINITY,
+            _ if self.hi.is_infinite() => Self::from(0.0),
+            _ => yn_positive(self, order),
+        };
+
+        // y(-n)(x) = (-1)^n yn(x)
+        if order % 2 == 1 && n < 0 {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Computes the modified Bessel function of the first kind of order
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.5).i0();
+    /// let b = TwoFloat::new_add(1.646723189772891, -4.915740168379194e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn i0(self) -> Self {
+        if self.hi.is_nan() {
+            Self::NAN
+        } else if self.hi.is_infinite() {
+            Self::INFINITY
+        } else {
+            i_positive(self.abs(), 0)
+        }
+    }
+
+    /// Computes the modified Bessel function of the first kind of order one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.5).i1();
+    /// let b = TwoFloat::new_add(0.9816664285779075, 5.0693952885555516e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn i1(self) -> Self {
+        if self.hi.is_nan() {
+            return Self::NAN;
+        }
+
+        let result = if self.hi.is_infinite() {
+            Self::INFINITY
+        } else {
+            i_positive(self.abs(), 1)
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Computes the modified Bessel function of the second kind of order
+    /// zero. Returns NaN for negative arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.5).k0();
+    /// let b = TwoFloat::new_add(0.21380556264752573, 4.68759480496476e-18);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn k0(self) -> Self {
+        if self.hi.is_nan() || self.hi < 0.0 {
+            Self::NAN
+        } else if self.hi == 0.0 {
+            Self::INFINITY
+        } else if self.hi.is_infinite() {
+            Self::from(0.0)
+        } else {
+            k_positive(self, 0)
+        }
+    }
+
+    /// Computes the modified Bessel function of the second kind of order
+    /// one. Returns NaN for negative arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.5).k1();
+    /// let b = TwoFloat::new_add(0.2773878004568438, 1.3375376623581298e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn k1(self) -> Self {
+        if self.hi.is_nan() || self.hi < 0.0 {
+            Self::NAN
+        } else if self.hi == 0.0 {
+            Self::INFINITY
+        } else if self.hi.is_infinite() {
+            Self::from(0.0)
+        } else {
+            k_positive(self, 1)
+        }
+    }
+}
@@ -0,0 +1,635 @@
+use hexf::hexf64;
+
+use crate::{
+    consts::{FRAC_PI_2, PI},
+    TwoFloat,
+};
+
+// Maximum number of steps of the arithmetic-geometric mean
+const AGM_STEPS: usize = 40;
+
+// The arithmetic-geometric mean is stopped when the terms agree to this
+// relative precision, after which a single further step is sufficient
+const AGM_TOLERANCE: f64 = hexf64!("0x1.0p-52");
+
+// Landen's transformation is stopped when c(n)/a(n) falls below this value
+const LANDEN_TOLERANCE: f64 = hexf64!("0x1.0p-107");
+
+// The duplication algorithm for R_F is stopped when the arguments agree to
+// this relative precision, as the error of the series of degree 7 is of
+// order 8 in the differences
+const RF_TOLERANCE: f64 = hexf64!("0x1.0p-14");
+
+// The duplication algorithms for R_D and R_J are stopped when the arguments
+// agree to this relative precision, as the error of the series of degree 5
+// is of order 6 in the differences
+const RD_TOLERANCE: f64 = hexf64!("0x1.0p-19");
+
+// Computes sqrt(1 - k^2) without loss of precision for k close to 1
+fn complementary_modulus(k: TwoFloat) -> TwoFloat {
+    ((1.0 - k) * (1.0 + k)).sqrt()
+}
+
+// Computes the arithmetic-geometric mean of 1 and sqrt(1 - k^2) for
+// 0 <= k < 1, storing the values of a(n) and c(n) = (a(n-1) - b(n-1))/2
+// used by Landen's transformation. Returns the number of steps.
+fn agm_sequence(
+    k: TwoFloat,
+    a: &mut [TwoFloat; AGM_STEPS],
+    c: &mut [TwoFloat; AGM_STEPS],
+) -> usize {
+    a[0] = TwoFloat::from(1.0);
+    c[0] = k;
+    let mut b = complementary_modulus(k);
+    let mut n = 0;
+    while n + 1 < AGM_STEPS && c[n].hi > LANDEN_TOLERANCE * a[n].hi {
+        a[n + 1] = (a[n] + b) * 0.5;
+        b = (a[n] * b).sqrt();
+
+        // a(n)^2 - b(n)^2 = c(n)^2, which avoids cancellation
+        c[n + 1] = c[n] * c[n] / (a[n + 1] * 4.0);
+        n += 1;
+    }
+    n
+}
+
+// Computes the arithmetic-geometric mean of two positive values
+fn agm(mut a: TwoFloat, mut b: TwoFloat) -> TwoFloat {
+    for _ in 0..AGM_STEPS {
+        if (a - b).abs() <= a * AGM_TOLERANCE {
+            break;
+        }
+        let next = (a + b) * 0.5;
+        b = (a * b).sqrt();
+        a = next;
+    }
+    (a + b) * 0.5
+}
+
+// Computes the modified arithmetic-geometric mean of two positive values,
+// as defined by Adlaj (2012)
+fn modified_agm(mut x: TwoFloat, mut y: TwoFloat) -> TwoFloat {
+    let mut z = TwoFloat::from(0.0);
+    for _ in 0..AGM_STEPS {
+        if (x - y).abs() <= x * AGM_TOLERANCE {
+            break;
+        }
+        let r = ((x - z) * (y - z)).sqrt();
+
+        // z + r is rewritten to avoid cancellation, as z <= 0
+        let next_y = (x * y - z * (x + y)) / (r - z);
+        x = (x + y) * 0.5;
+        y = next_y;
+        z -= r;
+    }
+    (x + y) * 0.5
+}
+
+// Refines an estimate of the angle atan2(y, x) by a Newton step, which
+// doubles the number of correct bits. The polynomial approximations of asin
+// and atan are accurate to only around 45 and 75 bits respectively.
+fn refine_angle(theta: TwoFloat, y: TwoFloat, x: TwoFloat) -> TwoFloat {
+    let (s, c) = theta.sin_cos();
+    theta + (y * c - x * s) / (x * c + y * s)
+}
+
+// Computes sn(u, k) and cn(u, k) for 0 < k < 1 and |u| <= K(k)/2 using
+// Landen's descending transformation, given the arithmetic-geometric mean
+// sequence for k
+fn landen_sn_cn(u: TwoFloat, a: &[TwoFloat], c: &[TwoFloat], steps: usize) -> (TwoFloat, TwoFloat) {
+    let mut phi = u * a[steps] * libm::ldexp(1.0, steps as i32);
+    for n in (1..=steps).rev() {
+        let y = c[n] / a[n] * phi.sin();
+        let x = ((1.0 - y) * (1.0 + y)).sqrt();
+        let psi = refine_angle(refine_angle(y.asin(), y, x), y, x);
+        phi = (phi + psi) * 0.5;
+    }
+    phi.sin_cos()
+}
+
+// Computes R_F(x, y, z) for non-negative arguments by the duplication
+// algorithm followed by the series of degree 7 given by Carlson (1995)
+fn rf(mut x: TwoFloat, mut y: TwoFloat, mut z: TwoFloat) -> TwoFloat {
+    let a0 = (x + y + z) / 3.0;
+    let (dx, dy) = (a0 - x, a0 - y);
+    let mut a = a0;
+    let mut scale = 1.0;
+    while libm::fmax(libm::fabs(dx.hi), libm::fabs(dy.hi)).max(libm::fabs((a0 - z).hi))
+        > RF_TOLERANCE * scale * a.hi
+    {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        x = (x + lambda) * 0.25;
+        y = (y + lambda) * 0.25;
+        z = (z + lambda) * 0.25;
+        a = (a + lambda) * 0.25;
+        scale *= 4.0;
+    }
+
+    let dx = dx / (a * scale);
+    let dy = dy / (a * scale);
+    let dz = -(dx + dy);
+    let e2 = dx * dy - dz * dz;
+    let e3 = dx * dy * dz;
+    let series = 1.0 - e2 / 10.0 + e3 / 14.0 + e2 * e2 / 24.0
+        - e2 * e3 * 3.0 / 44.0
+        - e2 * e2 * e2 * 5.0 / 208.0
+        + e3 * e3 * 3.0 / 104.0
+        + e2 * e2 * e3 / 16.0;
+    series / a.sqrt()
+}
+
+// Computes the series of degree 5 for R_D and R_J from the elementary
+// symmetric functions of the scaled differences
+fn rd_rj_series(e2: TwoFloat, e3: TwoFloat, e4: TwoFloat, e5: TwoFloat) -> TwoFloat {
+    1.0 - e2 * 3.0 / 14.0 + e3 / 6.0 + e2 * e2 * 9.0 / 88.0 - e4 * 3.0 / 22.0 - e2 * e3 * 9.0 / 52.0
+        + e5 * 3.0 / 26.0
+}
+
+// Computes R_D(x, y, z) for non-negative x and y and positive z by the
+// duplication algorithm
+fn rd(mut x: TwoFloat, mut y: TwoFloat, mut z: TwoFloat) -> TwoFloat {
+    let a0 = (x + y + z * 3.0) / 5.0;
+    let (dx, dy, dz) = (a0 - x, a0 - y, a0 - z);
+    let mut a = a0;
+    let mut scale = 1.0;
+    let mut sum = TwoFloat::from(0.0);
+    while libm::fmax(libm::fabs(dx.hi), libm::fabs(dy.hi)).max(libm::fabs(dz.hi))
+        > RD_TOLERANCE * scale * a.hi
+    {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        sum += (sz * (z + lambda) * scale).recip();
+        x = (x + lambda) * 0.25;
+        y = (y + lambda) * 0.25;
+        z = (z + lambda) * 0.25;
+        a = (a + lambda) * 0.25;
+        scale *= 4.0;
+    }
+
+    let dx = dx / (a * scale);
+    let dy = dy / (a * scale);
+    let dz = -(dx + dy) / 3.0;
+    let xy = dx * dy;
+    let z2 = dz * dz;
+    let e2 = xy - z2 * 6.0;
+    let e3 = (xy * 3.0 - z2 * 8.0) * dz;
+    let e4 = (xy - z2) * z2 * 3.0;
+    let e5 = xy * z2 * dz;
+    sum * 3.0 + rd_rj_series(e2, e3, e4, e5) / (a * a.sqrt() * scale)
+}
+
+// Computes R_C(x, y) for non-negative x and positive y
+fn rc(x: TwoFloat, y: TwoFloat) -> TwoFloat {
+    if x == y {
+        x.sqrt().recip()
+    } else if x < y {
+        let d = (y - x).sqrt();
+        if x.hi == 0.0 {
+            FRAC_PI_2 / d
+        } else {
+            let sx = x.sqrt();
+            refine_angle((d / sx).atan(), d, sx) / d
+        }
+    } else {
+        let d = (x - y).sqrt();
+        let sx = x.sqrt();
+        if y.hi > 0.5 * x.hi {
+            (d / sx).atanh() / d
+        } else {
+            ((sx + d) / y.sqrt()).ln() / d
+        }
+    }
+}
+
+// Computes R_J(x, y, z, p) for non-negative x, y and z and positive p by
+// the duplication algorithm given by Carlson (1995)
+fn rj(mut x: TwoFloat, mut y: TwoFloat, mut z: TwoFloat, mut p: TwoFloat) -> TwoFloat {
+    let a0 = (x + y + z + p * 2.0) / 5.0;
+    let (dx, dy, dz, dp) = (a0 - x, a0 - y, a0 - z, a0 - p);
+    let delta = (p - x) * (p - y) * (p - z);
+    let mut a = a0;
+    let mut scale = 1.0;
+    let mut sum = TwoFloat::from(0.0);
+    while libm::fmax(libm::fabs(dx.hi), libm::fabs(dy.hi))
+        .max(libm::fabs(dz.hi))
+        .max(libm::fabs(dp.hi))
+        > RD_TOLERANCE * scale * a.hi
+    {
+        let (sx, sy, sz, sp) = (x.sqrt(), y.sqrt(), z.sqrt(), p.sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        let d = (sp + sx) * (sp + sy) * (sp + sz);
+        let e = delta / (d * d * (scale * scale * scale));
+        sum += rc(TwoFloat::from(1.0), 1.0 + e) / (d * scale);
+        x = (x + lambda) * 0.25;
+        y = (y + lambda) * 0.25;
+        z = (z + lambda) * 0.25;
+        p = (p + lambda) * 0.25;
+        a = (a + lambda) * 0.25;
+        scale *= 4.0;
+    }
+
+    let dx = dx / (a * scale);
+    let dy = dy / (a * scale);
+    let dz = dz / (a * scale);
+    let dp = -(dx + dy + dz) * 0.5;
+    let xyz = dx * dy * dz;
+    let p2 = dp * dp;
+    let e2 = dx * dy + dx * dz + dy * dz - p2 * 3.0;
+    let e3 = xyz + e2 * dp * 2.0 + p2 * dp * 4.0;
+    let e4 = (xyz * 2.0 + e2 * dp + p2 * dp * 3.0) * dp;
+    let e5 = xyz * p2;
+    sum * 6.0 + rd_rj_series(e2, e3, e4, e5) / (a * a.sqrt() * scale)
+}
+
+// Checks that the arguments of the Carlson integrals are non-negative with
+// at most `zeros` of them equal to zero. Positive infinity is allowed.
+fn valid_carlson_arguments(args: &[TwoFloat], zeros: usize) -> bool {
+    args.iter().all(|x| x.hi >= 0.0) && args.iter().filter(|x| x.hi == 0.0).count() <= zeros
+}
+
+// The Carlson integrals tend to zero as any of their arguments tends to
+// infinity
+fn infinite_carlson_argument(args: &[TwoFloat]) -> bool {
+    args.iter().any(|x| x.hi.is_infinite())
+}
+
+// Computes 1 - k^2 sin^2(phi), avoiding cancellation for k < 1 by rewriting
+// it as cos^2(phi) + k'^2 sin^2(phi).
+fn delta_squared(k: TwoFloat, s: TwoFloat, c: TwoFloat) -> TwoFloat {
+    if k < 1.0 {
+        c * c + (1.0 - k) * (1.0 + k) * s * s
+    } else {
+        let ks = k * s;
+        (1.0 - ks) * (1.0 + ks)
+    }
+}
+
+// Splits the amplitude into a multiple of pi and a remainder in the range
+// [-pi/2, pi/2]
+fn reduce_amplitude(phi: TwoFloat) -> (TwoFloat, f64) {
+    let m = libm::round(phi.hi / PI.hi);
+    let remainder = phi - PI * m;
+    (remainder, m)
+}
+
+impl TwoFloat {
+    /// Computes Carlson's symmetric elliptic integral of the first kind
+    /// `R_F(x, y, z)`, where `x` is the value. Returns NaN if any argument
+    /// is negative or more than one is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0).carlson_rf(TwoFloat::from(2.0), TwoFloat::from(0.0));
+    /// let b = TwoFloat::new_add(1.3110287771460598, 9.58215479734899e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn carlson_rf(self, y: Self, z: Self) -> Self {
+        if !valid_carlson_arguments(&[self, y, z], 1) {
+            return Self::NAN;
+        }
+        if infinite_carlson_argument(&[self, y, z]) {
+            return Self::from(0.0);
+        }
+        rf(self, y, z)
+    }
+
+    /// Computes Carlson's degenerate elliptic integral `R_C(x, y)`, where
+    /// `x` is the value. Returns NaN if `x` is negative or `y` is not
+    /// positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.0).carlson_rc(TwoFloat::from(1.0));
+    /// let b = twofloat::consts::FRAC_PI_2;
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn carlson_rc(self, y: Self) -> Self {
+        if !valid_carlson_arguments(&[self], 1) || !valid_carlson_arguments(&[y], 0) {
+            return Self::NAN;
+        }
+        if infinite_carlson_argument(&[self, y]) {
+            return Self::from(0.0);
+        }
+        rc(self, y)
+    }
+
+    /// Computes Carlson's symmetric elliptic integral of the second kind
+    /// `R_D(x, y, z)`, where `x` is the value. Returns NaN if any argument
+    /// is negative, `z` is zero or both `x` and `y` are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.0).carlson_rd(TwoFloat::from(2.0), TwoFloat::from(1.0));
+    /// let b = TwoFloat::new_add(1.7972103521033884, -1.087599545318684e-16);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn carlson_rd(self, y: Self, z: Self) -> Self {
+        if !valid_carlson_arguments(&[self, y], 1) || !valid_carlson_arguments(&[z], 0) {
+            return Self::NAN;
+        }
+        if infinite_carlson_argument(&[self, y, z]) {
+            return Self::from(0.0);
+        }
+        rd(self, y, z)
+    }
+
+    /// Computes Carlson's symmetric elliptic integral of the third kind
+    /// `R_J(x, y, z, p)`, where `x` is the value. Returns NaN if any argument
+    /// is negative, `p` is zero or more than one of `x`, `y` and `z` are
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.0)
+    ///     .carlson_rj(TwoFloat::from(1.0), TwoFloat::from(2.0), TwoFloat::from(3.0));
+    /// let b = TwoFloat::new_add(0.7768862377858233, -1.0206575239892958e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn carlson_rj(self, y: Self, z: Self, p: Self) -> Self {
+        if !valid_carlson_arguments(&[self, y, z], 1) || !valid_carlson_arguments(&[p], 0) {
+            return Self::NAN;
+        }
+        if infinite_carlson_argument(&[self, y, z, p]) {
+            return Self::from(0.0);
+        }
+        rj(self, y, z, p)
+    }
+
+    /// Computes the complete elliptic integral of the first kind `K(k)`,
+    /// where the value is the modulus `k`, using the arithmetic-geometric
+    /// mean. Returns NaN if `|k| > 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.5).ellint_k();
+    /// let b = TwoFloat::new_add(1.685750354812596, -9.540573578758024e-18);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn ellint_k(self) -> Self {
+        let k = self.abs();
+        if !k.is_valid() || k > 1.0 {
+            Self::NAN
+        } else if k == 1.0 {
+            Self::INFINITY
+        } else {
+            FRAC_PI_2 / agm(Self::from(1.0), complementary_modulus(k))
+        }
+    }
+
+    /// Computes the complete elliptic integral of the second kind `E(k)`,
+    /// where the value is the modulus `k`, using the modified
+    /// arithmetic-geometric mean. Returns NaN if `|k| > 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.5).ellint_e();
+    /// let b = TwoFloat::new_add(1.4674622093394272, -1.5002704549503613e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn ellint_e(self) -> Self {
+        let k = self.abs();
+        if !k.is_valid() || k > 1.0 {
+            Self::NAN
+        } else if k == 1.0 {
+            Self::from(1.0)
+        } else {
+            let k12 = (1.0 - k) * (1.0 + k);
+            FRAC_PI_2 * modified_agm(Self::from(1.0), k12) / agm(Self::from(1.0), k12.sqrt())
+        }
+    }
+
+    /// Computes the incomplete elliptic integral of the first kind
+    /// `F(phi, k)`, where the value is the modulus `k`, using Carlson's
+    /// integral `R_F`. Returns NaN if `k sin(phi)` is larger than 1 in
+    /// magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.5).ellint_k_incomplete(TwoFloat::from(1.0));
+    /// let b = TwoFloat::new_add(1.0373561200021773, 4.059058818810134e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn ellint_k_incomplete(self, phi: Self) -> Self {
+        if !self.is_valid() || !phi.is_valid() {
+            return Self::NAN;
+        }
+        if phi.hi == 0.0 {
+            return Self::from(phi.hi);
+        }
+
+        let k = self.abs();
+        let (phi, m) = reduce_amplitude(phi);
+        let (s, c) = phi.sin_cos();
+        let ks = k * s.abs();
+        if ks > 1.0 {
+            return Self::NAN;
+        }
+
+        let d2 = delta_squared(k, s, c);
+        if d2.hi == 0.0 && c.hi == 0.0 {
+            return if s.hi > 0.0 {
+                Self::INFINITY
+            } else {
+                Self::NEG_INFINITY
+            };
+        }
+
+        let result = s * rf(c * c, d2, Self::from(1.0));
+        if m == 0.0 {
+            result
+        } else if k == 1.0 {
+            // The amplitude passes a singularity at an odd multiple of pi/2
+            if m > 0.0 {
+                Self::INFINITY
+            } else {
+                Self::NEG_INFINITY
+            }
+        } else {
+            result + k.ellint_k() * (2.0 * m)
+        }
+    }
+
+    /// Computes the incomplete elliptic integral of the second kind
+    /// `E(phi, k)`, where the value is the modulus `k`, using Carlson's
+    /// integrals `R_F` and `R_D`. Returns NaN if `k sin(phi)` is larger
+    /// than 1 in magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.5).ellint_e_incomplete(TwoFloat::from(1.0));
+    /// let b = TwoFloat::new_add(0.9648764542686274, 3.732908717941553e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn ellint_e_incomplete(self, phi: Self) -> Self {
+        if !self.is_valid() || !phi.is_valid() {
+            return Self::NAN;
+        }
+        if phi.hi == 0.0 {
+            return Self::from(phi.hi);
+        }
+
+        let k = self.abs();
+        let (phi, m) = reduce_amplitude(phi);
+        let (s, c) = phi.sin_cos();
+        let ks = k * s.abs();
+        if ks > 1.0 {
+            return Self::NAN;
+        }
+
+        let d2 = delta_squared(k, s, c);
+        let result = if k == 1.0 {
+            s
+        } else if k < 1.0 {
+            // All terms are positive, avoiding cancellation as k approaches
+            // 1 (DLMF 19.25.10)
+            let k2 = k * k;
+            let k12 = (1.0 - k) * (1.0 + k);
+            let s3 = s * s * s;
+            k12 * s * rf(c * c, d2, Self::from(1.0))
+                + k2 * k12 * s3 * rd(c * c, Self::from(1.0), d2) / 3.0
+                + k2 * s * c / d2.sqrt()
+        } else {
+            let c2 = c * c;
+            s * rf(c2, d2, Self::from(1.0)) - k * k * s * s * s * rd(c2, d2, Self::from(1.0)) / 3.0
+        };
+
+        if m == 0.0 {
+            result
+        } else {
+            result + k.ellint_e() * (2.0 * m)
+        }
+    }
+
+    /// Computes the Jacobi elliptic functions `sn(u, k)`, `cn(u, k)` and
+    /// `dn(u, k)`, where the value is the argument `u` and `k` is the
+    /// modulus.
+    ///
+    /// The argument is reduced modulo the quarter period `K(k)` and the
+    /// functions are evaluated using Landen's descending transformation.
+    /// Moduli larger than 1 use the reciprocal modulus transformation. As
+    /// the quarter period is only known to double-double precision, the
+    /// error of the reduction grows with the number of quarter periods, and
+    /// close to the zeros of `sn` and `cn` only the absolute error is small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let (sn, cn, dn) = TwoFloat::from(1.0).jacobi_sn_cn_dn(TwoFloat::from(0.5));
+    /// let a = TwoFloat::new_add(0.8226355781298623, 3.813705180072152e-17);
+    ///
+    /// assert!(((sn - a) / a).abs() < 1e-30);
+    /// assert!((sn * sn + cn * cn - 1.0).abs() < 1e-30);
+    /// assert!((dn * dn + sn * sn * 0.25 - 1.0).abs() < 1e-30);
+    /// ```
+    pub fn jacobi_sn_cn_dn(self, k: Self) -> (Self, Self, Self) {
+        if !self.is_valid() || !k.is_valid() {
+            return (Self::NAN, Self::NAN, Self::NAN);
+        }
+        if self.hi == 0.0 {
+            return (Self::from(self.hi), Self::from(1.0), Self::from(1.0));
+        }
+
+        let k = k.abs();
+        if k > 1.0 {
+            // sn(u, k) = sn(ku, 1/k)/k, cn(u, k) = dn(ku, 1/k) and
+            // dn(u, k) = cn(ku, 1/k)
+            let (sn, cn, dn) = (self * k).jacobi_sn_cn_dn(k.recip());
+            return (sn / k, dn, cn);
+        } else if k == 1.0 {
+            let sech = self.cosh().recip();
+            return (self.tanh(), sech, sech);
+        } else if k.hi == 0.0 {
+            let (s, c) = self.sin_cos();
+            return (s, c, Self::from(1.0));
+        }
+
+        let mut a = [Self::from(0.0); AGM_STEPS];
+        let mut c = [Self::from(0.0); AGM_STEPS];
+        let steps = agm_sequence(k, &mut a, &mut c);
+        let quarter = FRAC_PI_2 / a[steps];
+
+        // Reduce the argument to [-K/2, K/2] and a number of quarter periods
+        let n = libm::round(self.hi / quarter.hi);
+        let u = self - quarter * n;
+        let (s, cn) = landen_sn_cn(u, &a, &c, steps);
+        let k1 = complementary_modulus(k);
+        let d = delta_squared(k, s, cn).sqrt();
+
+        match (n as i64).rem_euclid(4) {
+            0 => (s, cn, d),
+            1 => (cn / d, -k1 * s / d, k1 / d),
+            2 => (-s, -cn, d),
+            _ => (-cn / d, k1 * s / d, k1 / d),
+        }
+    }
+
+    /// Computes the Jacobi elliptic function `sn(u, k)`, where the value is
+    /// the argument `u` and `k` is the modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let k = TwoFloat::from(0.5);
+    /// let a = k.ellint_k().jacobi_sn(k);
+    ///
+    /// assert!((a - 1.0).abs() < 1e-30);
+    /// ```
+    pub fn jacobi_sn(self, k: Self) -> Self {
+        self.jacobi_sn_cn_dn(k).0
+    }
+
+    /// Computes the Jacobi elliptic function `cn(u, k)`, where the value is
+    /// the argument `u` and `k` is the modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.0).jacobi_cn(TwoFloat::from(0.5));
+    /// assert_eq!(a, 1.0);
+    /// ```
+    pub fn jacobi_cn(self, k: Self) -> Self {
+        self.jacobi_sn_cn_dn(k).1
+    }
+
+    /// Computes the Jacobi elliptic function `dn(u, k)`, where the value is
+    /// the argument `u` and `k` is the modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.0).jacobi_dn(TwoFloat::from(0.5));
+    /// assert_eq!(a, 1.0);
+    /// ```
+    pub fn jacobi_dn(self, k: Self) -> Self {
+        self.jacobi_sn_cn_dn(k).2
+    }
+}
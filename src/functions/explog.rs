@@ -1,6 +1,10 @@
 use hexf::hexf64;
 
-use crate::{consts::LN_2, TwoFloat};
+use crate::{
+    arithmetic::{fast_two_sum, scale_pow2},
+    consts::LN_2,
+    TwoFloat,
+};
 
 // 1/ln(2)
 const FRAC_1_LN_2: TwoFloat = TwoFloat {
@@ -8,127 +12,1657 @@ const FRAC_1_LN_2: TwoFloat = TwoFloat {
     lo: hexf64!("0x1.777d0ffda0d24p-56"),
 };
 
-// ln(10)
-const LN_10: TwoFloat = TwoFloat {
-    hi: hexf64!("0x1.26bb1bbb55516p1"),
-    lo: hexf64!("-0x1.f48ad494ea3e9p-53"),
+// 1/ln(10)
+const FRAC_1_LN_10: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.bcb7b1526e50ep-2"),
+    lo: hexf64!("0x1.95355baaafad3p-57"),
+};
+
+// ln(2) split into a 42-bit high part, so that multiplying it by a binary
+// exponent is exact, and a double-double remainder
+const LN_2_HI: f64 = hexf64!("0x1.62e42fefa3800p-1");
+const LN_2_LO: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.ef35793c76730p-45"),
+    lo: hexf64!("0x1.f97b57a079a19p-103"),
 };
 
-// ln(3/2)
-const LN_FRAC_3_2: TwoFloat = TwoFloat {
-    hi: hexf64!("0x1.9f323ecbf984cp-2"),
-    lo: hexf64!("-0x1.a92e513217f5cp-59"),
+// log10(2) split in the same way as ln(2)
+const LOG10_2_HI: f64 = hexf64!("0x1.34413509f7800p-2");
+const LOG10_2_LO: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.fef311f12b358p-46"),
+    lo: hexf64!("0x1.6f922f04d5a62p-102"),
 };
 
 // limits
-const EXP_UPPER_LIMIT: f64 = 709.0;
-const EXP_LOWER_LIMIT: f64 = -709.0;
+const EXP_UPPER_LIMIT: f64 = 709.79;
+const EXP_LOWER_LIMIT: f64 = -745.2;
+const EXP2_UPPER_LIMIT: f64 = 1024.0;
+const EXP2_LOWER_LIMIT: f64 = -1076.0;
+
+// Beyond this magnitude the argument of `exp` is reduced by a multiple of
+// ln(2), and the result scaled by the corresponding power of two
+const EXP_SCALING_LIMIT: f64 = 708.0;
 
 const FRAC_FACT: [TwoFloat; 21] = [
     TwoFloat {
-        // 1/0!
-        hi: hexf64!("0x1.0000000000000p+0"),
-        lo: hexf64!("0x0.0p+0"),
+        // 1/0!
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("0x0.0p+0"),
+    },
+    TwoFloat {
+        // 1/1!
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("0x0.0p+0"),
+    },
+    TwoFloat {
+        // 1/2!
+        hi: hexf64!("0x1.0000000000000p-1"),
+        lo: hexf64!("0x0.0p+0"),
+    },
+    TwoFloat {
+        // 1/3!
+        hi: hexf64!("0x1.5555555555555p-3"),
+        lo: hexf64!("0x1.5555555555555p-57"),
+    },
+    TwoFloat {
+        // 1/4!
+        hi: hexf64!("0x1.5555555555555p-5"),
+        lo: hexf64!("0x1.5555555555555p-59"),
+    },
+    TwoFloat {
+        // 1/5!
+        hi: hexf64!("0x1.1111111111111p-7"),
+        lo: hexf64!("0x1.1111111111111p-63"),
+    },
+    TwoFloat {
+        // 1/6!
+        hi: hexf64!("0x1.6c16c16c16c17p-10"),
+        lo: hexf64!("-0x1.f49f49f49f49fp-65"),
+    },
+    TwoFloat {
+        // 1/7!
+        hi: hexf64!("0x1.a01a01a01a01ap-13"),
+        lo: hexf64!("0x1.a01a01a01a01ap-73"),
+    },
+    TwoFloat {
+        // 1/8!
+        hi: hexf64!("0x1.a01a01a01a01ap-16"),
+        lo: hexf64!("0x1.a01a01a01a01ap-76"),
+    },
+    TwoFloat {
+        // 1/9!
+        hi: hexf64!("0x1.71de3a556c734p-19"),
+        lo: hexf64!("-0x1.c154f8ddc6c00p-73"),
+    },
+    TwoFloat {
+        // 1/10!
+        hi: hexf64!("0x1.27e4fb7789f5cp-22"),
+        lo: hexf64!("0x1.cbbc05b4fa99ap-76"),
+    },
+    TwoFloat {
+        // 1/11!
+        hi: hexf64!("0x1.ae64567f544e4p-26"),
+        lo: hexf64!("-0x1.c062e06d1f209p-80"),
+    },
+    TwoFloat {
+        // 1/12!
+        hi: hexf64!("0x1.1eed8eff8d898p-29"),
+        lo: hexf64!("-0x1.2aec959e14c06p-83"),
+    },
+    TwoFloat {
+        // 1/13!
+        hi: hexf64!("0x1.6124613a86d09p-33"),
+        lo: hexf64!("0x1.f28e0cc748ebep-87"),
+    },
+    TwoFloat {
+        // 1/14!
+        hi: hexf64!("0x1.93974a8c07c9dp-37"),
+        lo: hexf64!("0x1.05d6f8a2efd1fp-92"),
+    },
+    TwoFloat {
+        // 1/15!
+        hi: hexf64!("0x1.ae7f3e733b81fp-41"),
+        lo: hexf64!("0x1.1d8656b0ee8cbp-97"),
+    },
+    TwoFloat {
+        // 1/16!
+        hi: hexf64!("0x1.ae7f3e733b81fp-45"),
+        lo: hexf64!("0x1.1d8656b0ee8cbp-101"),
+    },
+    TwoFloat {
+        // 1/17!
+        hi: hexf64!("0x1.952c77030ad4ap-49"),
+        lo: hexf64!("0x1.ac981465ddc6cp-103"),
+    },
+    TwoFloat {
+        // 1/18!
+        hi: hexf64!("0x1.6827863b97d97p-53"),
+        lo: hexf64!("0x1.eec01221a8b0bp-107"),
+    },
+    TwoFloat {
+        // 1/19!
+        hi: hexf64!("0x1.2f49b46814157p-57"),
+        lo: hexf64!("0x1.2650f61dbdcb4p-112"),
+    },
+    TwoFloat {
+        // 1/20!
+        hi: hexf64!("0x1.e542ba4020225p-62"),
+        lo: hexf64!("0x1.ea72b4afe3c2fp-120"),
+    },
+];
+
+// Polynomial coefficients of (ln(1 + x) - x + x^2/2) / x^3 on [-1/192, 1/192]
+const LN_1P_COEFFS: [TwoFloat; 11] = [
+    TwoFloat {
+        hi: hexf64!("0x1.5555555555555p-2"),
+        lo: hexf64!("0x1.555555555554dp-56"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.0000000000000p-2"),
+        lo: hexf64!("-0x1.b9ef63a23aa70p-87"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.999999999999ap-3"),
+        lo: hexf64!("-0x1.9999997604b67p-57"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.5555555555555p-3"),
+        lo: hexf64!("-0x1.55077cfc9bda7p-57"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.2492492492492p-3"),
+        lo: hexf64!("0x1.23c63e46983a2p-57"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.000000000003dp-3"),
+        lo: hexf64!("-0x1.b7f61e37b111dp-57"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.c71c71c71c7f7p-4"),
+        lo: hexf64!("-0x1.526a71b3858f6p-59"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.9999998fb5036p-4"),
+        lo: hexf64!("-0x1.2443ccdcc5febp-63"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.745d173868105p-4"),
+        lo: hexf64!("-0x1.31c1bea5c9f7fp-59"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.555ae8d2246f6p-4"),
+        lo: hexf64!("0x1.46d5c80582b23p-59"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.3b19d6da9acddp-4"),
+        lo: hexf64!("-0x1.b23a7fff038d5p-58"),
+    },
+];
+
+// Values of ln(1 + n/128) for -32 <= n <= 64
+const LN_1P_128TH: [TwoFloat; 97] = [
+    TwoFloat {
+        // ln(1 - 32/128)
+        hi: hexf64!("-0x1.269621134db92p-2"),
+        lo: hexf64!("-0x1.e0efadd9db02bp-56"),
+    },
+    TwoFloat {
+        // ln(1 - 31/128)
+        hi: hexf64!("-0x1.1bf99635a6b95p-2"),
+        lo: hexf64!("0x1.12aeb84249223p-57"),
+    },
+    TwoFloat {
+        // ln(1 - 30/128)
+        hi: hexf64!("-0x1.1178e8227e47cp-2"),
+        lo: hexf64!("0x1.0e63a5f01c691p-57"),
+    },
+    TwoFloat {
+        // ln(1 - 29/128)
+        hi: hexf64!("-0x1.07138604d5862p-2"),
+        lo: hexf64!("-0x1.cdb16ed4e9138p-56"),
+    },
+    TwoFloat {
+        // ln(1 - 28/128)
+        hi: hexf64!("-0x1.f991c6cb3b379p-3"),
+        lo: hexf64!("-0x1.f665066f980a2p-57"),
+    },
+    TwoFloat {
+        // ln(1 - 27/128)
+        hi: hexf64!("-0x1.e530effe71012p-3"),
+        lo: hexf64!("-0x1.2276041f43042p-59"),
+    },
+    TwoFloat {
+        // ln(1 - 26/128)
+        hi: hexf64!("-0x1.d1037f2655e7bp-3"),
+        lo: hexf64!("-0x1.60629242471a2p-57"),
+    },
+    TwoFloat {
+        // ln(1 - 25/128)
+        hi: hexf64!("-0x1.bd087383bd8adp-3"),
+        lo: hexf64!("-0x1.dd355f6a516d7p-60"),
+    },
+    TwoFloat {
+        // ln(1 - 24/128)
+        hi: hexf64!("-0x1.a93ed3c8ad9e3p-3"),
+        lo: hexf64!("-0x1.bcafa9de97203p-57"),
+    },
+    TwoFloat {
+        // ln(1 - 23/128)
+        hi: hexf64!("-0x1.95a5adcf7017fp-3"),
+        lo: hexf64!("-0x1.142c507fb7a3dp-58"),
+    },
+    TwoFloat {
+        // ln(1 - 22/128)
+        hi: hexf64!("-0x1.823c16551a3c2p-3"),
+        lo: hexf64!("0x1.1232ce70be781p-57"),
+    },
+    TwoFloat {
+        // ln(1 - 21/128)
+        hi: hexf64!("-0x1.6f0128b756abcp-3"),
+        lo: hexf64!("0x1.8de59c21e166cp-57"),
+    },
+    TwoFloat {
+        // ln(1 - 20/128)
+        hi: hexf64!("-0x1.5bf406b543db2p-3"),
+        lo: hexf64!("0x1.1f5b44c0df7e7p-61"),
+    },
+    TwoFloat {
+        // ln(1 - 19/128)
+        hi: hexf64!("-0x1.4913d8333b561p-3"),
+        lo: hexf64!("0x1.0d5604930f135p-58"),
+    },
+    TwoFloat {
+        // ln(1 - 18/128)
+        hi: hexf64!("-0x1.365fcb0159016p-3"),
+        lo: hexf64!("-0x1.7d411a5b944adp-58"),
+    },
+    TwoFloat {
+        // ln(1 - 17/128)
+        hi: hexf64!("-0x1.23d712a49c202p-3"),
+        lo: hexf64!("0x1.6e38161051d69p-57"),
+    },
+    TwoFloat {
+        // ln(1 - 16/128)
+        hi: hexf64!("-0x1.1178e8227e47cp-3"),
+        lo: hexf64!("0x1.0e63a5f01c691p-58"),
+    },
+    TwoFloat {
+        // ln(1 - 15/128)
+        hi: hexf64!("-0x1.fe89139dbd566p-4"),
+        lo: hexf64!("0x1.ac9f4215f9393p-58"),
+    },
+    TwoFloat {
+        // ln(1 - 14/128)
+        hi: hexf64!("-0x1.da727638446a2p-4"),
+        lo: hexf64!("-0x1.401fa71733019p-58"),
+    },
+    TwoFloat {
+        // ln(1 - 13/128)
+        hi: hexf64!("-0x1.b6ac88dad5b1cp-4"),
+        lo: hexf64!("0x1.0057eed1ca59fp-59"),
+    },
+    TwoFloat {
+        // ln(1 - 12/128)
+        hi: hexf64!("-0x1.9335e5d594989p-4"),
+        lo: hexf64!("0x1.478a85704ccb7p-58"),
+    },
+    TwoFloat {
+        // ln(1 - 11/128)
+        hi: hexf64!("-0x1.700d30aeac0e1p-4"),
+        lo: hexf64!("0x1.72566212cdd05p-61"),
+    },
+    TwoFloat {
+        // ln(1 - 10/128)
+        hi: hexf64!("-0x1.4d3115d207eacp-4"),
+        lo: hexf64!("-0x1.769f42c7842ccp-58"),
+    },
+    TwoFloat {
+        // ln(1 - 9/128)
+        hi: hexf64!("-0x1.2aa04a44717a5p-4"),
+        lo: hexf64!("0x1.d15d38d2fa3f7p-58"),
+    },
+    TwoFloat {
+        // ln(1 - 8/128)
+        hi: hexf64!("-0x1.08598b59e3a07p-4"),
+        lo: hexf64!("0x1.dd7009902bf32p-58"),
+    },
+    TwoFloat {
+        // ln(1 - 7/128)
+        hi: hexf64!("-0x1.ccb73cdddb2ccp-5"),
+        lo: hexf64!("0x1.e48fb0500efd4p-59"),
+    },
+    TwoFloat {
+        // ln(1 - 6/128)
+        hi: hexf64!("-0x1.894aa149fb343p-5"),
+        lo: hexf64!("-0x1.a8be97660a23dp-60"),
+    },
+    TwoFloat {
+        // ln(1 - 5/128)
+        hi: hexf64!("-0x1.466aed42de3eap-5"),
+        lo: hexf64!("0x1.cdd6f7f4a137ep-59"),
+    },
+    TwoFloat {
+        // ln(1 - 4/128)
+        hi: hexf64!("-0x1.0415d89e74444p-5"),
+        lo: hexf64!("-0x1.c05cf1d753622p-59"),
+    },
+    TwoFloat {
+        // ln(1 - 3/128)
+        hi: hexf64!("-0x1.8492528c8cabfp-6"),
+        lo: hexf64!("0x1.d192d0619fa67p-60"),
+    },
+    TwoFloat {
+        // ln(1 - 2/128)
+        hi: hexf64!("-0x1.0205658935847p-6"),
+        lo: hexf64!("-0x1.27c8e8416e71fp-60"),
+    },
+    TwoFloat {
+        // ln(1 - 1/128)
+        hi: hexf64!("-0x1.010157588de71p-7"),
+        lo: hexf64!("-0x1.46662d417ced0p-62"),
+    },
+    TwoFloat {
+        // ln(1 + 0/128)
+        hi: hexf64!("0x0.0p+0"),
+        lo: hexf64!("0x0.0p+0"),
+    },
+    TwoFloat {
+        // ln(1 + 1/128)
+        hi: hexf64!("0x1.fe02a6b106789p-8"),
+        lo: hexf64!("-0x1.e44b7e3711ebfp-67"),
+    },
+    TwoFloat {
+        // ln(1 + 2/128)
+        hi: hexf64!("0x1.fc0a8b0fc03e4p-7"),
+        lo: hexf64!("-0x1.83092c59642a1p-62"),
+    },
+    TwoFloat {
+        // ln(1 + 3/128)
+        hi: hexf64!("0x1.7b91b07d5b11bp-6"),
+        lo: hexf64!("-0x1.5b602ace3a510p-60"),
+    },
+    TwoFloat {
+        // ln(1 + 4/128)
+        hi: hexf64!("0x1.f829b0e783300p-6"),
+        lo: hexf64!("0x1.33e3f04f1ef23p-60"),
+    },
+    TwoFloat {
+        // ln(1 + 5/128)
+        hi: hexf64!("0x1.39e87b9febd60p-5"),
+        lo: hexf64!("-0x1.5bfa937f551bbp-59"),
+    },
+    TwoFloat {
+        // ln(1 + 6/128)
+        hi: hexf64!("0x1.77458f632dcfcp-5"),
+        lo: hexf64!("0x1.18d3ca87b9296p-59"),
+    },
+    TwoFloat {
+        // ln(1 + 7/128)
+        hi: hexf64!("0x1.b42dd711971bfp-5"),
+        lo: hexf64!("-0x1.eb9759c130499p-60"),
+    },
+    TwoFloat {
+        // ln(1 + 8/128)
+        hi: hexf64!("0x1.f0a30c01162a6p-5"),
+        lo: hexf64!("0x1.85f325c5bbacdp-59"),
+    },
+    TwoFloat {
+        // ln(1 + 9/128)
+        hi: hexf64!("0x1.16536eea37ae1p-4"),
+        lo: hexf64!("-0x1.79da3e8c22cdap-60"),
+    },
+    TwoFloat {
+        // ln(1 + 10/128)
+        hi: hexf64!("0x1.341d7961bd1d1p-4"),
+        lo: hexf64!("-0x1.b599f227becbbp-58"),
+    },
+    TwoFloat {
+        // ln(1 + 11/128)
+        hi: hexf64!("0x1.51b073f06183fp-4"),
+        lo: hexf64!("0x1.a49e39a1a8be4p-58"),
+    },
+    TwoFloat {
+        // ln(1 + 12/128)
+        hi: hexf64!("0x1.6f0d28ae56b4cp-4"),
+        lo: hexf64!("-0x1.906d99184b992p-58"),
+    },
+    TwoFloat {
+        // ln(1 + 13/128)
+        hi: hexf64!("0x1.8c345d6319b21p-4"),
+        lo: hexf64!("-0x1.4a697ab3424a9p-61"),
+    },
+    TwoFloat {
+        // ln(1 + 14/128)
+        hi: hexf64!("0x1.a926d3a4ad563p-4"),
+        lo: hexf64!("0x1.942f48aa70ea9p-58"),
+    },
+    TwoFloat {
+        // ln(1 + 15/128)
+        hi: hexf64!("0x1.c5e548f5bc743p-4"),
+        lo: hexf64!("0x1.5d617ef8161b1p-60"),
+    },
+    TwoFloat {
+        // ln(1 + 16/128)
+        hi: hexf64!("0x1.e27076e2af2e6p-4"),
+        lo: hexf64!("-0x1.61578001e0162p-60"),
+    },
+    TwoFloat {
+        // ln(1 + 17/128)
+        hi: hexf64!("0x1.fec9131dbeabbp-4"),
+        lo: hexf64!("-0x1.5746b9981b36cp-58"),
+    },
+    TwoFloat {
+        // ln(1 + 18/128)
+        hi: hexf64!("0x1.0d77e7cd08e59p-3"),
+        lo: hexf64!("0x1.9a5dc5e9030acp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 19/128)
+        hi: hexf64!("0x1.1b72ad52f67a0p-3"),
+        lo: hexf64!("0x1.483023472cd74p-58"),
+    },
+    TwoFloat {
+        // ln(1 + 20/128)
+        hi: hexf64!("0x1.29552f81ff523p-3"),
+        lo: hexf64!("0x1.301771c407dbfp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 21/128)
+        hi: hexf64!("0x1.371fc201e8f74p-3"),
+        lo: hexf64!("0x1.de6cb62af18a0p-58"),
+    },
+    TwoFloat {
+        // ln(1 + 22/128)
+        hi: hexf64!("0x1.44d2b6ccb7d1ep-3"),
+        lo: hexf64!("0x1.9f4f6543e1f88p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 23/128)
+        hi: hexf64!("0x1.526e5e3a1b438p-3"),
+        lo: hexf64!("-0x1.746ff8a470d3ap-57"),
+    },
+    TwoFloat {
+        // ln(1 + 24/128)
+        hi: hexf64!("0x1.5ff3070a793d4p-3"),
+        lo: hexf64!("-0x1.bc60efafc6f6ep-58"),
+    },
+    TwoFloat {
+        // ln(1 + 25/128)
+        hi: hexf64!("0x1.6d60fe719d21dp-3"),
+        lo: hexf64!("-0x1.caae268ecd179p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 26/128)
+        hi: hexf64!("0x1.7ab890210d909p-3"),
+        lo: hexf64!("0x1.be36b2d6a0608p-59"),
+    },
+    TwoFloat {
+        // ln(1 + 27/128)
+        hi: hexf64!("0x1.87fa06520c911p-3"),
+        lo: hexf64!("-0x1.bf7fdbfa08d9ap-57"),
+    },
+    TwoFloat {
+        // ln(1 + 28/128)
+        hi: hexf64!("0x1.9525a9cf456b4p-3"),
+        lo: hexf64!("0x1.d904c1d4e2e26p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 29/128)
+        hi: hexf64!("0x1.a23bc1fe2b563p-3"),
+        lo: hexf64!("0x1.93711b07a998cp-59"),
+    },
+    TwoFloat {
+        // ln(1 + 30/128)
+        hi: hexf64!("0x1.af3c94e80bff3p-3"),
+        lo: hexf64!("-0x1.398cff3641985p-58"),
+    },
+    TwoFloat {
+        // ln(1 + 31/128)
+        hi: hexf64!("0x1.bc286742d8cd6p-3"),
+        lo: hexf64!("0x1.4fce744870f55p-58"),
+    },
+    TwoFloat {
+        // ln(1 + 32/128)
+        hi: hexf64!("0x1.c8ff7c79a9a22p-3"),
+        lo: hexf64!("-0x1.4f689f8434012p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 33/128)
+        hi: hexf64!("0x1.d5c216b4fbb91p-3"),
+        lo: hexf64!("0x1.6e443597e4d40p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 34/128)
+        hi: hexf64!("0x1.e27076e2af2e6p-3"),
+        lo: hexf64!("-0x1.61578001e0162p-59"),
+    },
+    TwoFloat {
+        // ln(1 + 35/128)
+        hi: hexf64!("0x1.ef0adcbdc5936p-3"),
+        lo: hexf64!("0x1.48637950dc20dp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 36/128)
+        hi: hexf64!("0x1.fb9186d5e3e2bp-3"),
+        lo: hexf64!("-0x1.caaae64f21acbp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 37/128)
+        hi: hexf64!("0x1.0402594b4d041p-2"),
+        lo: hexf64!("-0x1.28ec217a5022dp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 38/128)
+        hi: hexf64!("0x1.0a324e27390e3p-2"),
+        lo: hexf64!("0x1.7dcfde8061c03p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 39/128)
+        hi: hexf64!("0x1.1058bf9ae4ad5p-2"),
+        lo: hexf64!("0x1.89fa0ab4cb31dp-58"),
+    },
+    TwoFloat {
+        // ln(1 + 40/128)
+        hi: hexf64!("0x1.1675cababa60ep-2"),
+        lo: hexf64!("0x1.ce63eab883717p-61"),
+    },
+    TwoFloat {
+        // ln(1 + 41/128)
+        hi: hexf64!("0x1.1c898c16999fbp-2"),
+        lo: hexf64!("-0x1.0e5c62aff1c44p-60"),
+    },
+    TwoFloat {
+        // ln(1 + 42/128)
+        hi: hexf64!("0x1.22941fbcf7966p-2"),
+        lo: hexf64!("-0x1.76f5eb09628afp-56"),
+    },
+    TwoFloat {
+        // ln(1 + 43/128)
+        hi: hexf64!("0x1.2895a13de86a3p-2"),
+        lo: hexf64!("0x1.7ad24c13f040ep-56"),
+    },
+    TwoFloat {
+        // ln(1 + 44/128)
+        hi: hexf64!("0x1.2e8e2bae11d31p-2"),
+        lo: hexf64!("-0x1.8f4cdb95ebdf9p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 45/128)
+        hi: hexf64!("0x1.347dd9a987d55p-2"),
+        lo: hexf64!("-0x1.4dd4c580919f8p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 46/128)
+        hi: hexf64!("0x1.3a64c556945eap-2"),
+        lo: hexf64!("-0x1.c68651945f97cp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 47/128)
+        hi: hexf64!("0x1.404308686a7e4p-2"),
+        lo: hexf64!("-0x1.0bcfb6082ce6dp-56"),
+    },
+    TwoFloat {
+        // ln(1 + 48/128)
+        hi: hexf64!("0x1.4618bc21c5ec2p-2"),
+        lo: hexf64!("0x1.f42decdeccf1dp-56"),
+    },
+    TwoFloat {
+        // ln(1 + 49/128)
+        hi: hexf64!("0x1.4be5f957778a1p-2"),
+        lo: hexf64!("-0x1.259b35b04813dp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 50/128)
+        hi: hexf64!("0x1.51aad872df82dp-2"),
+        lo: hexf64!("0x1.3927ac19f55e3p-59"),
+    },
+    TwoFloat {
+        // ln(1 + 51/128)
+        hi: hexf64!("0x1.5767717455a6cp-2"),
+        lo: hexf64!("0x1.526adb283660cp-56"),
+    },
+    TwoFloat {
+        // ln(1 + 52/128)
+        hi: hexf64!("0x1.5d1bdbf5809cap-2"),
+        lo: hexf64!("0x1.4236383dc7fe1p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 53/128)
+        hi: hexf64!("0x1.62c82f2b9c795p-2"),
+        lo: hexf64!("0x1.7b7af915300e5p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 54/128)
+        hi: hexf64!("0x1.686c81e9b14afp-2"),
+        lo: hexf64!("-0x1.ddea0f7f58e3dp-57"),
+    },
+    TwoFloat {
+        // ln(1 + 55/128)
+        hi: hexf64!("0x1.6e08eaa2ba1e4p-2"),
+        lo: hexf64!("-0x1.cfb1b39ca3a0fp-56"),
+    },
+    TwoFloat {
+        // ln(1 + 56/128)
+        hi: hexf64!("0x1.739d7f6bbd007p-2"),
+        lo: hexf64!("-0x1.8c76ceb014b04p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 57/128)
+        hi: hexf64!("0x1.792a55fdd47a2p-2"),
+        lo: hexf64!("0x1.f057691fe9ed7p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 58/128)
+        hi: hexf64!("0x1.7eaf83b82afc3p-2"),
+        lo: hexf64!("0x1.92ce979ed2950p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 59/128)
+        hi: hexf64!("0x1.842d1da1e8b17p-2"),
+        lo: hexf64!("0x1.24ec519784676p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 60/128)
+        hi: hexf64!("0x1.89a3386c1425bp-2"),
+        lo: hexf64!("-0x1.29639dfbbf0fbp-56"),
+    },
+    TwoFloat {
+        // ln(1 + 61/128)
+        hi: hexf64!("0x1.8f11e873662c7p-2"),
+        lo: hexf64!("0x1.f85da755a61a3p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 62/128)
+        hi: hexf64!("0x1.947941c2116fbp-2"),
+        lo: hexf64!("-0x1.16cc8bae0bbe4p-56"),
+    },
+    TwoFloat {
+        // ln(1 + 63/128)
+        hi: hexf64!("0x1.99d958117e08bp-2"),
+        lo: hexf64!("-0x1.a2b6889dc3e72p-57"),
+    },
+    TwoFloat {
+        // ln(1 + 64/128)
+        hi: hexf64!("0x1.9f323ecbf984cp-2"),
+        lo: hexf64!("-0x1.a92e513217f5cp-59"),
+    },
+];
+
+// Values of log2(1 + n/128) for -32 <= n <= 64
+const LOG2_1P_128TH: [TwoFloat; 97] = [
+    TwoFloat {
+        // log2(1 - 32/128)
+        hi: hexf64!("-0x1.a8ff971810a5ep-2"),
+        lo: hexf64!("-0x1.817fd3b7d7e5dp-58"),
+    },
+    TwoFloat {
+        // log2(1 - 31/128)
+        hi: hexf64!("-0x1.99b072a96c6b2p-2"),
+        lo: hexf64!("-0x1.bca36fd02def0p-56"),
+    },
+    TwoFloat {
+        // log2(1 - 30/128)
+        hi: hexf64!("-0x1.8a8980abfbd32p-2"),
+        lo: hexf64!("-0x1.99aa6df8b7d83p-56"),
+    },
+    TwoFloat {
+        // log2(1 - 29/128)
+        hi: hexf64!("-0x1.7b89f02cf2aadp-2"),
+        lo: hexf64!("-0x1.8f89e2eb553b2p-57"),
+    },
+    TwoFloat {
+        // log2(1 - 28/128)
+        hi: hexf64!("-0x1.6cb0f6865c8eap-2"),
+        lo: hexf64!("-0x1.b6d40900b2502p-61"),
+    },
+    TwoFloat {
+        // log2(1 - 27/128)
+        hi: hexf64!("-0x1.5dfdcf1eeae0ep-2"),
+        lo: hexf64!("-0x1.10b5b643a6ecbp-56"),
+    },
+    TwoFloat {
+        // log2(1 - 26/128)
+        hi: hexf64!("-0x1.4f6fbb2cec598p-2"),
+        lo: hexf64!("-0x1.e393a16b94b52p-56"),
+    },
+    TwoFloat {
+        // log2(1 - 25/128)
+        hi: hexf64!("-0x1.4106017c3eca3p-2"),
+        lo: hexf64!("0x1.c658d602e66b0p-56"),
+    },
+    TwoFloat {
+        // log2(1 - 24/128)
+        hi: hexf64!("-0x1.32bfee370ee68p-2"),
+        lo: hexf64!("-0x1.968925e378d68p-56"),
+    },
+    TwoFloat {
+        // log2(1 - 23/128)
+        hi: hexf64!("-0x1.249cd2b13cd6cp-2"),
+        lo: hexf64!("-0x1.34107c0e54aedp-56"),
+    },
+    TwoFloat {
+        // log2(1 - 22/128)
+        hi: hexf64!("-0x1.169c05363f158p-2"),
+        lo: hexf64!("-0x1.c8d43e017579bp-56"),
+    },
+    TwoFloat {
+        // log2(1 - 21/128)
+        hi: hexf64!("-0x1.08bce0d95fa38p-2"),
+        lo: hexf64!("0x1.3d56efe4338fep-58"),
+    },
+    TwoFloat {
+        // log2(1 - 20/128)
+        hi: hexf64!("-0x1.f5fd8a9063e35p-3"),
+        lo: hexf64!("0x1.bdc0426c3c274p-57"),
+    },
+    TwoFloat {
+        // log2(1 - 19/128)
+        hi: hexf64!("-0x1.dac22d3e441d3p-3"),
+        lo: hexf64!("0x1.ba8b1f646ab12p-63"),
+    },
+    TwoFloat {
+        // log2(1 - 18/128)
+        hi: hexf64!("-0x1.bfc67a7fff4ccp-3"),
+        lo: hexf64!("-0x1.bc0af7b82e7d7p-61"),
+    },
+    TwoFloat {
+        // log2(1 - 17/128)
+        hi: hexf64!("-0x1.a5094b54d2828p-3"),
+        lo: hexf64!("-0x1.013b6eaceb921p-57"),
+    },
+    TwoFloat {
+        // log2(1 - 16/128)
+        hi: hexf64!("-0x1.8a8980abfbd32p-3"),
+        lo: hexf64!("-0x1.99aa6df8b7d83p-57"),
+    },
+    TwoFloat {
+        // log2(1 - 15/128)
+        hi: hexf64!("-0x1.7046031c79f85p-3"),
+        lo: hexf64!("0x1.0798d1aa21694p-57"),
+    },
+    TwoFloat {
+        // log2(1 - 14/128)
+        hi: hexf64!("-0x1.563dc29ffacb2p-3"),
+        lo: hexf64!("-0x1.90e41bca6ef96p-60"),
+    },
+    TwoFloat {
+        // log2(1 - 13/128)
+        hi: hexf64!("-0x1.3c6fb650cde51p-3"),
+        lo: hexf64!("0x1.7a6ed4e1b0936p-57"),
+    },
+    TwoFloat {
+        // log2(1 - 12/128)
+        hi: hexf64!("-0x1.22dadc2ab3497p-3"),
+        lo: hexf64!("0x1.696e2866c718ep-58"),
+    },
+    TwoFloat {
+        // log2(1 - 11/128)
+        hi: hexf64!("-0x1.097e38ce60649p-3"),
+        lo: hexf64!("-0x1.5d243efd93259p-58"),
+    },
+    TwoFloat {
+        // log2(1 - 10/128)
+        hi: hexf64!("-0x1.e0b1ae8f2fd56p-4"),
+        lo: hexf64!("-0x1.92ce9636c90a0p-58"),
+    },
+    TwoFloat {
+        // log2(1 - 9/128)
+        hi: hexf64!("-0x1.aed391ab6674ep-4"),
+        lo: hexf64!("-0x1.40238de7ea9f1p-58"),
+    },
+    TwoFloat {
+        // log2(1 - 8/128)
+        hi: hexf64!("-0x1.7d60496cfbb4cp-4"),
+        lo: hexf64!("-0x1.9ced1447e30adp-58"),
+    },
+    TwoFloat {
+        // log2(1 - 7/128)
+        hi: hexf64!("-0x1.4c560fe68af88p-4"),
+        lo: hexf64!("-0x1.c141e66faaaadp-61"),
+    },
+    TwoFloat {
+        // log2(1 - 6/128)
+        hi: hexf64!("-0x1.1bb32a600549dp-4"),
+        lo: hexf64!("-0x1.98c5452bbce74p-61"),
+    },
+    TwoFloat {
+        // log2(1 - 5/128)
+        hi: hexf64!("-0x1.d6ebd1f1febfep-5"),
+        lo: hexf64!("-0x1.155660710eb2ap-63"),
+    },
+    TwoFloat {
+        // log2(1 - 4/128)
+        hi: hexf64!("-0x1.77394c9d958d5p-5"),
+        lo: hexf64!("-0x1.77970e03f821cp-59"),
+    },
+    TwoFloat {
+        // log2(1 - 3/128)
+        hi: hexf64!("-0x1.184b8e4c56af8p-5"),
+        lo: hexf64!("-0x1.491f06c085bc2p-60"),
+    },
+    TwoFloat {
+        // log2(1 - 2/128)
+        hi: hexf64!("-0x1.743ee861f3556p-6"),
+        lo: hexf64!("-0x1.b2a41b08fbe06p-61"),
+    },
+    TwoFloat {
+        // log2(1 - 1/128)
+        hi: hexf64!("-0x1.72c7ba20f7327p-7"),
+        lo: hexf64!("-0x1.6d746128b1857p-61"),
+    },
+    TwoFloat {
+        // log2(1 + 0/128)
+        hi: hexf64!("0x0.0p+0"),
+        lo: hexf64!("0x0.0p+0"),
+    },
+    TwoFloat {
+        // log2(1 + 1/128)
+        hi: hexf64!("0x1.6fe50b6ef0851p-7"),
+        lo: hexf64!("0x1.fe38dec005e54p-61"),
+    },
+    TwoFloat {
+        // log2(1 + 2/128)
+        hi: hexf64!("0x1.6e79685c2d22ap-6"),
+        lo: hexf64!("-0x1.d6476077b9fbdp-60"),
+    },
+    TwoFloat {
+        // log2(1 + 3/128)
+        hi: hexf64!("0x1.11cd1d5133413p-5"),
+        lo: hexf64!("-0x1.27ebafb056cb9p-61"),
+    },
+    TwoFloat {
+        // log2(1 + 4/128)
+        hi: hexf64!("0x1.6bad3758efd87p-5"),
+        lo: hexf64!("0x1.89b03784b5be1p-60"),
+    },
+    TwoFloat {
+        // log2(1 + 5/128)
+        hi: hexf64!("0x1.c4dfab90aab5fp-5"),
+        lo: hexf64!("-0x1.60e0f2c3388f0p-62"),
+    },
+    TwoFloat {
+        // log2(1 + 6/128)
+        hi: hexf64!("0x1.0eb389fa29f9bp-4"),
+        lo: hexf64!("-0x1.30c22d15199b8p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 7/128)
+        hi: hexf64!("0x1.3aa2fdd27f1c3p-4"),
+        lo: hexf64!("-0x1.3fd9776f25acfp-59"),
+    },
+    TwoFloat {
+        // log2(1 + 8/128)
+        hi: hexf64!("0x1.663f6fac91316p-4"),
+        lo: hexf64!("0x1.f3314e0985116p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 9/128)
+        hi: hexf64!("0x1.918a16e46335bp-4"),
+        lo: hexf64!("-0x1.463736dac9317p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 10/128)
+        hi: hexf64!("0x1.bc84240adabbap-4"),
+        lo: hexf64!("0x1.8ecb169b9465fp-58"),
+    },
+    TwoFloat {
+        // log2(1 + 11/128)
+        hi: hexf64!("0x1.e72ec117fa5b2p-4"),
+        lo: hexf64!("0x1.cbdb5d9dc29f2p-60"),
+    },
+    TwoFloat {
+        // log2(1 + 12/128)
+        hi: hexf64!("0x1.08c588cda79e4p-3"),
+        lo: hexf64!("-0x1.a7610e40bd6abp-57"),
+    },
+    TwoFloat {
+        // log2(1 + 13/128)
+        hi: hexf64!("0x1.1dcd197552b7bp-3"),
+        lo: hexf64!("0x1.7a9150c1e0e58p-57"),
+    },
+    TwoFloat {
+        // log2(1 + 14/128)
+        hi: hexf64!("0x1.32ae9e278ae1ap-3"),
+        lo: hexf64!("0x1.f51f2c075a74cp-59"),
+    },
+    TwoFloat {
+        // log2(1 + 15/128)
+        hi: hexf64!("0x1.476a9f983f74dp-3"),
+        lo: hexf64!("0x1.89c74a0b21fb6p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 16/128)
+        hi: hexf64!("0x1.5c01a39fbd688p-3"),
+        lo: hexf64!("-0x1.817fd3b7d7e5dp-57"),
+    },
+    TwoFloat {
+        // log2(1 + 17/128)
+        hi: hexf64!("0x1.70742d4ef027fp-3"),
+        lo: hexf64!("0x1.4e00e7d6bbf3ep-58"),
+    },
+    TwoFloat {
+        // log2(1 + 18/128)
+        hi: hexf64!("0x1.84c2bd02f03b3p-3"),
+        lo: hexf64!("-0x1.16edb88c4e2b5p-62"),
+    },
+    TwoFloat {
+        // log2(1 + 19/128)
+        hi: hexf64!("0x1.98edd077e70dfp-3"),
+        lo: hexf64!("0x1.7d6746548b95cp-62"),
+    },
+    TwoFloat {
+        // log2(1 + 20/128)
+        hi: hexf64!("0x1.acf5e2db4ec94p-3"),
+        lo: hexf64!("-0x1.01ee1343fe7cap-59"),
+    },
+    TwoFloat {
+        // log2(1 + 21/128)
+        hi: hexf64!("0x1.c0db6cdd94deep-3"),
+        lo: hexf64!("0x1.0389b662673fcp-57"),
+    },
+    TwoFloat {
+        // log2(1 + 22/128)
+        hi: hexf64!("0x1.d49ee4c325970p-3"),
+        lo: hexf64!("-0x1.b85a54d7ee2fdp-58"),
+    },
+    TwoFloat {
+        // log2(1 + 23/128)
+        hi: hexf64!("0x1.e840be74e6a4dp-3"),
+        lo: hexf64!("-0x1.c1b061571081ep-58"),
+    },
+    TwoFloat {
+        // log2(1 + 24/128)
+        hi: hexf64!("0x1.fbc16b902680ap-3"),
+        lo: hexf64!("0x1.1d46ccc53c278p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 25/128)
+        hi: hexf64!("0x1.0790adbb03009p-2"),
+        lo: hexf64!("0x1.bc0c69a675517p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 26/128)
+        hi: hexf64!("0x1.11307dad30b76p-2"),
+        lo: hexf64!("-0x1.a7b47d2c352d9p-57"),
+    },
+    TwoFloat {
+        // log2(1 + 27/128)
+        hi: hexf64!("0x1.1ac05b291f070p-2"),
+        lo: hexf64!("0x1.4a31ce1b7e328p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 28/128)
+        hi: hexf64!("0x1.24407ab0e073ap-2"),
+        lo: hexf64!("-0x1.f6e91ad16ecffp-56"),
+    },
+    TwoFloat {
+        // log2(1 + 29/128)
+        hi: hexf64!("0x1.2db10fc4d9aafp-2"),
+        lo: hexf64!("0x1.bc4de8f631bcfp-56"),
+    },
+    TwoFloat {
+        // log2(1 + 30/128)
+        hi: hexf64!("0x1.37124cea4cdedp-2"),
+        lo: hexf64!("-0x1.3376649b4fc09p-57"),
+    },
+    TwoFloat {
+        // log2(1 + 31/128)
+        hi: hexf64!("0x1.406463b1b0449p-2"),
+        lo: hexf64!("0x1.d6cbcd10948cdp-56"),
+    },
+    TwoFloat {
+        // log2(1 + 32/128)
+        hi: hexf64!("0x1.49a784bcd1b8bp-2"),
+        lo: hexf64!("-0x1.b6d40900b2502p-62"),
+    },
+    TwoFloat {
+        // log2(1 + 33/128)
+        hi: hexf64!("0x1.52dbdfc4c96b3p-2"),
+        lo: hexf64!("0x1.f73d83987f26dp-56"),
+    },
+    TwoFloat {
+        // log2(1 + 34/128)
+        hi: hexf64!("0x1.5c01a39fbd688p-2"),
+        lo: hexf64!("-0x1.817fd3b7d7e5dp-56"),
+    },
+    TwoFloat {
+        // log2(1 + 35/128)
+        hi: hexf64!("0x1.6518fe4677ba7p-2"),
+        lo: hexf64!("-0x1.add8712376167p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 36/128)
+        hi: hexf64!("0x1.6e221cd9d0cdep-2"),
+        lo: hexf64!("0x1.5e35482d13dc1p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 37/128)
+        hi: hexf64!("0x1.771d2ba7efb3cp-2"),
+        lo: hexf64!("-0x1.b90132aeddb58p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 38/128)
+        hi: hexf64!("0x1.800a563161c54p-2"),
+        lo: hexf64!("0x1.9575b04fa6fbdp-57"),
+    },
+    TwoFloat {
+        // log2(1 + 39/128)
+        hi: hexf64!("0x1.88e9c72e0b226p-2"),
+        lo: hexf64!("-0x1.6d266d6cdc959p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 40/128)
+        hi: hexf64!("0x1.91bba891f1709p-2"),
+        lo: hexf64!("-0x1.2d352bea51e59p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 41/128)
+        hi: hexf64!("0x1.9a802391e232fp-2"),
+        lo: hexf64!("0x1.a5db68721ca61p-57"),
+    },
+    TwoFloat {
+        // log2(1 + 42/128)
+        hi: hexf64!("0x1.a33760a7f6051p-2"),
+        lo: hexf64!("-0x1.8a0efca1a184fp-56"),
+    },
+    TwoFloat {
+        // log2(1 + 43/128)
+        hi: hexf64!("0x1.abe18797f1f49p-2"),
+        lo: hexf64!("-0x1.e5b8daaa73a43p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 44/128)
+        hi: hexf64!("0x1.b47ebf73882a1p-2"),
+        lo: hexf64!("-0x1.6fae441c09d76p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 45/128)
+        hi: hexf64!("0x1.bd0f2e9e79031p-2"),
+        lo: hexf64!("-0x1.52ef4c737fba5p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 46/128)
+        hi: hexf64!("0x1.c592fad295b56p-2"),
+        lo: hexf64!("0x1.f9fb952bbbcccp-56"),
+    },
+    TwoFloat {
+        // log2(1 + 47/128)
+        hi: hexf64!("0x1.ce0a4923a587dp-2"),
+        lo: hexf64!("-0x1.b517ae88c2fd3p-57"),
+    },
+    TwoFloat {
+        // log2(1 + 48/128)
+        hi: hexf64!("0x1.d6753e032ea0fp-2"),
+        lo: hexf64!("-0x1.c141e66faaaadp-62"),
+    },
+    TwoFloat {
+        // log2(1 + 49/128)
+        hi: hexf64!("0x1.ded3fd442364cp-2"),
+        lo: hexf64!("0x1.3aec658457c41p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 50/128)
+        hi: hexf64!("0x1.e726aa1e754d2p-2"),
+        lo: hexf64!("0x1.8a33c25e8e226p-59"),
+    },
+    TwoFloat {
+        // log2(1 + 51/128)
+        hi: hexf64!("0x1.ef6d67328e220p-2"),
+        lo: hexf64!("0x1.f47806a0e4105p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 52/128)
+        hi: hexf64!("0x1.f7a8568cb06cfp-2"),
+        lo: hexf64!("-0x1.8f3673ffdd785p-57"),
+    },
+    TwoFloat {
+        // log2(1 + 53/128)
+        hi: hexf64!("0x1.ffd799a83ff9bp-2"),
+        lo: hexf64!("-0x1.18ce032f41d1ep-56"),
+    },
+    TwoFloat {
+        // log2(1 + 54/128)
+        hi: hexf64!("0x1.03fda8b97997fp-1"),
+        lo: hexf64!("0x1.9ca1a3202b3d7p-56"),
+    },
+    TwoFloat {
+        // log2(1 + 55/128)
+        hi: hexf64!("0x1.0809cf27f703dp-1"),
+        lo: hexf64!("0x1.496cf074560fbp-55"),
+    },
+    TwoFloat {
+        // log2(1 + 56/128)
+        hi: hexf64!("0x1.0c10500d63aa6p-1"),
+        lo: hexf64!("0x1.62095d4a6d897p-55"),
+    },
+    TwoFloat {
+        // log2(1 + 57/128)
+        hi: hexf64!("0x1.10113b153c8eap-1"),
+        lo: hexf64!("0x1.ec7376b9beb39p-55"),
+    },
+    TwoFloat {
+        // log2(1 + 58/128)
+        hi: hexf64!("0x1.140c9faa1e544p-1"),
+        lo: hexf64!("-0x1.87a96b573a7edp-55"),
+    },
+    TwoFloat {
+        // log2(1 + 59/128)
+        hi: hexf64!("0x1.18028cf72976ap-1"),
+        lo: hexf64!("0x1.3ae3a5f4514cdp-55"),
+    },
+    TwoFloat {
+        // log2(1 + 60/128)
+        hi: hexf64!("0x1.1bf311e95d00ep-1"),
+        lo: hexf64!("-0x1.c4aec56233279p-57"),
+    },
+    TwoFloat {
+        // log2(1 + 61/128)
+        hi: hexf64!("0x1.1fde3d30e8126p-1"),
+        lo: hexf64!("0x1.0905751ce113cp-55"),
+    },
+    TwoFloat {
+        // log2(1 + 62/128)
+        hi: hexf64!("0x1.23c41d42727c8p-1"),
+        lo: hexf64!("0x1.01d98c3531027p-58"),
+    },
+    TwoFloat {
+        // log2(1 + 63/128)
+        hi: hexf64!("0x1.27a4c0585cbf8p-1"),
+        lo: hexf64!("0x1.5e13b838eba7dp-59"),
+    },
+    TwoFloat {
+        // log2(1 + 64/128)
+        hi: hexf64!("0x1.2b803473f7ad1p-1"),
+        lo: hexf64!("-0x1.817fd3b7d7e5dp-58"),
+    },
+];
+
+// Values of log10(1 + n/128) for -32 <= n <= 64
+const LOG10_1P_128TH: [TwoFloat; 97] = [
+    TwoFloat {
+        // log10(1 - 32/128)
+        hi: hexf64!("-0x1.ffbfc2bbc7803p-4"),
+        lo: hexf64!("-0x1.d60df12c2e13dp-58"),
+    },
+    TwoFloat {
+        // log10(1 - 31/128)
+        hi: hexf64!("-0x1.ed50a4a26eafcp-4"),
+        lo: hexf64!("0x1.0a89525f3e005p-61"),
+    },
+    TwoFloat {
+        // log10(1 - 30/128)
+        hi: hexf64!("-0x1.db11ed766abf4p-4"),
+        lo: hexf64!("-0x1.96e1e0dda0b3dp-59"),
+    },
+    TwoFloat {
+        // log10(1 - 29/128)
+        hi: hexf64!("-0x1.c902a19e65111p-4"),
+        lo: hexf64!("-0x1.ce424933542a9p-58"),
+    },
+    TwoFloat {
+        // log10(1 - 28/128)
+        hi: hexf64!("-0x1.b721cd17157e3p-4"),
+        lo: hexf64!("0x1.a8267d18975f3p-58"),
+    },
+    TwoFloat {
+        // log10(1 - 27/128)
+        hi: hexf64!("-0x1.a56e8325f5c87p-4"),
+        lo: hexf64!("0x1.113a1fc340179p-60"),
+    },
+    TwoFloat {
+        // log10(1 - 26/128)
+        hi: hexf64!("-0x1.93e7de0fc3e80p-4"),
+        lo: hexf64!("0x1.d0d01941798b6p-58"),
+    },
+    TwoFloat {
+        // log10(1 - 25/128)
+        hi: hexf64!("-0x1.828cfed29a215p-4"),
+        lo: hexf64!("0x1.3f06091db6450p-58"),
     },
     TwoFloat {
-        // 1/1!
-        hi: hexf64!("0x1.0000000000000p+0"),
-        lo: hexf64!("0x0.0p+0"),
+        // log10(1 - 24/128)
+        hi: hexf64!("-0x1.715d0ce367afcp-4"),
+        lo: hexf64!("0x1.49840eaa6487bp-58"),
     },
     TwoFloat {
-        // 1/2!
-        hi: hexf64!("0x1.0000000000000p-1"),
+        // log10(1 - 23/128)
+        hi: hexf64!("-0x1.605735ee985f1p-4"),
+        lo: hexf64!("0x1.86e2f4130bd29p-61"),
+    },
+    TwoFloat {
+        // log10(1 - 22/128)
+        hi: hexf64!("-0x1.4f7aad9bbcbafp-4"),
+        lo: hexf64!("0x1.5d69e39196f27p-59"),
+    },
+    TwoFloat {
+        // log10(1 - 21/128)
+        hi: hexf64!("-0x1.3ec6ad5407868p-4"),
+        lo: hexf64!("-0x1.c4c753d98645ap-58"),
+    },
+    TwoFloat {
+        // log10(1 - 20/128)
+        hi: hexf64!("-0x1.2e3a740b7800fp-4"),
+        lo: hexf64!("0x1.aef53f2ecddb9p-58"),
+    },
+    TwoFloat {
+        // log10(1 - 19/128)
+        hi: hexf64!("-0x1.1dd5460c8b16fp-4"),
+        lo: hexf64!("0x1.f587e7a233c80p-59"),
+    },
+    TwoFloat {
+        // log10(1 - 18/128)
+        hi: hexf64!("-0x1.0d966cc6500fap-4"),
+        lo: hexf64!("0x1.195d4e57a7a13p-58"),
+    },
+    TwoFloat {
+        // log10(1 - 17/128)
+        hi: hexf64!("-0x1.fafa6d397efdbp-5"),
+        lo: hexf64!("-0x1.62f731cfeb27bp-61"),
+    },
+    TwoFloat {
+        // log10(1 - 16/128)
+        hi: hexf64!("-0x1.db11ed766abf4p-5"),
+        lo: hexf64!("-0x1.96e1e0dda0b3dp-60"),
+    },
+    TwoFloat {
+        // log10(1 - 15/128)
+        hi: hexf64!("-0x1.bb7209d1e24e5p-5"),
+        lo: hexf64!("-0x1.4af56a02aee6fp-59"),
+    },
+    TwoFloat {
+        // log10(1 - 14/128)
+        hi: hexf64!("-0x1.9c197abf00dd7p-5"),
+        lo: hexf64!("0x1.86148dd4cfbf5p-61"),
+    },
+    TwoFloat {
+        // log10(1 - 13/128)
+        hi: hexf64!("-0x1.7d070145f4fd7p-5"),
+        lo: hexf64!("-0x1.2d4e10b03cee8p-59"),
+    },
+    TwoFloat {
+        // log10(1 - 12/128)
+        hi: hexf64!("-0x1.5e3966b7e9295p-5"),
+        lo: hexf64!("-0x1.bb9cd2fb75402p-61"),
+    },
+    TwoFloat {
+        // log10(1 - 11/128)
+        hi: hexf64!("-0x1.3faf7c663060ep-5"),
+        lo: hexf64!("0x1.9d0e7e0ac0ee2p-59"),
+    },
+    TwoFloat {
+        // log10(1 - 10/128)
+        hi: hexf64!("-0x1.21681b5c8c213p-5"),
+        lo: hexf64!("-0x1.63610c66f42b2p-59"),
+    },
+    TwoFloat {
+        // log10(1 - 9/128)
+        hi: hexf64!("-0x1.0362241e638ecp-5"),
+        lo: hexf64!("-0x1.7db4db93811b8p-59"),
+    },
+    TwoFloat {
+        // log10(1 - 8/128)
+        hi: hexf64!("-0x1.cb38fccd8bfdbp-6"),
+        lo: hexf64!("-0x1.a5aca518d962ep-60"),
+    },
+    TwoFloat {
+        // log10(1 - 7/128)
+        hi: hexf64!("-0x1.902c31d62a843p-6"),
+        lo: hexf64!("-0x1.d5af81a520735p-60"),
+    },
+    TwoFloat {
+        // log10(1 - 6/128)
+        hi: hexf64!("-0x1.559bd2406c3bap-6"),
+        lo: hexf64!("-0x1.521ad5fa98432p-63"),
+    },
+    TwoFloat {
+        // log10(1 - 5/128)
+        hi: hexf64!("-0x1.1b85d6044e9aep-6"),
+        lo: hexf64!("0x1.646ebcccb6894p-66"),
+    },
+    TwoFloat {
+        // log10(1 - 4/128)
+        hi: hexf64!("-0x1.c3d0837784c41p-7"),
+        lo: hexf64!("0x1.8d01e8ac8a78bp-61"),
+    },
+    TwoFloat {
+        // log10(1 - 3/128)
+        hi: hexf64!("-0x1.51824c7587eb0p-7"),
+        lo: hexf64!("0x1.4c944fe8f1a45p-62"),
+    },
+    TwoFloat {
+        // log10(1 - 2/128)
+        hi: hexf64!("-0x1.c03a80ae5e054p-8"),
+        lo: hexf64!("0x1.f4ab82393c268p-62"),
+    },
+    TwoFloat {
+        // log10(1 - 1/128)
+        hi: hexf64!("-0x1.be76bd77b4fc3p-9"),
+        lo: hexf64!("-0x1.ad96bce53f818p-66"),
+    },
+    TwoFloat {
+        // log10(1 + 0/128)
+        hi: hexf64!("0x0.0p+0"),
         lo: hexf64!("0x0.0p+0"),
     },
     TwoFloat {
-        // 1/3!
-        hi: hexf64!("0x1.5555555555555p-3"),
-        lo: hexf64!("0x1.5555555555555p-57"),
+        // log10(1 + 1/128)
+        hi: hexf64!("0x1.bafd47221ed26p-9"),
+        lo: hexf64!("0x1.9706ea523f0a5p-63"),
     },
     TwoFloat {
-        // 1/4!
-        hi: hexf64!("0x1.5555555555555p-5"),
-        lo: hexf64!("0x1.5555555555555p-59"),
+        // log10(1 + 2/128)
+        hi: hexf64!("0x1.b9476a4fcd10fp-8"),
+        lo: hexf64!("-0x1.3b252df477a75p-63"),
     },
     TwoFloat {
-        // 1/5!
-        hi: hexf64!("0x1.1111111111111p-7"),
-        lo: hexf64!("0x1.1111111111111p-63"),
+        // log10(1 + 3/128)
+        hi: hexf64!("0x1.49b0851443684p-7"),
+        lo: hexf64!("-0x1.8f207a6d0d0b1p-62"),
     },
     TwoFloat {
-        // 1/6!
-        hi: hexf64!("0x1.6c16c16c16c17p-10"),
-        lo: hexf64!("-0x1.f49f49f49f49fp-65"),
+        // log10(1 + 4/128)
+        hi: hexf64!("0x1.b5e908eb13790p-7"),
+        lo: hexf64!("0x1.f2e9fe367a511p-64"),
     },
     TwoFloat {
-        // 1/7!
-        hi: hexf64!("0x1.a01a01a01a01ap-13"),
-        lo: hexf64!("0x1.a01a01a01a01ap-73"),
+        // log10(1 + 5/128)
+        hi: hexf64!("0x1.10a83a8446c78p-6"),
+        lo: hexf64!("-0x1.7b9fd5428084fp-60"),
     },
     TwoFloat {
-        // 1/8!
-        hi: hexf64!("0x1.a01a01a01a01ap-16"),
-        lo: hexf64!("0x1.a01a01a01a01ap-76"),
+        // log10(1 + 6/128)
+        hi: hexf64!("0x1.45f4f5acb8be0p-6"),
+        lo: hexf64!("0x1.dda7897a55eb5p-60"),
     },
     TwoFloat {
-        // 1/9!
-        hi: hexf64!("0x1.71de3a556c734p-19"),
-        lo: hexf64!("-0x1.c154f8ddc6c00p-73"),
+        // log10(1 + 7/128)
+        hi: hexf64!("0x1.7adc3df3b1ff8p-6"),
+        lo: hexf64!("0x1.b980714c596a3p-62"),
     },
     TwoFloat {
-        // 1/10!
-        hi: hexf64!("0x1.27e4fb7789f5cp-22"),
-        lo: hexf64!("0x1.cbbc05b4fa99ap-76"),
+        // log10(1 + 8/128)
+        hi: hexf64!("0x1.af5f92b00e610p-6"),
+        lo: hexf64!("-0x1.6487d64961833p-60"),
     },
     TwoFloat {
-        // 1/11!
-        hi: hexf64!("0x1.ae64567f544e4p-26"),
-        lo: hexf64!("-0x1.c062e06d1f209p-80"),
+        // log10(1 + 9/128)
+        hi: hexf64!("0x1.e3806acbd058fp-6"),
+        lo: hexf64!("0x1.af3eb3b443356p-63"),
     },
     TwoFloat {
-        // 1/12!
-        hi: hexf64!("0x1.1eed8eff8d898p-29"),
-        lo: hexf64!("-0x1.2aec959e14c06p-83"),
+        // log10(1 + 10/128)
+        hi: hexf64!("0x1.0ba01a8170000p-5"),
+        lo: hexf64!("0x1.5f1d45244f437p-60"),
     },
     TwoFloat {
-        // 1/13!
-        hi: hexf64!("0x1.6124613a86d09p-33"),
-        lo: hexf64!("0x1.f28e0cc748ebep-87"),
+        // log10(1 + 11/128)
+        hi: hexf64!("0x1.25502c0fc314cp-5"),
+        lo: hexf64!("-0x1.ff894a084ae68p-59"),
     },
     TwoFloat {
-        // 1/14!
-        hi: hexf64!("0x1.93974a8c07c9dp-37"),
-        lo: hexf64!("0x1.05d6f8a2efd1fp-92"),
+        // log10(1 + 12/128)
+        hi: hexf64!("0x1.3ed1199a5e425p-5"),
+        lo: hexf64!("0x1.ba93eba3e387fp-64"),
     },
     TwoFloat {
-        // 1/15!
-        hi: hexf64!("0x1.ae7f3e733b81fp-41"),
-        lo: hexf64!("0x1.1d8656b0ee8cbp-97"),
+        // log10(1 + 13/128)
+        hi: hexf64!("0x1.58238eeb353dap-5"),
+        lo: hexf64!("0x1.efd454f7ea69ap-59"),
     },
     TwoFloat {
-        // 1/16!
-        hi: hexf64!("0x1.ae7f3e733b81fp-45"),
-        lo: hexf64!("0x1.1d8656b0ee8cbp-101"),
+        // log10(1 + 14/128)
+        hi: hexf64!("0x1.71483427d2a99p-5"),
+        lo: hexf64!("-0x1.8f0f77fcff1d9p-60"),
     },
     TwoFloat {
-        // 1/17!
-        hi: hexf64!("0x1.952c77030ad4ap-49"),
-        lo: hexf64!("0x1.ac981465ddc6cp-103"),
+        // log10(1 + 15/128)
+        hi: hexf64!("0x1.8a3fadeb847f4p-5"),
+        lo: hexf64!("-0x1.b144b06126f68p-59"),
     },
     TwoFloat {
-        // 1/18!
-        hi: hexf64!("0x1.6827863b97d97p-53"),
-        lo: hexf64!("0x1.eec01221a8b0bp-107"),
+        // log10(1 + 16/128)
+        hi: hexf64!("0x1.a30a9d609efeap-5"),
+        lo: hexf64!("-0x1.ebf33e9410429p-60"),
     },
     TwoFloat {
-        // 1/19!
-        hi: hexf64!("0x1.2f49b46814157p-57"),
-        lo: hexf64!("0x1.2650f61dbdcb4p-112"),
+        // log10(1 + 17/128)
+        hi: hexf64!("0x1.bba9a058dfd84p-5"),
+        lo: hexf64!("0x1.a9796c3448989p-61"),
     },
     TwoFloat {
-        // 1/20!
-        hi: hexf64!("0x1.e542ba4020225p-62"),
-        lo: hexf64!("0x1.ea72b4afe3c2fp-120"),
+        // log10(1 + 18/128)
+        hi: hexf64!("0x1.d41d5164facb4p-5"),
+        lo: hexf64!("-0x1.7f9dc537bfbfbp-59"),
+    },
+    TwoFloat {
+        // log10(1 + 19/128)
+        hi: hexf64!("0x1.ec6647eb58808p-5"),
+        lo: hexf64!("0x1.1f406230b3528p-59"),
+    },
+    TwoFloat {
+        // log10(1 + 20/128)
+        hi: hexf64!("0x1.02428c1f08016p-4"),
+        lo: hexf64!("-0x1.5943d4373d44ap-60"),
+    },
+    TwoFloat {
+        // log10(1 + 21/128)
+        hi: hexf64!("0x1.0e3d29d81165ep-4"),
+        lo: hexf64!("0x1.89565863c8cf4p-58"),
+    },
+    TwoFloat {
+        // log10(1 + 22/128)
+        hi: hexf64!("0x1.1a23445501816p-4"),
+        lo: hexf64!("-0x1.f990c2c07d3b5p-59"),
+    },
+    TwoFloat {
+        // log10(1 + 23/128)
+        hi: hexf64!("0x1.25f5215eb594ap-4"),
+        lo: hexf64!("-0x1.06ad025ca3a44p-59"),
+    },
+    TwoFloat {
+        // log10(1 + 24/128)
+        hi: hexf64!("0x1.31b3055c47118p-4"),
+        lo: hexf64!("0x1.b420b9b202eddp-64"),
+    },
+    TwoFloat {
+        // log10(1 + 25/128)
+        hi: hexf64!("0x1.3d5d335c53179p-4"),
+        lo: hexf64!("-0x1.a83d8a6eb8e2ep-59"),
+    },
+    TwoFloat {
+        // log10(1 + 26/128)
+        hi: hexf64!("0x1.48f3ed1df48fbp-4"),
+        lo: hexf64!("0x1.782120ed9fd02p-58"),
+    },
+    TwoFloat {
+        // log10(1 + 27/128)
+        hi: hexf64!("0x1.5477731973e85p-4"),
+        lo: hexf64!("-0x1.e1bcfb0476f5dp-58"),
+    },
+    TwoFloat {
+        // log10(1 + 28/128)
+        hi: hexf64!("0x1.5fe80488af4fdp-4"),
+        lo: hexf64!("-0x1.5b6acfce71752p-58"),
+    },
+    TwoFloat {
+        // log10(1 + 29/128)
+        hi: hexf64!("0x1.6b45df6f3e2c9p-4"),
+        lo: hexf64!("0x1.643835531d8eep-58"),
+    },
+    TwoFloat {
+        // log10(1 + 30/128)
+        hi: hexf64!("0x1.769140a2526fdp-4"),
+        lo: hexf64!("-0x1.ac4c370ae3c1dp-58"),
+    },
+    TwoFloat {
+        // log10(1 + 31/128)
+        hi: hexf64!("0x1.81ca63d05a44ap-4"),
+        lo: hexf64!("-0x1.f639ecb00a83ap-58"),
+    },
+    TwoFloat {
+        // log10(1 + 32/128)
+        hi: hexf64!("0x1.8cf183886480dp-4"),
+        lo: hexf64!("-0x1.935d381a0844fp-58"),
+    },
+    TwoFloat {
+        // log10(1 + 33/128)
+        hi: hexf64!("0x1.9806d9414a209p-4"),
+        lo: hexf64!("0x1.c81cca3dd9b7bp-58"),
+    },
+    TwoFloat {
+        // log10(1 + 34/128)
+        hi: hexf64!("0x1.a30a9d609efeap-4"),
+        lo: hexf64!("-0x1.ebf33e9410429p-59"),
+    },
+    TwoFloat {
+        // log10(1 + 35/128)
+        hi: hexf64!("0x1.adfd07416be07p-4"),
+        lo: hexf64!("-0x1.448acb08c4bcap-63"),
+    },
+    TwoFloat {
+        // log10(1 + 36/128)
+        hi: hexf64!("0x1.b8de4d3ab3d98p-4"),
+        lo: hexf64!("-0x1.446d00b829ad4p-61"),
+    },
+    TwoFloat {
+        // log10(1 + 37/128)
+        hi: hexf64!("0x1.c3aea4a5c6effp-4"),
+        lo: hexf64!("-0x1.8b9190212e5bap-58"),
+    },
+    TwoFloat {
+        // log10(1 + 38/128)
+        hi: hexf64!("0x1.ce6e41e463da5p-4"),
+        lo: hexf64!("-0x1.6f0603909a181p-61"),
+    },
+    TwoFloat {
+        // log10(1 + 39/128)
+        hi: hexf64!("0x1.d91d5866aa99cp-4"),
+        lo: hexf64!("-0x1.ce84c9eaee37ap-58"),
+    },
+    TwoFloat {
+        // log10(1 + 40/128)
+        hi: hexf64!("0x1.e3bc1ab0e19fep-4"),
+        lo: hexf64!("0x1.eab1529f83ac7p-59"),
+    },
+    TwoFloat {
+        // log10(1 + 41/128)
+        hi: hexf64!("0x1.ee4aba610f204p-4"),
+        lo: hexf64!("0x1.c427300821266p-58"),
+    },
+    TwoFloat {
+        // log10(1 + 42/128)
+        hi: hexf64!("0x1.f8c9683468191p-4"),
+        lo: hexf64!("-0x1.ec7f2dac60a5cp-58"),
+    },
+    TwoFloat {
+        // log10(1 + 43/128)
+        hi: hexf64!("0x1.019c2a064b486p-3"),
+        lo: hexf64!("0x1.c5e9d9a0e1fd9p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 44/128)
+        hi: hexf64!("0x1.06cbd67a6c3b6p-3"),
+        lo: hexf64!("0x1.5163143f60061p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 45/128)
+        hi: hexf64!("0x1.0bf3d0937c41cp-3"),
+        lo: hexf64!("0x1.e17a06836db63p-58"),
+    },
+    TwoFloat {
+        // log10(1 + 46/128)
+        hi: hexf64!("0x1.11142f0811357p-3"),
+        lo: hexf64!("-0x1.b8c4f1b08949bp-59"),
+    },
+    TwoFloat {
+        // log10(1 + 47/128)
+        hi: hexf64!("0x1.162d082ac9d10p-3"),
+        lo: hexf64!("-0x1.c6397435bc5b6p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 48/128)
+        hi: hexf64!("0x1.1b3e71ec94f7bp-3"),
+        lo: hexf64!("-0x1.1113336d7c017p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 49/128)
+        hi: hexf64!("0x1.204881dee8777p-3"),
+        lo: hexf64!("0x1.54b04da9d7f6dp-57"),
+    },
+    TwoFloat {
+        // log10(1 + 50/128)
+        hi: hexf64!("0x1.254b4d35e7d3cp-3"),
+        lo: hexf64!("0x1.d7958ffee72acp-59"),
+    },
+    TwoFloat {
+        // log10(1 + 51/128)
+        hi: hexf64!("0x1.2a46e8ca7ba2ap-3"),
+        lo: hexf64!("-0x1.aa8c8a1f1c5fbp-57"),
+    },
+    TwoFloat {
+        // log10(1 + 52/128)
+        hi: hexf64!("0x1.2f3b691c5a001p-3"),
+        lo: hexf64!("-0x1.072d03df862acp-57"),
+    },
+    TwoFloat {
+        // log10(1 + 53/128)
+        hi: hexf64!("0x1.3428e2540096dp-3"),
+        lo: hexf64!("0x1.db19f0230af8bp-58"),
+    },
+    TwoFloat {
+        // log10(1 + 54/128)
+        hi: hexf64!("0x1.390f6844a0b83p-3"),
+        lo: hexf64!("0x1.4eaa9265471b5p-62"),
+    },
+    TwoFloat {
+        // log10(1 + 55/128)
+        hi: hexf64!("0x1.3def0e6dfdf85p-3"),
+        lo: hexf64!("-0x1.5eff6a51557dep-59"),
+    },
+    TwoFloat {
+        // log10(1 + 56/128)
+        hi: hexf64!("0x1.42c7e7fe3fc02p-3"),
+        lo: hexf64!("-0x1.d22abd8abe1b5p-58"),
+    },
+    TwoFloat {
+        // log10(1 + 57/128)
+        hi: hexf64!("0x1.479a07d3b6411p-3"),
+        lo: hexf64!("0x1.0b28e96c1434fp-57"),
+    },
+    TwoFloat {
+        // log10(1 + 58/128)
+        hi: hexf64!("0x1.4c65807e93338p-3"),
+        lo: hexf64!("0x1.0cb15e9cbb524p-58"),
+    },
+    TwoFloat {
+        // log10(1 + 59/128)
+        hi: hexf64!("0x1.512a644296c3dp-3"),
+        lo: hexf64!("-0x1.3da42e36a831ep-57"),
+    },
+    TwoFloat {
+        // log10(1 + 60/128)
+        hi: hexf64!("0x1.55e8c518b10f8p-3"),
+        lo: hexf64!("0x1.66fc0dd411a45p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 61/128)
+        hi: hexf64!("0x1.5aa0b4b0988fap-3"),
+        lo: hexf64!("-0x1.c2d2132aa11d3p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 62/128)
+        hi: hexf64!("0x1.5f52447255c92p-3"),
+        lo: hexf64!("0x1.39b9a5665fe36p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 63/128)
+        hi: hexf64!("0x1.63fd857fc49bbp-3"),
+        lo: hexf64!("-0x1.60fccbbe64ad0p-57"),
+    },
+    TwoFloat {
+        // log10(1 + 64/128)
+        hi: hexf64!("0x1.68a288b60b7fcp-3"),
+        lo: hexf64!("0x1.5b1121872a033p-58"),
     },
 ];
 
@@ -483,6 +2017,80 @@ fn expm1_128th(n: i32) -> TwoFloat {
     EXPM1_128TH[(n + 32) as usize]
 }
 
+/// Multiplies two values keeping the cross terms to full precision, which
+/// gives a smaller error than the `Mul` implementation.
+fn mul_accurate(a: TwoFloat, b: TwoFloat) -> TwoFloat {
+    let p = TwoFloat::new_mul(a.hi, b.hi);
+    let q1 = TwoFloat::new_mul(a.hi, b.lo);
+    let q2 = TwoFloat::new_mul(a.lo, b.hi);
+    let s1 = TwoFloat::new_add(p.lo, q1.hi);
+    let s2 = TwoFloat::new_add(s1.hi, q2.hi);
+    let tail = s1.lo + s2.lo + q1.lo + q2.lo + a.lo * b.lo;
+    let v = fast_two_sum(p.hi, s2.hi);
+    fast_two_sum(v.hi, v.lo + tail)
+}
+
+// Largest power of ten which is exactly representable as a `TwoFloat`, as
+// 5^45 < 2^106
+const MAX_EXACT_POWER_OF_TEN: i32 = 45;
+
+/// Returns `n` if the value is exactly `10^n`, which is only possible for
+/// non-negative `n` up to `MAX_EXACT_POWER_OF_TEN`.
+fn exact_power_of_ten(x: TwoFloat) -> Option<i32> {
+    let n = libm::round(libm::log10(x.hi));
+    if !(0.0..=MAX_EXACT_POWER_OF_TEN as f64).contains(&n) {
+        return None;
+    }
+
+    // Compare the value divided by 2^n with 5^n < 2^105 as an integer, where
+    // the division is exact for integer words
+    let n = n as i32;
+    let is_integer = |w: f64| w == libm::trunc(w);
+    let (hi, lo) = (libm::ldexp(x.hi, -n), libm::ldexp(x.lo, -n));
+    if is_integer(x.lo)
+        && is_integer(hi)
+        && is_integer(lo)
+        && hi as i128 + lo as i128 == 5i128.pow(n as u32)
+    {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// Splits a finite positive value into `2^e * m` with `3/4 <= m < 3/2`,
+/// returning `e`, the index of the closest entry `c = 1 + n/128` in the
+/// logarithm tables, `t` and `ln(1 + t) - t`, where `m = c * (1 + t)`.
+///
+/// As `|t| <= 1/192`, `ln(1 + t)` is evaluated using a minimax polynomial.
+/// The terms are returned separately so that they can be scaled for other
+/// bases without losing accuracy.
+fn ln_reduced(x: TwoFloat) -> (i32, usize, TwoFloat, TwoFloat) {
+    // Scale subnormal values into the normal range, the low word is zero
+    let (x, offset) = if x.hi < f64::MIN_POSITIVE {
+        (TwoFloat::from(x.hi * hexf64!("0x1.0p54")), -54)
+    } else {
+        (x, 0)
+    };
+
+    let bits = x.hi.to_bits();
+    let mut e = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    if bits & (1 << 51) != 0 {
+        e += 1;
+    }
+
+    let m = TwoFloat {
+        hi: mul_pow2(x.hi, -e),
+        lo: mul_pow2(x.lo, -e),
+    };
+
+    let n = libm::round(128.0 * (m.hi - 1.0));
+    let c = 1.0 + n / 128.0;
+    let t = (m - c) / c;
+    let r = t * t * polynomial!(t, -0.5, LN_1P_COEFFS);
+    (e + offset, (n as i32 + 32) as usize, t, r)
+}
+
 /// Compute exp(1/2)^n with n = 32*a + b
 ///
 /// exp(1/2)^n = exp(32/2)^a * exp(1/2)^b
@@ -927,6 +2535,16 @@ impl TwoFloat {
     /// (Shout-out to the author of  [libxprec](https://github.com/tuwien-cms/libxprec) for
     /// pointing it out )
     ///
+    /// Arguments beyond ±708 are first reduced by a multiple `k` of ln(2),
+    /// and the result multiplied by 2<sup>k</sup>, so that the result
+    /// overflows only above ln(`f64::MAX`) ≈ 709.78 and underflows gradually
+    /// to zero below around -745.13.
+    ///
+    /// In testing the absolute error is less than 16u² times the result
+    /// plus 2<sup>-1072</sup>, where u = 2<sup>-53</sup>. The absolute term
+    /// allows for the low word being subnormal for results below around
+    /// 2<sup>-968</sup>, whose relative error is therefore larger.
+    ///
     /// # Examples
     ///
     /// ```
@@ -941,14 +2559,22 @@ impl TwoFloat {
         if self.hi <= EXP_LOWER_LIMIT {
             Self::from(0.0)
         } else if self.hi >= EXP_UPPER_LIMIT {
-            Self {
-                hi: f64::INFINITY,
-                lo: 0.0,
-            }
+            Self::INFINITY
         } else if self.hi == 0.0 {
             Self::from(1.0)
         } else if self.hi.is_nan() {
             Self::NAN
+        } else if libm::fabs(self.hi) > EXP_SCALING_LIMIT {
+            // x = k ln(2) + r, where the product with the high part of ln(2)
+            // is exact as k has at most 11 bits
+            let k = libm::round(self.hi / LN_2_HI);
+            let r = (self - k * LN_2_HI) - LN_2_LO * k;
+            let result = scale_pow2(r.exp(), k as i32);
+            if !result.hi.is_finite() {
+                Self::INFINITY
+            } else {
+                result
+            }
         } else {
             // Compute the exponential of x = y/2 + z
             // Where y = round(2*x) giving z <= 0.25
@@ -973,6 +2599,10 @@ impl TwoFloat {
     /// Returns `e^(self) - 1` in a way that provides additional accuracy
     /// when the value is close to zero.
     ///
+    /// Arguments up to 2 in magnitude are halved until they are in range of
+    /// the table-based reduction used by `exp`, with the doubling formula
+    /// `expm1(2x) = expm1(x) * (expm1(x) + 2)` applied to the result.
+    ///
     /// # Examples
     ///
     /// ```
@@ -991,23 +2621,37 @@ impl TwoFloat {
     /// assert!(((c-res)/res).abs() < 1e-29);
     /// ```
     pub fn exp_m1(self) -> Self {
-        if self < -LN_2 || self > LN_FRAC_3_2 {
-            self.exp() - 1.0
-        } else {
-            let x = self.abs();
-            let r = polynomial!(x, 1.0, FRAC_FACT[2..15]);
-            if self < 0.0 {
-                self * r * self.exp()
-            } else {
-                self * r
+        if self.hi.is_nan() {
+            Self::NAN
+        } else if self.hi == 0.0 {
+            Self::from(self.hi)
+        } else if self.hi.abs() <= 0.25 {
+            self.expm1_quarter()
+        } else if self.hi.abs() <= 2.0 {
+            let mut k = 0;
+            let mut x = self;
+            while x.hi.abs() > 0.25 {
+                x /= 2.0;
+                k += 1;
+            }
+
+            let mut result = x.expm1_quarter();
+            for _ in 0..k {
+                result = mul_accurate(result, result + 2.0);
             }
+            result
+        } else {
+            self.exp() - 1.0
         }
     }
 
     /// Returns `2^(self)`.
     ///
-    /// where self = k + r * n,  k > 0 and n = 2^9 = 512
-    /// The taylor series for the small value of r converges very fast
+    /// The argument is split as `self = k + r` where `k` is the nearest
+    /// integer, and the result computed as `exp(r ln(2))` multiplied by
+    /// 2<sup>k</sup>. The result overflows only for arguments of at least
+    /// 1024, and underflows gradually through the subnormal range to zero
+    /// below -1075.
     ///
     /// # Examples
     ///
@@ -1018,51 +2662,36 @@ impl TwoFloat {
     /// let c = (TwoFloat::from(0.5)*twofloat::consts::LN_2).exp();
     /// let res = twofloat::consts::SQRT_2;
     ///
-    /// assert!((a - res).abs() < 1e-29);
+    /// assert!((a - res).abs() < 1e-31);
     /// assert!((b - res).abs() < 1e-31);
     /// assert!((c - res).abs() < 1e-31);
     /// ```
     pub fn exp2(self) -> Self {
-        if self < -1074.0 {
+        if self.hi.is_nan() {
+            Self::NAN
+        } else if self >= EXP2_UPPER_LIMIT {
+            Self::INFINITY
+        } else if self.hi < EXP2_LOWER_LIMIT {
             Self::from(0.0)
-        } else if self >= 1023.0 {
-            Self {
-                hi: f64::INFINITY,
-                lo: f64::INFINITY,
-            }
         } else {
             let k = libm::round(self.hi);
-            let r = (self - k) * LN_2 / 512.0;
-            //let x = self * LN_2;
-            let mut r1 = polynomial!(r, FRAC_FACT[..12]);
-
-            // Recover rescaling of r
-            r1 = r1 * r1; // 2^(r * 2)
-            r1 = r1 * r1; // 2^(r * 4)
-            r1 = r1 * r1; // 2^(r * 8)
-            r1 = r1 * r1; // 2^(r * 16)
-            r1 = r1 * r1; // 2^(r * 32)
-            r1 = r1 * r1; // 2^(r * 64)
-            r1 = r1 * r1; // 2^(r * 128)
-            r1 = r1 * r1; // 2^(r * 256)
-            r1 = r1 * r1; // 2^(r * 512)
-
-            //let r1 = polynomial!(r, 1.0, EXP2_COEFFS);
-            if k == 0.0 {
-                r1
+            let r = (self - k) * LN_2;
+            let result = scale_pow2(r.exp(), k as i32);
+            if !result.hi.is_finite() {
+                Self::INFINITY
             } else {
-                Self {
-                    hi: mul_pow2(r1.hi, k as i32),
-                    lo: mul_pow2(r1.lo, k as i32),
-                }
+                result
             }
         }
     }
 
     /// Returns the natural logarithm of the value.
     ///
-    /// Uses Newton–Raphson iteration which depends on the `exp` function, so
-    /// may not be fully accurate to the full precision of a `TwoFloat`.
+    /// The argument is reduced using a table of logarithms and the remainder
+    /// is evaluated with a polynomial approximation, giving an error of less
+    /// than 2 ulp, or a relative error of less than 8u² where
+    /// u = 2<sup>-53</sup>. Returns negative infinity for zero and NaN for
+    /// negative values.
     ///
     /// # Example
     ///
@@ -1071,21 +2700,32 @@ impl TwoFloat {
     /// assert!((a - 1.0).abs() < 1e-31);
     /// ```
     pub fn ln(self) -> Self {
-        if self == 1.0 {
-            Self::from(0.0)
-        } else if self <= 0.0 {
-            Self::NAN
+        match self.log_special() {
+            Some(result) => result,
+            None => {
+                let (e, index, t, r) = ln_reduced(self);
+                let e = e as f64;
+                ((LN_1P_128TH[index] + LN_2_LO * e) + (t + r)) + e * LN_2_HI
+            }
+        }
+    }
+
+    fn log_special(self) -> Option<Self> {
+        if self.hi.is_nan() || self.hi < 0.0 {
+            Some(Self::NAN)
+        } else if self.hi == 0.0 {
+            Some(Self::NEG_INFINITY)
+        } else if self.hi.is_infinite() {
+            Some(Self::INFINITY)
         } else {
-            let mut x = Self::from(libm::log(self.hi));
-            x += self * (-x).exp() - 1.0;
-            x += self * (-x).exp() - 1.0;
-            x + self * (-x).exp() - 1.0
+            None
         }
     }
 
     /// Returns the natural logarithm of `1 + self`.
     ///
-    /// Uses Newton–Raphson iteration which depends on the `expm1` function
+    /// The reduction used by `ln` is applied to `1 + self` without rounding
+    /// the sum, so the result is accurate for values close to zero.
     ///
     /// # Example
     ///
@@ -1093,20 +2733,35 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(-0.5);
     /// let b = a.ln_1p();
-    /// let c = -twofloat::consts::LN_2;//0.1f64.ln_1p();
-    /// assert!((b - c).abs() < 1e-29);
+    /// let c = -twofloat::consts::LN_2;
+    /// assert!((b - c).abs() < 1e-31);
     /// ```
     pub fn ln_1p(self) -> Self {
-        if self == 0.0 {
-            Self::from(0.0)
-        } else if self <= -1.0 {
+        if self.hi == 0.0 {
+            self
+        } else if self.hi.is_nan() || self.hi < -1.0 {
             Self::NAN
+        } else if self.hi <= -0.5 || self.hi >= hexf64!("0x1.0p52") {
+            // The sum is exact for x <= -1/2 and the rounding error is
+            // negligible compared with the result for large values
+            (self + 1.0).ln()
         } else {
-            let mut x = Self::from(libm::log1p(self.hi));
-            let mut e = x.exp_m1();
-            x -= (e - self) / (e + 1.0);
-            e = x.exp_m1();
-            x - (e - self) / (e + 1.0)
+            // Write 1 + x = 2^e * c * (1 + t), where 2^-e - c is exact and
+            // cancels with 2^-e * x, so that t is computed without error
+            let bits = (1.0 + self.hi).to_bits();
+            let mut e = ((bits >> 52) & 0x7ff) as i32 - 1023;
+            if bits & (1 << 51) != 0 {
+                e += 1;
+            }
+
+            let scale = mul_pow2(1.0, -e);
+            let n = libm::round(128.0 * (scale * (1.0 + self.hi) - 1.0));
+            let c = 1.0 + n / 128.0;
+            let t = ((scale - c) + self * scale) / c;
+            let r = t * t * polynomial!(t, -0.5, LN_1P_COEFFS);
+            let index = (n as i32 + 32) as usize;
+            let e = e as f64;
+            ((LN_1P_128TH[index] + LN_2_LO * e) + (t + r)) + e * LN_2_HI
         }
     }
 
@@ -1131,8 +2786,9 @@ impl TwoFloat {
 
     /// Returns the base 2 logarithm of the number.
     ///
-    /// Uses Newton–Raphson iteration which depends on the `exp2` function,
-    /// so may not be fully accurate to the full precision of a `TwoFloat`.
+    /// Uses the same argument reduction as [`ln`](TwoFloat::ln), with the
+    /// binary exponent added exactly, so the error is less than 2 ulp and
+    /// powers of two give exact results.
     ///
     /// # Examples
     ///
@@ -1143,31 +2799,46 @@ impl TwoFloat {
     /// assert!(a - 6.0 == 0.0, "{}", a);
     /// ```
     pub fn log2(self) -> Self {
-        if self == 1.0 {
-            Self::from(1.0)
-        } else if self <= 0.0 {
-            Self::NAN
-        } else {
-            let mut x = Self::from(libm::log2(self.hi));
-            x += (self * (-x).exp2() - 1.0) * FRAC_1_LN_2;
-            x + (self * (-x).exp2() - 1.0) * FRAC_1_LN_2
+        match self.log_special() {
+            Some(result) => result,
+            None => {
+                let (e, index, t, r) = ln_reduced(self);
+                let y = mul_accurate(t, FRAC_1_LN_2) + r * FRAC_1_LN_2;
+                (LOG2_1P_128TH[index] + y) + e as f64
+            }
         }
     }
 
     /// Returns the base 10 logarithm of the number.
     ///
-    /// This is a convenience method that computes `self.ln() / LN_10`, no
-    /// additional accuracy is provided.
+    /// Uses the same argument reduction as [`ln`](TwoFloat::ln), with the
+    /// error less than 2 ulp. Powers of ten which are exactly representable,
+    /// from 1 to 10<sup>45</sup>, give exact results.
     ///
     /// # Examples
     ///
     /// ```
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(100.0).log10();
-    /// assert!((a - 2.0).abs() < 1e-30, "{}", a);
+    /// assert!(a - 2.0 == 0.0, "{}", a);
+    ///
+    /// let b = (TwoFloat::from(1.0) / 100.0).log10();
+    /// assert!((b + 2.0).abs() < 1e-30, "{}", b);
     /// ```
     pub fn log10(self) -> Self {
-        self.ln() / LN_10
+        match self.log_special() {
+            Some(result) => result,
+            None => {
+                if let Some(n) = exact_power_of_ten(self) {
+                    return Self::from(n as f64);
+                }
+
+                let (e, index, t, r) = ln_reduced(self);
+                let e = e as f64;
+                let y = mul_accurate(t, FRAC_1_LN_10) + r * FRAC_1_LN_10;
+                ((LOG10_1P_128TH[index] + LOG10_2_LO * e) + y) + e * LOG10_2_HI
+            }
+        }
     }
 }
 
@@ -1,7 +1,8 @@
 use crate::{arithmetic::fast_two_sum, TwoFloat};
 
 impl TwoFloat {
-    /// Returns the fractional part of the number.
+    /// Returns the fractional part of the number, which is `NAN` for
+    /// infinite values and has the sign of the number unless it is zero.
     ///
     /// # Examples
     ///
@@ -14,9 +15,13 @@ impl TwoFloat {
     /// assert_eq!(b, TwoFloat::new_add(-1.0, 1e-200));
     /// ```
     pub fn fract(self) -> Self {
+        if !self.hi.is_finite() {
+            return Self::NAN;
+        }
+
         let hi_fract = libm::modf(self.hi).0;
         let lo_fract = libm::modf(self.lo).0;
-        if lo_fract == 0.0 {
+        let result = if lo_fract == 0.0 {
             hi_fract.into()
         } else if hi_fract == 0.0 {
             match (self.hi >= 0.0, self.lo >= 0.0) {
@@ -26,6 +31,13 @@ impl TwoFloat {
             }
         } else {
             fast_two_sum(libm::modf(self.hi).0, self.lo)
+        };
+
+        // As for `f64`, the fractional part of an integer is positive zero
+        if result.hi == 0.0 {
+            Self::from(0.0)
+        } else {
+            result
         }
     }
 
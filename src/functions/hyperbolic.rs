@@ -1,10 +1,42 @@
-use crate::TwoFloat;
+use hexf::hexf64;
+
+use crate::{consts::LN_2, TwoFloat};
+
+// Above this value exp(-x) is negligible compared with exp(x)
+const EXP_LARGE: f64 = 40.0;
+
+// Above this value exp(x) overflows, so the result is scaled
+const EXP_OVERFLOW: f64 = 700.0;
+
+// Above this value sqrt(x^2 + 1) and x are indistinguishable
+const SQRT_LARGE: f64 = hexf64!("0x1.0p54");
+
+// exp(2) / 2
+const FRAC_E2_2: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.d8e64b8d4ddaep+1"),
+    lo: hexf64!("-0x1.9e62e22efca4cp-54"),
+};
+
+/// Returns `exp(x) / 2` for `x >= 0`, avoiding overflow for values where the
+/// result is representable.
+fn half_exp(x: TwoFloat) -> TwoFloat {
+    if x.hi > EXP_OVERFLOW {
+        let result = (x - 2.0).exp() * FRAC_E2_2;
+        if result.hi.is_finite() {
+            result
+        } else {
+            TwoFloat::INFINITY
+        }
+    } else {
+        x.exp() / 2.0
+    }
+}
 
 impl TwoFloat {
     /// Hyperbolic cosine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// exponential function.
+    /// Computed from the exponential function, with the result scaled to
+    /// avoid overflow for large arguments.
     ///
     /// # Examples
     ///
@@ -17,13 +49,19 @@ impl TwoFloat {
     /// assert!((b - c).abs() < 1e-10);
     /// ```
     pub fn cosh(self) -> Self {
-        self.exp() / 2.0 + (-self).exp() / 2.0
+        let x = self.abs();
+        if x.hi > EXP_LARGE {
+            half_exp(x)
+        } else {
+            let e = x.exp();
+            e / 2.0 + 0.5 / e
+        }
     }
 
     /// Hyperbolic sine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// exponential function.
+    /// Small arguments are computed using `exp_m1` to avoid cancellation,
+    /// and the result is scaled to avoid overflow for large arguments.
     ///
     /// # Examples
     ///
@@ -34,15 +72,34 @@ impl TwoFloat {
     /// let c = 2.0f64.sinh();
     ///
     /// assert!((b - c).abs() < 1e-10);
+    ///
+    /// let d = TwoFloat::from(1e-20);
+    /// assert!(((d.sinh() - d) / d).abs() < 1e-30);
     /// ```
     pub fn sinh(self) -> Self {
-        self.exp() / 2.0 - (-self).exp() / 2.0
+        let x = self.abs();
+        let result = if x.hi < 1.0 {
+            // sinh(x) = (expm1(x) + expm1(x) / exp(x)) / 2
+            let e = x.exp_m1();
+            (e + e / (e + 1.0)) / 2.0
+        } else if x.hi > EXP_LARGE {
+            half_exp(x)
+        } else {
+            let e = x.exp();
+            e / 2.0 - 0.5 / e
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
     }
 
     /// Hyperbolic tangent function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// exponential function.
+    /// Computed using `exp_m1` for accuracy close to zero, returning `±1`
+    /// when the difference is smaller than the precision of the type.
     ///
     /// # Examples
     ///
@@ -53,17 +110,31 @@ impl TwoFloat {
     /// let c = 2.0f64.tanh();
     ///
     /// assert!((b - c).abs() < 1e-10);
+    ///
+    /// assert_eq!(TwoFloat::from(-1000.0).tanh(), -1.0);
     /// ```
     pub fn tanh(self) -> Self {
-        let e_plus = self.exp();
-        let e_minus = (-self).exp();
-        (e_plus - e_minus) / (e_plus + e_minus)
+        let x = self.abs();
+        let result = if x.hi > EXP_LARGE {
+            Self::from(1.0)
+        } else {
+            // tanh(x) = expm1(2x) / (expm1(2x) + 2)
+            let e = (x * 2.0).exp_m1();
+            e / (e + 2.0)
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
     }
 
     /// Inverse hyperbolic cosine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// `sqrt` and `ln` functions.
+    /// Computed using `ln_1p` for arguments close to 1, and the asymptotic
+    /// form `ln(2x)` for large arguments. Returns NaN for values less
+    /// than 1.
     ///
     /// # Examples
     ///
@@ -76,13 +147,23 @@ impl TwoFloat {
     /// assert!((b - c).abs() < 1e-10);
     /// ```
     pub fn acosh(self) -> Self {
-        (self + (self * self - 1.0).sqrt()).ln()
+        if self.hi.is_nan() || self < 1.0 {
+            Self::NAN
+        } else if self.hi.is_infinite() {
+            Self::INFINITY
+        } else if self.hi > SQRT_LARGE {
+            self.ln() + LN_2
+        } else {
+            // acosh(1 + t) = ln(1 + t + sqrt(t * (t + 2)))
+            let t = self - 1.0;
+            (t + (t * (t + 2.0)).sqrt()).ln_1p()
+        }
     }
 
     /// Inverse hyperbolic sine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// `sqrt` and `ln` functions.
+    /// Computed using `ln_1p` for accuracy close to zero, and the asymptotic
+    /// form `ln(2|x|)` for large arguments.
     ///
     /// # Examples
     ///
@@ -95,13 +176,28 @@ impl TwoFloat {
     /// assert!((b - c).abs() < 1e-10);
     /// ```
     pub fn asinh(self) -> Self {
-        (self + (self * self + 1.0).sqrt()).ln()
+        let x = self.abs();
+        let result = if x.hi.is_infinite() {
+            Self::INFINITY
+        } else if x.hi > SQRT_LARGE {
+            x.ln() + LN_2
+        } else {
+            // asinh(x) = ln(1 + x + x^2 / (1 + sqrt(x^2 + 1)))
+            let x2 = x * x;
+            (x + x2 / ((x2 + 1.0).sqrt() + 1.0)).ln_1p()
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
     }
 
     /// Inverse hyperbolic tangent function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// `ln` function.
+    /// Computed using `ln_1p` for accuracy close to zero. Returns infinity
+    /// for `±1` and NaN for values outside this range.
     ///
     /// # Examples
     ///
@@ -114,6 +210,22 @@ impl TwoFloat {
     /// assert!((b - c).abs() < 1e-10);
     /// ```
     pub fn atanh(self) -> Self {
-        ((1.0 + self) / (1.0 - self)).ln() / 2.0
+        let x = self.abs();
+        if x.hi.is_nan() || x > 1.0 {
+            return Self::NAN;
+        }
+
+        let result = if x == 1.0 {
+            Self::INFINITY
+        } else {
+            // atanh(x) = ln(1 + 2x / (1 - x)) / 2
+            (x * 2.0 / (1.0 - x)).ln_1p() / 2.0
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
     }
 }
@@ -0,0 +1,205 @@
+use hexf::hexf64;
+
+use crate::{consts::E, TwoFloat};
+
+// Below this value of x, W0(x) = x - x^2 to within the precision of the type
+const SMALL_LIMIT: f64 = hexf64!("0x1.0p-60");
+
+// Below this value of p = sqrt(2(ex + 1)), the series about the branch point
+// is accurate to within the precision of the type
+const BRANCH_LIMIT: f64 = hexf64!("0x1.0p-27");
+
+// Arguments closer than this to -1/e, relative to 1/e, are treated as lying
+// on the branch point, allowing for the rounding of -1/e
+const BRANCH_TOLERANCE: f64 = hexf64!("0x1.0p-104");
+
+// Maximum number of Halley iterations
+const HALLEY_STEPS: usize = 10;
+
+// The iteration is stopped once the correction falls below this value
+// relative to the result
+const HALLEY_TOLERANCE: f64 = hexf64!("0x1.0p-106");
+
+// Third word of e
+const E_LO2: f64 = hexf64!("-0x1.618713a31d3e2p-109");
+
+// Computes q = ex + 1, or NaN if x is below -1/e. As this suffers from
+// cancellation close to the branch point, the products are evaluated exactly
+// using a triple-word representation of e.
+fn branch_offset(x: TwoFloat) -> TwoFloat {
+    let q = (TwoFloat::new_mul(x.hi, E.hi) + 1.0)
+        + TwoFloat::new_mul(x.hi, E.lo)
+        + TwoFloat::new_mul(x.lo, E.hi)
+        + (x.lo * E.lo + x.hi * E_LO2);
+    if q.hi >= 0.0 {
+        q
+    } else if q.hi > -BRANCH_TOLERANCE {
+        TwoFloat::from(0.0)
+    } else {
+        TwoFloat::NAN
+    }
+}
+
+// Computes the series w = -1 + p - p^2/3 + 11p^3/72 about the branch point,
+// where p = sqrt(2q) and the sign of p selects the branch
+fn branch_series(p: TwoFloat) -> TwoFloat {
+    let c2 = TwoFloat::from(1.0) / 3.0;
+    let c3 = TwoFloat::from(11.0) / 72.0;
+    p * (1.0 - p * (c2 - p * c3)) - 1.0
+}
+
+// Halley step for the offset t = w + 1 from the branch point. Writing
+// w exp(w) - x = (g(t) - q)/e with g(t) = 1 - (1 - t) exp(t) avoids the
+// cancellation in the residual, where g is summed from its series
+// sum (k - 1) t^k/k! of positive terms.
+fn halley_branch(t: TwoFloat, q: TwoFloat) -> TwoFloat {
+    let mut power = t * t * 0.5;
+    let mut g = power;
+    let mut k = 3.0;
+    loop {
+        power *= t / k;
+        let term = power * (k - 1.0);
+        g += term;
+        if term.abs() <= g.abs() * HALLEY_TOLERANCE {
+            break;
+        }
+        k += 1.0;
+    }
+
+    let et = t.exp();
+    let dg = t * et;
+    let step = (g - q) / dg;
+    t - step / (1.0 - step * (1.0 + t) * et / (dg * 2.0))
+}
+
+// Computes W(x) for x < -1/4 close to the branch point, where the sign
+// selects the branch
+fn near_branch(x: TwoFloat, sign: f64) -> TwoFloat {
+    let q = branch_offset(x);
+    if !q.is_valid() {
+        return TwoFloat::NAN;
+    }
+
+    let p = (q * 2.0).sqrt();
+    let w = branch_series(p * sign);
+    if p.hi < BRANCH_LIMIT {
+        w
+    } else {
+        halley(w + 1.0, |t| halley_branch(t, q)) - 1.0
+    }
+}
+
+// Halley step for w exp(w) - x = 0
+fn halley_exp(w: TwoFloat, x: TwoFloat) -> TwoFloat {
+    let ew = w.exp();
+    let f = w * ew - x;
+    let w1 = w + 1.0;
+    w - f / (ew * w1 - (w + 2.0) * f / (w1 * 2.0))
+}
+
+// Halley step for w + ln|w| - ln|x| = 0, which avoids overflow for large w
+fn halley_log(w: TwoFloat, ln_x: TwoFloat) -> TwoFloat {
+    let f = w + w.abs().ln() - ln_x;
+    let df = (w + 1.0) / w;
+    let step = f / df;
+    w - step / (1.0 + step / (w * w * df * 2.0))
+}
+
+// Applies Halley's method until the correction is negligible
+fn halley(mut w: TwoFloat, step: impl Fn(TwoFloat) -> TwoFloat) -> TwoFloat {
+    for _ in 0..HALLEY_STEPS {
+        let next = step(w);
+        if !next.is_valid() {
+            return w;
+        }
+
+        let converged = (next - w).abs() <= next.abs() * HALLEY_TOLERANCE;
+        w = next;
+        if converged {
+            break;
+        }
+    }
+
+    w
+}
+
+impl TwoFloat {
+    /// Computes the principal branch `W0` of the Lambert W function, the
+    /// solution `w >= -1` of `w exp(w) = x`, where `x` is the value.
+    ///
+    /// The result is found using Halley's method, starting from the series
+    /// about the branch point at `-1/e` or an asymptotic estimate. Returns NaN
+    /// if the value is less than `-1/e`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = twofloat::consts::E.lambert_w0();
+    /// assert!((a - 1.0).abs() < 1e-30);
+    ///
+    /// let b = TwoFloat::from(1.0).lambert_w0();
+    /// let c = TwoFloat::new_add(0.5671432904097838, 3.2888566875211743e-17);
+    /// assert!(((b - c) / c).abs() < 1e-30);
+    /// ```
+    pub fn lambert_w0(self) -> Self {
+        if !self.is_valid() {
+            return if self.hi == f64::INFINITY {
+                Self::INFINITY
+            } else {
+                Self::NAN
+            };
+        }
+
+        if self.hi.abs() < SMALL_LIMIT {
+            return self - self * self;
+        }
+
+        if self.hi < -0.25 {
+            near_branch(self, 1.0)
+        } else {
+            let l = libm::log1p(self.hi);
+            let w = Self::from(l * (1.0 - libm::log1p(l) / (2.0 + l)));
+            if self.hi > 1.0 {
+                let ln_x = self.ln();
+                halley(w, |w| halley_log(w, ln_x))
+            } else {
+                halley(w, |w| halley_exp(w, self))
+            }
+        }
+    }
+
+    /// Computes the lower branch `W-1` of the Lambert W function, the solution
+    /// `w <= -1` of `w exp(w) = x`, where `x` is the value.
+    ///
+    /// The result is found using Halley's method, starting from the series
+    /// about the branch point at `-1/e` or an asymptotic estimate. Returns NaN
+    /// if the value is less than `-1/e` or positive, and negative infinity if
+    /// the value is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(-0.1).lambert_wm1();
+    /// let b = TwoFloat::new_add(-3.577152063957297, -1.794970675198664e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    /// ```
+    pub fn lambert_wm1(self) -> Self {
+        if !self.is_valid() || self.hi > 0.0 {
+            return Self::NAN;
+        } else if self.hi == 0.0 {
+            return Self::NEG_INFINITY;
+        }
+
+        if self.hi < -0.25 {
+            near_branch(self, -1.0)
+        } else {
+            let l1 = libm::log(-self.hi);
+            let l2 = libm::log(-l1);
+            let ln_x = (-self).ln();
+            halley(Self::from(l1 - l2 + l2 / l1), |w| halley_log(w, ln_x))
+        }
+    }
+}
@@ -1,9 +1,17 @@
-use crate::TwoFloat;
+use crate::{
+    arithmetic::{needs_scaling, scale_pow2},
+    TwoFloat,
+};
 
 impl TwoFloat {
     /// Returns the square root of the number, using equation 4 from Karp &
     /// Markstein (1997).
     ///
+    /// In testing the relative error is less than 16u², where
+    /// u = 2<sup>-53</sup>. Values close to the limits of the range of `f64`
+    /// are scaled by an even power of two so that the square of the estimate
+    /// neither overflows nor underflows.
+    ///
     /// # Examples
     ///
     /// ```
@@ -14,10 +22,13 @@ impl TwoFloat {
     /// assert!(b * b - a < 1e-16);
     /// ```
     pub fn sqrt(self) -> Self {
-        if self.hi < 0.0 || (self.hi == 0.0 && self.lo < 0.0) {
+        if self.hi.is_nan() || self.hi < 0.0 || (self.hi == 0.0 && self.lo < 0.0) {
             Self::NAN
-        } else if self.hi == 0.0 && self.lo == 0.0 {
-            Self { hi: 0.0, lo: 0.0 }
+        } else if self.hi == 0.0 || self.hi.is_infinite() {
+            Self::from(self.hi)
+        } else if needs_scaling(self.hi) {
+            let n = libm::ilogb(self.hi) / 2;
+            scale_pow2(scale_pow2(self, -2 * n).sqrt(), n)
         } else {
             let x = libm::sqrt(self.hi).recip();
             let y = self.hi * x;
@@ -37,6 +48,10 @@ impl TwoFloat {
     /// assert!(b.powi(3) - a < 1e-16);
     /// ```
     pub fn cbrt(self) -> Self {
+        if self.hi == 0.0 || !self.hi.is_finite() {
+            return Self::from(self.hi);
+        }
+
         let mut x = Self::from(libm::cbrt(self.hi));
         let mut x2 = x * x;
         x -= (x2 * x - self) / (3.0 * x2);
@@ -58,13 +73,28 @@ impl TwoFloat {
     /// assert!((c - 5.0).abs() < 1e-10);
     /// ```
     pub fn hypot(self, other: Self) -> Self {
-        (self * self + other * other).sqrt()
+        if self.hi.is_infinite() || other.hi.is_infinite() {
+            return Self::INFINITY;
+        }
+
+        // Scale by a power of two so that the squares neither overflow nor
+        // underflow
+        let max = libm::fmax(self.hi.abs(), other.hi.abs());
+        if max == 0.0 || !max.is_finite() {
+            return (self * self + other * other).sqrt();
+        }
+
+        let exponent = libm::ilogb(max).clamp(-1022, 1023);
+        let scale = libm::ldexp(1.0, -exponent);
+        let (x, y) = (self * scale, other * scale);
+        (x * x + y * y).sqrt() * libm::ldexp(1.0, exponent)
     }
 
     /// Returns the value raised to the power `y`.
     ///
     /// This method is quite inaccurate, where possible `powi`, `sqrt` or
-    /// `cbrt` should be preferred.
+    /// `cbrt` should be preferred. Special values are handled as for
+    /// `f64::powf`.
     ///
     /// # Examples
     ///
@@ -77,30 +107,54 @@ impl TwoFloat {
     /// assert!((c + 125.0).abs() < 1e-9, "{}", c);
     /// ```
     pub fn powf(self, y: Self) -> Self {
-        match (self == 0.0, y == 0.0) {
-            (true, true) => Self::NAN,
-            (true, false) => Self::from(0.0),
-            (false, true) => Self::from(1.0),
-            (false, false) => {
-                if self.is_sign_positive() {
-                    (y * self.ln()).exp()
-                } else if libm::modf(y.hi).0 != 0.0 || libm::modf(y.lo).0 != 0.0 {
-                    Self::NAN
-                } else {
-                    let abs_result = (y * self.abs().ln()).exp();
-                    let low_trunc = if libm::trunc(y.lo) == 0.0 {
-                        libm::trunc(y.hi)
-                    } else {
-                        libm::trunc(y.lo)
-                    };
+        if y == 0.0 {
+            return Self::from(1.0);
+        } else if self == 1.0 {
+            return self;
+        } else if self.hi.is_nan() || y.hi.is_nan() {
+            return Self::NAN;
+        }
 
-                    if low_trunc % 2.0 == 0.0 {
-                        abs_result
-                    } else {
-                        -abs_result
-                    }
-                }
+        let odd = y.is_odd_integer();
+        if y.hi.is_infinite() {
+            let abs = self.abs();
+            if abs == 1.0 {
+                Self::from(1.0)
+            } else if (abs > 1.0) == (y.hi > 0.0) {
+                Self::INFINITY
+            } else {
+                Self::from(0.0)
+            }
+        } else if self.hi == 0.0 || self.hi.is_infinite() {
+            // Zero and infinity raised to the power are zero or infinite,
+            // negative only if the value is negative and the power is odd
+            let magnitude = if (self.hi == 0.0) == (y.hi > 0.0) {
+                0.0
+            } else {
+                f64::INFINITY
+            };
+            if odd {
+                Self::from(libm::copysign(magnitude, self.hi))
+            } else {
+                Self::from(magnitude)
+            }
+        } else if self.hi > 0.0 {
+            (y * self.ln()).exp()
+        } else if y.fract() != 0.0 {
+            Self::NAN
+        } else {
+            let abs_result = (y * self.abs().ln()).exp();
+            if odd {
+                -abs_result
+            } else {
+                abs_result
             }
         }
     }
+
+    // Checks if the value is an odd integer. Values large enough that the
+    // low word does not hold the units are even.
+    fn is_odd_integer(self) -> bool {
+        self.hi.is_finite() && self.fract() == 0.0 && (self * 0.5).fract() != 0.0
+    }
 }
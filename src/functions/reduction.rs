@@ -0,0 +1,302 @@
+use crate::{arithmetic::fast_two_sum, consts::FRAC_PI_2, quad, QuadFloat, TwoFloat};
+
+// Bits of 2/pi following the binary point, most significant first. Enough
+// bits are stored to reduce the largest finite f64 with 382 bits of
+// precision remaining after the integer part is discarded.
+const TWO_OVER_PI: [u64; 26] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+    0xa9e391615ee61b08,
+    0x6599855f14a06840,
+    0x8dffd8804d732731,
+    0x06061556ca73a8c9,
+    0x60e27bc08c6b47c4,
+    0x19c367cddce8092a,
+];
+
+// Number of 64-bit words in the fixed-point value of x * 2/pi modulo 4,
+// which has 2 integer bits and 382 fractional bits.
+const FIXED_WORDS: usize = 6;
+
+// Number of 64-bit words of 2/pi multiplied by the significand, including
+// 128 guard bits below the precision of the result.
+const WINDOW_WORDS: usize = 8;
+
+type Fixed = [u64; FIXED_WORDS];
+
+/// Returns the 64 bits of 2/pi starting at the given bit position, where
+/// position 0 is the first bit after the binary point. Bits before the
+/// binary point are zero.
+fn two_over_pi_bits(position: i32) -> u64 {
+    if position <= -64 {
+        0
+    } else if position < 0 {
+        TWO_OVER_PI[0] >> -position
+    } else {
+        let index = (position / 64) as usize;
+        let shift = position % 64;
+        let word = TWO_OVER_PI.get(index).map_or(0, |w| w << shift);
+        match TWO_OVER_PI.get(index + 1) {
+            Some(next) if shift > 0 => word | next >> (64 - shift),
+            _ => word,
+        }
+    }
+}
+
+/// Computes the fractional part of `|x| * 2/pi / 4` as a little-endian
+/// fixed-point number.
+fn reduce_f64(x: f64) -> Fixed {
+    let bits = x.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let (significand, exponent) = if biased_exponent == 0 {
+        (bits & ((1 << 52) - 1), -1074)
+    } else {
+        ((bits & ((1 << 52) - 1)) | (1 << 52), biased_exponent - 1075)
+    };
+
+    // Bits of 2/pi before this position only contribute multiples of 4
+    let start = exponent - 2;
+    let mut window = [0u64; WINDOW_WORDS];
+    for (i, word) in window.iter_mut().rev().enumerate() {
+        *word = two_over_pi_bits(start + 64 * i as i32);
+    }
+
+    let mut product = [0u64; WINDOW_WORDS + 1];
+    let mut carry = 0u128;
+    for (p, &w) in product.iter_mut().zip(window.iter()) {
+        let t = (significand as u128) * (w as u128) + carry;
+        *p = t as u64;
+        carry = t >> 64;
+    }
+    product[WINDOW_WORDS] = carry as u64;
+
+    // Discard the guard bits and the bits representing multiples of 4
+    let mut result = [0u64; FIXED_WORDS];
+    result.copy_from_slice(&product[2..2 + FIXED_WORDS]);
+    result
+}
+
+fn add_fixed(a: &mut Fixed, b: &Fixed) {
+    let mut carry = false;
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        let (s1, c1) = x.overflowing_add(y);
+        let (s2, c2) = s1.overflowing_add(carry as u64);
+        *x = s2;
+        carry = c1 || c2;
+    }
+}
+
+fn sub_fixed(a: &mut Fixed, b: &Fixed) {
+    let mut borrow = false;
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        let (d1, b1) = x.overflowing_sub(y);
+        let (d2, b2) = d1.overflowing_sub(borrow as u64);
+        *x = d2;
+        borrow = b1 || b2;
+    }
+}
+
+/// Converts a fixed-point value with 382 fractional bits to a `TwoFloat`.
+fn fixed_to_twofloat(value: &Fixed) -> TwoFloat {
+    let top = match value.iter().rposition(|&w| w != 0) {
+        Some(top) => top,
+        None => return TwoFloat::from(0.0),
+    };
+
+    // Normalize so that the leading bit is the top bit of the first word
+    let shift = value[top].leading_zeros();
+    let word = |i: usize| -> u64 {
+        let hi = value.get(i).copied().unwrap_or(0);
+        let lo = if i > 0 { value[i - 1] } else { 0 };
+        if shift == 0 {
+            hi
+        } else {
+            (hi << shift) | (lo >> (64 - shift))
+        }
+    };
+
+    let a0 = word(top);
+    let a1 = if top >= 1 { word(top - 1) } else { 0 };
+    let a2 = if top >= 2 { word(top - 2) } else { 0 };
+
+    // Exponent of the least significant bit of a0
+    let exponent = 64 * top as i32 - shift as i32 - 382;
+    let x1 = libm::scalbn((a0 >> 11) as f64, exponent + 11);
+    let x2 = libm::scalbn((((a0 & 0x7ff) << 42) | (a1 >> 22)) as f64, exponent - 42);
+    let x3 = libm::scalbn((a1 & 0x3f_ffff) as f64, exponent - 64)
+        + libm::scalbn(a2 as f64, exponent - 128);
+    let t = fast_two_sum(x2, x3);
+    let u = fast_two_sum(x1, t.hi);
+    fast_two_sum(u.hi, u.lo + t.lo)
+}
+
+/// Converts a fixed-point value with 382 fractional bits to a `QuadFloat`.
+fn fixed_to_quad(value: &Fixed) -> QuadFloat {
+    // Each half-word is exactly representable, and the halves are in
+    // decreasing order of magnitude
+    let mut terms = [0.0; 2 * FIXED_WORDS];
+    for (i, &w) in value.iter().rev().enumerate() {
+        let exponent = 64 * (FIXED_WORDS - 1 - i) as i32 - 382;
+        terms[2 * i] = libm::scalbn((w >> 32) as f64, exponent + 32);
+        terms[2 * i + 1] = libm::scalbn((w & 0xffff_ffff) as f64, exponent);
+    }
+
+    quad::renormalize(&mut terms)
+}
+
+/// Computes `|x| * 2/pi` modulo 4 for a value given as non-overlapping words,
+/// returning the quadrant, the magnitude of the fractional part in the range
+/// [0, 1/2] and whether the fractional part is negative.
+fn reduce_words(words: &[f64]) -> (u8, Fixed, bool) {
+    let mut y = reduce_f64(words[0]);
+    for &w in &words[1..] {
+        let y_w = reduce_f64(w);
+        if (words[0] < 0.0) == (w < 0.0) {
+            add_fixed(&mut y, &y_w);
+        } else {
+            sub_fixed(&mut y, &y_w);
+        }
+    }
+
+    // Round to the nearest integer, leaving the fractional part in the
+    // range [-1/2, 1/2)
+    let half: Fixed = [0, 0, 0, 0, 0, 1 << 61];
+    add_fixed(&mut y, &half);
+    let quadrant = (y[FIXED_WORDS - 1] >> 62) as u8;
+    y[FIXED_WORDS - 1] &= (1 << 62) - 1;
+
+    if y[FIXED_WORDS - 1] & (1 << 61) != 0 {
+        y[FIXED_WORDS - 1] &= !(1 << 61);
+        (quadrant, y, false)
+    } else {
+        let mut magnitude = half;
+        sub_fixed(&mut magnitude, &y);
+        (quadrant, magnitude, true)
+    }
+}
+
+/// Reduces a finite value modulo pi/2 using the Payne–Hanek algorithm,
+/// returning the remainder in the range [-pi/4, pi/4] and the quadrant.
+///
+/// The high and low words are each multiplied by a window of the bits of
+/// 2/pi chosen so that the integer part modulo 4 and 382 fractional bits
+/// are obtained exactly, so the result is accurate for all finite values.
+pub(crate) fn rem_frac_pi_2(x: TwoFloat) -> (TwoFloat, u8) {
+    let (quadrant, magnitude, negative) = reduce_words(&[x.hi, x.lo]);
+    let fraction = if negative {
+        -fixed_to_twofloat(&magnitude)
+    } else {
+        fixed_to_twofloat(&magnitude)
+    };
+
+    let remainder = fraction * FRAC_PI_2;
+    if x.hi < 0.0 {
+        (-remainder, (4 - quadrant) & 3)
+    } else {
+        (remainder, quadrant)
+    }
+}
+
+/// Reduces a finite `QuadFloat` modulo pi/2 as for `rem_frac_pi_2`. The
+/// remainder has an absolute error of around 2^-380, so values very close to
+/// a multiple of pi/2 lose relative precision.
+pub(crate) fn rem_frac_pi_2_quad(x: QuadFloat) -> (QuadFloat, u8) {
+    let (quadrant, magnitude, negative) = reduce_words(&x.words);
+    let fraction = if negative {
+        -fixed_to_quad(&magnitude)
+    } else {
+        fixed_to_quad(&magnitude)
+    };
+
+    let remainder = fraction * quad::consts::FRAC_PI_2;
+    if x.words[0] < 0.0 {
+        (-remainder, (4 - quadrant) & 3)
+    } else {
+        (remainder, quadrant)
+    }
+}
+
+/// Reduces a value modulo `4 * quarter`, returning the remainder in the
+/// range [-quarter/2, quarter/2] and the number of quarter periods.
+///
+/// The period must be exactly representable, as for arguments measured in
+/// half turns or degrees. Each step is exact, so multiples of the quarter
+/// period give a remainder of exactly zero. The sign of a zero remainder is
+/// chosen so that the sine takes the sign of `x` at even multiples and the
+/// cosine is positive zero at odd multiples, as for `sinPi` and `cosPi` in
+/// IEEE 754.
+pub(crate) fn rem_quarter_period(x: TwoFloat, quarter: f64) -> (TwoFloat, u8) {
+    if x.hi == 0.0 {
+        // Keeps the sign of zero
+        return (x, 0);
+    }
+
+    let period = 4.0 * quarter;
+    let y = TwoFloat::new_add(x.hi % period, x.lo % period);
+    let n = libm::round(y.hi / quarter);
+    let remainder = TwoFloat::new_add(y.hi - n * quarter, y.lo);
+    let quadrant = (n as i32).rem_euclid(4) as u8;
+    if remainder.hi != 0.0 {
+        return (remainder, quadrant);
+    }
+
+    let negative = match quadrant {
+        0 => x.hi < 0.0,
+        2 => x.hi > 0.0,
+        1 => true,
+        _ => false,
+    };
+    let zero = if negative { -0.0 } else { 0.0 };
+    (TwoFloat { hi: zero, lo: zero }, quadrant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_over_pi_bits_test() {
+        assert_eq!(two_over_pi_bits(0), TWO_OVER_PI[0]);
+        assert_eq!(two_over_pi_bits(-4), TWO_OVER_PI[0] >> 4);
+        assert_eq!(two_over_pi_bits(-64), 0);
+        assert_eq!(
+            two_over_pi_bits(68),
+            (TWO_OVER_PI[1] << 4) | (TWO_OVER_PI[2] >> 60)
+        );
+        assert_eq!(two_over_pi_bits(64 * 26), 0);
+    }
+
+    #[test]
+    fn rem_quarter_period_test() {
+        let (r, q) = rem_quarter_period(TwoFloat::from(1.0), 0.5);
+        assert_eq!((r, q), (TwoFloat::from(0.0), 2));
+
+        let (r, q) = rem_quarter_period(TwoFloat::from(-0.75), 0.5);
+        assert_eq!((r, q), (TwoFloat::from(0.25), 2));
+
+        let (r, q) = rem_quarter_period(TwoFloat::new_add(1e300, 1e283), 90.0);
+        assert_eq!((r, q), (TwoFloat::from(44.0), 2));
+
+        let (r, q) = rem_quarter_period(TwoFloat::from(-2.0), 0.5);
+        assert_eq!(q, 0);
+        assert!(r.hi == 0.0 && r.hi.is_sign_negative());
+    }
+}
@@ -14,9 +14,13 @@ impl TwoFloat {
     /// assert_eq!(b, TwoFloat::new_add(1.0, -1.0e-300));
     /// ```
     pub fn abs(&self) -> Self {
-        if self.hi > 0.0
-            || (self.hi == 0.0 && self.hi.is_sign_positive() && self.lo.is_sign_positive())
-        {
+        if self.hi == 0.0 {
+            // Both words of a zero become positive
+            Self {
+                hi: 0.0,
+                lo: libm::fabs(self.lo),
+            }
+        } else if self.hi > 0.0 {
             *self
         } else {
             -self
@@ -85,9 +89,9 @@ impl TwoFloat {
 
     /// Returns a number that represents the sign of the value.
     ///
-    /// * `1.0` if the number is positive or `+0.0`
-    /// * `-1.0` if the number is negative or `-0.0`
-    /// * Invalid value otherwise
+    /// * `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// * `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// * `NAN` if the number is `NAN`
     ///
     /// # Examples
     /// # use twofloat::TwoFloat;
@@ -97,14 +101,12 @@ impl TwoFloat {
     /// assert_eq!(a.signum(), 1.0);
     /// assert_eq!(b.signum(), -1.0);
     pub fn signum(&self) -> Self {
-        if self.is_valid() {
-            if self.is_sign_positive() {
-                Self::from(1.0)
-            } else {
-                Self::from(-1.0)
-            }
-        } else {
+        if self.hi.is_nan() || self.lo.is_nan() {
             Self::NAN
+        } else if self.is_sign_positive() {
+            Self::from(1.0)
+        } else {
+            Self::from(-1.0)
         }
     }
 }
@@ -119,8 +121,16 @@ mod tests {
             TwoFloat { hi: 0.0, lo: 0.0 }.abs(),
             TwoFloat { hi: 0.0, lo: 0.0 }
         );
-        assert!(TwoFloat { hi: 0.0, lo: -0.0 }.abs().lo.is_sign_positive());
-        assert!(TwoFloat { hi: -0.0, lo: 0.0 }.abs().lo.is_sign_negative());
+        for &zero in [
+            TwoFloat { hi: 0.0, lo: -0.0 },
+            TwoFloat { hi: -0.0, lo: 0.0 },
+            TwoFloat { hi: -0.0, lo: -0.0 },
+        ]
+        .iter()
+        {
+            let result = zero.abs();
+            assert!(result.hi.is_sign_positive() && result.lo.is_sign_positive());
+        }
     }
 
     #[test]
@@ -1,133 +1,106 @@
-use core::convert::TryFrom;
-
 use hexf::hexf64;
 
+use super::reduction::{rem_frac_pi_2, rem_quarter_period};
 use crate::{
+    arithmetic::scale_pow2,
+    base::RAD_PER_DEG,
     consts::{FRAC_PI_2, FRAC_PI_4, PI},
     TwoFloat,
 };
 
 // Polynomial coefficients of sin(x)-x on [0,pi/4]
-const SIN_COEFFS: [TwoFloat; 7] = [
+const SIN_COEFFS: [TwoFloat; 11] = [
     TwoFloat {
         hi: hexf64!("-0x1.5555555555555p-3"),
-        lo: hexf64!("-0x1.3a26e9901c14ap-57"),
-    },
-    TwoFloat {
-        hi: hexf64!("0x1.1111111111105p-7"),
-        lo: hexf64!("-0x1.487cfb2f402fap-63"),
-    },
-    TwoFloat {
-        hi: hexf64!("-0x1.a01a01a017e07p-13"),
-        lo: hexf64!("-0x1.22340ff667d3fp-67"),
-    },
-    TwoFloat {
-        hi: hexf64!("0x1.71de3a526314fp-19"),
-        lo: hexf64!("0x1.1ddd0a161cfa7p-75"),
+        lo: hexf64!("-0x1.5555555555555p-57"),
     },
     TwoFloat {
-        hi: hexf64!("-0x1.ae6451ad6a8ebp-26"),
-        lo: hexf64!("0x1.cb014c3ddfd85p-84"),
+        hi: hexf64!("0x1.1111111111111p-7"),
+        lo: hexf64!("0x1.1111111110732p-63"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.612010f363e7dp-33"),
-        lo: hexf64!("0x1.0dba7b1b83a01p-88"),
+        hi: hexf64!("-0x1.a01a01a01a01ap-13"),
+        lo: hexf64!("-0x1.a01a010287c2ep-73"),
     },
     TwoFloat {
-        hi: hexf64!("-0x1.aa6c431516f76p-41"),
-        lo: hexf64!("0x1.df71e9b9b179bp-95"),
+        hi: hexf64!("0x1.71de3a556c734p-19"),
+        lo: hexf64!("-0x1.c15508557eb09p-73"),
     },
-];
-
-// Polynomial coefficients of cos(x)-1+x^2/2 on [0,pi/4]
-const COS_COEFFS: [TwoFloat; 7] = [
     TwoFloat {
-        hi: hexf64!("0x1.5555555555555p-5"),
-        lo: hexf64!("0x1.4b27f9ddea57ap-59"),
+        hi: hexf64!("-0x1.ae64567f544e4p-26"),
+        lo: hexf64!("0x1.c0c6d96a27562p-80"),
     },
     TwoFloat {
-        hi: hexf64!("-0x1.6c16c16c16c0fp-10"),
-        lo: hexf64!("0x1.1e7208a68629bp-64"),
+        hi: hexf64!("0x1.6124613a86d09p-33"),
+        lo: hexf64!("0x1.e45fb55308560p-89"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.a01a01a018bcdp-16"),
-        lo: hexf64!("0x1.adea7f883a49cp-71"),
+        hi: hexf64!("-0x1.ae7f3e733b663p-41"),
+        lo: hexf64!("0x1.f3b286a6e4043p-95"),
     },
     TwoFloat {
-        hi: hexf64!("-0x1.27e4fb75e002ap-22"),
-        lo: hexf64!("-0x1.a26582a390382p-76"),
+        hi: hexf64!("0x1.952c7702b6bb2p-49"),
+        lo: hexf64!("-0x1.0e1a43dce323ep-103"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.1eed8c87a5a51p-29"),
-        lo: hexf64!("-0x1.551d13b8d9c61p-85"),
+        hi: hexf64!("-0x1.2f49b3c4803fcp-57"),
+        lo: hexf64!("-0x1.9b5bf75c52224p-111"),
     },
     TwoFloat {
-        hi: hexf64!("-0x1.93931ca96bc22p-37"),
-        lo: hexf64!("0x1.25124fcc17b3fp-91"),
+        hi: hexf64!("0x1.71b7642b717ffp-66"),
+        lo: hexf64!("-0x1.69b9b1b141c28p-120"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.aabaa8059719cp-45"),
-        lo: hexf64!("0x1.4cf2f15ef56d1p-99"),
+        hi: hexf64!("-0x1.73ff36ff86cf5p-75"),
+        lo: hexf64!("0x1.4b33c7757a98ep-130"),
     },
 ];
 
-// Polynomial coefficients of tan(x)-x on [0,pi/4]
-const TAN_COEFFS: [TwoFloat; 14] = [
-    TwoFloat {
-        hi: hexf64!("0x1.555555555530fp-2"),
-        lo: hexf64!("-0x1.38ef22c4b8238p-56"),
-    },
-    TwoFloat {
-        hi: hexf64!("0x1.111111112c40ap-3"),
-        lo: hexf64!("0x1.db464d0cd9cb4p-57"),
-    },
-    TwoFloat {
-        hi: hexf64!("0x1.ba1ba1a984e9fp-5"),
-        lo: hexf64!("0x1.b2454b6b23d17p-61"),
-    },
+// Polynomial coefficients of cos(x)-1+x^2/2 on [0,pi/4]
+const COS_COEFFS: [TwoFloat; 11] = [
     TwoFloat {
-        hi: hexf64!("0x1.664f4b43a4fefp-6"),
-        lo: hexf64!("0x1.bb1ac07d3ba2fp-61"),
+        hi: hexf64!("0x1.5555555555555p-5"),
+        lo: hexf64!("0x1.5555555555555p-59"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.226ded039b30dp-7"),
-        lo: hexf64!("-0x1.9110570c2853ap-63"),
+        hi: hexf64!("-0x1.6c16c16c16c17p-10"),
+        lo: hexf64!("0x1.f49f49f49f624p-65"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.d6ddaf4100a51p-9"),
-        lo: hexf64!("-0x1.654e37a706894p-65"),
+        hi: hexf64!("0x1.a01a01a01a01ap-16"),
+        lo: hexf64!("0x1.a01a016f99b4ep-76"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.7d2be8d9e1761p-10"),
-        lo: hexf64!("-0x1.d33168adc5b21p-64"),
+        hi: hexf64!("-0x1.27e4fb7789f5cp-22"),
+        lo: hexf64!("-0x1.cbbc00f22ab3fp-76"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.395c8b79b1e68p-11"),
-        lo: hexf64!("-0x1.5c77d3711fefdp-66"),
+        hi: hexf64!("0x1.1eed8eff8d898p-29"),
+        lo: hexf64!("-0x1.2b0b5b27107f0p-83"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.c3c79cdabdf3ep-13"),
-        lo: hexf64!("0x1.7af98e21b704bp-69"),
+        hi: hexf64!("-0x1.93974a8c07c9dp-37"),
+        lo: hexf64!("0x1.95c67547d2740p-93"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.399dadec87c3ap-13"),
-        lo: hexf64!("-0x1.793a97fd365d5p-68"),
+        hi: hexf64!("0x1.ae7f3e733b70dp-45"),
+        lo: hexf64!("0x1.9e78b46d6d29cp-99"),
     },
     TwoFloat {
-        hi: hexf64!("-0x1.6a82ab57290c9p-15"),
-        lo: hexf64!("0x1.48e1069bffaafp-73"),
+        hi: hexf64!("-0x1.6827863b64145p-53"),
+        lo: hexf64!("0x1.81c50c9e89b4fp-108"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.b3221d6d8c4b6p-14"),
-        lo: hexf64!("-0x1.049e004213205p-69"),
+        hi: hexf64!("0x1.e542b976bd4e0p-62"),
+        lo: hexf64!("0x1.1dd5c9c425a5cp-117"),
     },
     TwoFloat {
-        hi: hexf64!("-0x1.b4a2a3d0229eap-15"),
-        lo: hexf64!("0x1.cb2115b70d6e3p-69"),
+        hi: hexf64!("-0x1.0ce2a38e880d1p-70"),
+        lo: hexf64!("0x1.7c8dec101016bp-124"),
     },
     TwoFloat {
-        hi: hexf64!("0x1.7917a05c89f91p-16"),
-        lo: hexf64!("-0x1.9ff84f5cc7024p-70"),
+        hi: hexf64!("0x1.f03635e974557p-80"),
+        lo: hexf64!("0x1.defff1d447875p-136"),
     },
 ];
 
@@ -249,17 +222,63 @@ const ATAN_FRAC_3_2: TwoFloat = TwoFloat {
     lo: hexf64!("0x1.007887af0cbbdp-56"),
 };
 
-fn quadrant(value: TwoFloat) -> (TwoFloat, i8) {
+// Gives the result of `atan2` in half turns where either argument is zero or
+// infinite, with the signs of zero following IEEE 754
+fn special_half_turns(y: TwoFloat, x: TwoFloat) -> Option<f64> {
+    let turns = if y.hi == 0.0 {
+        if x.hi.is_sign_positive() {
+            0.0
+        } else {
+            1.0
+        }
+    } else if y.hi.is_infinite() {
+        match x.hi {
+            f64::INFINITY => 0.25,
+            f64::NEG_INFINITY => 0.75,
+            _ => 0.5,
+        }
+    } else if x.hi == 0.0 {
+        0.5
+    } else if x.hi.is_infinite() {
+        if x.hi > 0.0 {
+            0.0
+        } else {
+            1.0
+        }
+    } else {
+        return None;
+    };
+
+    Some(libm::copysign(turns, y.hi))
+}
+
+// Computes the angle of the point (x, y) in half turns, where both
+// coordinates are finite and positive. The estimate from `atan` is accurate
+// to around 75 bits, so it is refined by a Newton step on
+// y cos(θ) - x sin(θ) = 0. The coordinates are scaled so that the products
+// neither overflow nor lose precision through underflow.
+fn first_quadrant_half_turns(y: TwoFloat, x: TwoFloat) -> TwoFloat {
+    if y == x {
+        return TwoFloat::from(0.25);
+    }
+
+    let scale = -libm::ilogb(y.hi.max(x.hi));
+    let (y, x) = (scale_pow2(y, scale), scale_pow2(x, scale));
+    let theta = if y < x {
+        (y / x).atan()
+    } else {
+        FRAC_PI_2 - (x / y).atan()
+    };
+
+    let (s, c) = theta.sin_cos();
+    (theta + (y * c - x * s) / (x * c + y * s)) / PI
+}
+
+fn quadrant(value: TwoFloat) -> (TwoFloat, u8) {
     if value.abs() < FRAC_PI_4 {
         (value, 0)
     } else {
-        let quotient = (value / FRAC_PI_2).round();
-        let remainder = value - quotient * FRAC_PI_2;
-        match i8::try_from(quotient % 4.0) {
-            Ok(quadrant) if quadrant >= 0 => (remainder, quadrant),
-            Ok(quadrant) if quadrant >= -4 => (remainder, 4 + quadrant),
-            _ => (TwoFloat::NAN, 0),
-        }
+        rem_frac_pi_2(value)
     }
 }
 
@@ -273,11 +292,6 @@ fn restricted_cos(x: TwoFloat) -> TwoFloat {
     polynomial!(x2, 1.0, -0.5, COS_COEFFS)
 }
 
-fn restricted_tan(x: TwoFloat) -> TwoFloat {
-    let x2 = x * x;
-    x * polynomial!(x2, 1.0, TAN_COEFFS)
-}
-
 fn restricted_asin(x: TwoFloat) -> TwoFloat {
     let x2 = x * x;
     x * polynomial!(x2, 1.0, ASIN_COEFFS)
@@ -288,9 +302,55 @@ fn restricted_atan(x: TwoFloat) -> TwoFloat {
     x * polynomial!(x2, 1.0, ATAN_COEFFS)
 }
 
+fn sin_quadrant(x: TwoFloat, quadrant: u8) -> TwoFloat {
+    match quadrant {
+        0 => restricted_sin(x),
+        1 => restricted_cos(x),
+        2 => -restricted_sin(x),
+        _ => -restricted_cos(x),
+    }
+}
+
+fn cos_quadrant(x: TwoFloat, quadrant: u8) -> TwoFloat {
+    match quadrant {
+        0 => restricted_cos(x),
+        1 => -restricted_sin(x),
+        2 => -restricted_cos(x),
+        _ => restricted_sin(x),
+    }
+}
+
+fn sin_cos_quadrant(x: TwoFloat, quadrant: u8) -> (TwoFloat, TwoFloat) {
+    let s = restricted_sin(x);
+    let c = restricted_cos(x);
+    match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+fn tan_quadrant(x: TwoFloat, quadrant: u8) -> TwoFloat {
+    let s = restricted_sin(x);
+    let c = restricted_cos(x);
+    match quadrant {
+        0 | 2 => s / c,
+        // Only reachable for exact reductions of odd multiples of a quarter
+        // period, where the result is a pole
+        1 if s.hi == 0.0 => TwoFloat::INFINITY,
+        3 if s.hi == 0.0 => TwoFloat::NEG_INFINITY,
+        _ => -c / s,
+    }
+}
+
 impl TwoFloat {
     /// Computes the sine of the value (in radians).
     ///
+    /// Large arguments are reduced using the Payne–Hanek algorithm, so the
+    /// result is accurate for all finite values. The relative error is less
+    /// than 16u², where u = 2<sup>-53</sup>.
+    ///
     /// # Examples
     ///
     /// ```
@@ -306,15 +366,11 @@ impl TwoFloat {
             return Self::NAN;
         }
         let (x, quadrant) = quadrant(self);
-        match quadrant {
-            0 => restricted_sin(x),
-            1 => restricted_cos(x),
-            2 => -restricted_sin(x),
-            _ => -restricted_cos(x),
-        }
+        sin_quadrant(x, quadrant)
     }
 
-    /// Computes the cosine of the value (in radians)
+    /// Computes the cosine of the value (in radians). As for `sin`, the
+    /// relative error is less than 16u², where u = 2<sup>-53</sup>.
     ///
     /// # Examples
     ///
@@ -331,12 +387,7 @@ impl TwoFloat {
             return Self::NAN;
         }
         let (x, quadrant) = quadrant(self);
-        match quadrant {
-            0 => restricted_cos(x),
-            1 => -restricted_sin(x),
-            2 => -restricted_cos(x),
-            _ => restricted_sin(x),
-        }
+        cos_quadrant(x, quadrant)
     }
 
     /// Simultaneously computes the sine and cosine of the value. Returns a
@@ -358,14 +409,7 @@ impl TwoFloat {
             return (Self::NAN, Self::NAN);
         }
         let (x, quadrant) = quadrant(self);
-        let s = restricted_sin(x);
-        let c = restricted_cos(x);
-        match quadrant {
-            0 => (s, c),
-            1 => (c, -s),
-            2 => (-s, -c),
-            _ => (-c, s),
-        }
+        sin_cos_quadrant(x, quadrant)
     }
 
     /// Computes the tangent of the value (in radians).
@@ -382,13 +426,10 @@ impl TwoFloat {
     /// ```
     pub fn tan(self) -> Self {
         if !self.is_valid() {
-            return self;
+            return Self::NAN;
         }
         let (x, quadrant) = quadrant(self);
-        match quadrant {
-            0 | 2 => restricted_tan(x),
-            _ => -1.0 / restricted_tan(x),
-        }
+        tan_quadrant(x, quadrant)
     }
 
     /// Computes the arcsine of the value. Return value is in radians in the
@@ -458,14 +499,14 @@ impl TwoFloat {
     /// assert!((b - c).abs() < 1e-10);
     /// ```
     pub fn atan(self) -> Self {
-        if !self.is_valid() {
-            Self::NAN
-        } else if self.hi.is_infinite() {
+        if self.hi.is_infinite() {
             if self.hi.is_sign_positive() {
                 FRAC_PI_2
             } else {
                 -FRAC_PI_2
             }
+        } else if !self.is_valid() {
+            Self::NAN
         } else {
             let x = self.abs();
             let k = 4.0 * x + 0.25;
@@ -505,20 +546,10 @@ impl TwoFloat {
     /// assert!((theta + 3.0 * twofloat::consts::FRAC_PI_4).abs() < 1e-10);
     /// ```
     pub fn atan2(self, other: Self) -> Self {
-        if self.hi == 0.0 {
-            if other.hi.is_sign_positive() {
-                Self::from(0.0)
-            } else if self.hi.is_sign_positive() {
-                PI
-            } else {
-                -PI
-            }
-        } else if other.hi == 0.0 {
-            if self.hi.is_sign_positive() {
-                FRAC_PI_2
-            } else {
-                -FRAC_PI_2
-            }
+        if self.hi.is_nan() || other.hi.is_nan() {
+            Self::NAN
+        } else if let Some(turns) = special_half_turns(self, other) {
+            PI * turns
         } else {
             let a = (self / other).atan();
             if other.hi.is_sign_positive() {
@@ -530,6 +561,254 @@ impl TwoFloat {
             }
         }
     }
+
+    /// Computes the sine of `π * self`.
+    ///
+    /// The argument is reduced exactly in units of half turns, so the result
+    /// is exactly zero for integer values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(1.0).sin_pi(), 0.0);
+    /// assert_eq!(TwoFloat::from(-1.5).sin_pi(), 1.0);
+    ///
+    /// let a = TwoFloat::from(0.25).sin_pi();
+    /// assert!((a - twofloat::consts::FRAC_1_SQRT_2).abs() < 1e-30);
+    /// ```
+    pub fn sin_pi(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = rem_quarter_period(self, 0.5);
+        sin_quadrant(x * PI, quadrant)
+    }
+
+    /// Computes the cosine of `π * self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(0.5).cos_pi(), 0.0);
+    /// assert_eq!(TwoFloat::from(3.0).cos_pi(), -1.0);
+    /// ```
+    pub fn cos_pi(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = rem_quarter_period(self, 0.5);
+        cos_quadrant(x * PI, quadrant)
+    }
+
+    /// Simultaneously computes the sine and cosine of `π * self`. Returns a
+    /// tuple with the sine as the first element and the cosine as the second
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let (s, c) = TwoFloat::from(2.5).sincos_pi();
+    ///
+    /// assert_eq!(s, 1.0);
+    /// assert_eq!(c, 0.0);
+    /// ```
+    pub fn sincos_pi(self) -> (Self, Self) {
+        if !self.is_valid() {
+            return (Self::NAN, Self::NAN);
+        }
+        let (x, quadrant) = rem_quarter_period(self, 0.5);
+        sin_cos_quadrant(x * PI, quadrant)
+    }
+
+    /// Computes the tangent of `π * self`, returning infinity for odd
+    /// multiples of one half.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(0.25).tan_pi(), 1.0);
+    /// assert_eq!(TwoFloat::from(2.0).tan_pi(), 0.0);
+    /// assert_eq!(TwoFloat::from(0.5).tan_pi(), TwoFloat::INFINITY);
+    /// ```
+    pub fn tan_pi(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = rem_quarter_period(self, 0.5);
+        tan_quadrant(x * PI, quadrant)
+    }
+
+    /// Computes the arcsine of the value divided by `π`. Return value is in
+    /// the range [-1/2, 1/2] or an invalid value if the input value is
+    /// outside the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(-1.0).asin_pi(), -0.5);
+    ///
+    /// let a = TwoFloat::from(0.5).asin_pi();
+    /// assert!((a - TwoFloat::from(6.0).recip()).abs() < 1e-31);
+    /// ```
+    pub fn asin_pi(self) -> Self {
+        if !self.is_valid() || self.abs() > 1.0 {
+            Self::NAN
+        } else {
+            self.atan2_pi(((1.0 - self) * (1.0 + self)).sqrt())
+        }
+    }
+
+    /// Computes the four quadrant arctangent of `self` (y) and `other` (x)
+    /// divided by `π`, giving a value in the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let y = TwoFloat::from(-1.0);
+    /// let x = TwoFloat::from(-1.0);
+    ///
+    /// assert_eq!(TwoFloat::atan2_pi(y, x), -0.75);
+    /// ```
+    pub fn atan2_pi(self, other: Self) -> Self {
+        if self.hi.is_nan() || other.hi.is_nan() {
+            Self::NAN
+        } else if let Some(turns) = special_half_turns(self, other) {
+            Self::from(turns)
+        } else {
+            let a = first_quadrant_half_turns(self.abs(), other.abs());
+            let a = if other.hi.is_sign_positive() {
+                a
+            } else {
+                1.0 - a
+            };
+            if self.hi.is_sign_positive() {
+                a
+            } else {
+                -a
+            }
+        }
+    }
+
+    /// Computes the sine of the value in degrees.
+    ///
+    /// The argument is reduced exactly modulo 360 degrees, so the result is
+    /// exactly zero for multiples of 180 degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(180.0).sind(), 0.0);
+    /// assert_eq!(TwoFloat::from(-270.0).sind(), 1.0);
+    ///
+    /// let a = TwoFloat::from(30.0).sind();
+    /// assert!((a - 0.5).abs() < 1e-30);
+    /// ```
+    pub fn sind(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = rem_quarter_period(self, 90.0);
+        sin_quadrant(x * RAD_PER_DEG, quadrant)
+    }
+
+    /// Computes the cosine of the value in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(90.0).cosd(), 0.0);
+    /// assert_eq!(TwoFloat::from(540.0).cosd(), -1.0);
+    /// ```
+    pub fn cosd(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = rem_quarter_period(self, 90.0);
+        cos_quadrant(x * RAD_PER_DEG, quadrant)
+    }
+
+    /// Simultaneously computes the sine and cosine of the value in degrees.
+    /// Returns a tuple with the sine as the first element and the cosine as
+    /// the second element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let (s, c) = TwoFloat::from(-90.0).sincosd();
+    ///
+    /// assert_eq!(s, -1.0);
+    /// assert_eq!(c, 0.0);
+    /// ```
+    pub fn sincosd(self) -> (Self, Self) {
+        if !self.is_valid() {
+            return (Self::NAN, Self::NAN);
+        }
+        let (x, quadrant) = rem_quarter_period(self, 90.0);
+        sin_cos_quadrant(x * RAD_PER_DEG, quadrant)
+    }
+
+    /// Computes the tangent of the value in degrees, returning infinity for
+    /// odd multiples of 90 degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(-180.0).tand(), 0.0);
+    /// assert_eq!(TwoFloat::from(270.0).tand(), TwoFloat::NEG_INFINITY);
+    ///
+    /// let a = TwoFloat::from(45.0).tand();
+    /// assert!((a - 1.0).abs() < 1e-30);
+    /// ```
+    pub fn tand(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = rem_quarter_period(self, 90.0);
+        tan_quadrant(x * RAD_PER_DEG, quadrant)
+    }
+
+    /// Computes the arcsine of the value in degrees. Return value is in the
+    /// range [-90, 90] or an invalid value if the input value is outside the
+    /// range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(1.0).asind(), 90.0);
+    ///
+    /// let a = TwoFloat::from(0.5).asind();
+    /// assert!((a - 30.0).abs() < 1e-29);
+    /// ```
+    pub fn asind(self) -> Self {
+        self.asin_pi() * 180.0
+    }
+
+    /// Computes the four quadrant arctangent of `self` (y) and `other` (x)
+    /// in degrees, giving a value in the range [-180, 180].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let y = TwoFloat::from(1.0);
+    /// let x = TwoFloat::from(-1.0);
+    ///
+    /// assert_eq!(TwoFloat::atan2d(y, x), 135.0);
+    /// ```
+    pub fn atan2d(self, other: Self) -> Self {
+        self.atan2_pi(other) * 180.0
+    }
 }
 
 #[cfg(test)]
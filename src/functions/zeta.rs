@@ -0,0 +1,863 @@
+use core::ops::{Add, Mul};
+
+use hexf::hexf64;
+
+use crate::{
+    consts::{FRAC_PI_2, LN_2, PI, TAU},
+    TwoFloat,
+};
+
+// Number of terms summed directly in the Euler-Maclaurin summation, which is
+// increased by the magnitude of the order
+const EULER_MACLAURIN_TERMS: usize = 20;
+
+// Above this value zeta(s) = 1 + 2^-s to within the precision of the type
+const ZETA_DIRECT: f64 = 70.0;
+
+// Below this value zeta(s) is computed using the reflection formula. Closer to
+// zero the reflection loses the low bits of s when forming 1 - s next to the
+// pole, so Borwein's algorithm is used instead.
+const ZETA_REFLECTION: f64 = -0.125;
+
+// Below this magnitude of the argument the polylogarithm is summed directly
+const SERIES_LIMIT: f64 = 0.5;
+
+// Above this order the series of the polylogarithm converges quickly for all
+// arguments of magnitude at most 1
+const SERIES_ORDER: f64 = 30.0;
+
+// Terms smaller than this relative to the sum are neglected
+const TERM_EPSILON: f64 = hexf64!("0x1.0p-110");
+
+// Initial step of the double exponential quadrature
+const QUADRATURE_STEP: f64 = 0.125;
+
+// Maximum number of times the quadrature step is halved
+const QUADRATURE_LEVELS: usize = 5;
+
+// The quadrature is stopped once successive estimates agree to this relative
+// tolerance, as the error is roughly squared with each halving of the step
+const QUADRATURE_TOLERANCE: f64 = hexf64!("0x1.0p-60");
+
+// Above this value of ln(-x) the Fermi-Dirac integral is split at its step
+const INTEGRAL_SPLIT: f64 = 1.0;
+
+// ln(2*pi)
+const LN_2PI: TwoFloat = TwoFloat {
+    hi: hexf64!("0x1.d67f1c864beb5p+0"),
+    lo: hexf64!("-0x1.65b5a1b7ff5dfp-54"),
+};
+
+// Coefficients B(2k)/(2k)! of the Euler-Maclaurin summation, where B(2k) are
+// the Bernoulli numbers, for k = 1, 2, ...
+const BERNOULLI_COEFFS: [TwoFloat; 24] = [
+    TwoFloat {
+        hi: hexf64!("0x1.5555555555555p-4"),
+        lo: hexf64!("0x1.5555555555555p-58"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.6c16c16c16c17p-10"),
+        lo: hexf64!("0x1.f49f49f49f49fp-65"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.1566abc011567p-15"),
+        lo: hexf64!("-0x1.50ffbaa655100p-69"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.bbd779334ef0bp-21"),
+        lo: hexf64!("0x1.4e65f77088199p-75"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.66a8f2bf70ebep-26"),
+        lo: hexf64!("-0x1.75a7bb0599f07p-80"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.22805d644267fp-31"),
+        lo: hexf64!("0x1.16a73200360d2p-88"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.d6db2c4e09162p-37"),
+        lo: hexf64!("-0x1.1ed444b9ec588p-95"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.7da4e1f79955cp-42"),
+        lo: hexf64!("-0x1.2ff894d037a17p-96"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.355871d652e9ep-47"),
+        lo: hexf64!("-0x1.88d4ccd141422p-101"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.f57d968caacf1p-53"),
+        lo: hexf64!("0x1.9c31f0af5255fp-108"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.967e1f09c376fp-58"),
+        lo: hexf64!("-0x1.3ea5a927db8abp-116"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.497d9033a2b5cp-63"),
+        lo: hexf64!("-0x1.b843f32aad364p-117"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0b132d7c6ad06p-68"),
+        lo: hexf64!("0x1.01d4526c8e75ep-122"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.b0f72d59f1c16p-74"),
+        lo: hexf64!("-0x1.f30b7489fb679p-128"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.5ef2da4cca26dp-79"),
+        lo: hexf64!("0x1.6b993adfdd467p-133"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.1c77df96de38bp-84"),
+        lo: hexf64!("0x1.dac59dd0d33acp-143"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.cd299de521b62p-90"),
+        lo: hexf64!("-0x1.4075f86821e83p-144"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.75cde656574a7p-95"),
+        lo: hexf64!("0x1.89cf9cb4d5178p-150"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.2efe8db3b4adfp-100"),
+        lo: hexf64!("-0x1.cc0e9671edd3fp-155"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.eb322904761ffp-106"),
+        lo: hexf64!("0x1.3082df2e94ceep-162"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.8e25ff9328464p-111"),
+        lo: hexf64!("0x1.857b060807e1ap-166"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.42ba1a349b5d3p-116"),
+        lo: hexf64!("0x1.a4e6a837d57bbp-171"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0597b61cb30d4p-121"),
+        lo: hexf64!("-0x1.c3c944137025dp-175"),
+    },
+    TwoFloat {
+        hi: hexf64!("-0x1.a813f6eaa7073p-127"),
+        lo: hexf64!("0x1.625a078b3ac75p-182"),
+    },
+];
+
+// Coefficients (d(n) - d(k))/d(n) of Borwein's algorithm for the zeta function
+// with n = 44, where d(k) = n sum (n + i - 1)! 4^i/((n - i)! (2i)!) over i <= k
+const BORWEIN_COEFFS: [TwoFloat; 44] = [
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.13010cbfa1583p-111"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.04082e1df27f7p-99"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.4800ab22a0004p-90"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.4ad2a58ec628ap-82"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.64f3bc08798b6p-75"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.de114297473a4p-69"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.b2fe2552b16f3p-63"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.0000000000000p+0"),
+        lo: hexf64!("-0x1.1dbe65ff8fdb1p-57"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffffffffffffep-1"),
+        lo: hexf64!("-0x1.b1502b8d46433p-56"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fffffffffffc9p-1"),
+        lo: hexf64!("0x1.81f1aec1713eep-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffffffffffbcap-1"),
+        lo: hexf64!("-0x1.bb21dcff365a6p-59"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fffffffffbc3ep-1"),
+        lo: hexf64!("-0x1.4cc5bde37711cp-56"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffffffffc7874p-1"),
+        lo: hexf64!("0x1.f6c0138f2e4eep-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fffffffd877a9p-1"),
+        lo: hexf64!("-0x1.9e3189f0978fep-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffffffe87ddedp-1"),
+        lo: hexf64!("0x1.89340dad38724p-56"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffffff4031012p-1"),
+        lo: hexf64!("-0x1.284b977e2bf4bp-58"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fffffab70e2ccp-1"),
+        lo: hexf64!("-0x1.e07044602fecfp-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffffdf8ca8599p-1"),
+        lo: hexf64!("-0x1.46f374b2140c0p-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffff5183179bbp-1"),
+        lo: hexf64!("-0x1.3a5f8e98c41c4p-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fffcc61ec7eaap-1"),
+        lo: hexf64!("-0x1.30c263551d6fcp-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fff27f7fd4daap-1"),
+        lo: hexf64!("0x1.3845bb3426a70p-60"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ffcdd5973412ep-1"),
+        lo: hexf64!("0x1.d1a32a28f6bc1p-56"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.ff59f3c4ee4afp-1"),
+        lo: hexf64!("0x1.c40b3ba1e4d8bp-57"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fe14cf7e5e718p-1"),
+        lo: hexf64!("-0x1.8d15ef97d1b05p-60"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.fae9af8cfa4efp-1"),
+        lo: hexf64!("0x1.4dc5b48406ac6p-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.f3e0a85cf54e9p-1"),
+        lo: hexf64!("-0x1.7923bef056366p-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.e5f75948242d4p-1"),
+        lo: hexf64!("0x1.c717e69c3fba7p-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.cd77dc8279ef8p-1"),
+        lo: hexf64!("-0x1.0d064e68f5685p-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.a71119ec36e3cp-1"),
+        lo: hexf64!("-0x1.399c0e920932dp-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.718ab2e3f3751p-1"),
+        lo: hexf64!("-0x1.d688a90b6826ep-55"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.2f50e0f81983fp-1"),
+        lo: hexf64!("-0x1.c9e4bf3802c9ap-58"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.cd80985d1084bp-2"),
+        lo: hexf64!("0x1.a78d7df3a164cp-56"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.411fc1ac75fe4p-2"),
+        lo: hexf64!("-0x1.100900135c7d3p-56"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.9381c1b9ffae2p-3"),
+        lo: hexf64!("0x1.359ff6a74dfedp-58"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.c3f0efbc744e8p-4"),
+        lo: hexf64!("-0x1.22c95d9d1267fp-58"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.bd27afbd13932p-5"),
+        lo: hexf64!("-0x1.60573b2cb79dcp-59"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.7be52a5d7030ep-6"),
+        lo: hexf64!("-0x1.9deab8b699a27p-62"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.140546e1701b8p-7"),
+        lo: hexf64!("-0x1.f70c508960b2ep-61"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.4e1fed3a3b8bdp-9"),
+        lo: hexf64!("-0x1.e0b6cd3ee17e8p-63"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.4752cf70b8ba1p-11"),
+        lo: hexf64!("0x1.00d91ad37dfa0p-66"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.f20ac6b68c935p-14"),
+        lo: hexf64!("-0x1.46ba438280e6bp-68"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.13cf4d8931113p-16"),
+        lo: hexf64!("-0x1.fc336addfc075p-71"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.8b51825377eecp-20"),
+        lo: hexf64!("-0x1.e12110d27c6d3p-75"),
+    },
+    TwoFloat {
+        hi: hexf64!("0x1.13010cbfa1583p-24"),
+        lo: hexf64!("-0x1.85f53d80dd5dap-78"),
+    },
+];
+
+// Complex number with the operations needed for the Hurwitz zeta function of
+// complex argument
+#[derive(Clone, Copy)]
+struct Complex {
+    re: TwoFloat,
+    im: TwoFloat,
+}
+
+impl Complex {
+    fn norm(self) -> TwoFloat {
+        self.re.hypot(self.im)
+    }
+
+    fn recip(self) -> Self {
+        let d = self.re * self.re + self.im * self.im;
+        Self {
+            re: self.re / d,
+            im: -self.im / d,
+        }
+    }
+
+    // The polynomial approximation of atan does not reach full precision, so
+    // the argument is refined by a Newton step
+    fn ln(self) -> Self {
+        let theta = self.im.atan2(self.re);
+        let (s, c) = theta.sin_cos();
+        Self {
+            re: (self.re * self.re + self.im * self.im).ln() * 0.5,
+            im: theta + (self.im * c - self.re * s) / (self.re * c + self.im * s),
+        }
+    }
+
+    fn exp(self) -> Self {
+        let m = self.re.exp();
+        let (s, c) = self.im.sin_cos();
+        Self {
+            re: m * c,
+            im: m * s,
+        }
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl Add<f64> for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: f64) -> Self {
+        Self {
+            re: self.re + rhs,
+            im: self.im,
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl Mul<TwoFloat> for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: TwoFloat) -> Self {
+        Self {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+}
+
+// Computes the Hurwitz zeta function zeta(s, a) for a with positive real part
+// using Euler-Maclaurin summation
+fn hurwitz_zeta(s: TwoFloat, a: Complex) -> Complex {
+    let n = EULER_MACLAURIN_TERMS + libm::ceil(s.hi.abs()) as usize;
+    let mut sum = (0..n).fold(
+        Complex {
+            re: TwoFloat::from(0.0),
+            im: TwoFloat::from(0.0),
+        },
+        |acc, k| acc + ((a + k as f64).ln() * -s).exp(),
+    );
+
+    let w = a + n as f64;
+    let w_recip = w.recip();
+    let power = (w.ln() * -s).exp();
+    sum = sum + power * w * (s - 1.0).recip() + power * TwoFloat::from(0.5);
+
+    let w2_recip = w_recip * w_recip;
+    let mut factor = power * w_recip * s;
+    for (j, &coeff) in BERNOULLI_COEFFS.iter().enumerate() {
+        let term = factor * coeff;
+        sum = sum + term;
+        if term.norm() < sum.norm() * TERM_EPSILON {
+            break;
+        }
+
+        let j = 2.0 * j as f64;
+        factor = factor * w2_recip * ((s + (j + 1.0)) * (s + (j + 2.0)));
+    }
+
+    sum
+}
+
+// Computes zeta(s) for -1/8 <= s < 1 using Borwein's algorithm for the
+// alternating series of the Dirichlet eta function, which avoids the
+// cancellation of the Euler-Maclaurin summation in this range
+fn zeta_borwein(s: TwoFloat) -> TwoFloat {
+    let sum = BORWEIN_COEFFS
+        .iter()
+        .enumerate()
+        .fold(TwoFloat::from(0.0), |acc, (k, &coeff)| {
+            let term = coeff * TwoFloat::from((k + 1) as f64).powf(-s);
+            if k % 2 == 0 {
+                acc + term
+            } else {
+                acc - term
+            }
+        });
+
+    // zeta(s) = eta(s)/(1 - 2^(1 - s))
+    -sum / ((1.0 - s) * LN_2).exp_m1()
+}
+
+// Computes gamma(t) (2 pi)^-t for t = 1 - s. Rather than evaluating the power
+// using the exponential, whose error grows with its argument, t is reduced to
+// the range [1, 2) with the recurrence of the gamma function, which also
+// avoids intermediate overflow.
+fn reflection_factor(s: TwoFloat) -> TwoFloat {
+    let t = 1.0 - s;
+    let mut floor = libm::floor(t.hi);
+    let mut f = t - floor;
+    if f.hi < 0.0 {
+        f += 1.0;
+        floor -= 1.0;
+    }
+
+    let f = f + 1.0;
+    let m = floor as i32 - 1;
+    let base = f.gamma() * (-f * LN_2PI).exp();
+    if m >= 0 {
+        (0..m).fold(base, |acc, j| acc * ((f + f64::from(j)) / TAU))
+    } else {
+        (1..=-m).fold(base, |acc, j| acc * (TAU / (f - f64::from(j))))
+    }
+}
+
+// Sums the series Li_s(x) = sum x^k/k^s, which converges quickly for small
+// arguments or large orders
+fn polylog_series(s: TwoFloat, x: TwoFloat) -> TwoFloat {
+    let mut power = x;
+    let mut sum = x;
+    let mut k = 2.0;
+    loop {
+        power *= x;
+        let term = power * TwoFloat::from(k).powf(-s);
+        sum += term;
+        // For negative orders the terms only decrease beyond k = -2s
+        if term.abs() <= sum.abs() * TERM_EPSILON && k > -2.0 * s.hi {
+            return sum;
+        }
+
+        k += 1.0;
+    }
+}
+
+// Computes Li_s(x) for s <= 0 using the representation in terms of the
+// Hurwitz zeta function,
+// Li_s(x) = gamma(1 - s) (2 pi)^(s - 1) 2 Re(i^(1 - s) zeta(1 - s, a)),
+// where a = 1/2 - i ln(-x)/(2 pi) for x < 0. For 0 < x < 1, a = 1 - i ln(x)/(2
+// pi) and the term gamma(1 - s) (-ln(x))^(s - 1) is added.
+fn polylog_hurwitz(s: TwoFloat, x: TwoFloat) -> TwoFloat {
+    let (a, extra) = if x.hi < 0.0 {
+        let a = Complex {
+            re: TwoFloat::from(0.5),
+            im: -(-x).ln() / TAU,
+        };
+        (a, TwoFloat::from(0.0))
+    } else {
+        let mu = x.ln();
+        let a = Complex {
+            re: TwoFloat::from(1.0),
+            im: -mu / TAU,
+        };
+        (a, (1.0 - s).gamma() * (-mu).powf(s - 1.0))
+    };
+
+    let z = hurwitz_zeta(1.0 - s, a);
+    let (sin, cos) = ((1.0 - s) * 0.5).sincos_pi();
+    extra + reflection_factor(s) * (cos * z.re - sin * z.im) * 2.0
+}
+
+// Computes Li_s(x) for non-integer s > 0 and 1/2 < x < 1 from the expansion
+// in mu = ln(x),
+// Li_s(x) = gamma(1 - s) (-mu)^(s - 1) + sum zeta(s - k) mu^k/k!
+fn polylog_log_series(s: TwoFloat, x: TwoFloat) -> TwoFloat {
+    let mu = x.ln();
+    let mut sum = (1.0 - s).gamma() * (-mu).powf(s - 1.0);
+    let mut power = TwoFloat::from(1.0);
+    let mut k = 0.0;
+    loop {
+        let term = (s - k).zeta() * power;
+        sum += term;
+        // The terms only decrease once zeta(s - k) is past its minimum
+        if term.abs() <= sum.abs() * TERM_EPSILON && k > s.hi {
+            return sum;
+        }
+
+        k += 1.0;
+        power *= mu / k;
+    }
+}
+
+// Sums term(tau) over the nodes first + k spacing and their negatives for
+// k >= 0, until the terms are negligible in each direction
+fn sum_nodes(term: &impl Fn(f64) -> TwoFloat, first: f64, spacing: f64) -> TwoFloat {
+    let mut sum = if first == 0.0 {
+        term(0.0)
+    } else {
+        term(first) + term(-first)
+    };
+
+    for &direction in &[1.0, -1.0] {
+        let mut tau = first;
+        loop {
+            tau += spacing;
+            let value = term(direction * tau);
+            if !value.is_valid() {
+                break;
+            }
+
+            sum += value;
+            if value.abs() <= sum.abs() * TERM_EPSILON {
+                break;
+            }
+        }
+    }
+
+    sum
+}
+
+// Integrates a function transformed to decay double exponentially over the
+// real line using the trapezoidal rule, halving the step until successive
+// estimates agree
+fn double_exponential(term: impl Fn(f64) -> TwoFloat) -> TwoFloat {
+    let mut step = QUADRATURE_STEP;
+    let mut sum = sum_nodes(&term, 0.0, step);
+    let mut estimate = sum * step;
+    for _ in 0..QUADRATURE_LEVELS {
+        step *= 0.5;
+        sum += sum_nodes(&term, step, 2.0 * step);
+        let next = sum * step;
+        let converged = (next - estimate).abs() <= next.abs() * QUADRATURE_TOLERANCE;
+        estimate = next;
+        if converged {
+            break;
+        }
+    }
+
+    estimate
+}
+
+// Returns the logarithm of the node t = exp(pi/2 sinh(tau)) of the
+// exp-sinh rule for integrals over t > 0, with the node and its weight
+fn exp_sinh_node(tau: f64) -> (TwoFloat, TwoFloat, TwoFloat) {
+    let tau = TwoFloat::from(tau);
+    let ln_t = FRAC_PI_2 * tau.sinh();
+    let t = ln_t.exp();
+    (ln_t, t, t * FRAC_PI_2 * tau.cosh())
+}
+
+// Returns the node t of the tanh-sinh rule for integrals over 0 < t < length,
+// with length - t and the weight
+fn tanh_sinh_node(tau: f64, length: TwoFloat) -> (TwoFloat, TwoFloat, TwoFloat) {
+    let tau = TwoFloat::from(tau);
+    let e = (-PI * tau.sinh()).exp();
+    let d = 1.0 + e;
+    let weight = length * PI * tau.cosh() * e / (d * d);
+    (length / d, length * e / d, weight)
+}
+
+// Computes Li_s(x) for s > 0 and x < -1/2 from the Fermi-Dirac integral
+// -Li_s(x) = 1/gamma(s) integral t^(s-1)/(exp(t - eta) + 1) dt over t > 0,
+// where eta = ln(-x), using double exponential quadrature. Subtracting
+// p t^(s-1), where p = 1/(exp(-eta) + 1), removes the singularity at t = 0.
+fn polylog_integral(s: TwoFloat, x: TwoFloat) -> TwoFloat {
+    let eta = (-x).ln();
+    let ln_gamma = s.ln_gamma().0;
+    let p = -x / (1.0 - x);
+    let integral = if eta.hi <= INTEGRAL_SPLIT {
+        // The subtracted term is p t^(s-1) exp(-t), with integral p gamma(s)
+        p + double_exponential(|tau| {
+            let (ln_t, t, weight) = exp_sinh_node(tau);
+            let power = ((s - 1.0) * ln_t - ln_gamma).exp();
+            weight * power * p * (-t).exp_m1() * x / (t.exp() - x)
+        })
+    } else {
+        // For larger eta the integral is split at the step in the integrand,
+        // subtracting the term over 0 < t < eta with integral p eta^s/s
+        let lower = double_exponential(|tau| {
+            let (t, r, weight) = tanh_sinh_node(tau, eta);
+            let power = ((s - 1.0) * t.ln() - ln_gamma).exp();
+            let e = (-r).exp();
+            -weight * power * p * (e + x.recip()) / (e + 1.0)
+        });
+        let upper = double_exponential(|tau| {
+            let (_, v, weight) = exp_sinh_node(tau);
+            let power = ((s - 1.0) * (eta + v).ln() - ln_gamma).exp();
+            weight * power / (v.exp() + 1.0)
+        });
+        p * (s * eta.ln() - ln_gamma).exp() / s + lower + upper
+    };
+
+    -integral
+}
+
+// Computes Li_n(x) for integer n >= 2 and 1/2 < x < 1 from the expansion in
+// mu = ln(x),
+// Li_n(x) = mu^(n-1)/(n-1)! (H(n-1) - ln(-mu)) + sum zeta(n-k) mu^k/k!,
+// where the sum excludes k = n - 1 and H(n) is the nth harmonic number
+fn polylog_near_one(n: i32, x: TwoFloat) -> TwoFloat {
+    let mu = x.ln();
+    let mut sum = TwoFloat::from(0.0);
+    let mut power = TwoFloat::from(1.0);
+    let mut harmonic = TwoFloat::from(0.0);
+    for k in 0..n - 1 {
+        sum += TwoFloat::from(n - k).zeta() * power;
+        power *= mu / f64::from(k + 1);
+        harmonic += TwoFloat::from(f64::from(k + 1)).recip();
+    }
+
+    sum += power * (harmonic - (-mu).ln());
+
+    // zeta(0) = -1/2, zeta(-2j) = 0 and zeta(1 - 2j) = -(2j - 1)! B(2j)/(2j)!
+    let n = f64::from(n);
+    power *= mu / n;
+    sum -= power * 0.5;
+
+    let mut factor = power * mu / (n + 1.0);
+    for (j, &coeff) in BERNOULLI_COEFFS.iter().enumerate() {
+        let term = factor * coeff;
+        sum -= term;
+        if term.abs() <= sum.abs() * TERM_EPSILON {
+            break;
+        }
+
+        let j = 2.0 * (j + 1) as f64;
+        factor *= mu * mu * (j * (j + 1.0)) / ((n + j) * (n + j + 1.0));
+    }
+
+    sum
+}
+
+// Computes Li_n(x) for integer n >= 2 and x < 1
+fn polylog_integer(n: i32, x: TwoFloat) -> TwoFloat {
+    if x.hi.abs() <= SERIES_LIMIT || (f64::from(n) >= SERIES_ORDER && x >= -1.0) {
+        polylog_series(TwoFloat::from(n), x)
+    } else if x.hi > 0.0 {
+        polylog_near_one(n, x)
+    } else if x == -1.0 {
+        // Li_n(-1) = -eta(n) = (2^(1 - n) - 1) zeta(n)
+        TwoFloat::from(n).zeta() * (libm::ldexp(1.0, 1 - n) - 1.0)
+    } else if x >= -1.0 {
+        // Li_n(x) = 2^(1 - n) Li_n(x^2) - Li_n(-x)
+        polylog_integer(n, x * x) * libm::ldexp(1.0, 1 - n) - polylog_integer(n, -x)
+    } else {
+        // Li_n(x) = (-1)^(n-1) Li_n(1/x) - 2 sum eta(2k) ln(-x)^(n-2k)/(n-2k)!,
+        // where eta(0) = 1/2 and eta(s) = (1 - 2^(1-s)) zeta(s) is the
+        // Dirichlet eta function
+        let ln_x = (-x).ln();
+        let ln_x2 = ln_x * ln_x;
+        let mut power = if n % 2 == 0 {
+            TwoFloat::from(1.0)
+        } else {
+            ln_x
+        };
+        let mut sum = TwoFloat::from(0.0);
+        for k in (0..=n / 2).rev() {
+            let eta = if k == 0 {
+                TwoFloat::from(0.5)
+            } else {
+                let s = TwoFloat::from(2 * k);
+                (1.0 - libm::ldexp(1.0, 1 - 2 * k)) * s.zeta()
+            };
+            sum += eta * power;
+
+            let m = f64::from(n - 2 * k);
+            power *= ln_x2 / ((m + 1.0) * (m + 2.0));
+        }
+
+        let inverse = polylog_integer(n, x.recip());
+        if n % 2 == 0 {
+            -inverse - sum * 2.0
+        } else {
+            inverse - sum * 2.0
+        }
+    }
+}
+
+impl TwoFloat {
+    /// Computes the Riemann zeta function of the value.
+    ///
+    /// The function is computed using Euler-Maclaurin summation for
+    /// arguments larger than 1 and Borwein's algorithm between -1/8 and 1,
+    /// with arguments below -1/8 using the reflection formula. Returns NaN if
+    /// the value is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.0).zeta();
+    /// let b = twofloat::consts::PI * twofloat::consts::PI / 6.0;
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    ///
+    /// assert_eq!(TwoFloat::from(-2.0).zeta(), 0.0);
+    /// ```
+    pub fn zeta(self) -> Self {
+        if !self.is_valid() {
+            if self.hi == f64::INFINITY {
+                Self::from(1.0)
+            } else {
+                Self::NAN
+            }
+        } else if self == 1.0 {
+            Self::NAN
+        } else if self.hi >= ZETA_DIRECT {
+            1.0 + (-self).exp2()
+        } else if self.hi < ZETA_REFLECTION {
+            // zeta(s) = 2 gamma(1 - s) (2 pi)^(s - 1) sin(pi s/2) zeta(1 - s)
+            let s = (self * 0.5).sin_pi();
+            if s.hi == 0.0 {
+                Self::from(0.0)
+            } else {
+                reflection_factor(self) * s * (1.0 - self).zeta() * 2.0
+            }
+        } else if self.hi < 1.0 {
+            zeta_borwein(self)
+        } else {
+            let a = Complex {
+                re: Self::from(1.0),
+                im: Self::from(0.0),
+            };
+            hurwitz_zeta(self, a).re
+        }
+    }
+
+    /// Computes the polylogarithm `Li_s(x)` of order `s`, where `s` is the
+    /// value, for real arguments `x <= 1`.
+    ///
+    /// The series in `x` is used for small arguments. Otherwise positive
+    /// non-integer orders use the expansion in `ln(x)` for `x > 0` and double
+    /// exponential quadrature of the Fermi-Dirac integral for `x < 0`, while
+    /// negative orders use a representation in terms of the Hurwitz zeta
+    /// function. Accuracy is reduced for orders close to an integer and for
+    /// large orders with `x < -1`. Positive integer orders use the expansion
+    /// in `ln(x)` and the inversion formula. Returns NaN if `x` is larger than
+    /// 1 or not finite, and infinity if `x` is 1 and `s <= 1`.
+    ///
+    /// The Fermi-Dirac integral of order `j` is `-Li_(j+1)(-exp(x))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.0).polylog(TwoFloat::from(0.5));
+    /// let b = TwoFloat::new_add(0.5822405264650125, 5.245286862080417e-17);
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    ///
+    /// let c = TwoFloat::from(1.5).polylog(TwoFloat::from(-10.0));
+    /// let d = TwoFloat::new_add(-3.285684082333893, 2.5162710958473886e-17);
+    /// assert!(((c - d) / d).abs() < 1e-30);
+    /// ```
+    pub fn polylog(self, x: Self) -> Self {
+        if !self.is_valid() || !x.is_valid() || x > 1.0 {
+            return Self::NAN;
+        } else if x == 1.0 {
+            return if self > 1.0 {
+                self.zeta()
+            } else {
+                Self::INFINITY
+            };
+        } else if x.hi == 0.0 {
+            return x;
+        }
+
+        let is_integer = self.lo == 0.0
+            && libm::trunc(self.hi) == self.hi
+            && self.hi.abs() <= f64::from(i32::MAX);
+        if is_integer && self.hi >= 0.0 {
+            match self.hi as i32 {
+                0 => x / (1.0 - x),
+                1 => -(-x).ln_1p(),
+                n => polylog_integer(n, x),
+            }
+        } else if x.hi.abs() <= SERIES_LIMIT || (self.hi >= SERIES_ORDER && x >= -1.0) {
+            polylog_series(self, x)
+        } else if self.hi > 0.0 {
+            if x.hi > 0.0 {
+                polylog_log_series(self, x)
+            } else {
+                polylog_integral(self, x)
+            }
+        } else if is_integer && x < -1.0 {
+            // Li_-n(x) = (-1)^(n+1) Li_-n(1/x) for n >= 1
+            let inverse = self.polylog(x.recip());
+            if self.hi % 2.0 == 0.0 {
+                -inverse
+            } else {
+                inverse
+            }
+        } else {
+            polylog_hurwitz(self, x)
+        }
+    }
+}
@@ -0,0 +1,421 @@
+use twofloat_decimal::{digit_value, make_f64, parse_sign, scan_number};
+
+use crate::{
+    parse::{decimal_error, error},
+    ParseErrorKind, TwoFloat, TwoFloatError,
+};
+
+const BUFFER_WORDS: usize = 36;
+
+// Large enough to hold the binary expansion of any dyadic value between
+// 2^-1076 and 2^1024.
+const BUFFER_BITS: usize = 64 * BUFFER_WORDS;
+
+/// Fixed-size buffer of binary digits, where index zero is the most
+/// significant. Nonzero digits beyond the capacity of the buffer are recorded
+/// in the `sticky` flag, so the stored value is slightly smaller than the
+/// exact value if it is set.
+#[derive(Clone, Copy)]
+pub(crate) struct BitBuffer {
+    words: [u64; BUFFER_WORDS],
+    pub(crate) len: usize,
+    sticky: bool,
+}
+
+impl BitBuffer {
+    pub(crate) const fn new() -> Self {
+        Self {
+            words: [0; BUFFER_WORDS],
+            len: 0,
+            sticky: false,
+        }
+    }
+
+    /// Creates a buffer of zeros with the given length.
+    pub(crate) const fn zeros(len: usize) -> Self {
+        let mut result = Self::new();
+        result.len = if len < BUFFER_BITS { len } else { BUFFER_BITS };
+        result
+    }
+
+    pub(crate) const fn get(&self, index: usize) -> bool {
+        index < self.len && (self.words[index / 64] >> (63 - index % 64)) & 1 != 0
+    }
+
+    const fn set(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (63 - index % 64);
+    }
+
+    const fn clear(&mut self, index: usize) {
+        self.words[index / 64] &= !(1 << (63 - index % 64));
+    }
+
+    /// Appends a digit after the existing digits.
+    const fn push(&mut self, bit: bool) {
+        if self.len < BUFFER_BITS {
+            if bit {
+                self.set(self.len);
+            }
+            self.len += 1;
+        } else if bit {
+            self.sticky = true;
+        }
+    }
+
+    /// Returns the index of the first one digit at or after `start`, or the
+    /// length of the buffer if there is none.
+    pub(crate) const fn first_one(&self, start: usize) -> usize {
+        let mut index = start;
+        while index < self.len && !self.get(index) {
+            index += 1;
+        }
+        index
+    }
+
+    /// Returns the index after the last one digit, or zero if there is none.
+    pub(crate) const fn end(&self) -> usize {
+        let mut index = self.len;
+        while index > 0 && !self.get(index - 1) {
+            index -= 1;
+        }
+        index
+    }
+
+    /// Returns whether any digits at or after `start` are nonzero.
+    const fn any_from(&self, start: usize) -> bool {
+        self.sticky || self.first_one(start) < self.len
+    }
+
+    /// Adds `mantissa`, with its least significant bit at `index`. The result
+    /// must fit in the buffer.
+    pub(crate) const fn add(&mut self, mut mantissa: u64, mut index: usize) {
+        let mut carry = false;
+        while mantissa != 0 || carry {
+            let sum = self.get(index) as u8 + (mantissa & 1) as u8 + carry as u8;
+            if sum & 1 != 0 {
+                self.set(index);
+            } else {
+                self.clear(index);
+            }
+            carry = sum > 1;
+            mantissa >>= 1;
+            if index == 0 {
+                break;
+            }
+            index -= 1;
+        }
+    }
+
+    /// Subtracts `mantissa`, with its least significant bit at `index`. The
+    /// result must be non-negative.
+    pub(crate) const fn sub(&mut self, mut mantissa: u64, mut index: usize) {
+        let mut borrow = false;
+        while mantissa != 0 || borrow {
+            let subtrahend = (mantissa & 1) as u8 + borrow as u8;
+            let digit = self.get(index) as u8;
+            if (digit + 2 - subtrahend) & 1 != 0 {
+                self.set(index);
+            } else {
+                self.clear(index);
+            }
+            borrow = digit < subtrahend;
+            mantissa >>= 1;
+            if index == 0 {
+                break;
+            }
+            index -= 1;
+        }
+    }
+
+    /// Replaces the digits `x` from `start` onwards with `1 - x`, in units of
+    /// the digit before `start`.
+    const fn complement(&mut self, start: usize) {
+        let mut index = start;
+        while index < self.len {
+            if self.get(index) {
+                self.clear(index);
+            } else {
+                self.set(index);
+            }
+            index += 1;
+        }
+
+        // A value with the sticky flag set lies strictly between the stored
+        // value and the next unit in the last place, so the complement does as
+        // well.
+        if self.sticky {
+            return;
+        }
+
+        let mut index = self.len;
+        while index > start {
+            index -= 1;
+            if self.get(index) {
+                self.clear(index);
+            } else {
+                self.set(index);
+                return;
+            }
+        }
+    }
+
+    /// Rounds the value with its leading one digit at `start` with weight
+    /// `2^top` to the nearest `f64`.
+    ///
+    /// Returns the rounded magnitude, whether it was rounded up, and the index
+    /// of the first digit that was not retained.
+    const fn round(&self, start: usize, top: i64) -> (f64, bool, usize) {
+        if top > 1023 {
+            return (f64::INFINITY, false, self.len);
+        }
+
+        let precision = if top >= -1022 { 53 } else { top + 1075 };
+        if precision < 0 {
+            return (0.0, false, start);
+        }
+
+        let precision = precision as usize;
+        let mut mantissa = 0u64;
+        let mut i = 0;
+        while i < precision {
+            mantissa = (mantissa << 1) | self.get(start + i) as u64;
+            i += 1;
+        }
+
+        let tail = start + precision;
+        let round_up = self.get(tail) && (self.any_from(tail + 1) || mantissa & 1 != 0);
+        let exponent = (top - precision as i64 + 1) as i32;
+        (
+            make_f64(mantissa + round_up as u64, exponent),
+            round_up,
+            tail,
+        )
+    }
+
+    /// Converts the value with its leading one digit at index zero with
+    /// weight `2^top` to the nearest `TwoFloat`.
+    const fn to_twofloat(mut self, top: i64, negative: bool) -> TwoFloat {
+        let (hi, lo) = if self.first_one(0) == self.len && !self.sticky {
+            (0.0, 0.0)
+        } else {
+            let (hi, round_up, tail) = self.round(0, top);
+            if hi.is_infinite() {
+                return if negative {
+                    TwoFloat::NEG_INFINITY
+                } else {
+                    TwoFloat::INFINITY
+                };
+            } else {
+                if round_up {
+                    self.complement(tail);
+                }
+                let start = self.first_one(tail);
+                let lo = if start < self.len {
+                    self.round(start, top - start as i64).0
+                } else {
+                    0.0
+                };
+                (hi, if round_up { -lo } else { lo })
+            }
+        };
+
+        if negative {
+            TwoFloat { hi: -hi, lo: -lo }
+        } else {
+            TwoFloat { hi, lo }
+        }
+    }
+}
+
+/// Checks whether the bytes from `start` match a lower-case word, ignoring
+/// case.
+const fn matches_word(bytes: &[u8], start: usize, word: &[u8]) -> bool {
+    if bytes.len() - start != word.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < word.len() {
+        if bytes[start + i].to_ascii_lowercase() != word[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Parses a hexadecimal floating point string of the form `0x1.8p-3`. The
+/// digits and exponent following the prefix are read by the same scanner as
+/// `Num::from_str_radix` uses for radix 16.
+const fn parse_hex(bytes: &[u8]) -> Result<TwoFloat, TwoFloatError> {
+    if bytes.is_empty() {
+        return Err(error(ParseErrorKind::Empty, 0));
+    }
+
+    let mut position = 0;
+    let negative = parse_sign(bytes, &mut position);
+
+    if matches_word(bytes, position, b"inf") || matches_word(bytes, position, b"infinity") {
+        return Ok(if negative {
+            TwoFloat::NEG_INFINITY
+        } else {
+            TwoFloat::INFINITY
+        });
+    }
+    if matches_word(bytes, position, b"nan") {
+        return Ok(TwoFloat::NAN);
+    }
+
+    if position + 1 >= bytes.len()
+        || bytes[position] != b'0'
+        || (bytes[position + 1] != b'x' && bytes[position + 1] != b'X')
+    {
+        let position = if position < bytes.len() && bytes[position] == b'0' {
+            position + 1
+        } else {
+            position
+        };
+        let kind = if position < bytes.len() {
+            ParseErrorKind::InvalidDigit
+        } else {
+            ParseErrorKind::MissingDigits
+        };
+        return Err(error(kind, position));
+    }
+
+    let number = match scan_number(bytes, position + 2, 16) {
+        Ok(number) => number,
+        Err(e) => return Err(decimal_error(e)),
+    };
+
+    // Leading zero bits are skipped, so track the index of the first one bit
+    // among all the bits read.
+    let mut bits = BitBuffer::new();
+    let mut bit_index = 0i64;
+    let mut first_one = -1i64;
+    let mut digit_count = 0i64;
+    let mut index = number.start;
+    while index < number.end {
+        if let Some(digit) = digit_value(bytes[index], 16) {
+            let mut shift = 4;
+            while shift > 0 {
+                shift -= 1;
+                let bit = (digit >> shift) & 1 != 0;
+                if first_one < 0 && bit {
+                    first_one = bit_index;
+                }
+                if first_one >= 0 {
+                    bits.push(bit);
+                }
+                bit_index += 1;
+            }
+            digit_count += 1;
+        }
+        index += 1;
+    }
+
+    let integer_digits = digit_count - number.fraction_digits as i64;
+    let top = 4 * integer_digits - 1 - first_one + number.exponent as i64;
+    Ok(bits.to_twofloat(top, negative))
+}
+
+impl TwoFloat {
+    /// Parses a hexadecimal floating point string, returning the nearest
+    /// `TwoFloat` value.
+    ///
+    /// The string consists of an optional sign, the prefix `0x`, a
+    /// hexadecimal significand with an optional point and an optional binary
+    /// exponent introduced by `p`. Underscores may be used as digit
+    /// separators. This is the format written by the `LowerHex` and
+    /// `UpperHex` implementations. The special values `inf`, `infinity` and
+    /// `nan` are also accepted.
+    ///
+    /// As this is a `const fn`, it may be used to define constants. See also
+    /// the [`twofloat!`] macro.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// const PI: TwoFloat = match TwoFloat::from_hex_str("0x1.921fb54442d18469898cc51701b8p+1") {
+    ///     Ok(value) => value,
+    ///     Err(_) => panic!("invalid constant"),
+    /// };
+    /// assert_eq!(PI, twofloat::consts::PI);
+    ///
+    /// let a = TwoFloat::from_hex_str("-0x1.8p-1").unwrap();
+    /// assert_eq!(a, -0.75);
+    /// ```
+    pub const fn from_hex_str(s: &str) -> Result<Self, TwoFloatError> {
+        parse_hex(s.as_bytes())
+    }
+}
+
+/// Creates a `TwoFloat` constant from a hexadecimal floating point literal.
+///
+/// The literal is parsed at compile time using [`TwoFloat::from_hex_str`],
+/// so an invalid literal results in a compilation error.
+///
+/// # Examples
+///
+/// ```
+/// use twofloat::{twofloat, TwoFloat};
+///
+/// const PI: TwoFloat = twofloat!("0x1.921fb54442d18469898cc51701b8p+1");
+/// assert_eq!(PI, twofloat::consts::PI);
+///
+/// let third = twofloat!("0x0.5555_5555_5555_5555_5555_5555_5555_55");
+/// assert_eq!(third.hi(), 1.0 / 3.0);
+/// ```
+#[macro_export]
+macro_rules! twofloat {
+    ($s:literal) => {{
+        const VALUE: $crate::TwoFloat = match $crate::TwoFloat::from_hex_str($s) {
+            Ok(value) => value,
+            Err(_) => panic!(concat!("invalid hexadecimal literal: ", $s)),
+        };
+        VALUE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitBuffer;
+    use crate::{ParseErrorKind, TwoFloat, TwoFloatError};
+
+    #[test]
+    fn bit_buffer_test() {
+        let mut bits = BitBuffer::zeros(8);
+        bits.add(0b1011, 7);
+        bits.add(0b0101, 7);
+        assert_eq!(bits.first_one(0), 3);
+        assert_eq!(bits.end(), 4);
+
+        bits.sub(0b1, 7);
+        assert_eq!(bits.first_one(0), 4);
+        assert_eq!(bits.end(), 8);
+        assert!(bits.get(5) && bits.get(6));
+    }
+
+    #[test]
+    fn parse_hex_error_test() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("1.5", ParseErrorKind::InvalidDigit, 0),
+            ("0", ParseErrorKind::MissingDigits, 1),
+            ("-0x", ParseErrorKind::MissingDigits, 3),
+            ("0x1.2.3", ParseErrorKind::InvalidDigit, 5),
+            ("0x1p", ParseErrorKind::MissingDigits, 4),
+            ("0x1g", ParseErrorKind::InvalidDigit, 3),
+        ];
+
+        for &(source, kind, position) in cases.iter() {
+            assert_eq!(
+                TwoFloat::from_hex_str(source),
+                Err(TwoFloatError::ParseError { kind, position }),
+                "Unexpected result parsing {:?}",
+                source
+            );
+        }
+    }
+}
@@ -0,0 +1,611 @@
+#![allow(clippy::extra_unused_lifetimes)]
+
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use hexf::hexf64;
+
+use crate::{
+    arithmetic::widen,
+    consts::{FRAC_PI_2, FRAC_PI_4},
+    functions::reduction::rem_frac_pi_2,
+    TwoFloat, TwoFloatError,
+};
+
+// Relative error bounds used to widen the results of the elementary
+// functions. Unlike the directed arithmetic operations, these are estimates
+// rather than proved bounds: the notes below count the operations of each
+// algorithm using the bounds of Joldes et al. (2017), at most 15u² + 56u³ per
+// double-double operation with u = 2^-53, but do not account rigorously for
+// every rounding. The totals are then inflated by a factor of at least 2^6,
+// and the errors observed in testing, including at the worst cases for
+// argument reduction, are below 16u².
+//
+// `exp` multiplies a product of two correctly rounded table entries for
+// exp(y/2) by 1 + expm1(z) with |z| <= 1/4, where expm1(z) is obtained from
+// the correctly rounded expm1(z0) and exp(z0) for a multiple z0 of 1/128 and a
+// Taylor series in |z - z0| <= 2^-8 truncated after a term below 2^-170. The
+// reduction z = x - y/2 has an error of at most 2u²|z| which changes the
+// result by a factor of at most 1 + u². With the errors of the five products
+// and sums and of the Horner scheme, whose terms decrease by a factor of at
+// least 2^8, the total is below 2^-97. Results scaled by 2^k beyond ±708 also
+// have the error of the reduction by k ln(2), where |k| <= 1075 and ln(2) is
+// stored to more than 140 bits, which adds less than 2^-125. Results below
+// 2^-968, whose low words lose precision, are covered by the absolute
+// allowance of `widen`.
+const EXP_ERROR_BOUND: f64 = hexf64!("0x1p-90");
+
+// `ln` writes x = 2^e c (1 + t) with c = 1 + n/128 and √½ <= 2^-e x < √2, so
+// that |t| < 2^-8 and the terms e ln(2), ln(c) and t are either of the same
+// sign or the result is dominated by t. Each term is therefore at most three
+// times the magnitude of the result. The table entries and ln(2) are
+// correctly rounded, t is computed by one division and the series in t has
+// terms decreasing by a factor of 2^8, so that the error of each term is
+// below 20u² of its magnitude and the total is below 2^-96.
+const LN_ERROR_BOUND: f64 = hexf64!("0x1p-90");
+
+// `sin` and `cos` evaluate minimax polynomials on [-π/4, π/4] whose
+// approximation errors are below 2^-106, with each of their twelve Horner
+// steps having an error below 20u² of the magnitude of its term. The terms are
+// less than the result, which is at least 0.9 times the reduced argument for
+// the sine and at least 0.7 for the cosine, so the total is below 2^-95.
+const TRIG_ERROR_BOUND: f64 = hexf64!("0x1p-90");
+
+// Absolute error of the reduction of arguments beyond π/4 modulo π/2, which
+// computes 382 fractional bits of x 2/π exactly so that its truncation error
+// is below π 2^-383, and the sine and cosine have derivatives at most one.
+// Rounding the fraction to a `TwoFloat` and multiplying it by π/2 add relative
+// errors of a few u² to the reduced argument, which are left to the estimate
+// above.
+const TRIG_REDUCTION_ERROR: f64 = hexf64!("0x1p-370");
+
+// Arguments beyond which the bounds of `exp` are obtained from a nearby
+// argument, so that widening never reaches the limits of the range of `f64`
+const EXP_UPPER_LIMIT: f64 = 709.0;
+const EXP_LOWER_LIMIT: f64 = -709.0;
+
+// Allowance for the error of the position of an argument within the period
+// of the trigonometric functions, in quarter periods
+const PHASE_ERROR: f64 = hexf64!("0x1p-40");
+
+/// Represents a closed interval with lower and upper bounds of type `T`.
+///
+/// For `Interval<TwoFloat>`, the arithmetic operators return intervals
+/// which are guaranteed to contain the exact result for every value in the
+/// operands, as their bounds are computed using the directed rounding
+/// operations such as [`TwoFloat::add_down`] and [`TwoFloat::add_up`], which
+/// rely on proved error bounds. The function `sqrt` uses
+/// [`TwoFloat::sqrt_down`] and [`TwoFloat::sqrt_up`], whose results are
+/// verified using the directed division, so it is rigorous as well.
+///
+/// The functions `exp`, `ln`, `sin` and `cos` are not rigorous: they widen
+/// the results of the corresponding `TwoFloat` functions by error bounds
+/// estimated from the operation counts of their algorithms, which are well
+/// beyond the errors observed in testing but have not been proved.
+///
+/// Operations which have no defined result for any value in the operands,
+/// such as the square root of a negative interval, return
+/// [`Interval::NAN`].
+///
+/// # Examples
+///
+/// ```
+/// # use core::convert::TryFrom;
+/// # use twofloat::{Interval, TwoFloat};
+/// let a = Interval::try_from((TwoFloat::from(1.0), TwoFloat::from(2.0))).unwrap();
+/// let b = Interval::from(TwoFloat::from(3.0));
+/// let c = (a + b) / b;
+///
+/// assert!(c.contains(TwoFloat::new_div(4.0, 3.0)));
+/// assert!(c.contains(TwoFloat::new_div(5.0, 3.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T: Copy> Interval<T> {
+    /// Returns the lower bound of the interval.
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    /// Returns the upper bound of the interval.
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+}
+
+impl Interval<TwoFloat> {
+    /// The interval containing all values.
+    pub const ENTIRE: Self = Self {
+        lo: TwoFloat::NEG_INFINITY,
+        hi: TwoFloat::INFINITY,
+    };
+
+    /// Represents an invalid interval, returned by operations which have no
+    /// defined result.
+    pub const NAN: Self = Self {
+        lo: TwoFloat::NAN,
+        hi: TwoFloat::NAN,
+    };
+
+    // The range of the sine and cosine functions
+    const UNIT: Self = Self {
+        lo: TwoFloat::from_f64(-1.0),
+        hi: TwoFloat::from_f64(1.0),
+    };
+
+    /// Returns `true` if the bounds are not NaN and the lower bound is less
+    /// than or equal to the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Interval, TwoFloat};
+    /// let a = Interval::from(TwoFloat::from(-1.0));
+    ///
+    /// assert!(a.is_valid());
+    /// assert!(!a.sqrt().is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        less_or_equal(self.lo, self.hi)
+    }
+
+    /// Returns `true` if the interval contains the value.
+    pub fn contains(&self, value: TwoFloat) -> bool {
+        less_or_equal(self.lo, value) && less_or_equal(value, self.hi)
+    }
+
+    /// Returns an upper bound on the width of the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use twofloat::{Interval, TwoFloat};
+    /// let a = Interval::try_from((TwoFloat::from(1.0), TwoFloat::from(3.0))).unwrap();
+    ///
+    /// assert!(a.width() >= 2.0);
+    /// assert!(a.width() - 2.0 < 1e-30);
+    /// ```
+    pub fn width(&self) -> TwoFloat {
+        self.hi.sub_up(self.lo)
+    }
+
+    /// Returns the midpoint of the interval, rounded to nearest.
+    pub fn midpoint(&self) -> TwoFloat {
+        self.lo * 0.5 + self.hi * 0.5
+    }
+
+    /// Returns an interval containing the square roots of the non-negative
+    /// values in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Interval, TwoFloat};
+    /// let a = Interval::from(TwoFloat::from(2.0)).sqrt();
+    ///
+    /// assert!(a.contains(TwoFloat::from(2.0).sqrt()));
+    /// assert!(a.lo() * a.lo() < 2.0);
+    /// assert!(a.hi() * a.hi() > 2.0);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        if !self.is_valid() || self.hi < 0.0 {
+            return Self::NAN;
+        }
+
+        let lo = if self.lo <= 0.0 {
+            TwoFloat::from(0.0)
+        } else {
+            self.lo.sqrt_down()
+        };
+
+        Self {
+            lo,
+            hi: self.hi.sqrt_up(),
+        }
+    }
+
+    /// Returns an interval containing the exponentials of the values in the
+    /// interval.
+    ///
+    /// The bounds use an estimated rather than a proved error bound, as
+    /// described in the documentation of [`Interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::E, Interval, TwoFloat};
+    /// let a = Interval::from(TwoFloat::from(1.0)).exp();
+    ///
+    /// assert!(a.contains(E));
+    /// ```
+    pub fn exp(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+
+        Self {
+            lo: exp_bound(self.lo, false),
+            hi: exp_bound(self.hi, true),
+        }
+    }
+
+    /// Returns an interval containing the natural logarithms of the positive
+    /// values in the interval.
+    ///
+    /// The bounds use an estimated rather than a proved error bound, as
+    /// described in the documentation of [`Interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::LN_2, Interval, TwoFloat};
+    /// let a = Interval::from(TwoFloat::from(2.0)).ln();
+    ///
+    /// assert!(a.contains(LN_2));
+    /// ```
+    pub fn ln(self) -> Self {
+        if !self.is_valid() || self.hi < 0.0 {
+            return Self::NAN;
+        }
+
+        let lo = if self.lo <= 0.0 {
+            TwoFloat::NEG_INFINITY
+        } else {
+            ln_bound(self.lo, false)
+        };
+
+        Self {
+            lo,
+            hi: ln_bound(self.hi, true),
+        }
+    }
+
+    /// Returns an interval containing the sines of the values in the
+    /// interval.
+    ///
+    /// The bounds use an estimated rather than a proved error bound, as
+    /// described in the documentation of [`Interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use twofloat::{Interval, TwoFloat};
+    /// let a = Interval::try_from((TwoFloat::from(1.0), TwoFloat::from(2.0))).unwrap();
+    /// let b = a.sin();
+    ///
+    /// assert_eq!(b.hi(), 1.0);
+    /// assert!(b.contains(TwoFloat::from(1.0).sin()));
+    /// ```
+    pub fn sin(self) -> Self {
+        self.periodic(TwoFloat::sin, 1.0, 3.0)
+    }
+
+    /// Returns an interval containing the cosines of the values in the
+    /// interval.
+    ///
+    /// The bounds use an estimated rather than a proved error bound, as
+    /// described in the documentation of [`Interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use twofloat::{Interval, TwoFloat};
+    /// let a = Interval::try_from((TwoFloat::from(-1.0), TwoFloat::from(4.0))).unwrap();
+    /// let b = a.cos();
+    ///
+    /// assert_eq!(b.lo(), -1.0);
+    /// assert_eq!(b.hi(), 1.0);
+    /// ```
+    pub fn cos(self) -> Self {
+        self.periodic(TwoFloat::cos, 0.0, 2.0)
+    }
+
+    // Evaluates a trigonometric function with the maximum and minimum at the
+    // given positions within the period, measured in quarter periods
+    fn periodic(self, f: fn(TwoFloat) -> TwoFloat, peak: f64, trough: f64) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        } else if !self.lo.hi.is_finite() || !self.hi.hi.is_finite() {
+            return Self::UNIT;
+        }
+
+        let width = (self.hi - self.lo).hi / FRAC_PI_2.hi;
+        if width >= 4.0 {
+            return Self::UNIT;
+        }
+
+        let start = phase(self.lo) - PHASE_ERROR;
+        let end = start + width * (1.0 + PHASE_ERROR) + 2.0 * PHASE_ERROR;
+
+        let (a, b) = (
+            (trig_bound(f, self.lo, false), trig_bound(f, self.lo, true)),
+            (trig_bound(f, self.hi, false), trig_bound(f, self.hi, true)),
+        );
+        let mut lo = lower(a.0, b.0);
+        let mut hi = upper(a.1, b.1);
+
+        if contains_phase(start, end, peak) {
+            hi = Self::UNIT.hi;
+        }
+        if contains_phase(start, end, trough) {
+            lo = Self::UNIT.lo;
+        }
+
+        Self {
+            lo: upper(lo, Self::UNIT.lo),
+            hi: lower(hi, Self::UNIT.hi),
+        }
+    }
+}
+
+// Compares values by their high words first, so that unlike the comparison
+// operators of `TwoFloat` infinite values are ordered correctly
+fn compare(a: TwoFloat, b: TwoFloat) -> Option<Ordering> {
+    match a.hi.partial_cmp(&b.hi) {
+        Some(Ordering::Equal) if a.hi.is_finite() => a.lo.partial_cmp(&b.lo),
+        ordering => ordering,
+    }
+}
+
+fn less_or_equal(a: TwoFloat, b: TwoFloat) -> bool {
+    matches!(compare(a, b), Some(Ordering::Less | Ordering::Equal))
+}
+
+// Returns the lesser of the values, ignoring NaN values in the second argument
+fn lower(a: TwoFloat, b: TwoFloat) -> TwoFloat {
+    if compare(b, a) == Some(Ordering::Less) {
+        b
+    } else {
+        a
+    }
+}
+
+// Returns the greater of the values, ignoring NaN values in the second
+// argument
+fn upper(a: TwoFloat, b: TwoFloat) -> TwoFloat {
+    if compare(b, a) == Some(Ordering::Greater) {
+        b
+    } else {
+        a
+    }
+}
+
+// Outside the range in which `exp` is accurate the bounds are obtained from
+// a nearby argument, relying on the function being increasing
+fn exp_bound(x: TwoFloat, up: bool) -> TwoFloat {
+    match (x.hi, up) {
+        (f64::INFINITY, _) => TwoFloat::INFINITY,
+        (f64::NEG_INFINITY, _) => TwoFloat::from(0.0),
+        (hi, true) if hi >= EXP_UPPER_LIMIT => TwoFloat::INFINITY,
+        (hi, false) if hi >= EXP_UPPER_LIMIT => {
+            exp_bound(TwoFloat::from(EXP_UPPER_LIMIT - 1.0), false)
+        }
+        (hi, true) if hi <= EXP_LOWER_LIMIT => {
+            exp_bound(TwoFloat::from(EXP_LOWER_LIMIT + 1.0), true)
+        }
+        (hi, false) if hi <= EXP_LOWER_LIMIT => TwoFloat::from(0.0),
+        _ => widen(x.exp(), EXP_ERROR_BOUND, up),
+    }
+}
+
+fn ln_bound(x: TwoFloat, up: bool) -> TwoFloat {
+    if x.hi.is_infinite() {
+        x
+    } else if x.hi == 0.0 {
+        TwoFloat::NEG_INFINITY
+    } else {
+        widen(x.ln(), LN_ERROR_BOUND, up)
+    }
+}
+
+// Arguments below π/4 are not reduced, so only the error of the polynomial
+// approximation applies
+fn trig_bound(f: fn(TwoFloat) -> TwoFloat, x: TwoFloat, up: bool) -> TwoFloat {
+    let bound = widen(f(x), TRIG_ERROR_BOUND, up);
+    if x.abs() < FRAC_PI_4 {
+        bound
+    } else if up {
+        bound.add_up(TwoFloat::from(TRIG_REDUCTION_ERROR))
+    } else {
+        bound.sub_down(TwoFloat::from(TRIG_REDUCTION_ERROR))
+    }
+}
+
+// Returns the approximate position of the value within the period of the
+// trigonometric functions, in quarter periods
+fn phase(x: TwoFloat) -> f64 {
+    let (remainder, quadrant) = rem_frac_pi_2(x);
+    quadrant as f64 + remainder.hi / FRAC_PI_2.hi
+}
+
+// Checks whether the range of positions contains the target position modulo
+// the period
+fn contains_phase(start: f64, end: f64, target: f64) -> bool {
+    libm::ceil((start - target) / 4.0) <= libm::floor((end - target) / 4.0)
+}
+
+// Computes a bound on the product, where zero times infinity is taken to be
+// zero as the limit of products of finite values
+fn product_bound(a: TwoFloat, b: TwoFloat, up: bool) -> TwoFloat {
+    if a == 0.0 || b == 0.0 {
+        TwoFloat::from(0.0)
+    } else if up {
+        a.mul_up(b)
+    } else {
+        a.mul_down(b)
+    }
+}
+
+impl From<TwoFloat> for Interval<TwoFloat> {
+    fn from(value: TwoFloat) -> Self {
+        Self {
+            lo: value,
+            hi: value,
+        }
+    }
+}
+
+impl From<f64> for Interval<TwoFloat> {
+    fn from(value: f64) -> Self {
+        Self::from(TwoFloat::from(value))
+    }
+}
+
+impl TryFrom<(TwoFloat, TwoFloat)> for Interval<TwoFloat> {
+    type Error = TwoFloatError;
+
+    fn try_from(value: (TwoFloat, TwoFloat)) -> Result<Self, Self::Error> {
+        let interval = Self {
+            lo: value.0,
+            hi: value.1,
+        };
+
+        if interval.is_valid() {
+            Ok(interval)
+        } else {
+            Err(Self::Error::ConversionError {})
+        }
+    }
+}
+
+impl From<Interval<TwoFloat>> for (TwoFloat, TwoFloat) {
+    fn from(value: Interval<TwoFloat>) -> Self {
+        (value.lo, value.hi)
+    }
+}
+
+unary_ops! {
+    fn Neg::neg(self: &Interval<TwoFloat>) -> Interval<TwoFloat> {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+binary_ops! {
+    fn Add::add<'a, 'b>(self: &'a Interval<TwoFloat>, rhs: &'b Interval<TwoFloat>) -> Interval<TwoFloat> {
+        if !self.is_valid() || !rhs.is_valid() {
+            return Interval::NAN;
+        }
+
+        Interval {
+            lo: self.lo.add_down(rhs.lo),
+            hi: self.hi.add_up(rhs.hi),
+        }
+    }
+
+    fn Sub::sub<'a, 'b>(self: &'a Interval<TwoFloat>, rhs: &'b Interval<TwoFloat>) -> Interval<TwoFloat> {
+        if !self.is_valid() || !rhs.is_valid() {
+            return Interval::NAN;
+        }
+
+        Interval {
+            lo: self.lo.sub_down(rhs.hi),
+            hi: self.hi.sub_up(rhs.lo),
+        }
+    }
+
+    fn Mul::mul<'a, 'b>(self: &'a Interval<TwoFloat>, rhs: &'b Interval<TwoFloat>) -> Interval<TwoFloat> {
+        if !self.is_valid() || !rhs.is_valid() {
+            return Interval::NAN;
+        }
+
+        let ends = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+
+        Interval {
+            lo: ends
+                .iter()
+                .map(|&(a, b)| product_bound(a, b, false))
+                .fold(TwoFloat::INFINITY, lower),
+            hi: ends
+                .iter()
+                .map(|&(a, b)| product_bound(a, b, true))
+                .fold(TwoFloat::NEG_INFINITY, upper),
+        }
+    }
+
+    /// Divides two intervals. If zero is strictly inside the divisor the
+    /// result is the entire real line, while if it is an endpoint of the
+    /// divisor the result is bounded on one side unless the dividend also
+    /// contains zero. The result is `Interval::NAN` if the divisor is zero.
+    fn Div::div<'a, 'b>(self: &'a Interval<TwoFloat>, rhs: &'b Interval<TwoFloat>) -> Interval<TwoFloat> {
+        if !self.is_valid() || !rhs.is_valid() || (rhs.lo == 0.0 && rhs.hi == 0.0) {
+            return Interval::NAN;
+        } else if rhs.hi == 0.0 {
+            return -*self / -*rhs;
+        } else if rhs.lo == 0.0 {
+            // Dividing by values in (0, d] keeps the sign of the dividend and
+            // increases its magnitude without bound, where the quotient of
+            // infinite values is NaN and is replaced by zero
+            let zero = TwoFloat::from(0.0);
+            return if self.lo >= 0.0 {
+                Interval {
+                    lo: upper(zero, self.lo.div_down(rhs.hi)),
+                    hi: if self.hi == 0.0 { zero } else { TwoFloat::INFINITY },
+                }
+            } else if self.hi <= 0.0 {
+                Interval {
+                    lo: TwoFloat::NEG_INFINITY,
+                    hi: lower(zero, self.hi.div_up(rhs.hi)),
+                }
+            } else {
+                Interval::ENTIRE
+            };
+        } else if rhs.lo < 0.0 && rhs.hi > 0.0 {
+            return Interval::ENTIRE;
+        }
+
+        // Quotients of infinite values are NaN and are ignored when finding
+        // the bounds, which is correct as these only occur at the corners
+        let ends = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+
+        Interval {
+            lo: ends
+                .iter()
+                .map(|&(a, b)| a.div_down(b))
+                .fold(TwoFloat::INFINITY, lower),
+            hi: ends
+                .iter()
+                .map(|&(a, b)| a.div_up(b))
+                .fold(TwoFloat::NEG_INFINITY, upper),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_phase, phase};
+    use crate::{consts::PI, TwoFloat};
+
+    #[test]
+    fn phase_test() {
+        assert_eq!(phase(TwoFloat::from(0.0)), 0.0);
+        assert!((phase(PI) - 2.0).abs() < 1e-15);
+        assert!((phase(-PI * 0.5) - 3.0).abs() < 1e-15);
+
+        assert!(contains_phase(0.5, 1.5, 1.0));
+        assert!(contains_phase(3.5, 5.5, 1.0));
+        assert!(!contains_phase(1.5, 4.5, 1.0));
+    }
+}
@@ -28,8 +28,24 @@ utility functions `abs()`, `is_positive_sign()` and `is_negative_sign()`.
 Most mathematical functions defined on `f64` are provided, though the
 implementations should be regarded as preliminary.
 
-Operations on non-finite values are not supported. At the moment this is not
-automatically checked. The `is_valid()` method is provided for this purpose.
+Non-finite values follow IEEE 754 semantics as for `f64`: operations
+propagate `NAN`, infinities and the sign of zero, and `NAN` is unordered in
+comparisons. Infinities are represented with a zero low word, and `NAN` with
+both words `NAN`. The `is_valid()` method checks that a value is finite and
+normalized.
+
+Methods such as `checked_add()`, `checked_div()` and `checked_ln()` return a
+`TwoFloatError` instead of a non-finite result, distinguishing overflow,
+division by zero, domain errors and `NAN` operands. The `StrictTwoFloat`
+wrapper type performs these checks in its operators and functions in debug
+builds, panicking on the first invalid result.
+
+```
+# use twofloat::{TwoFloat, TwoFloatError};
+let a = TwoFloat::from(1e300);
+assert_eq!(a.checked_mul(a), Err(TwoFloatError::Overflow));
+assert_eq!(TwoFloat::from(-1.0).checked_sqrt(), Err(TwoFloatError::DomainError));
+```
 
 If the `std` feature is enabled (as it is by default), the fused multiply-add
 operation from the standard library is used. This *may* be more performant if
@@ -40,6 +56,73 @@ implementation is used.
 If the `serde` feature is enabled, serialization and deserialization is
 possible through the Serde library.
 
+If the `macros` feature is enabled, the `tf!` macro creates `TwoFloat`
+constants from decimal literals, with the correctly rounded value computed at
+compile time.
+
+If the `complex` feature is enabled, the `TwoComplex` type provides complex
+arithmetic and elementary functions, with conversions to and from the types of
+the `num-complex` crate.
+
+Where double-double precision is insufficient, the `QuadFloat` type
+represents values as the sum of four non-overlapping `f64` values, giving
+around 212 bits of precision. It provides the basic arithmetic operators,
+square roots, exponentials, logarithms and trigonometric functions, and
+converts to and from `TwoFloat`.
+
+```
+# use twofloat::{QuadFloat, TwoFloat};
+let a = QuadFloat::from(TwoFloat::new_div(1.0, 3.0));
+let b = QuadFloat::from(2.0).sqrt();
+let c = TwoFloat::from(a * b);
+```
+
+For targets where `f32` arithmetic is preferred, the `TwoF32` type
+represents values as the sum of two non-overlapping `f32` values, giving
+around 48 bits of precision. The arithmetic operators are implemented
+directly on the `f32` words, while the mathematical functions are evaluated
+using `TwoFloat`.
+
+```
+# use twofloat::{TwoF32, TwoFloat};
+let a = TwoF32::new_div(1.0, 3.0);
+let b: TwoF32 = "0.1".parse().unwrap();
+let c = TwoFloat::from(a + b);
+```
+
+For verified computations, the `Interval<TwoFloat>` type represents a closed
+interval whose arithmetic operators return bounds which are guaranteed to
+contain the exact results, while its elementary functions are widened by
+conservative bounds derived from the analysis of their errors. The bounds
+are computed using directed rounding operations such as
+`TwoFloat::add_down` and `TwoFloat::add_up`, which are also available with
+an explicit `Round` direction through methods such as
+`TwoFloat::add_rounded`.
+
+```
+# use core::convert::TryFrom;
+# use twofloat::{Interval, TwoFloat};
+let a = Interval::try_from((TwoFloat::from(1.0), TwoFloat::from(2.0))).unwrap();
+let b = (a * a + Interval::from(1.0)).sqrt();
+assert!(b.contains(TwoFloat::from(2.0).sqrt()));
+```
+
+Sums, dot products and polynomials of slices can be evaluated accurately
+using the `sum_k`, `dot` and `eval_poly` functions. These use compensated
+algorithms, so the result remains accurate when the terms cancel.
+
+```
+# use twofloat::TwoFloat;
+let x = [1e30, 3.0, -1e30];
+let y = [1.0, 0.5, 1.0];
+assert_eq!(twofloat::dot(&x, &y), 1.5);
+assert_eq!(twofloat::sum_k::<2>(&x), 3.0);
+
+let coefficients = [-2.0, 0.0, 1.0].map(TwoFloat::from);
+let root = TwoFloat::from(2.0).sqrt();
+assert!(twofloat::eval_poly(&coefficients, root).abs() < 1e-30);
+```
+
 ## Known issues
 
 * The MinGW `fma` implementation appears to give incorrect results in some
@@ -52,10 +135,39 @@ possible through the Serde library.
   Transactions on Mathematical Software, Association for Computing Machinery,
   2017, 44 (2), pp.1 - 27. 10.1145/3121432. hal-01351529v3
 
+* Yozo Hida, Xiaoye S. Li, David H. Bailey. Library for double-double and
+  quad-double arithmetic. Lawrence Berkeley National Laboratory Technical
+  Report, 2001.
+
 * Alan H. Karp, Peter Markstein. High Precision Division and Square Root. ACM
   Transactions on Mathematical Software, Association for Computing Machinery,
   1997, 23 (4), pp. 561-589. 10.1145/279232.279237.
 
+* C. Chiarella, A. Reichel. On the evaluation of integrals related to the
+  error function. Mathematics of Computation, 1968, 22 (101), pp. 137-143.
+
+* Mary H. Payne, Robert N. Hanek. Radian reduction for trigonometric
+  functions. ACM SIGNUM Newsletter, 1983, 18 (1), pp. 19-24.
+  10.1145/1057600.1057602.
+
+* B. C. Carlson. Numerical computation of real or complex elliptic integrals.
+  Numerical Algorithms, 1995, 10 (1), pp. 13-26. 10.1007/BF02198293.
+
+* Semjon Adlaj. An eloquent formula for the perimeter of an ellipse. Notices
+  of the AMS, 2012, 59 (8), pp. 1094-1099. 10.1090/noti879.
+
+* Peter Borwein. An efficient algorithm for the Riemann zeta function.
+  Constructive, Experimental, and Nonlinear Analysis, CMS Conference
+  Proceedings, 2000, 27, pp. 29-34.
+
+* Hidetosi Takahasi, Masatake Mori. Double exponential formulas for numerical
+  integration. Publications of the Research Institute for Mathematical
+  Sciences, 1974, 9 (3), pp. 721-741. 10.2977/prims/1195192451.
+
+* Takeshi Ogita, Siegfried M. Rump, Shin'ichi Oishi. Accurate sum and dot
+  product. SIAM Journal on Scientific Computing, 2005, 26 (6), pp. 1955-1988.
+  10.1137/030601818.
+
 * S. Chevillard, M. Joldeș and C. Lauter. Sollya: an environment for the
   development of numerical codes. Mathematical Software - ICMS 2010, pp.
   28–31.
@@ -81,6 +193,11 @@ mod test_util;
 
 mod arithmetic;
 mod base;
+mod checked;
+mod compensated;
+
+#[cfg(feature = "complex")]
+mod complex;
 
 /// Basic mathematical constants.
 ///
@@ -90,12 +207,35 @@ pub mod consts;
 mod convert;
 mod format;
 mod functions;
+mod hex;
+mod interval;
+
+#[macro_use]
 mod num_integration;
 
+mod ordered;
+mod parse;
+mod quad;
+mod strict;
+mod twof32;
+
 #[cfg(feature = "serde")]
 mod serialization;
 
+pub use arithmetic::Round;
 pub use base::no_overlap;
+pub use compensated::{dot, eval_poly, sum_k, DotProduct};
+#[cfg(feature = "complex")]
+pub use complex::TwoComplex;
+pub use format::DisplayParts;
+pub use interval::Interval;
+pub use ordered::{NotNanTwoFloat, OrderedTwoFloat};
+pub use quad::QuadFloat;
+pub use strict::StrictTwoFloat;
+pub use twof32::{no_overlap_f32, TwoF32};
+
+#[cfg(feature = "macros")]
+pub use twofloat_macros::tf;
 
 pub mod iter;
 
@@ -110,18 +250,63 @@ pub struct TwoFloat {
 
 /// The error type for `TwoFloat` operations.
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TwoFloatError {
     /// Indicates invalid conversion to/from `TwoFloat`
     ConversionError,
-    ParseError,
+    /// Indicates that a string could not be parsed as a `TwoFloat`
+    ParseError {
+        /// The reason parsing failed
+        kind: ParseErrorKind,
+        /// The byte offset in the input at which parsing failed
+        position: usize,
+    },
+    /// Indicates that the result of an operation on finite values overflowed
+    Overflow,
+    /// Indicates that an operation was evaluated outside its domain
+    DomainError,
+    /// Indicates a division by zero, or an operation evaluated at a pole
+    DivisionByZero,
+    /// Indicates that an operand of an operation was `NAN`
+    NaNInput,
+}
+
+/// The reason a string could not be parsed as a `TwoFloat`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input string was empty
+    Empty,
+    /// The input contained an unexpected character
+    InvalidDigit,
+    /// The input ended before the digits of the mantissa or exponent
+    MissingDigits,
+    /// The radix was outside the range 2 to 36
+    InvalidRadix,
 }
 
 impl fmt::Display for TwoFloatError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::ConversionError => f.pad("invalid TwoFloat conversion"),
-            Self::ParseError => f.pad("parsing not supported"),
+            Self::ParseError { kind, position } => {
+                write!(f, "{} at position {}", kind, position)
+            }
+            Self::Overflow => f.pad("TwoFloat operation overflowed"),
+            Self::DomainError => f.pad("TwoFloat operation outside its domain"),
+            Self::DivisionByZero => f.pad("TwoFloat division by zero"),
+            Self::NaNInput => f.pad("NaN operand in TwoFloat operation"),
+        }
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => f.pad("cannot parse TwoFloat from empty string"),
+            Self::InvalidDigit => f.pad("invalid digit found in string"),
+            Self::MissingDigits => f.pad("expected digits in string"),
+            Self::InvalidRadix => f.pad("radix must be in the range 2 to 36"),
         }
     }
 }
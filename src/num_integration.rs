@@ -1,815 +1,839 @@
 use core::{convert::TryFrom, num::FpCategory};
 
-use hexf::hexf64;
 use num_traits::{Inv, Pow};
 
 use crate::{consts, TwoFloat, TwoFloatError};
 
-impl num_traits::Num for TwoFloat {
-    type FromStrRadixErr = TwoFloatError;
+// Implements the `num_traits` traits for a multi-word floating point type.
+// `words` gives the words of a value, with the most significant first, and
+// `from_float` creates a value from a single word at compile time.
+macro_rules! float_traits {
+    (
+        type: $t:ident,
+        float: $f:ty,
+        from_float: $from_float:path,
+        consts: $consts:ident,
+        from_str_radix: $from_str_radix:expr,
+        mul_add: $mul_add:expr,
+        words: $words:expr $(,)?
+    ) => {
+        impl num_traits::Num for $t {
+            type FromStrRadixErr = TwoFloatError;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                ($from_str_radix)(str, radix)
+            }
+        }
 
-    fn from_str_radix(_str: &str, _radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        Err(TwoFloatError::ParseError)
-    }
-}
+        impl num_traits::ConstZero for $t {
+            const ZERO: $t = $from_float(0.0);
+        }
 
-impl num_traits::ConstZero for TwoFloat {
-    const ZERO: TwoFloat = consts::ZERO;
-}
+        impl num_traits::ConstOne for $t {
+            const ONE: $t = $from_float(1.0);
+        }
 
-impl num_traits::ConstOne for TwoFloat {
-    const ONE: TwoFloat = consts::ONE;
-}
+        impl num_traits::Zero for $t {
+            #[inline]
+            fn zero() -> Self {
+                $t::default()
+            }
 
-impl num_traits::Zero for TwoFloat {
-    #[inline]
-    fn zero() -> Self {
-        TwoFloat::default()
-    }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == $t::default()
+            }
+        }
 
-    #[inline]
-    fn is_zero(&self) -> bool {
-        *self == TwoFloat::default()
-    }
-}
+        impl num_traits::One for $t {
+            #[inline]
+            fn one() -> Self {
+                $from_float(1.0)
+            }
+        }
 
-impl num_traits::One for TwoFloat {
-    #[inline]
-    fn one() -> Self {
-        TwoFloat { hi: 1.0, lo: 0.0 }
-    }
-}
+        impl num_traits::Bounded for $t {
+            #[inline]
+            fn min_value() -> $t {
+                $t::MIN
+            }
 
-impl num_traits::Bounded for TwoFloat {
-    #[inline]
-    fn min_value() -> TwoFloat {
-        TwoFloat::MIN
-    }
+            #[inline]
+            fn max_value() -> $t {
+                $t::MAX
+            }
+        }
 
-    #[inline]
-    fn max_value() -> TwoFloat {
-        TwoFloat::MAX
-    }
-}
+        impl num_traits::Signed for $t {
+            #[inline]
+            fn abs(&self) -> Self {
+                $t::abs(self)
+            }
 
-impl num_traits::Signed for TwoFloat {
-    #[inline]
-    fn abs(&self) -> Self {
-        TwoFloat::abs(self)
-    }
-
-    #[inline]
-    fn abs_sub(&self, other: &Self) -> Self {
-        TwoFloat::abs(&(self - other))
-    }
-
-    #[inline]
-    fn signum(&self) -> Self {
-        TwoFloat::signum(self)
-    }
-
-    #[inline]
-    fn is_positive(&self) -> bool {
-        TwoFloat::is_sign_positive(self)
-    }
-
-    #[inline]
-    fn is_negative(&self) -> bool {
-        TwoFloat::is_sign_negative(self)
-    }
-}
+            #[inline]
+            fn abs_sub(&self, other: &Self) -> Self {
+                $t::abs(&(self - other))
+            }
+
+            #[inline]
+            fn signum(&self) -> Self {
+                $t::signum(self)
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                $t::is_sign_positive(self)
+            }
 
-impl num_traits::FromPrimitive for TwoFloat {
-    #[inline]
-    fn from_i8(n: i8) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_i16(n: i16) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_i32(n: i32) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_i64(n: i64) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_i128(n: i128) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    fn from_isize(n: isize) -> Option<Self> {
-        match core::mem::size_of::<isize>() {
-            1 => Self::from_i8(n as i8),
-            2 => Self::from_i16(n as i16),
-            4 => Self::from_i32(n as i32),
-            8 => Self::from_i64(n as i64),
-            16 => Self::from_i128(n as i128),
-            _ => None,
+            #[inline]
+            fn is_negative(&self) -> bool {
+                $t::is_sign_negative(self)
+            }
         }
-    }
-
-    #[inline]
-    fn from_u8(n: u8) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_u16(n: u16) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_u32(n: u32) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_u64(n: u64) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    #[inline]
-    fn from_u128(n: u128) -> Option<Self> {
-        Some(TwoFloat::from(n))
-    }
-
-    fn from_usize(n: usize) -> Option<Self> {
-        match core::mem::size_of::<usize>() {
-            1 => Self::from_u8(n as u8),
-            2 => Self::from_u16(n as u16),
-            4 => Self::from_u32(n as u32),
-            8 => Self::from_u64(n as u64),
-            16 => Self::from_u128(n as u128),
-            _ => None,
+
+        impl num_traits::FromPrimitive for $t {
+            #[inline]
+            fn from_i8(n: i8) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_i16(n: i16) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_i32(n: i32) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            fn from_isize(n: isize) -> Option<Self> {
+                match core::mem::size_of::<isize>() {
+                    1 => Self::from_i8(n as i8),
+                    2 => Self::from_i16(n as i16),
+                    4 => Self::from_i32(n as i32),
+                    8 => Self::from_i64(n as i64),
+                    16 => Self::from_i128(n as i128),
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            fn from_u8(n: u8) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_u16(n: u16) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_u32(n: u32) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                Some($t::from(n))
+            }
+
+            fn from_usize(n: usize) -> Option<Self> {
+                match core::mem::size_of::<usize>() {
+                    1 => Self::from_u8(n as u8),
+                    2 => Self::from_u16(n as u16),
+                    4 => Self::from_u32(n as u32),
+                    8 => Self::from_u64(n as u64),
+                    16 => Self::from_u128(n as u128),
+                    _ => None,
+                }
+            }
         }
-    }
-}
 
-impl num_traits::ToPrimitive for TwoFloat {
-    #[inline]
-    fn to_i8(&self) -> Option<i8> {
-        i8::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_i16(&self) -> Option<i16> {
-        i16::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_i32(&self) -> Option<i32> {
-        i32::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_i64(&self) -> Option<i64> {
-        i64::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_i128(&self) -> Option<i128> {
-        i128::try_from(self).ok()
-    }
-
-    fn to_isize(&self) -> Option<isize> {
-        match core::mem::size_of::<isize>() {
-            1 => self.to_i8().map(|i| i as isize),
-            2 => self.to_i16().map(|i| i as isize),
-            4 => self.to_i32().map(|i| i as isize),
-            8 => self.to_i64().map(|i| i as isize),
-            16 => self.to_i128().map(|i| i as isize),
-            _ => None,
+        impl num_traits::ToPrimitive for $t {
+            #[inline]
+            fn to_i8(&self) -> Option<i8> {
+                i8::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_i16(&self) -> Option<i16> {
+                i16::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_i32(&self) -> Option<i32> {
+                i32::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                i64::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                i128::try_from(self).ok()
+            }
+
+            fn to_isize(&self) -> Option<isize> {
+                match core::mem::size_of::<isize>() {
+                    1 => self.to_i8().map(|i| i as isize),
+                    2 => self.to_i16().map(|i| i as isize),
+                    4 => self.to_i32().map(|i| i as isize),
+                    8 => self.to_i64().map(|i| i as isize),
+                    16 => self.to_i128().map(|i| i as isize),
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            fn to_u8(&self) -> Option<u8> {
+                u8::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_u16(&self) -> Option<u16> {
+                u16::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_u32(&self) -> Option<u32> {
+                u32::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                u64::try_from(self).ok()
+            }
+
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                u128::try_from(self).ok()
+            }
+
+            fn to_usize(&self) -> Option<usize> {
+                match core::mem::size_of::<usize>() {
+                    1 => self.to_u8().map(|u| u as usize),
+                    2 => self.to_u16().map(|u| u as usize),
+                    4 => self.to_u32().map(|u| u as usize),
+                    8 => self.to_u64().map(|u| u as usize),
+                    16 => self.to_u128().map(|u| u as usize),
+                    _ => None,
+                }
+            }
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.into())
+            }
         }
-    }
-
-    #[inline]
-    fn to_u8(&self) -> Option<u8> {
-        u8::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_u16(&self) -> Option<u16> {
-        u16::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_u32(&self) -> Option<u32> {
-        u32::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_u64(&self) -> Option<u64> {
-        u64::try_from(self).ok()
-    }
-
-    #[inline]
-    fn to_u128(&self) -> Option<u128> {
-        u128::try_from(self).ok()
-    }
-
-    fn to_usize(&self) -> Option<usize> {
-        match core::mem::size_of::<usize>() {
-            1 => self.to_u8().map(|u| u as usize),
-            2 => self.to_u16().map(|u| u as usize),
-            4 => self.to_u32().map(|u| u as usize),
-            8 => self.to_u64().map(|u| u as usize),
-            16 => self.to_u128().map(|u| u as usize),
-            _ => None,
+
+        impl num_traits::NumCast for $t {
+            fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
+                const INT_THRESHOLD: f64 = hexf::hexf64!("0x1.0p53");
+                if let Some(f) = n.to_f64() {
+                    if libm::fabs(f) <= INT_THRESHOLD {
+                        Some(f.into())
+                    } else if let Some(i) = n.to_i128() {
+                        Some(i.into())
+                    } else {
+                        Some(n.to_u128().map_or_else(|| f.into(), |u| u.into()))
+                    }
+                } else if let Some(i) = n.to_i128() {
+                    Some(i.into())
+                } else {
+                    n.to_u128().map(|u| u.into())
+                }
+            }
         }
-    }
-    #[inline]
-    fn to_f64(&self) -> Option<f64> {
-        Some(self.into())
-    }
-}
 
-impl num_traits::NumCast for TwoFloat {
-    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
-        const INT_THRESHOLD: f64 = hexf64!("0x1.0p53");
-        if let Some(f) = n.to_f64() {
-            if libm::fabs(f) <= INT_THRESHOLD {
-                Some(f.into())
-            } else if let Some(i) = n.to_i128() {
-                Some(i.into())
-            } else {
-                Some(n.to_u128().map_or_else(|| f.into(), |u| u.into()))
-            }
-        } else if let Some(i) = n.to_i128() {
-            Some(i.into())
-        } else {
-            n.to_u128().map(|u| u.into())
+        #[allow(non_snake_case)]
+        impl num_traits::FloatConst for $t {
+            #[inline]
+            fn E() -> Self {
+                <$t>::from($consts::E)
+            }
+
+            #[inline]
+            fn FRAC_1_PI() -> Self {
+                <$t>::from($consts::FRAC_1_PI)
+            }
+
+            #[inline]
+            fn FRAC_1_SQRT_2() -> Self {
+                <$t>::from($consts::FRAC_1_SQRT_2)
+            }
+
+            #[inline]
+            fn FRAC_2_PI() -> Self {
+                <$t>::from($consts::FRAC_2_PI)
+            }
+
+            #[inline]
+            fn FRAC_2_SQRT_PI() -> Self {
+                <$t>::from($consts::FRAC_2_SQRT_PI)
+            }
+
+            #[inline]
+            fn FRAC_PI_2() -> Self {
+                <$t>::from($consts::FRAC_PI_2)
+            }
+
+            #[inline]
+            fn FRAC_PI_3() -> Self {
+                <$t>::from($consts::FRAC_PI_3)
+            }
+
+            #[inline]
+            fn FRAC_PI_4() -> Self {
+                <$t>::from($consts::FRAC_PI_4)
+            }
+
+            #[inline]
+            fn FRAC_PI_6() -> Self {
+                <$t>::from($consts::FRAC_PI_6)
+            }
+
+            #[inline]
+            fn FRAC_PI_8() -> Self {
+                <$t>::from($consts::FRAC_PI_8)
+            }
+
+            #[inline]
+            fn LN_10() -> Self {
+                <$t>::from($consts::LN_10)
+            }
+
+            #[inline]
+            fn LN_2() -> Self {
+                <$t>::from($consts::LN_2)
+            }
+
+            #[inline]
+            fn LOG10_E() -> Self {
+                <$t>::from($consts::LOG10_E)
+            }
+
+            #[inline]
+            fn LOG2_E() -> Self {
+                <$t>::from($consts::LOG2_E)
+            }
+
+            #[inline]
+            fn PI() -> Self {
+                <$t>::from($consts::PI)
+            }
+
+            #[inline]
+            fn SQRT_2() -> Self {
+                <$t>::from($consts::SQRT_2)
+            }
+
+            #[inline]
+            fn TAU() -> Self {
+                <$t>::from($consts::TAU)
+            }
+
+            #[inline]
+            fn LOG10_2() -> Self {
+                <$t>::from($consts::LOG10_2)
+            }
+
+            #[inline]
+            fn LOG2_10() -> Self {
+                <$t>::from($consts::LOG2_10)
+            }
         }
-    }
-}
 
-#[allow(non_snake_case)]
-impl num_traits::FloatConst for TwoFloat {
-    #[inline]
-    fn E() -> Self {
-        consts::E
-    }
-
-    #[inline]
-    fn FRAC_1_PI() -> Self {
-        consts::FRAC_1_PI
-    }
-
-    #[inline]
-    fn FRAC_1_SQRT_2() -> Self {
-        consts::FRAC_1_SQRT_2
-    }
-
-    #[inline]
-    fn FRAC_2_PI() -> Self {
-        consts::FRAC_2_PI
-    }
-
-    #[inline]
-    fn FRAC_2_SQRT_PI() -> Self {
-        consts::FRAC_2_SQRT_PI
-    }
-
-    #[inline]
-    fn FRAC_PI_2() -> Self {
-        consts::FRAC_PI_2
-    }
-
-    #[inline]
-    fn FRAC_PI_3() -> Self {
-        consts::FRAC_PI_3
-    }
-
-    #[inline]
-    fn FRAC_PI_4() -> Self {
-        consts::FRAC_PI_4
-    }
-
-    #[inline]
-    fn FRAC_PI_6() -> Self {
-        consts::FRAC_PI_6
-    }
-
-    #[inline]
-    fn FRAC_PI_8() -> Self {
-        consts::FRAC_PI_8
-    }
-
-    #[inline]
-    fn LN_10() -> Self {
-        consts::LN_10
-    }
-
-    #[inline]
-    fn LN_2() -> Self {
-        consts::LN_2
-    }
-
-    #[inline]
-    fn LOG10_E() -> Self {
-        consts::LOG10_E
-    }
-
-    #[inline]
-    fn LOG2_E() -> Self {
-        consts::LOG2_E
-    }
-
-    #[inline]
-    fn PI() -> Self {
-        consts::PI
-    }
-
-    #[inline]
-    fn SQRT_2() -> Self {
-        consts::SQRT_2
-    }
-
-    #[inline]
-    fn TAU() -> Self {
-        consts::TAU
-    }
-
-    #[inline]
-    fn LOG10_2() -> Self {
-        consts::LOG10_2
-    }
-
-    #[inline]
-    fn LOG2_10() -> Self {
-        consts::LOG2_10
-    }
-}
+        impl num_traits::float::FloatCore for $t {
+            fn infinity() -> Self {
+                $t::INFINITY
+            }
 
-impl num_traits::float::FloatCore for TwoFloat {
-    fn infinity() -> Self {
-        TwoFloat::INFINITY
-    }
-
-    fn neg_infinity() -> Self {
-        TwoFloat::NEG_INFINITY
-    }
-
-    #[inline]
-    fn nan() -> Self {
-        TwoFloat::NAN
-    }
-
-    #[inline]
-    fn neg_zero() -> Self {
-        TwoFloat { hi: -0.0, lo: 0.0 }
-    }
-
-    #[inline]
-    fn min_value() -> Self {
-        TwoFloat::MIN
-    }
-
-    #[inline]
-    fn min_positive_value() -> Self {
-        TwoFloat::MIN_POSITIVE
-    }
-
-    #[inline]
-    fn epsilon() -> Self {
-        TwoFloat::EPSILON
-    }
-
-    #[inline]
-    fn max_value() -> Self {
-        TwoFloat::MAX
-    }
-
-    #[inline]
-    fn classify(self) -> FpCategory {
-        self.hi.classify()
-    }
-
-    #[inline]
-    fn to_degrees(self) -> Self {
-        TwoFloat::to_degrees(self)
-    }
-
-    #[inline]
-    fn to_radians(self) -> Self {
-        TwoFloat::to_radians(self)
-    }
-
-    fn integer_decode(self) -> (u64, i16, i8) {
-        panic!("cannot decode mantissa to u64")
-    }
-
-    #[inline]
-    fn is_nan(self) -> bool {
-        self.hi.is_nan() || self.lo.is_nan()
-    }
-
-    #[inline]
-    fn is_infinite(self) -> bool {
-        self.hi.is_infinite() || self.lo.is_infinite()
-    }
-
-    #[inline]
-    fn is_finite(self) -> bool {
-        self.is_valid()
-    }
-
-    #[inline]
-    fn is_normal(self) -> bool {
-        self.hi.is_normal()
-    }
-
-    #[inline]
-    fn floor(self) -> Self {
-        TwoFloat::floor(self)
-    }
-
-    #[inline]
-    fn ceil(self) -> Self {
-        TwoFloat::ceil(self)
-    }
-
-    #[inline]
-    fn round(self) -> Self {
-        TwoFloat::round(self)
-    }
-
-    #[inline]
-    fn trunc(self) -> Self {
-        TwoFloat::trunc(self)
-    }
-
-    #[inline]
-    fn fract(self) -> Self {
-        TwoFloat::fract(self)
-    }
-
-    #[inline]
-    fn abs(self) -> Self {
-        TwoFloat::abs(&self)
-    }
-
-    #[inline]
-    fn signum(self) -> Self {
-        TwoFloat::signum(&self)
-    }
-
-    #[inline]
-    fn is_sign_positive(self) -> bool {
-        TwoFloat::is_sign_positive(&self)
-    }
-
-    #[inline]
-    fn is_sign_negative(self) -> bool {
-        TwoFloat::is_sign_negative(&self)
-    }
-
-    #[inline]
-    fn min(self, other: Self) -> Self {
-        TwoFloat::min(self, other)
-    }
-
-    #[inline]
-    fn max(self, other: Self) -> Self {
-        TwoFloat::max(self, other)
-    }
-
-    #[inline]
-    fn recip(self) -> Self {
-        TwoFloat::recip(self)
-    }
-
-    #[inline]
-    fn powi(self, exp: i32) -> Self {
-        TwoFloat::powi(self, exp)
-    }
-}
+            fn neg_infinity() -> Self {
+                $t::NEG_INFINITY
+            }
 
-impl num_traits::Float for TwoFloat {
-    fn infinity() -> Self {
-        TwoFloat::INFINITY
-    }
-
-    fn neg_infinity() -> Self {
-        TwoFloat::NEG_INFINITY
-    }
-
-    #[inline]
-    fn nan() -> Self {
-        TwoFloat::NAN
-    }
-
-    #[inline]
-    fn neg_zero() -> Self {
-        TwoFloat { hi: -0.0, lo: 0.0 }
-    }
-
-    #[inline]
-    fn min_value() -> Self {
-        TwoFloat::MIN
-    }
-
-    #[inline]
-    fn min_positive_value() -> Self {
-        TwoFloat::MIN_POSITIVE
-    }
-
-    #[inline]
-    fn epsilon() -> Self {
-        TwoFloat::EPSILON
-    }
-
-    #[inline]
-    fn max_value() -> Self {
-        TwoFloat::MAX
-    }
-
-    #[inline]
-    fn classify(self) -> FpCategory {
-        self.hi.classify()
-    }
-
-    #[inline]
-    fn to_degrees(self) -> Self {
-        TwoFloat::to_degrees(self)
-    }
-
-    #[inline]
-    fn to_radians(self) -> Self {
-        TwoFloat::to_radians(self)
-    }
-
-    fn integer_decode(self) -> (u64, i16, i8) {
-        panic!("cannot decode mantissa to u64")
-    }
-
-    #[inline]
-    fn is_nan(self) -> bool {
-        self.hi.is_nan() || self.lo.is_nan()
-    }
-
-    #[inline]
-    fn is_infinite(self) -> bool {
-        self.hi.is_infinite() || self.lo.is_infinite()
-    }
-
-    #[inline]
-    fn is_finite(self) -> bool {
-        self.is_valid()
-    }
-
-    #[inline]
-    fn is_normal(self) -> bool {
-        self.hi.is_normal()
-    }
-
-    #[inline]
-    fn floor(self) -> Self {
-        TwoFloat::floor(self)
-    }
-
-    #[inline]
-    fn ceil(self) -> Self {
-        TwoFloat::ceil(self)
-    }
-
-    #[inline]
-    fn round(self) -> Self {
-        TwoFloat::round(self)
-    }
-
-    #[inline]
-    fn trunc(self) -> Self {
-        TwoFloat::trunc(self)
-    }
-
-    #[inline]
-    fn fract(self) -> Self {
-        TwoFloat::fract(self)
-    }
-
-    #[inline]
-    fn abs(self) -> Self {
-        TwoFloat::abs(&self)
-    }
-
-    #[inline]
-    fn signum(self) -> Self {
-        TwoFloat::signum(&self)
-    }
-
-    #[inline]
-    fn is_sign_positive(self) -> bool {
-        TwoFloat::is_sign_positive(&self)
-    }
-
-    #[inline]
-    fn is_sign_negative(self) -> bool {
-        TwoFloat::is_sign_negative(&self)
-    }
-
-    #[inline]
-    fn min(self, other: Self) -> Self {
-        TwoFloat::min(self, other)
-    }
-
-    #[inline]
-    fn max(self, other: Self) -> Self {
-        TwoFloat::max(self, other)
-    }
-
-    #[inline]
-    fn recip(self) -> Self {
-        TwoFloat::recip(self)
-    }
-
-    #[inline]
-    fn powi(self, exp: i32) -> Self {
-        TwoFloat::powi(self, exp)
-    }
-
-    #[inline]
-    fn mul_add(self, a: Self, b: Self) -> Self {
-        (self * a) + b
-    }
-
-    #[inline]
-    fn powf(self, n: Self) -> Self {
-        TwoFloat::powf(self, n)
-    }
-
-    #[inline]
-    fn sqrt(self) -> Self {
-        TwoFloat::sqrt(self)
-    }
-
-    #[inline]
-    fn exp(self) -> Self {
-        TwoFloat::exp(self)
-    }
-
-    #[inline]
-    fn exp2(self) -> Self {
-        TwoFloat::exp2(self)
-    }
-
-    #[inline]
-    fn ln(self) -> Self {
-        TwoFloat::ln(self)
-    }
-
-    #[inline]
-    fn log(self, base: Self) -> Self {
-        TwoFloat::log(self, base)
-    }
-
-    #[inline]
-    fn log2(self) -> Self {
-        TwoFloat::log2(self)
-    }
-
-    #[inline]
-    fn log10(self) -> Self {
-        TwoFloat::log10(self)
-    }
-
-    #[inline]
-    fn abs_sub(self, other: Self) -> Self {
-        TwoFloat::abs(&(self - other))
-    }
-
-    #[inline]
-    fn cbrt(self) -> Self {
-        TwoFloat::cbrt(self)
-    }
-
-    #[inline]
-    fn hypot(self, other: Self) -> Self {
-        TwoFloat::hypot(self, other)
-    }
-
-    #[inline]
-    fn sin(self) -> Self {
-        TwoFloat::sin(self)
-    }
-
-    #[inline]
-    fn cos(self) -> Self {
-        TwoFloat::cos(self)
-    }
-
-    #[inline]
-    fn tan(self) -> Self {
-        TwoFloat::tan(self)
-    }
-
-    #[inline]
-    fn asin(self) -> Self {
-        TwoFloat::asin(self)
-    }
-
-    #[inline]
-    fn acos(self) -> Self {
-        TwoFloat::acos(self)
-    }
-
-    #[inline]
-    fn atan(self) -> Self {
-        TwoFloat::atan(self)
-    }
-
-    #[inline]
-    fn atan2(self, other: Self) -> Self {
-        TwoFloat::atan2(self, other)
-    }
-
-    #[inline]
-    fn sin_cos(self) -> (Self, Self) {
-        TwoFloat::sin_cos(self)
-    }
-
-    #[inline]
-    fn exp_m1(self) -> Self {
-        TwoFloat::exp_m1(self)
-    }
-
-    #[inline]
-    fn ln_1p(self) -> Self {
-        TwoFloat::ln_1p(self)
-    }
-
-    #[inline]
-    fn sinh(self) -> Self {
-        TwoFloat::sinh(self)
-    }
-
-    #[inline]
-    fn cosh(self) -> Self {
-        TwoFloat::cosh(self)
-    }
-
-    #[inline]
-    fn tanh(self) -> Self {
-        TwoFloat::tanh(self)
-    }
-
-    #[inline]
-    fn asinh(self) -> Self {
-        TwoFloat::asinh(self)
-    }
-
-    #[inline]
-    fn acosh(self) -> Self {
-        TwoFloat::acosh(self)
-    }
-
-    #[inline]
-    fn atanh(self) -> Self {
-        TwoFloat::atanh(self)
-    }
-}
+            #[inline]
+            fn nan() -> Self {
+                $t::NAN
+            }
 
-unary_ops! {
-    fn Inv::inv(self: &TwoFloat) -> TwoFloat {
-        TwoFloat::recip(*self)
-    }
-}
+            #[inline]
+            fn neg_zero() -> Self {
+                $from_float(-0.0)
+            }
 
-binary_ops! {
-    fn Pow::pow<'a, 'b>(self: &'a TwoFloat, rhs: &'b i8) -> TwoFloat {
-        TwoFloat::powi(*self, *rhs as i32)
-    }
+            #[inline]
+            fn min_value() -> Self {
+                $t::MIN
+            }
 
-    fn Pow::pow<'a, 'b>(self: &'a TwoFloat, rhs: &'b i16) -> TwoFloat {
-        TwoFloat::powi(*self, *rhs as i32)
-    }
+            #[inline]
+            fn min_positive_value() -> Self {
+                $t::MIN_POSITIVE
+            }
 
-    fn Pow::pow<'a, 'b>(self: &'a TwoFloat, rhs: &'b i32) -> TwoFloat {
-        TwoFloat::powi(*self, *rhs)
-    }
+            #[inline]
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
 
-    fn Pow::pow<'a, 'b>(self: &'a TwoFloat, rhs: &'b u8) -> TwoFloat {
-        TwoFloat::powi(*self, *rhs as i32)
-    }
+            #[inline]
+            fn max_value() -> Self {
+                $t::MAX
+            }
 
-    fn Pow::pow<'a, 'b>(self: &'a TwoFloat, rhs: &'b u16) -> TwoFloat {
-        TwoFloat::powi(*self, *rhs as i32)
-    }
-}
+            #[inline]
+            fn classify(self) -> FpCategory {
+                ($words)(self)[0].classify()
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
 
-binary_ops! {
-    fn Pow::pow<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        TwoFloat::powf(*self, (*rhs).into())
-    }
+            fn integer_decode(self) -> (u64, i16, i8) {
+                panic!("cannot decode mantissa to u64")
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                ($words)(self).iter().any(|w| w.is_nan())
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                ($words)(self)[0].is_infinite() && !num_traits::float::FloatCore::is_nan(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                self.is_valid()
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                ($words)(self)[0].is_normal()
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                $t::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> Self {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                $t::abs(&self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                $t::signum(&self)
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                $t::is_sign_positive(&self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(&self)
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn recip(self) -> Self {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn powi(self, exp: i32) -> Self {
+                $t::powi(self, exp)
+            }
+        }
+
+        impl num_traits::Float for $t {
+            fn infinity() -> Self {
+                $t::INFINITY
+            }
+
+            fn neg_infinity() -> Self {
+                $t::NEG_INFINITY
+            }
+
+            #[inline]
+            fn nan() -> Self {
+                $t::NAN
+            }
+
+            #[inline]
+            fn neg_zero() -> Self {
+                $from_float(-0.0)
+            }
+
+            #[inline]
+            fn min_value() -> Self {
+                $t::MIN
+            }
+
+            #[inline]
+            fn min_positive_value() -> Self {
+                $t::MIN_POSITIVE
+            }
+
+            #[inline]
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                $t::MAX
+            }
+
+            #[inline]
+            fn classify(self) -> FpCategory {
+                ($words)(self)[0].classify()
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                panic!("cannot decode mantissa to u64")
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                ($words)(self).iter().any(|w| w.is_nan())
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                ($words)(self)[0].is_infinite() && !num_traits::float::FloatCore::is_nan(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                self.is_valid()
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                ($words)(self)[0].is_normal()
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                $t::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> Self {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                $t::abs(&self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                $t::signum(&self)
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                $t::is_sign_positive(&self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(&self)
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn recip(self) -> Self {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn powi(self, exp: i32) -> Self {
+                $t::powi(self, exp)
+            }
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                ($mul_add)(self, a, b)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn exp2(self) -> Self {
+                $t::exp2(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn log(self, base: Self) -> Self {
+                $t::log(self, base)
+            }
+
+            #[inline]
+            fn log2(self) -> Self {
+                $t::log2(self)
+            }
+
+            #[inline]
+            fn log10(self) -> Self {
+                $t::log10(self)
+            }
+
+            #[inline]
+            fn abs_sub(self, other: Self) -> Self {
+                $t::abs(&(self - other))
+            }
+
+            #[inline]
+            fn cbrt(self) -> Self {
+                $t::cbrt(self)
+            }
+
+            #[inline]
+            fn hypot(self, other: Self) -> Self {
+                $t::hypot(self, other)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                $t::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> Self {
+                $t::exp_m1(self)
+            }
+
+            #[inline]
+            fn ln_1p(self) -> Self {
+                $t::ln_1p(self)
+            }
+
+            #[inline]
+            fn sinh(self) -> Self {
+                $t::sinh(self)
+            }
+
+            #[inline]
+            fn cosh(self) -> Self {
+                $t::cosh(self)
+            }
+
+            #[inline]
+            fn tanh(self) -> Self {
+                $t::tanh(self)
+            }
+
+            #[inline]
+            fn asinh(self) -> Self {
+                $t::asinh(self)
+            }
+
+            #[inline]
+            fn acosh(self) -> Self {
+                $t::acosh(self)
+            }
+
+            #[inline]
+            fn atanh(self) -> Self {
+                $t::atanh(self)
+            }
+        }
+
+        unary_ops! {
+            fn Inv::inv(self: &$t) -> $t {
+                $t::recip(*self)
+            }
+        }
+
+        binary_ops! {
+            fn Pow::pow<'a, 'b>(self: &'a $t, rhs: &'b i8) -> $t {
+                $t::powi(*self, *rhs as i32)
+            }
+
+            fn Pow::pow<'a, 'b>(self: &'a $t, rhs: &'b i16) -> $t {
+                $t::powi(*self, *rhs as i32)
+            }
+
+            fn Pow::pow<'a, 'b>(self: &'a $t, rhs: &'b i32) -> $t {
+                $t::powi(*self, *rhs)
+            }
+
+            fn Pow::pow<'a, 'b>(self: &'a $t, rhs: &'b u8) -> $t {
+                $t::powi(*self, *rhs as i32)
+            }
+
+            fn Pow::pow<'a, 'b>(self: &'a $t, rhs: &'b u16) -> $t {
+                $t::powi(*self, *rhs as i32)
+            }
+        }
+
+        binary_ops! {
+            fn Pow::pow<'a, 'b>(self: &'a $t, rhs: &'b $f) -> $t {
+                $t::powf(*self, (*rhs).into())
+            }
+
+            fn Pow::pow<'a, 'b>(self: &'a $t, rhs: &'b $t) -> $t {
+                $t::powf(*self, *rhs)
+            }
+        }
+    };
+}
 
-    fn Pow::pow<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        TwoFloat::powf(*self, *rhs)
-    }
+float_traits! {
+    type: TwoFloat,
+    float: f64,
+    from_float: TwoFloat::from_f64,
+    consts: consts,
+    from_str_radix: crate::parse::from_str_radix,
+    mul_add: TwoFloat::mul_add,
+    words: |x: TwoFloat| [x.hi, x.lo],
 }
@@ -0,0 +1,240 @@
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::{TwoFloat, TwoFloatError};
+
+// Gives the value used for comparison and hashing, in which the words are
+// canonical, all zeros are positive and all NaN values are `NAN`
+fn ordered_value(value: TwoFloat) -> TwoFloat {
+    let (hi, lo) = value.canonical_words();
+    if hi.is_nan() {
+        TwoFloat::NAN
+    } else if hi == 0.0 {
+        TwoFloat::from(0.0)
+    } else {
+        TwoFloat { hi, lo }
+    }
+}
+
+fn ordered_cmp(a: TwoFloat, b: TwoFloat) -> Ordering {
+    ordered_value(a).total_cmp(&ordered_value(b))
+}
+
+fn ordered_hash<H: Hasher>(value: TwoFloat, state: &mut H) {
+    let (hi, lo) = ordered_value(value).canonical_words();
+    hi.to_bits().hash(state);
+    lo.to_bits().hash(state);
+}
+
+/// A wrapper for `TwoFloat` which implements `Eq`, `Ord` and `Hash`, so that
+/// it can be used as the key of a `BTreeMap` or `HashMap`.
+///
+/// Values are ordered numerically, except that all `NAN` values are equal to
+/// each other and greater than all other values, and negative zero is equal
+/// to positive zero. Values which differ only in the representation of their
+/// words, such as infinities with different low words, are equal and have the
+/// same hash.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::BTreeSet;
+/// # use twofloat::{OrderedTwoFloat, TwoFloat};
+/// let values: BTreeSet<_> = [TwoFloat::NAN, TwoFloat::from(2.0), TwoFloat::from(-0.0)]
+///     .iter()
+///     .map(|&x| OrderedTwoFloat(x))
+///     .collect();
+/// let sorted: Vec<_> = values.iter().map(|x| x.0).collect();
+///
+/// assert_eq!(sorted[..2], [TwoFloat::from(0.0), TwoFloat::from(2.0)]);
+/// assert!(sorted[2].hi().is_nan());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct OrderedTwoFloat(pub TwoFloat);
+
+impl PartialEq for OrderedTwoFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedTwoFloat {}
+
+impl PartialOrd for OrderedTwoFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedTwoFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        ordered_cmp(self.0, other.0)
+    }
+}
+
+impl Hash for OrderedTwoFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ordered_hash(self.0, state);
+    }
+}
+
+impl Deref for OrderedTwoFloat {
+    type Target = TwoFloat;
+
+    fn deref(&self) -> &TwoFloat {
+        &self.0
+    }
+}
+
+impl From<TwoFloat> for OrderedTwoFloat {
+    fn from(value: TwoFloat) -> Self {
+        Self(value)
+    }
+}
+
+impl From<f64> for OrderedTwoFloat {
+    fn from(value: f64) -> Self {
+        Self(TwoFloat::from(value))
+    }
+}
+
+impl From<OrderedTwoFloat> for TwoFloat {
+    fn from(value: OrderedTwoFloat) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for OrderedTwoFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A `TwoFloat` which is guaranteed not to be `NAN`, implementing `Eq`,
+/// `Ord` and `Hash`.
+///
+/// Values are ordered numerically, with negative zero equal to positive
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use twofloat::{NotNanTwoFloat, TwoFloat, TwoFloatError};
+/// let key = NotNanTwoFloat::new(TwoFloat::new_div(1.0, 3.0)).unwrap();
+/// let mut map = HashMap::new();
+/// map.insert(key, "third");
+///
+/// assert_eq!(map[&key], "third");
+/// assert_eq!(NotNanTwoFloat::new(TwoFloat::NAN), Err(TwoFloatError::NaNInput));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct NotNanTwoFloat(TwoFloat);
+
+impl NotNanTwoFloat {
+    /// Creates a `NotNanTwoFloat`, returning [`TwoFloatError::NaNInput`] if
+    /// the value is `NAN`.
+    pub fn new(value: TwoFloat) -> Result<Self, TwoFloatError> {
+        if value.hi.is_nan() || value.lo.is_nan() {
+            Err(TwoFloatError::NaNInput)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> TwoFloat {
+        self.0
+    }
+}
+
+impl PartialEq for NotNanTwoFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NotNanTwoFloat {}
+
+impl PartialOrd for NotNanTwoFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NotNanTwoFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        ordered_cmp(self.0, other.0)
+    }
+}
+
+impl Hash for NotNanTwoFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ordered_hash(self.0, state);
+    }
+}
+
+impl Deref for NotNanTwoFloat {
+    type Target = TwoFloat;
+
+    fn deref(&self) -> &TwoFloat {
+        &self.0
+    }
+}
+
+impl TryFrom<TwoFloat> for NotNanTwoFloat {
+    type Error = TwoFloatError;
+
+    fn try_from(value: TwoFloat) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<NotNanTwoFloat> for TwoFloat {
+    fn from(value: NotNanTwoFloat) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for NotNanTwoFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ordered_value, OrderedTwoFloat};
+    use crate::TwoFloat;
+
+    #[test]
+    fn ordered_value_test() {
+        let zero = ordered_value(TwoFloat::from(-0.0));
+        assert!(zero.hi.is_sign_positive() && zero.lo.is_sign_positive());
+
+        let nan = ordered_value(TwoFloat {
+            hi: 1.0,
+            lo: f64::NAN,
+        });
+        assert!(nan.hi.is_nan() && nan.hi.is_sign_positive());
+
+        let value = TwoFloat::new_add(1.0, -1e-20);
+        assert_eq!(ordered_value(value), value);
+
+        // The words are normalized before zeros are mapped, so that the order
+        // agrees with total_cmp
+        let value = TwoFloat { hi: 0.0, lo: 1.5 };
+        assert_eq!(ordered_value(value), TwoFloat::from(1.5));
+        assert_eq!(
+            OrderedTwoFloat(value).cmp(&OrderedTwoFloat(TwoFloat::from(0.0))),
+            value.total_cmp(&TwoFloat::from(0.0))
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use core::str::FromStr;
+
+use twofloat_decimal::{parse_radix, ParseError, WordFormat};
+
+use crate::{ParseErrorKind, QuadFloat, TwoF32, TwoFloat, TwoFloatError};
+
+pub(crate) const fn error(kind: ParseErrorKind, position: usize) -> TwoFloatError {
+    TwoFloatError::ParseError { kind, position }
+}
+
+pub(crate) const fn decimal_error(e: ParseError) -> TwoFloatError {
+    match e {
+        ParseError::InvalidDigit(position) => error(ParseErrorKind::InvalidDigit, position),
+        ParseError::MissingDigits(position) => error(ParseErrorKind::MissingDigits, position),
+    }
+}
+
+fn parse_special(s: &str) -> Option<f64> {
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'+') => (false, &s[1..]),
+        Some(b'-') => (true, &s[1..]),
+        _ => (false, s),
+    };
+
+    if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+        Some(if negative {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        })
+    } else if rest.eq_ignore_ascii_case("nan") {
+        Some(f64::NAN)
+    } else {
+        None
+    }
+}
+
+/// Parses a string in the given radix into `N` non-overlapping words of the
+/// given format widened to `f64`, where each word is the remaining error of
+/// the previous words rounded to nearest.
+///
+/// The special values `inf`, `infinity` and `nan` are recognized regardless
+/// of the radix. A decimal exponent introduced by `e` is accepted for radix
+/// 10, and a binary exponent introduced by `p` is accepted for radix 16.
+pub(crate) fn parse_words<const N: usize>(
+    s: &str,
+    radix: u32,
+    format: &WordFormat,
+) -> Result<[f64; N], TwoFloatError> {
+    if !(2..=36).contains(&radix) {
+        return Err(error(ParseErrorKind::InvalidRadix, 0));
+    }
+
+    if s.is_empty() {
+        return Err(error(ParseErrorKind::Empty, 0));
+    }
+
+    if let Some(value) = parse_special(s) {
+        return Ok([value; N]);
+    }
+
+    parse_radix(s, radix, format).map_err(decimal_error)
+}
+
+/// Parses a string in the given radix, as used by `Num::from_str_radix`.
+pub(crate) fn from_str_radix(s: &str, radix: u32) -> Result<TwoFloat, TwoFloatError> {
+    parse_words(s, radix, &WordFormat::F64).map(|[hi, lo]| {
+        if hi.is_finite() {
+            TwoFloat { hi, lo }
+        } else {
+            TwoFloat::from(hi)
+        }
+    })
+}
+
+/// Parses a string in the given radix into a `TwoF32`.
+pub(crate) fn from_str_radix_f32(s: &str, radix: u32) -> Result<TwoF32, TwoFloatError> {
+    parse_words(s, radix, &WordFormat::F32).map(|[hi, lo]| {
+        if hi.is_finite() {
+            TwoF32 {
+                hi: hi as f32,
+                lo: lo as f32,
+            }
+        } else if hi.is_nan() {
+            TwoF32::NAN
+        } else {
+            TwoF32::from_f32(hi as f32)
+        }
+    })
+}
+
+/// Parses a string in the given radix into a `QuadFloat`.
+pub(crate) fn from_str_radix_quad(s: &str, radix: u32) -> Result<QuadFloat, TwoFloatError> {
+    parse_words(s, radix, &WordFormat::F64).map(|words| {
+        if words[0].is_finite() {
+            QuadFloat { words }
+        } else if words[0].is_nan() {
+            QuadFloat::NAN
+        } else {
+            QuadFloat::from_f64(words[0])
+        }
+    })
+}
+
+impl FromStr for TwoFloat {
+    type Err = TwoFloatError;
+
+    /// Parses a decimal string, returning the nearest `TwoFloat` value.
+    ///
+    /// The high word is the input rounded to the nearest `f64`, and the low
+    /// word is the remaining difference rounded to the nearest `f64`. The
+    /// accepted format matches that of `f64`, with the addition of
+    /// underscores as digit separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let pi: TwoFloat = "3.14159265358979323846264338327950288".parse().unwrap();
+    /// assert_eq!(pi, twofloat::consts::PI);
+    ///
+    /// let a: TwoFloat = "-1_000.5e-3".parse().unwrap();
+    /// assert_eq!(a.hi(), -1.0005);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_str_radix(s, 10)
+    }
+}
+
+impl FromStr for QuadFloat {
+    type Err = TwoFloatError;
+
+    /// Parses a decimal string, returning the nearest `QuadFloat` value.
+    ///
+    /// Each word is the remaining difference from the previous words rounded
+    /// to the nearest `f64`, with the same format as for `TwoFloat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{QuadFloat, TwoFloat};
+    /// let a: QuadFloat = "0.1".parse().unwrap();
+    /// assert_eq!(TwoFloat::from(a), "0.1".parse::<TwoFloat>().unwrap());
+    /// assert!((a * 10.0 - 1.0).abs() < 1e-63);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_str_radix_quad(s, 10)
+    }
+}
+
+impl FromStr for TwoF32 {
+    type Err = TwoFloatError;
+
+    /// Parses a decimal string, returning the nearest `TwoF32` value.
+    ///
+    /// The high word is the input rounded to the nearest `f32`, and the low
+    /// word is the remaining difference rounded to the nearest `f32`, with
+    /// the same format as for `TwoFloat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a: TwoF32 = "0.1".parse().unwrap();
+    /// assert_eq!(a.hi(), 0.1f32);
+    /// assert!((a * 10.0 - 1.0).abs() < 1e-14);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_str_radix_f32(s, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseErrorKind, TwoFloat, TwoFloatError};
+
+    fn parse_error(s: &str) -> (ParseErrorKind, usize) {
+        match s.parse::<TwoFloat>() {
+            Err(TwoFloatError::ParseError { kind, position }) => (kind, position),
+            other => panic!("Unexpected result {:?} parsing {:?}", other, s),
+        }
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert_eq!(parse_error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(parse_error("-"), (ParseErrorKind::MissingDigits, 1));
+        assert_eq!(parse_error("."), (ParseErrorKind::MissingDigits, 1));
+        assert_eq!(parse_error("_1"), (ParseErrorKind::InvalidDigit, 0));
+        assert_eq!(parse_error("1.2.3"), (ParseErrorKind::InvalidDigit, 3));
+        assert_eq!(parse_error("1e"), (ParseErrorKind::MissingDigits, 2));
+        assert_eq!(parse_error("1e+_2"), (ParseErrorKind::InvalidDigit, 3));
+        assert_eq!(parse_error("12x"), (ParseErrorKind::InvalidDigit, 2));
+        assert_eq!(parse_error(" 1"), (ParseErrorKind::InvalidDigit, 0));
+    }
+}
@@ -0,0 +1,460 @@
+use core::cmp::Ordering;
+
+use hexf::hexf64;
+
+use crate::base::no_overlap;
+
+mod arithmetic;
+pub(crate) mod consts;
+mod convert;
+mod explog;
+mod fraction;
+mod hyperbolic;
+mod num_integration;
+mod power;
+mod trigonometry;
+
+pub(crate) use arithmetic::renormalize;
+
+/// Represents a four-word floating point type, represented as the sum of four
+/// non-overlapping `f64` values, giving around 212 bits of precision.
+///
+/// The arithmetic follows the quad-double algorithms of Hida, Li and Bailey
+/// (2001), built from the same error-free transformations as `TwoFloat`.
+/// Mathematical functions refine the corresponding `TwoFloat` result where
+/// possible, so their accuracy is limited to a few units in the last place.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{QuadFloat, TwoFloat};
+/// let a = QuadFloat::from(1.0) / 3.0;
+/// let b = a * 3.0 - 1.0;
+///
+/// assert!(b.abs() < 1e-63);
+/// assert_eq!(TwoFloat::from(a), TwoFloat::from(1.0) / 3.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuadFloat {
+    pub(crate) words: [f64; 4],
+}
+
+impl QuadFloat {
+    /// Mantissa size of the quad-double structure of QuadFloat
+    /// aka the number of significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 212;
+
+    /// Smallest finite `QuadFloat` value.
+    pub const MIN: Self = Self {
+        words: [
+            f64::MIN,
+            hexf64!("-0x1.fffffffffffffp+969"),
+            hexf64!("-0x1.fffffffffffffp+915"),
+            hexf64!("-0x1.fffffffffffffp+861"),
+        ],
+    };
+
+    /// Smallest positive normal `QuadFloat` value.
+    pub const MIN_POSITIVE: Self = Self::from_f64(f64::MIN_POSITIVE);
+
+    /// Largest finite `QuadFloat` value.
+    pub const MAX: Self = Self {
+        words: [
+            f64::MAX,
+            hexf64!("0x1.fffffffffffffp+969"),
+            hexf64!("0x1.fffffffffffffp+915"),
+            hexf64!("0x1.fffffffffffffp+861"),
+        ],
+    };
+
+    /// Represents an error value equivalent to `f64::NAN`.
+    pub const NAN: Self = Self {
+        words: [f64::NAN; 4],
+    };
+
+    /// Represents the difference between 1.0 and the next representable normal value.
+    pub const EPSILON: Self = Self::from_f64(f64::MIN_POSITIVE);
+
+    /// A positive infinite value
+    pub const INFINITY: Self = Self::from_f64(f64::INFINITY);
+
+    /// A negative infinite value
+    pub const NEG_INFINITY: Self = Self::from_f64(f64::NEG_INFINITY);
+
+    /// Creates a new QuadFloat from a constant `f64` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// const value: QuadFloat = QuadFloat::from_f64(1.0);
+    /// assert_eq!(value.hi(), 1.0);
+    /// ```
+    pub const fn from_f64(value: f64) -> Self {
+        Self {
+            words: [value, 0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Returns the most significant word of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let value = QuadFloat::from(1.0) + 1.0e-200;
+    /// assert_eq!(value.hi(), 1.0);
+    /// ```
+    pub fn hi(&self) -> f64 {
+        self.words[0]
+    }
+
+    /// Returns the words of `self`, most significant first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let value = (QuadFloat::from(1.0) + 1.0e-20) + 1.0e-40;
+    /// assert_eq!(value.words(), [1.0, 1.0e-20, 1.0e-40, 0.0]);
+    /// ```
+    pub fn words(&self) -> [f64; 4] {
+        self.words
+    }
+
+    /// Returns `true` if `self` is a valid value, where all words are finite
+    /// and each word does not overlap the next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = (QuadFloat::from(1.0) + 1.0e-300).is_valid();
+    /// let b = (QuadFloat::from(1.0e300) * 1.0e300).is_valid();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.words.iter().all(|w| w.is_finite())
+            && self.words.windows(2).all(|w| no_overlap(w[0], w[1]))
+    }
+
+    /// Returns the minimum of two numbers. If one of the arguments is `NAN`,
+    /// the other is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(35.2) + 1e-84;
+    /// let b = QuadFloat::from(35.2) - 1e-93;
+    ///
+    /// assert_eq!(a.min(b), b);
+    /// ```
+    pub fn min(self, other: Self) -> Self {
+        if !self.is_valid() {
+            other
+        } else if !other.is_valid() || self <= other {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the maximum of two numbers. If one of the arguments is `NAN`,
+    /// the other is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(35.2) + 1e-84;
+    /// let b = QuadFloat::from(35.2) - 1e-93;
+    ///
+    /// assert_eq!(a.max(b), a);
+    /// ```
+    pub fn max(self, other: Self) -> Self {
+        if !self.is_valid() {
+            other
+        } else if !other.is_valid() || self >= other {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the absolute value of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-1.0) + 1.0e-300;
+    ///
+    /// assert_eq!(a.abs(), QuadFloat::from(1.0) - 1.0e-300);
+    /// ```
+    pub fn abs(&self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            *self
+        }
+    }
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(0.0).is_sign_positive();
+    /// let b = (QuadFloat::from(-1.0) + 1.0e-300).is_sign_positive();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    /// ```
+    pub fn is_sign_positive(&self) -> bool {
+        self.words[0].is_sign_positive()
+    }
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-0.0).is_sign_negative();
+    /// let b = (QuadFloat::from(1.0) - 1.0e-300).is_sign_negative();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    /// ```
+    pub fn is_sign_negative(&self) -> bool {
+        self.words[0].is_sign_negative()
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-1.0) + 1.0e-200;
+    /// let b = QuadFloat::from(0.3);
+    ///
+    /// assert_eq!(a.copysign(&b), -a);
+    /// ```
+    pub fn copysign(&self, sign: &Self) -> Self {
+        if self.is_sign_positive() == sign.is_sign_positive() {
+            *self
+        } else {
+            -self
+        }
+    }
+
+    /// Returns a number that represents the sign of the value.
+    ///
+    /// * `1.0` if the number is positive or `+0.0`
+    /// * `-1.0` if the number is negative or `-0.0`
+    /// * Invalid value otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(3.5);
+    /// let b = QuadFloat::from(-0.0);
+    ///
+    /// assert_eq!(a.signum(), 1.0);
+    /// assert_eq!(b.signum(), -1.0);
+    /// ```
+    pub fn signum(&self) -> Self {
+        if self.is_valid() {
+            if self.is_sign_positive() {
+                Self::from(1.0)
+            } else {
+                Self::from(-1.0)
+            }
+        } else {
+            Self::NAN
+        }
+    }
+
+    /// Converts degrees to radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(180.0).to_radians();
+    /// let b = QuadFloat::from(-1.0).acos();
+    ///
+    /// assert!((a - b).abs() < 1e-62);
+    /// ```
+    pub fn to_radians(self) -> Self {
+        self * consts::RAD_PER_DEG
+    }
+
+    /// Converts radians to degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-1.0).acos().to_degrees();
+    ///
+    /// assert!((a - 180.0).abs() < 1e-60);
+    /// ```
+    pub fn to_degrees(self) -> Self {
+        self * consts::DEG_PER_RAD
+    }
+
+    /// Takes the reciprocal (inverse) of the number, `1/x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(67.2) + 5.7e-53;
+    /// let b = a.recip();
+    /// let difference = b.recip() - a;
+    ///
+    /// assert!(difference.abs() < 1e-60);
+    /// ```
+    pub fn recip(self) -> Self {
+        1.0 / self
+    }
+
+    /// Raises the number to an integer power. As for `f64`, any value
+    /// raised to the power of zero is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.0).powi(3);
+    /// let b = QuadFloat::from(0.0).powi(0);
+    ///
+    /// assert_eq!(a, 8.0);
+    /// assert_eq!(b, 1.0);
+    /// ```
+    pub fn powi(self, n: i32) -> Self {
+        match n {
+            0 => Self::from(1.0),
+            1 => self,
+            -1 => self.recip(),
+            _ => {
+                let mut result = Self::from(1.0);
+                let mut n_pos = n.unsigned_abs();
+                let mut value = self;
+                while n_pos > 0 {
+                    if (n_pos & 1) != 0 {
+                        result *= &value;
+                    }
+                    value *= value;
+                    n_pos >>= 1;
+                }
+                if n > 0 {
+                    result
+                } else {
+                    result.recip()
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<f64> for QuadFloat {
+    fn eq(&self, other: &f64) -> bool {
+        self.eq(&Self::from(*other))
+    }
+}
+
+impl PartialEq<QuadFloat> for f64 {
+    fn eq(&self, other: &QuadFloat) -> bool {
+        QuadFloat::from(*self).eq(other)
+    }
+}
+
+impl PartialEq<QuadFloat> for QuadFloat {
+    fn eq(&self, other: &QuadFloat) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<f64> for QuadFloat {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.partial_cmp(&Self::from(*other))
+    }
+}
+
+impl PartialOrd<QuadFloat> for f64 {
+    fn partial_cmp(&self, other: &QuadFloat) -> Option<Ordering> {
+        QuadFloat::from(*self).partial_cmp(other)
+    }
+}
+
+impl PartialOrd<QuadFloat> for QuadFloat {
+    fn partial_cmp(&self, other: &QuadFloat) -> Option<Ordering> {
+        if self
+            .words
+            .iter()
+            .chain(other.words.iter())
+            .any(|w| w.is_nan())
+        {
+            return None;
+        }
+
+        // The words are non-overlapping, so the first differing word decides
+        // the order. Infinities are only compared by their leading word.
+        for (a, b) in self.words.iter().zip(other.words.iter()) {
+            match a.partial_cmp(b) {
+                Some(Ordering::Equal) if a.is_finite() => continue,
+                ordering => return ordering,
+            }
+        }
+
+        Some(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuadFloat;
+
+    #[test]
+    fn default_test() {
+        let value: QuadFloat = Default::default();
+        assert_eq!(value, QuadFloat::from(0.0));
+    }
+
+    #[test]
+    fn ord_test() {
+        let a = QuadFloat::from(1.0) + 1e-40;
+        let b = (QuadFloat::from(1.0) + 1e-40) + 1e-80;
+
+        assert!(a < b);
+        assert!(-a > -b);
+        assert!(b < QuadFloat::INFINITY);
+        assert!(QuadFloat::NEG_INFINITY < -b);
+        assert_eq!(QuadFloat::INFINITY, QuadFloat::INFINITY);
+        assert!(QuadFloat::NAN.partial_cmp(&a).is_none());
+    }
+
+    #[test]
+    fn valid_test() {
+        assert!(QuadFloat::MAX.is_valid());
+        assert!(QuadFloat::MIN.is_valid());
+        assert!(!QuadFloat::NAN.is_valid());
+        assert!(!QuadFloat {
+            words: [1.0, 0.0, 1e-40, 0.0]
+        }
+        .is_valid());
+        assert!(!QuadFloat {
+            words: [1.0, 0.5, 0.0, 0.0]
+        }
+        .is_valid());
+    }
+}
@@ -0,0 +1,548 @@
+#![allow(clippy::extra_unused_lifetimes)]
+
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::{arithmetic::fast_two_sum, QuadFloat, TwoFloat};
+
+// Number of terms of the exact value of a fused multiply-add: the sixteen
+// products of the words and their errors, together with the addend
+const FMA_TERMS: usize = 36;
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    TwoFloat::new_add(a, b).into()
+}
+
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    TwoFloat::new_mul(a, b).into()
+}
+
+// Sums three values into three non-overlapping values
+fn three_sum(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
+    let (t1, t2) = two_sum(a, b);
+    let (s0, t3) = two_sum(c, t1);
+    let (s1, s2) = two_sum(t2, t3);
+    (s0, s1, s2)
+}
+
+// Sums three values into two values, discarding the smallest error term
+fn three_sum2(a: f64, b: f64, c: f64) -> (f64, f64) {
+    let (t1, t2) = two_sum(a, b);
+    let (s0, t3) = two_sum(c, t1);
+    (s0, t2 + t3)
+}
+
+/// Renormalizes terms arranged in roughly decreasing order of magnitude into
+/// four non-overlapping words, following Hida, Li and Bailey (2001). Terms
+/// below the precision of the last word are added to it.
+pub(crate) fn renormalize(terms: &mut [f64]) -> QuadFloat {
+    if terms[0].is_nan() {
+        return QuadFloat::NAN;
+    } else if terms[0].is_infinite() {
+        return QuadFloat::from_f64(terms[0]);
+    }
+
+    for i in (1..terms.len()).rev() {
+        let s = fast_two_sum(terms[i - 1], terms[i]);
+        terms[i - 1] = s.hi;
+        terms[i] = s.lo;
+    }
+
+    let mut words = [0.0; 4];
+    let mut k = 0;
+    let mut s = terms[0];
+    for &t in &terms[1..] {
+        if k == 3 {
+            s += t;
+            continue;
+        }
+
+        let u = fast_two_sum(s, t);
+        if u.lo != 0.0 {
+            words[k] = u.hi;
+            k += 1;
+            s = u.lo;
+        } else {
+            s = u.hi;
+        }
+    }
+
+    words[k] = s;
+    QuadFloat { words }
+}
+
+// Adds two values of the accumulator (u, v) to c, returning a word if it
+// is complete
+fn three_accumulate(u: &mut f64, v: &mut f64, c: f64) -> Option<f64> {
+    let (s, b) = two_sum(*v, c);
+    let (s, a) = two_sum(*u, s);
+    match (a != 0.0, b != 0.0) {
+        (true, true) => {
+            *u = a;
+            *v = b;
+            Some(s)
+        }
+        (_, false) => {
+            *u = s;
+            *v = a;
+            None
+        }
+        (false, true) => {
+            *u = s;
+            *v = b;
+            None
+        }
+    }
+}
+
+fn add_f64(a: &QuadFloat, b: f64) -> QuadFloat {
+    let (c0, e) = two_sum(a.words[0], b);
+    let (c1, e) = two_sum(a.words[1], e);
+    let (c2, e) = two_sum(a.words[2], e);
+    let (c3, e) = two_sum(a.words[3], e);
+    renormalize(&mut [c0, c1, c2, c3, e])
+}
+
+// Adds the words of both values in decreasing order of magnitude, which
+// remains accurate when the leading words cancel
+fn add(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    let mut terms = [0.0; 8];
+    let (mut i, mut j) = (0, 0);
+    for term in terms.iter_mut() {
+        *term = if j == 4 || (i < 4 && a.words[i].abs() > b.words[j].abs()) {
+            i += 1;
+            a.words[i - 1]
+        } else {
+            j += 1;
+            b.words[j - 1]
+        };
+    }
+
+    let (mut u, mut v) = two_sum(terms[0], terms[1]);
+    let mut words = [0.0; 4];
+    let mut k = 0;
+    for &t in &terms[2..] {
+        if k == 4 {
+            words[3] += t;
+        } else if let Some(word) = three_accumulate(&mut u, &mut v, t) {
+            words[k] = word;
+            k += 1;
+        }
+    }
+
+    if k < 4 {
+        words[k] = u;
+        if k < 3 {
+            words[k + 1] = v;
+        }
+    }
+
+    renormalize(&mut words)
+}
+
+fn mul_f64(a: &QuadFloat, b: f64) -> QuadFloat {
+    let (p0, q0) = two_prod(a.words[0], b);
+    let (p1, q1) = two_prod(a.words[1], b);
+    let (p2, q2) = two_prod(a.words[2], b);
+    let p3 = a.words[3] * b;
+
+    let (s1, s2) = two_sum(q0, p1);
+    let (s2, q1, p2) = three_sum(s2, q1, p2);
+    let (s3, q2) = three_sum2(q1, q2, p3);
+    renormalize(&mut [p0, s1, s2, s3, q2 + p2])
+}
+
+// Multiplies two values, keeping the error terms of all partial products
+// down to order eps^3 relative to the result
+fn mul(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    let [a0, a1, a2, a3] = a.words;
+    let [b0, b1, b2, b3] = b.words;
+
+    // Order 1 and eps
+    let (p0, q0) = two_prod(a0, b0);
+    let (p1, q1) = two_prod(a0, b1);
+    let (p2, q2) = two_prod(a1, b0);
+
+    // Order eps^2
+    let (p3, q3) = two_prod(a0, b2);
+    let (p4, q4) = two_prod(a1, b1);
+    let (p5, q5) = two_prod(a2, b0);
+
+    let (p1, p2, q0) = three_sum(p1, p2, q0);
+
+    // Sum p2, q1, q2, p3, p4 and p5 into s0, s1 and s2
+    let (p2, q1, q2) = three_sum(p2, q1, q2);
+    let (p3, p4, p5) = three_sum(p3, p4, p5);
+    let (s0, t0) = two_sum(p2, p3);
+    let (s1, t1) = two_sum(q1, p4);
+    let s2 = q2 + p5;
+    let (s1, t0) = two_sum(s1, t0);
+    let s2 = s2 + (t0 + t1);
+
+    // Order eps^3
+    let (p6, q6) = two_prod(a0, b3);
+    let (p7, q7) = two_prod(a1, b2);
+    let (p8, q8) = two_prod(a2, b1);
+    let (p9, q9) = two_prod(a3, b0);
+
+    // Sum q0, s1, q3, q4, q5, p6, p7, p8 and p9 into t0 and t1
+    let (q0, q3) = two_sum(q0, q3);
+    let (q4, q5) = two_sum(q4, q5);
+    let (p6, p7) = two_sum(p6, p7);
+    let (p8, p9) = two_sum(p8, p9);
+    let (t0, t1) = two_sum(q0, q4);
+    let t1 = t1 + (q3 + q5);
+    let (r0, r1) = two_sum(p6, p8);
+    let r1 = r1 + (p7 + p9);
+    let (q3, q4) = two_sum(t0, r0);
+    let q4 = q4 + (t1 + r1);
+    let (t0, t1) = two_sum(q3, s1);
+
+    // Order eps^4
+    let t1 = t1 + q4 + (a1 * b3 + a2 * b2 + a3 * b1) + (q6 + q7 + q8 + q9) + s2;
+
+    renormalize(&mut [p0, p1, s0, t0, t1])
+}
+
+// Long division following Hida, Li and Bailey (2001): each word of the
+// quotient is the leading word of the remainder divided by the leading word
+// of the divisor, and the product of the divisor and that word is subtracted
+// from the remainder before the next step
+fn div(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    let mut quotient = [0.0; 5];
+    let mut remainder = *a;
+    quotient[0] = a.words[0] / b.words[0];
+    for i in 1..5 {
+        remainder = add(&remainder, &-mul_f64(b, quotient[i - 1]));
+        quotient[i] = remainder.words[0] / b.words[0];
+    }
+
+    if !quotient[0].is_finite() || quotient[0] == 0.0 {
+        QuadFloat::from(quotient[0])
+    } else {
+        renormalize(&mut quotient)
+    }
+}
+
+// Computes a*b + c by adding the exact partial products and the addend into
+// an expansion of non-overlapping terms, which is then renormalized
+fn mul_add(a: &QuadFloat, b: &QuadFloat, c: &QuadFloat) -> QuadFloat {
+    let leading = a.words[0] * b.words[0] + c.words[0];
+    if !leading.is_finite() {
+        return QuadFloat::from(leading);
+    }
+
+    let mut terms = [0.0; FMA_TERMS];
+    for (i, &x) in a.words.iter().enumerate() {
+        for (j, &y) in b.words.iter().enumerate() {
+            let (p, q) = two_prod(x, y);
+            terms[8 * i + 2 * j] = p;
+            terms[8 * i + 2 * j + 1] = q;
+        }
+    }
+    terms[32..].copy_from_slice(&c.words);
+
+    // Grow the expansion one term at a time, keeping its components in
+    // increasing order of magnitude without zeros
+    let mut expansion = [0.0; FMA_TERMS];
+    let mut length = 0;
+    for &term in terms.iter() {
+        let mut q = term;
+        let mut k = 0;
+        for i in 0..length {
+            let (s, e) = two_sum(q, expansion[i]);
+            q = s;
+            if e != 0.0 {
+                expansion[k] = e;
+                k += 1;
+            }
+        }
+        if q != 0.0 {
+            expansion[k] = q;
+            k += 1;
+        }
+        length = k;
+    }
+
+    if length == 0 {
+        return QuadFloat::from(leading);
+    }
+
+    let expansion = &mut expansion[..length];
+    expansion.reverse();
+    let result = renormalize(expansion);
+    if result.words[0].is_finite() {
+        result
+    } else {
+        QuadFloat::from(leading)
+    }
+}
+
+impl QuadFloat {
+    /// Computes `(self * a) + b` with a single rounding, so that no precision
+    /// is lost when the product and the addend cancel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1.0) / 3.0;
+    /// let b = -(a * a);
+    ///
+    /// // The rounding error of the product is retained
+    /// let c = a.mul_add(a, b);
+    /// assert!(c != 0.0 && c.abs() < 1e-63);
+    /// assert_eq!(a * a + b, 0.0);
+    /// ```
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        mul_add(&self, &a, &b)
+    }
+}
+
+fn rem(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    let quotient = div(a, b).trunc();
+    add(a, &-mul(&quotient, b))
+}
+
+unary_ops! {
+    fn Neg::neg(self: &QuadFloat) -> QuadFloat {
+        let [a0, a1, a2, a3] = self.words;
+        // Zero lower words stay positive, as for `TwoFloat`
+        QuadFloat {
+            words: [-a0, 0.0 - a1, 0.0 - a2, 0.0 - a3],
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of `QuadFloat` and `f64` by propagating the error
+    /// of each word into the next.
+    fn Add::add<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        add_f64(self, *rhs)
+    }
+
+    /// Implements addition of `f64` and `QuadFloat` by propagating the error
+    /// of each word into the next.
+    fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        add_f64(rhs, *self)
+    }
+
+    /// Implements addition of `QuadFloat` and `TwoFloat`.
+    fn Add::add<'a, 'b>(self: &'a QuadFloat, rhs: &'b TwoFloat) -> QuadFloat {
+        add(self, &QuadFloat::from(*rhs))
+    }
+
+    /// Implements addition of `TwoFloat` and `QuadFloat`.
+    fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        add(&QuadFloat::from(*self), rhs)
+    }
+
+    /// Implements addition of two `QuadFloat` values by merging their words
+    /// in order of decreasing magnitude.
+    fn Add::add<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        add(self, rhs)
+    }
+
+    /// Implements subtraction of `QuadFloat` and `f64` by propagating the
+    /// error of each word into the next.
+    fn Sub::sub<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        add_f64(self, -rhs)
+    }
+
+    /// Implements subtraction of `f64` and `QuadFloat` by propagating the
+    /// error of each word into the next.
+    fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        add_f64(&-rhs, *self)
+    }
+
+    /// Implements subtraction of `QuadFloat` and `TwoFloat`.
+    fn Sub::sub<'a, 'b>(self: &'a QuadFloat, rhs: &'b TwoFloat) -> QuadFloat {
+        add(self, &QuadFloat::from(-rhs))
+    }
+
+    /// Implements subtraction of `TwoFloat` and `QuadFloat`.
+    fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        add(&QuadFloat::from(*self), &-rhs)
+    }
+
+    /// Implements subtraction of two `QuadFloat` values by merging their
+    /// words in order of decreasing magnitude.
+    fn Sub::sub<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        add(self, &-rhs)
+    }
+
+    /// Implements multiplication of `QuadFloat` and `f64`.
+    fn Mul::mul<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        mul_f64(self, *rhs)
+    }
+
+    /// Implements multiplication of `f64` and `QuadFloat`.
+    fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        mul_f64(rhs, *self)
+    }
+
+    /// Implements multiplication of `QuadFloat` and `TwoFloat`.
+    fn Mul::mul<'a, 'b>(self: &'a QuadFloat, rhs: &'b TwoFloat) -> QuadFloat {
+        mul(self, &QuadFloat::from(*rhs))
+    }
+
+    /// Implements multiplication of `TwoFloat` and `QuadFloat`.
+    fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        mul(&QuadFloat::from(*self), rhs)
+    }
+
+    /// Implements multiplication of two `QuadFloat` values, keeping the
+    /// error terms of the partial products down to order eps^3.
+    fn Mul::mul<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        mul(self, rhs)
+    }
+
+    /// Implements division of `QuadFloat` and `f64` following Hida, Li and
+    /// Bailey (2001), computing each word of the quotient from the remainder of
+    /// the previous words.
+    fn Div::div<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        div(self, &QuadFloat::from(*rhs))
+    }
+
+    /// Implements division of `f64` and `QuadFloat` following Hida, Li and
+    /// Bailey (2001), computing each word of the quotient from the remainder of
+    /// the previous words.
+    fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        div(&QuadFloat::from(*self), rhs)
+    }
+
+    /// Implements division of `QuadFloat` and `TwoFloat` following Hida, Li and
+    /// Bailey (2001), computing each word of the quotient from the remainder of
+    /// the previous words.
+    fn Div::div<'a, 'b>(self: &'a QuadFloat, rhs: &'b TwoFloat) -> QuadFloat {
+        div(self, &QuadFloat::from(*rhs))
+    }
+
+    /// Implements division of `TwoFloat` and `QuadFloat` following Hida, Li and
+    /// Bailey (2001), computing each word of the quotient from the remainder of
+    /// the previous words.
+    fn Div::div<'a, 'b>(self: &'a TwoFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        div(&QuadFloat::from(*self), rhs)
+    }
+
+    /// Implements division of two `QuadFloat` values following Hida, Li and
+    /// Bailey (2001), computing each word of the quotient from the remainder of
+    /// the previous words.
+    fn Div::div<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        div(self, rhs)
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        rem(self, &QuadFloat::from(*rhs))
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        rem(&QuadFloat::from(*self), rhs)
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a QuadFloat, rhs: &'b TwoFloat) -> QuadFloat {
+        rem(self, &QuadFloat::from(*rhs))
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a TwoFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        rem(&QuadFloat::from(*self), rhs)
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        rem(self, rhs)
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = add_f64(self, *rhs);
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut QuadFloat, rhs: &'a TwoFloat) {
+        *self = add(self, &QuadFloat::from(*rhs));
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = add(self, rhs);
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = add_f64(self, -rhs);
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut QuadFloat, rhs: &'a TwoFloat) {
+        *self = add(self, &QuadFloat::from(-rhs));
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = add(self, &-rhs);
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = mul_f64(self, *rhs);
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut QuadFloat, rhs: &'a TwoFloat) {
+        *self = mul(self, &QuadFloat::from(*rhs));
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = mul(self, rhs);
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = div(self, &QuadFloat::from(*rhs));
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut QuadFloat, rhs: &'a TwoFloat) {
+        *self = div(self, &QuadFloat::from(*rhs));
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = div(self, rhs);
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = rem(self, &QuadFloat::from(*rhs));
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut QuadFloat, rhs: &'a TwoFloat) {
+        *self = rem(self, &QuadFloat::from(*rhs));
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = rem(self, rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add, renormalize};
+    use crate::QuadFloat;
+
+    #[test]
+    fn renormalize_test() {
+        let result = renormalize(&mut [1.0, 1e-17, 1e-34, 0.0, 1e-60]);
+        assert!(result.is_valid());
+        assert_eq!(result.hi(), 1.0);
+
+        let result = renormalize(&mut [1.0, 0.0, 0.0, 0.0, 2f64.powi(-300)]);
+        assert_eq!(result.words(), [1.0, 2f64.powi(-300), 0.0, 0.0]);
+    }
+
+    #[test]
+    fn add_cancellation_test() {
+        let a = QuadFloat {
+            words: [1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180)],
+        };
+        let b = QuadFloat {
+            words: [-1.0, -2f64.powi(-60), -2f64.powi(-120), 2f64.powi(-300)],
+        };
+
+        let result = add(&a, &b);
+        assert_eq!(result.words(), [2f64.powi(-180), 2f64.powi(-300), 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,263 @@
+use hexf::hexf64;
+
+use crate::QuadFloat;
+
+// Values determined using mpmath, with each word the nearest double to the
+// remainder of the previous words.
+
+/// Euler's number (e)
+pub(crate) const E: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.5bf0a8b145769p+1"),
+        hexf64!("0x1.4d57ee2b1013ap-53"),
+        hexf64!("-0x1.618713a31d3e2p-109"),
+        hexf64!("0x1.c5a6d2b53c26dp-163"),
+    ],
+};
+
+/// 1/π
+pub(crate) const FRAC_1_PI: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.45f306dc9c883p-2"),
+        hexf64!("-0x1.6b01ec5417056p-56"),
+        hexf64!("-0x1.6447e493ad4cep-110"),
+        hexf64!("0x1.e21c820ff28b2p-164"),
+    ],
+};
+
+/// 2/π
+pub(crate) const FRAC_2_PI: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.45f306dc9c883p-1"),
+        hexf64!("-0x1.6b01ec5417056p-55"),
+        hexf64!("-0x1.6447e493ad4cep-109"),
+        hexf64!("0x1.e21c820ff28b2p-163"),
+    ],
+};
+
+/// 2/sqrt(π)
+pub(crate) const FRAC_2_SQRT_PI: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.20dd750429b6dp+0"),
+        hexf64!("0x1.1ae3a914fed80p-56"),
+        hexf64!("-0x1.3cbbebf65f145p-111"),
+        hexf64!("-0x1.e0c574632f53ep-166"),
+    ],
+};
+
+/// 1/sqrt(2)
+pub(crate) const FRAC_1_SQRT_2: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.6a09e667f3bcdp-1"),
+        hexf64!("-0x1.bdd3413b26456p-55"),
+        hexf64!("0x1.57d3e3adec175p-109"),
+        hexf64!("0x1.2775099da2f59p-165"),
+    ],
+};
+
+/// π/2
+pub(crate) const FRAC_PI_2: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.921fb54442d18p+0"),
+        hexf64!("0x1.1a62633145c07p-54"),
+        hexf64!("-0x1.f1976b7ed8fbcp-110"),
+        hexf64!("0x1.4cf98e804177dp-164"),
+    ],
+};
+
+/// π/3
+pub(crate) const FRAC_PI_3: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.0c152382d7366p+0"),
+        hexf64!("-0x1.ee6913347c2a6p-54"),
+        hexf64!("-0x1.4bba47a9e5fd2p-110"),
+        hexf64!("-0x1.ccaef65529b02p-164"),
+    ],
+};
+
+/// π/4
+pub(crate) const FRAC_PI_4: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.921fb54442d18p-1"),
+        hexf64!("0x1.1a62633145c07p-55"),
+        hexf64!("-0x1.f1976b7ed8fbcp-111"),
+        hexf64!("0x1.4cf98e804177dp-165"),
+    ],
+};
+
+/// π/6
+pub(crate) const FRAC_PI_6: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.0c152382d7366p-1"),
+        hexf64!("-0x1.ee6913347c2a6p-55"),
+        hexf64!("-0x1.4bba47a9e5fd2p-111"),
+        hexf64!("-0x1.ccaef65529b02p-165"),
+    ],
+};
+
+/// π/8
+pub(crate) const FRAC_PI_8: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.921fb54442d18p-2"),
+        hexf64!("0x1.1a62633145c07p-56"),
+        hexf64!("-0x1.f1976b7ed8fbcp-112"),
+        hexf64!("0x1.4cf98e804177dp-166"),
+    ],
+};
+
+/// ln(10)
+pub(crate) const LN_10: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.26bb1bbb55516p+1"),
+        hexf64!("-0x1.f48ad494ea3e9p-53"),
+        hexf64!("-0x1.9ebae3ae0260cp-107"),
+        hexf64!("-0x1.2d10378be1cf1p-161"),
+    ],
+};
+
+/// ln(2)
+pub(crate) const LN_2: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.62e42fefa39efp-1"),
+        hexf64!("0x1.abc9e3b39803fp-56"),
+        hexf64!("0x1.7b57a079a1934p-111"),
+        hexf64!("-0x1.ace93a4ebe5d1p-165"),
+    ],
+};
+
+/// log<sub>10</sub>(e)
+pub(crate) const LOG10_E: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.bcb7b1526e50ep-2"),
+        hexf64!("0x1.95355baaafad3p-57"),
+        hexf64!("0x1.ee191f71a3012p-112"),
+        hexf64!("0x1.7268808e8fcb5p-167"),
+    ],
+};
+
+/// log<sub>2</sub>(e)
+pub(crate) const LOG2_E: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.71547652b82fep+0"),
+        hexf64!("0x1.777d0ffda0d24p-56"),
+        hexf64!("-0x1.60bb8a5442ab9p-110"),
+        hexf64!("-0x1.4b52d3ba6d74dp-166"),
+    ],
+};
+
+/// Archimedes' constant (π)
+pub(crate) const PI: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.921fb54442d18p+1"),
+        hexf64!("0x1.1a62633145c07p-53"),
+        hexf64!("-0x1.f1976b7ed8fbcp-109"),
+        hexf64!("0x1.4cf98e804177dp-163"),
+    ],
+};
+
+/// sqrt(2)
+pub(crate) const SQRT_2: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.6a09e667f3bcdp+0"),
+        hexf64!("-0x1.bdd3413b26456p-54"),
+        hexf64!("0x1.57d3e3adec175p-108"),
+        hexf64!("0x1.2775099da2f59p-164"),
+    ],
+};
+
+/// The full circle constant (τ)
+pub(crate) const TAU: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.921fb54442d18p+2"),
+        hexf64!("0x1.1a62633145c07p-52"),
+        hexf64!("-0x1.f1976b7ed8fbcp-108"),
+        hexf64!("0x1.4cf98e804177dp-162"),
+    ],
+};
+
+/// log<sub>10</sub>(2)
+pub(crate) const LOG10_2: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.34413509f79ffp-2"),
+        hexf64!("-0x1.9dc1da994fd21p-59"),
+        hexf64!("0x1.22f04d5a618a8p-114"),
+        hexf64!("0x1.e8f9a4c52f379p-168"),
+    ],
+};
+
+/// log<sub>2</sub>(10)
+pub(crate) const LOG2_10: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.a934f0979a371p+1"),
+        hexf64!("0x1.7f2495fb7fa6dp-53"),
+        hexf64!("0x1.fb699b2d8abfcp-107"),
+        hexf64!("0x1.bd9d6a748db56p-161"),
+    ],
+};
+
+pub(crate) const DEG_PER_RAD: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.ca5dc1a63c1f8p+5"),
+        hexf64!("-0x1.1e7ab456405f9p-49"),
+        hexf64!("-0x1.b505196fabb41p-103"),
+        hexf64!("-0x1.a07e91992ec5fp-161"),
+    ],
+};
+
+pub(crate) const RAD_PER_DEG: QuadFloat = QuadFloat {
+    words: [
+        hexf64!("0x1.1df46a2529d39p-6"),
+        hexf64!("0x1.5c1d8becdd291p-62"),
+        hexf64!("-0x1.1d937fa428858p-116"),
+        hexf64!("0x1.b5e6b8e502a9bp-173"),
+    ],
+};
+
+// Fifth word of ln(2), used in the argument reduction of exp
+pub(crate) const LN_2_TAIL: f64 = hexf64!("-0x1.23a2a82ea0c24p-219");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! const_check {
+        ($($name:ident),+) => {
+            $(
+                assert!($name.is_valid(), "{} is not valid", stringify!($name));
+                assert_eq!(
+                    $name.hi(),
+                    core::f64::consts::$name,
+                    "Incorrect leading word of {}",
+                    stringify!($name)
+                );
+            )+
+        };
+    }
+
+    #[test]
+    fn const_test() {
+        const_check!(
+            E,
+            FRAC_1_PI,
+            FRAC_2_PI,
+            FRAC_2_SQRT_PI,
+            FRAC_1_SQRT_2,
+            FRAC_PI_2,
+            FRAC_PI_3,
+            FRAC_PI_4,
+            FRAC_PI_6,
+            FRAC_PI_8,
+            LN_2,
+            LN_10,
+            LOG2_10,
+            LOG2_E,
+            LOG10_2,
+            LOG10_E,
+            PI,
+            SQRT_2,
+            TAU
+        );
+        assert!(DEG_PER_RAD.is_valid());
+        assert!(RAD_PER_DEG.is_valid());
+    }
+}
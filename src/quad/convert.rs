@@ -0,0 +1,197 @@
+use core::convert::{From, TryFrom};
+
+use hexf::hexf64;
+
+use crate::{
+    arithmetic::{fast_two_sum, special_value},
+    base::no_overlap,
+    QuadFloat, TwoFloat, TwoFloatError,
+};
+
+// Number of bits in each word when converting integers exactly
+const INTEGER_WORD_BITS: u32 = 53;
+
+const TWO_POW_128: f64 = hexf64!("0x1.0p128");
+
+impl From<TwoFloat> for QuadFloat {
+    fn from(value: TwoFloat) -> Self {
+        Self {
+            words: [value.hi, value.lo, 0.0, 0.0],
+        }
+    }
+}
+
+impl<'a> From<&'a TwoFloat> for QuadFloat {
+    fn from(value: &'a TwoFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<QuadFloat> for TwoFloat {
+    /// Rounds the value to the nearest `TwoFloat`.
+    fn from(value: QuadFloat) -> Self {
+        let [a0, a1, a2, a3] = value.words;
+        if !a0.is_finite() {
+            return special_value(a0);
+        }
+
+        // The sum may round up to infinity for values close to `MAX`
+        let result = fast_two_sum(a0, a1 + (a2 + a3));
+        if result.hi.is_finite() {
+            result
+        } else {
+            special_value(result.hi)
+        }
+    }
+}
+
+impl<'a> From<&'a QuadFloat> for TwoFloat {
+    fn from(value: &'a QuadFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<QuadFloat> for [f64; 4] {
+    fn from(value: QuadFloat) -> Self {
+        value.words
+    }
+}
+
+impl TryFrom<[f64; 4]> for QuadFloat {
+    type Error = TwoFloatError;
+
+    fn try_from(value: [f64; 4]) -> Result<Self, Self::Error> {
+        if value.windows(2).all(|w| no_overlap(w[0], w[1])) {
+            Ok(Self { words: value })
+        } else {
+            Err(Self::Error::ConversionError {})
+        }
+    }
+}
+
+macro_rules! float_convert {
+    ($type:tt) => {
+        impl From<$type> for QuadFloat {
+            fn from(value: $type) -> Self {
+                Self::from_f64(value as f64)
+            }
+        }
+
+        impl From<QuadFloat> for $type {
+            fn from(value: QuadFloat) -> Self {
+                value.words[0] as $type
+            }
+        }
+
+        impl<'a> From<&'a QuadFloat> for $type {
+            fn from(value: &'a QuadFloat) -> Self {
+                value.words[0] as $type
+            }
+        }
+    };
+}
+
+float_convert!(f64);
+float_convert!(f32);
+
+// Converts the magnitude of an integer exactly by splitting it into words of
+// at most 53 bits
+fn from_magnitude(magnitude: u128, negative: bool) -> QuadFloat {
+    let sign = if negative { -1.0 } else { 1.0 };
+    let mask = (1u128 << INTEGER_WORD_BITS) - 1;
+    let mut terms = [0.0; 3];
+    for (i, term) in terms.iter_mut().enumerate() {
+        let shift = INTEGER_WORD_BITS * (2 - i as u32);
+        let chunk = (magnitude >> shift) & mask;
+        *term = sign * libm::ldexp(chunk as f64, shift as i32);
+    }
+
+    super::renormalize(&mut terms)
+}
+
+// Sums the words of an integer value modulo 2^128
+fn to_wrapping(value: &QuadFloat) -> u128 {
+    value.words.iter().fold(0u128, |acc, &w| {
+        let magnitude = libm::fmod(w.abs(), TWO_POW_128) as u128;
+        if w >= 0.0 {
+            acc.wrapping_add(magnitude)
+        } else {
+            acc.wrapping_sub(magnitude)
+        }
+    })
+}
+
+macro_rules! int_convert {
+    ($type:tt, $value:ident => $split:expr) => {
+        impl From<$type> for QuadFloat {
+            fn from($value: $type) -> Self {
+                let (magnitude, negative) = $split;
+                from_magnitude(magnitude, negative)
+            }
+        }
+
+        impl TryFrom<QuadFloat> for $type {
+            type Error = TwoFloatError;
+
+            fn try_from(value: QuadFloat) -> Result<Self, Self::Error> {
+                let truncated = value.trunc();
+                if truncated >= QuadFloat::from($type::MIN)
+                    && truncated <= QuadFloat::from($type::MAX)
+                {
+                    Ok(to_wrapping(&truncated) as $type)
+                } else {
+                    Err(Self::Error::ConversionError {})
+                }
+            }
+        }
+
+        impl<'a> TryFrom<&'a QuadFloat> for $type {
+            type Error = TwoFloatError;
+
+            fn try_from(value: &'a QuadFloat) -> Result<Self, Self::Error> {
+                Self::try_from(*value)
+            }
+        }
+    };
+    (signed $($type:tt),+) => {
+        $(int_convert!($type, value => (value.unsigned_abs() as u128, value < 0));)+
+    };
+    (unsigned $($type:tt),+) => {
+        $(int_convert!($type, value => (value as u128, false));)+
+    };
+}
+
+int_convert!(signed i8, i16, i32, i64, i128);
+int_convert!(unsigned u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use crate::{QuadFloat, TwoFloat};
+
+    #[test]
+    fn integer_test() {
+        let value = QuadFloat::from(i128::MAX);
+        assert!(value.is_valid());
+        assert_eq!(i128::try_from(value), Ok(i128::MAX));
+        assert_eq!(i128::try_from(QuadFloat::from(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(u128::try_from(QuadFloat::from(u128::MAX)), Ok(u128::MAX));
+        assert!(u128::try_from(QuadFloat::from(u128::MAX) + 1.0).is_err());
+        assert!(u8::try_from(QuadFloat::from(-1.0)).is_err());
+        assert_eq!(i32::try_from(QuadFloat::from(3.0) - 1e-200), Ok(2));
+        assert_eq!(i64::try_from(QuadFloat::from(-7.5)), Ok(-7));
+    }
+
+    #[test]
+    fn twofloat_test() {
+        let a = TwoFloat::new_add(1.0, 1e-20);
+        assert_eq!(TwoFloat::from(QuadFloat::from(a)), a);
+
+        let b = (QuadFloat::from(a) + 2f64.powi(-130)) + 2f64.powi(-180);
+        assert_eq!(
+            TwoFloat::from(b),
+            TwoFloat::new_add(1.0, 1e-20 + 2f64.powi(-130))
+        );
+    }
+}
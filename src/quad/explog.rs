@@ -0,0 +1,308 @@
+use hexf::hexf64;
+
+use super::consts::{LN_2, LN_2_TAIL, LOG10_2, LOG10_E, LOG2_E};
+use crate::{QuadFloat, TwoFloat};
+
+// limits
+const EXP_UPPER_LIMIT: f64 = 709.79;
+const EXP_LOWER_LIMIT: f64 = -745.2;
+
+// The reduced argument of the exponential is halved this many times before
+// the series is summed, and the result doubled back up
+const EXP_HALVINGS: usize = 8;
+
+// Series terms are summed until they fall below this fraction of the first
+const SERIES_TOLERANCE: f64 = hexf64!("0x1.0p-216");
+
+/// Multiplies each word by `2^n`.
+pub(super) fn mul_pow2(x: QuadFloat, n: i32) -> QuadFloat {
+    QuadFloat {
+        words: x.words.map(|w| libm::scalbn(w, n)),
+    }
+}
+
+/// Computes `exp(x) - 1` for `|x| <= ln(2)/2` by summing the Taylor series of
+/// `x / 2^k`, then applying `expm1(2x) = expm1(x) * (expm1(x) + 2)`.
+fn expm1_reduced(x: QuadFloat) -> QuadFloat {
+    let x = mul_pow2(x, -(EXP_HALVINGS as i32));
+    let threshold = x.words[0].abs() * SERIES_TOLERANCE;
+    let mut term = x;
+    let mut sum = x;
+    let mut k = 1.0;
+    while term.words[0].abs() > threshold {
+        k += 1.0;
+        term = term * x / k;
+        sum += term;
+    }
+
+    for _ in 0..EXP_HALVINGS {
+        sum = sum * (sum + 2.0);
+    }
+    sum
+}
+
+/// Writes `x = 2^e * (1 + t)` with `1 + t` in the range [√½, √2), returning
+/// `e` and `t`. The subtraction is exact.
+fn split_exponent(x: QuadFloat) -> (i32, QuadFloat) {
+    let mut e = libm::ilogb(x.words[0]);
+    let mut m = mul_pow2(x, -e);
+    if m.words[0] > core::f64::consts::SQRT_2 {
+        e += 1;
+        m = mul_pow2(m, -1);
+    }
+    (e, m - 1.0)
+}
+
+impl QuadFloat {
+    /// Returns `e^(self)`, (the exponential function).
+    ///
+    /// The argument is reduced by a multiple of ln(2), which is subtracted
+    /// exactly, and the exponential of the remainder is computed from its
+    /// Taylor series.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.0).exp();
+    /// let b = QuadFloat::from(1.0).exp().powi(2);
+    ///
+    /// assert!(((a - b) / a).abs() < 1e-62);
+    /// ```
+    pub fn exp(self) -> Self {
+        let x0 = self.words[0];
+        if x0.is_nan() {
+            Self::NAN
+        } else if x0 <= EXP_LOWER_LIMIT {
+            Self::from(0.0)
+        } else if x0 >= EXP_UPPER_LIMIT {
+            Self::INFINITY
+        } else if x0 == 0.0 {
+            Self::from(1.0)
+        } else {
+            // x = n ln(2) + r, where each product with a word of ln(2) is
+            // exact as n has at most 11 bits
+            let n = libm::round(x0 / LN_2.words[0]);
+            let mut r = self;
+            for &w in &LN_2.words {
+                r -= TwoFloat::new_mul(n, w);
+            }
+            r -= n * LN_2_TAIL;
+
+            let result = mul_pow2(expm1_reduced(r) + 1.0, n as i32);
+            if result.words[0].is_infinite() {
+                Self::INFINITY
+            } else {
+                result
+            }
+        }
+    }
+
+    /// Returns `e^(self) - 1` in a way that provides additional accuracy
+    /// when the value is close to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1e-100);
+    /// let b = a.exp_m1();
+    ///
+    /// assert!(((b - a) / a - 5e-101).abs() < 1e-162);
+    /// ```
+    pub fn exp_m1(self) -> Self {
+        if self.words[0].is_nan() {
+            Self::NAN
+        } else if self.words[0].abs() <= LN_2.words[0] / 2.0 {
+            expm1_reduced(self)
+        } else {
+            self.exp() - 1.0
+        }
+    }
+
+    /// Returns `2^(self)`.
+    ///
+    /// The integer part of the argument is applied exactly, so integer
+    /// values give exact results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// assert_eq!(QuadFloat::from(-3.0).exp2(), 0.125);
+    ///
+    /// let a = QuadFloat::from(0.5).exp2();
+    /// assert!((a - QuadFloat::from(2.0).sqrt()).abs() < 1e-62);
+    /// ```
+    pub fn exp2(self) -> Self {
+        let x0 = self.words[0];
+        if x0.is_nan() {
+            Self::NAN
+        } else if x0 < -1080.0 {
+            Self::from(0.0)
+        } else if x0 >= 1024.0 {
+            Self::INFINITY
+        } else {
+            let n = libm::round(x0);
+            let r = self - n;
+            let result = if r == 0.0 {
+                Self::from(1.0)
+            } else {
+                (r * LN_2).exp()
+            };
+            mul_pow2(result, n as i32)
+        }
+    }
+
+    /// Returns the natural logarithm of the value.
+    ///
+    /// The binary exponent is removed and the logarithm of the remaining
+    /// value is computed with `ln_1p`. Returns negative infinity for zero and
+    /// NaN for negative values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1.0).exp().ln();
+    ///
+    /// assert!((a - 1.0).abs() < 1e-62);
+    /// ```
+    pub fn ln(self) -> Self {
+        match self.log_special() {
+            Some(result) => result,
+            None => {
+                let (e, t) = split_exponent(self);
+                t.ln_1p() + LN_2 * e as f64
+            }
+        }
+    }
+
+    fn log_special(self) -> Option<Self> {
+        let x0 = self.words[0];
+        if x0.is_nan() || x0 < 0.0 {
+            Some(Self::NAN)
+        } else if x0 == 0.0 {
+            Some(Self::NEG_INFINITY)
+        } else if x0.is_infinite() {
+            Some(Self::INFINITY)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the natural logarithm of `1 + self`.
+    ///
+    /// Close to zero the `TwoFloat` result is refined by a Newton step using
+    /// `exp_m1`, so the result is accurate for small values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1e-100);
+    /// let b = a.ln_1p();
+    ///
+    /// assert!(((b - a) / a + 5e-101).abs() < 1e-162);
+    /// ```
+    pub fn ln_1p(self) -> Self {
+        let x0 = self.words[0];
+        if x0 == 0.0 {
+            self
+        } else if x0.is_nan() || x0 < -1.0 {
+            Self::NAN
+        } else if x0 > -0.3 && x0 < 0.42 {
+            // A single step doubles the number of correct bits of the
+            // estimate
+            let y = Self::from(TwoFloat::from(self).ln_1p());
+            let e = y.exp_m1();
+            y - (e - self) / (e + 1.0)
+        } else {
+            (self + 1.0).ln()
+        }
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base.
+    ///
+    /// This is a convenience method that computes `self.ln() / base.ln()`, no
+    /// additional accuracy is provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(81.0);
+    /// let b = QuadFloat::from(3.0);
+    /// let c = QuadFloat::log(a, b);
+    ///
+    /// assert!((c - 4.0).abs() < 1e-62);
+    /// ```
+    pub fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    /// Returns the base 2 logarithm of the number.
+    ///
+    /// The binary exponent is added exactly, so powers of two give exact
+    /// results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// assert_eq!(QuadFloat::from(64.0).log2(), 6.0);
+    /// ```
+    pub fn log2(self) -> Self {
+        match self.log_special() {
+            Some(result) => result,
+            None => {
+                let (e, t) = split_exponent(self);
+                t.ln_1p() * LOG2_E + e as f64
+            }
+        }
+    }
+
+    /// Returns the base 10 logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(100.0).log10();
+    ///
+    /// assert!((a - 2.0).abs() < 1e-62);
+    /// ```
+    pub fn log10(self) -> Self {
+        match self.log_special() {
+            Some(result) => result,
+            None => {
+                let (e, t) = split_exponent(self);
+                t.ln_1p() * LOG10_E + LOG10_2 * e as f64
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::QuadFloat;
+
+    #[test]
+    fn exp_limits_test() {
+        assert_eq!(QuadFloat::from(-800.0).exp(), 0.0);
+        assert_eq!(QuadFloat::from(800.0).exp(), QuadFloat::INFINITY);
+        assert_eq!(QuadFloat::from(0.0).exp(), 1.0);
+
+        let a = QuadFloat::from(709.78).exp();
+        assert!(a.is_valid());
+        assert!(((a - 1.7928227943945e308) / a).abs() < 1e-11);
+    }
+
+    #[test]
+    fn ln_special_test() {
+        assert!(QuadFloat::from(-1.0).ln().hi().is_nan());
+        assert_eq!(QuadFloat::from(0.0).ln(), QuadFloat::NEG_INFINITY);
+        assert_eq!(QuadFloat::from(1.0).ln(), 0.0);
+        assert_eq!(QuadFloat::from(-1.0).ln_1p(), QuadFloat::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,138 @@
+use super::renormalize;
+use crate::QuadFloat;
+
+// Returns the index of the first word which is not an integer. As the words
+// do not overlap, the integer part of the value is determined by the words
+// before it and the integer part of this word.
+fn fractional_word(value: &QuadFloat) -> Option<usize> {
+    value.words.iter().position(|&w| libm::modf(w).0 != 0.0)
+}
+
+// Applies the rounding function to the first word which is not an integer,
+// discarding the words after it
+fn integer_part(value: QuadFloat, round: fn(f64) -> f64) -> QuadFloat {
+    match fractional_word(&value) {
+        Some(k) => {
+            let mut terms = [0.0; 4];
+            terms[..k].copy_from_slice(&value.words[..k]);
+            terms[k] = round(value.words[k]);
+            renormalize(&mut terms)
+        }
+        None => value,
+    }
+}
+
+impl QuadFloat {
+    /// Returns the fractional part of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = (QuadFloat::from(1.0) + 1e-200).fract();
+    /// let b = (QuadFloat::from(-1.0) + 1e-200).fract();
+    ///
+    /// assert_eq!(a, QuadFloat::from(1e-200));
+    /// assert_eq!(b, QuadFloat::from(-1.0) + 1e-200);
+    /// ```
+    pub fn fract(self) -> Self {
+        match fractional_word(&self) {
+            Some(_) => self - self.trunc(),
+            None => Self::from(libm::copysign(0.0, self.words[0])),
+        }
+    }
+
+    /// Returns the integer part of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = (QuadFloat::from(1.0) + 1e-200).trunc();
+    /// let b = (QuadFloat::from(1.0) - 1e-200).trunc();
+    ///
+    /// assert_eq!(a, QuadFloat::from(1.0));
+    /// assert_eq!(b, QuadFloat::from(0.0));
+    /// ```
+    pub fn trunc(self) -> Self {
+        if self.is_sign_positive() {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+
+    /// Returns the smallest integer greater than or equal to the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = (QuadFloat::from(1.0) + 1e-200).ceil();
+    /// let b = (QuadFloat::from(1.0) - 1e-200).ceil();
+    /// let c = (QuadFloat::from(-1.0) + 1e-200).ceil();
+    ///
+    /// assert_eq!(a, QuadFloat::from(2.0));
+    /// assert_eq!(b, QuadFloat::from(1.0));
+    /// assert_eq!(c, QuadFloat::from(0.0));
+    /// ```
+    pub fn ceil(self) -> Self {
+        integer_part(self, libm::ceil)
+    }
+
+    /// Returns the smallest integer less than or equal to the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = (QuadFloat::from(1.0) + 1e-200).floor();
+    /// let b = (QuadFloat::from(1.0) - 1e-200).floor();
+    /// let c = (QuadFloat::from(-1.0) + 1e-200).floor();
+    ///
+    /// assert_eq!(a, QuadFloat::from(1.0));
+    /// assert_eq!(b, QuadFloat::from(0.0));
+    /// assert_eq!(c, QuadFloat::from(-1.0));
+    /// ```
+    pub fn floor(self) -> Self {
+        integer_part(self, libm::floor)
+    }
+
+    /// Returns the nearest integer to the value. Round half-way cases away
+    /// from `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = (QuadFloat::from(1.0) + 1e-200).round();
+    /// let b = (QuadFloat::from(1.5) - 1e-200).round();
+    /// let c = QuadFloat::from(-0.5).round();
+    ///
+    /// assert_eq!(a, QuadFloat::from(1.0));
+    /// assert_eq!(b, QuadFloat::from(1.0));
+    /// assert_eq!(c, QuadFloat::from(-1.0));
+    /// ```
+    pub fn round(self) -> Self {
+        let integer = self.trunc();
+        if self.fract().abs() >= 0.5 {
+            integer + self.signum()
+        } else {
+            integer
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::QuadFloat;
+
+    #[test]
+    fn floor_test() {
+        let a = (QuadFloat::from(2f64.powi(80)) + 3.0) + 0.25;
+        assert_eq!(a.floor(), QuadFloat::from(2f64.powi(80)) + 3.0);
+        assert_eq!((-a).floor(), -(QuadFloat::from(2f64.powi(80)) + 4.0));
+        assert_eq!(a.fract(), QuadFloat::from(0.25));
+        assert_eq!((-a).fract(), QuadFloat::from(-0.25));
+    }
+}
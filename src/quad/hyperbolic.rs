@@ -0,0 +1,197 @@
+use hexf::hexf64;
+
+use super::consts::LN_2;
+use crate::QuadFloat;
+
+// Above this value exp(-x) is negligible compared with exp(x)
+const EXP_LARGE: f64 = 80.0;
+
+// Above this value exp(x) overflows, so the result is scaled
+const EXP_OVERFLOW: f64 = 700.0;
+
+// Above this value sqrt(x^2 + 1) and x are indistinguishable
+const SQRT_LARGE: f64 = hexf64!("0x1.0p107");
+
+/// Returns `exp(x) / 2` for `x >= 0`, avoiding overflow for values where the
+/// result is representable.
+fn half_exp(x: QuadFloat) -> QuadFloat {
+    if x.words[0] > EXP_OVERFLOW {
+        (x - LN_2).exp()
+    } else {
+        x.exp() / 2.0
+    }
+}
+
+impl QuadFloat {
+    /// Hyperbolic cosine function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.0);
+    /// let b = a.cosh();
+    /// let c = (a.exp() + (-a).exp()) / 2.0;
+    ///
+    /// assert!((b - c).abs() < 1e-61);
+    /// ```
+    pub fn cosh(self) -> Self {
+        let x = self.abs();
+        if x.words[0] > EXP_LARGE {
+            half_exp(x)
+        } else {
+            let e = x.exp();
+            e / 2.0 + 0.5 / e
+        }
+    }
+
+    /// Hyperbolic sine function.
+    ///
+    /// Small arguments are computed using `exp_m1` to avoid cancellation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1e-10);
+    /// let b = a.sinh();
+    ///
+    /// assert!(((b - a) / a.powi(3) - QuadFloat::from(1.0) / 6.0).abs() < 1e-21);
+    /// ```
+    pub fn sinh(self) -> Self {
+        let x = self.abs();
+        let result = if x.words[0] < 1.0 {
+            // sinh(x) = (expm1(x) + expm1(x) / exp(x)) / 2
+            let e = x.exp_m1();
+            (e + e / (e + 1.0)) / 2.0
+        } else if x.words[0] > EXP_LARGE {
+            half_exp(x)
+        } else {
+            let e = x.exp();
+            e / 2.0 - 0.5 / e
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Hyperbolic tangent function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(0.5);
+    /// let b = a.tanh();
+    ///
+    /// assert!((b - a.sinh() / a.cosh()).abs() < 1e-62);
+    /// assert_eq!(QuadFloat::from(-1000.0).tanh(), -1.0);
+    /// ```
+    pub fn tanh(self) -> Self {
+        let x = self.abs();
+        let result = if x.words[0] > EXP_LARGE {
+            Self::from(1.0)
+        } else {
+            // tanh(x) = expm1(2x) / (expm1(2x) + 2)
+            let e = (x * 2.0).exp_m1();
+            e / (e + 2.0)
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Inverse hyperbolic cosine function. Returns NaN for values less
+    /// than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.0);
+    /// let b = a.acosh();
+    ///
+    /// assert!((b.cosh() - a).abs() < 1e-61);
+    /// ```
+    pub fn acosh(self) -> Self {
+        if self.words[0].is_nan() || self < 1.0 {
+            Self::NAN
+        } else if self.words[0].is_infinite() {
+            Self::INFINITY
+        } else if self.words[0] > SQRT_LARGE {
+            self.ln() + LN_2
+        } else {
+            // acosh(1 + t) = ln(1 + t + sqrt(t * (t + 2)))
+            let t = self - 1.0;
+            (t + (t * (t + 2.0)).sqrt()).ln_1p()
+        }
+    }
+
+    /// Inverse hyperbolic sine function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.0);
+    /// let b = a.asinh();
+    ///
+    /// assert!((b.sinh() - a).abs() < 1e-61);
+    /// ```
+    pub fn asinh(self) -> Self {
+        let x = self.abs();
+        let result = if x.words[0].is_infinite() {
+            Self::INFINITY
+        } else if x.words[0] > SQRT_LARGE {
+            x.ln() + LN_2
+        } else {
+            // asinh(x) = ln(1 + x + x^2 / (1 + sqrt(x^2 + 1)))
+            let x2 = x * x;
+            (x + x2 / ((x2 + 1.0).sqrt() + 1.0)).ln_1p()
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Inverse hyperbolic tangent function. Returns infinity for `±1` and
+    /// NaN for values outside this range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(0.5);
+    /// let b = a.atanh();
+    ///
+    /// assert!((b.tanh() - a).abs() < 1e-62);
+    /// ```
+    pub fn atanh(self) -> Self {
+        let x = self.abs();
+        if x.words[0].is_nan() || x > 1.0 {
+            return Self::NAN;
+        }
+
+        let result = if x == 1.0 {
+            Self::INFINITY
+        } else {
+            // atanh(x) = ln(1 + 2x / (1 - x)) / 2
+            (x * 2.0 / (1.0 - x)).ln_1p() / 2.0
+        };
+
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use core::{convert::TryFrom, num::FpCategory};
+
+use num_traits::{Inv, Pow};
+
+use super::consts;
+use crate::{QuadFloat, TwoFloatError};
+
+float_traits! {
+    type: QuadFloat,
+    float: f64,
+    from_float: QuadFloat::from_f64,
+    consts: consts,
+    from_str_radix: crate::parse::from_str_radix_quad,
+    mul_add: QuadFloat::mul_add,
+    words: |x: QuadFloat| x.words,
+}
@@ -0,0 +1,138 @@
+use super::explog::mul_pow2;
+use crate::{QuadFloat, TwoFloat};
+
+// Number of Newton steps applied to the `TwoFloat` estimate of a root. The
+// first step is only accurate to within a few ulp, as the error of the
+// estimate is squared.
+const ROOT_STEPS: usize = 2;
+
+impl QuadFloat {
+    /// Returns the square root of the number.
+    ///
+    /// The `TwoFloat` square root is refined by Newton's method, where the
+    /// corrections only require `TwoFloat` precision. The value is scaled by
+    /// an even power of two so that the residuals do not underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.0);
+    /// let b = a.sqrt();
+    ///
+    /// assert!((b * b - a).abs() < 1e-62);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        if self.words[0] < 0.0 || self.words[0].is_nan() {
+            Self::NAN
+        } else if self.words[0] == 0.0 || self.words[0].is_infinite() {
+            self
+        } else {
+            let k = libm::ilogb(self.words[0]) / 2;
+            let x = mul_pow2(self, -2 * k);
+            let mut y = Self::from(TwoFloat::from(x).sqrt());
+            for _ in 0..ROOT_STEPS {
+                let correction = TwoFloat::from(x - y * y) / (TwoFloat::from(y) * 2.0);
+                y += correction;
+            }
+            mul_pow2(y, k)
+        }
+    }
+
+    /// Returns the cube root of the number.
+    ///
+    /// The `TwoFloat` cube root is refined by Newton's method, as for
+    /// `sqrt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-2.0);
+    /// let b = a.cbrt();
+    ///
+    /// assert!((b.powi(3) - a).abs() < 1e-62);
+    /// ```
+    pub fn cbrt(self) -> Self {
+        if self.words[0] == 0.0 || !self.words[0].is_finite() {
+            self
+        } else {
+            let k = libm::ilogb(self.words[0]) / 3;
+            let x = mul_pow2(self, -3 * k);
+            let mut y = Self::from(TwoFloat::from(x).cbrt());
+            for _ in 0..ROOT_STEPS {
+                let estimate = TwoFloat::from(y);
+                let correction = TwoFloat::from(x - y * y * y) / (estimate * estimate * 3.0);
+                y += correction;
+            }
+            mul_pow2(y, k)
+        }
+    }
+
+    /// Calculates the length of the hypotenuse of a right-angle triangle
+    /// given legs of length `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(3.0e300);
+    /// let b = QuadFloat::from(4.0e300);
+    /// let c = QuadFloat::hypot(a, b);
+    ///
+    /// assert!(((c - 5.0e300) / 5.0e300).abs() < 1e-62);
+    /// ```
+    pub fn hypot(self, other: Self) -> Self {
+        if self.words[0].is_infinite() || other.words[0].is_infinite() {
+            return Self::INFINITY;
+        }
+
+        // Scale by a power of two so that the squares neither overflow nor
+        // underflow
+        let max = libm::fmax(self.words[0].abs(), other.words[0].abs());
+        if max == 0.0 || !max.is_finite() {
+            return (self * self + other * other).sqrt();
+        }
+
+        let exponent = libm::ilogb(max).clamp(-1022, 1023);
+        let scale = libm::ldexp(1.0, -exponent);
+        let (x, y) = (self * scale, other * scale);
+        (x * x + y * y).sqrt() * libm::ldexp(1.0, exponent)
+    }
+
+    /// Returns the value raised to the power `y`, computed as `exp(y ln(x))`.
+    ///
+    /// Negative values may be raised to integer powers. Where possible
+    /// `powi`, `sqrt` or `cbrt` should be preferred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-5.0);
+    /// let b = QuadFloat::from(3.0);
+    /// let c = a.powf(b);
+    ///
+    /// assert!((c + 125.0).abs() < 1e-58);
+    /// ```
+    pub fn powf(self, y: Self) -> Self {
+        match (self == 0.0, y == 0.0) {
+            (_, true) => Self::from(1.0),
+            (true, false) => Self::from(0.0),
+            (false, false) => {
+                if self.is_sign_positive() {
+                    (y * self.ln()).exp()
+                } else if y.fract() != 0.0 {
+                    Self::NAN
+                } else {
+                    let abs_result = (y * self.abs().ln()).exp();
+                    if (y * 0.5).fract() == 0.0 {
+                        abs_result
+                    } else {
+                        -abs_result
+                    }
+                }
+            }
+        }
+    }
+}
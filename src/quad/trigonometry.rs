@@ -0,0 +1,297 @@
+use hexf::hexf64;
+
+use super::consts::{FRAC_PI_2, PI};
+use crate::{functions::reduction::rem_frac_pi_2_quad, QuadFloat, TwoFloat};
+
+// Series terms are summed until they fall below this fraction of the first
+const SERIES_TOLERANCE: f64 = hexf64!("0x1.0p-216");
+
+fn quadrant(value: QuadFloat) -> (QuadFloat, u8) {
+    if value.words[0].abs() < core::f64::consts::FRAC_PI_4 {
+        (value, 0)
+    } else {
+        rem_frac_pi_2_quad(value)
+    }
+}
+
+// Sums the Taylor series with alternating signs starting from the given
+// term, where each term is the previous multiplied by x^2 / (k (k + 1))
+fn taylor_series(first: QuadFloat, x2: QuadFloat, mut k: f64) -> QuadFloat {
+    let threshold = first.words[0].abs() * SERIES_TOLERANCE;
+    let mut term = first;
+    let mut sum = first;
+    while term.words[0].abs() > threshold {
+        term = -term * x2 / (k * (k + 1.0));
+        sum += term;
+        k += 2.0;
+    }
+    sum
+}
+
+fn restricted_sin(x: QuadFloat) -> QuadFloat {
+    taylor_series(x, x * x, 2.0)
+}
+
+fn restricted_cos(x: QuadFloat) -> QuadFloat {
+    taylor_series(QuadFloat::from(1.0), x * x, 1.0)
+}
+
+fn sin_quadrant(x: QuadFloat, quadrant: u8) -> QuadFloat {
+    match quadrant {
+        0 => restricted_sin(x),
+        1 => restricted_cos(x),
+        2 => -restricted_sin(x),
+        _ => -restricted_cos(x),
+    }
+}
+
+fn cos_quadrant(x: QuadFloat, quadrant: u8) -> QuadFloat {
+    match quadrant {
+        0 => restricted_cos(x),
+        1 => -restricted_sin(x),
+        2 => -restricted_cos(x),
+        _ => restricted_sin(x),
+    }
+}
+
+fn sin_cos_quadrant(x: QuadFloat, quadrant: u8) -> (QuadFloat, QuadFloat) {
+    let s = restricted_sin(x);
+    let c = restricted_cos(x);
+    match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+// Replaces infinite coordinates by ±1 and finite coordinates by zero, which
+// does not change the angle when either coordinate is infinite
+fn unit_direction(y: QuadFloat, x: QuadFloat) -> (QuadFloat, QuadFloat) {
+    let direction = |v: QuadFloat| {
+        let magnitude = if v.words[0].is_infinite() { 1.0 } else { 0.0 };
+        QuadFloat::from(libm::copysign(magnitude, v.words[0]))
+    };
+    (direction(y), direction(x))
+}
+
+impl QuadFloat {
+    /// Computes the sine of the value (in radians).
+    ///
+    /// Arguments are reduced using the Payne–Hanek algorithm, which leaves
+    /// an absolute error of around 2^-380 in the reduced argument.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.5);
+    /// let b = a.sin();
+    /// let c = 2.5f64.sin();
+    ///
+    /// assert!((b - c).abs() < 1e-15);
+    /// ```
+    pub fn sin(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = quadrant(self);
+        sin_quadrant(x, quadrant)
+    }
+
+    /// Computes the cosine of the value (in radians)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.5);
+    /// let b = a.cos();
+    /// let c = 2.5f64.cos();
+    ///
+    /// assert!((b - c).abs() < 1e-15);
+    /// ```
+    pub fn cos(self) -> Self {
+        if !self.is_valid() {
+            return Self::NAN;
+        }
+        let (x, quadrant) = quadrant(self);
+        cos_quadrant(x, quadrant)
+    }
+
+    /// Simultaneously computes the sine and cosine of the value. Returns a
+    /// tuple with the sine as the first element and the cosine as the second
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.5);
+    /// let (s, c) = a.sin_cos();
+    ///
+    /// assert!((s * s + c * c - 1.0).abs() < 1e-62);
+    /// ```
+    pub fn sin_cos(self) -> (Self, Self) {
+        if !self.is_valid() {
+            return (Self::NAN, Self::NAN);
+        }
+        let (x, quadrant) = quadrant(self);
+        sin_cos_quadrant(x, quadrant)
+    }
+
+    /// Computes the tangent of the value (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.5);
+    /// let b = a.tan();
+    ///
+    /// assert!((b - a.sin() / a.cos()).abs() < 1e-62);
+    /// ```
+    pub fn tan(self) -> Self {
+        if !self.is_valid() {
+            return self;
+        }
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+
+    /// Computes the arcsine of the value. Return value is in radians in the
+    /// range [-π/2, π/2] or an invalid value if the input value is outside
+    /// the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(0.7);
+    /// let b = a.asin();
+    ///
+    /// assert!((b.sin() - a).abs() < 1e-62);
+    /// ```
+    pub fn asin(self) -> Self {
+        if !self.is_valid() || self.abs() > 1.0 {
+            Self::NAN
+        } else {
+            Self::atan2(self, ((1.0 - self) * (1.0 + self)).sqrt())
+        }
+    }
+
+    /// Computes the arccosine of the value. Return value is in radians in
+    /// the range [0, π] or an invalid value if the input value is outside
+    /// the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-0.8);
+    /// let b = a.acos();
+    ///
+    /// assert!((b.cos() - a).abs() < 1e-62);
+    /// ```
+    pub fn acos(self) -> Self {
+        if !self.is_valid() || self.abs() > 1.0 {
+            Self::NAN
+        } else {
+            Self::atan2(((1.0 - self) * (1.0 + self)).sqrt(), self)
+        }
+    }
+
+    /// Computes the arctangent of the value. Return value is in radians in
+    /// the range [-π/2, π/2].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(3.5);
+    /// let b = a.atan();
+    ///
+    /// assert!((b.tan() - a).abs() < 1e-61);
+    /// ```
+    pub fn atan(self) -> Self {
+        Self::atan2(self, Self::from(1.0))
+    }
+
+    /// Computes the four quadrant arctangent of `self` (y) and `other` (x)
+    /// in radians.
+    ///
+    /// The `TwoFloat` result is refined by Newton steps on the sine or
+    /// cosine, whichever is better conditioned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let y = QuadFloat::from(-1.0);
+    /// let x = QuadFloat::from(-1.0);
+    /// let theta = QuadFloat::atan2(y, x);
+    ///
+    /// let pi = QuadFloat::from(-1.0).acos();
+    ///
+    /// assert!((theta + pi * 0.75).abs() < 1e-62);
+    /// ```
+    pub fn atan2(self, other: Self) -> Self {
+        if self.words[0].is_nan() || other.words[0].is_nan() {
+            return Self::NAN;
+        }
+
+        let (y, x) = if self.words[0].is_infinite() || other.words[0].is_infinite() {
+            unit_direction(self, other)
+        } else {
+            (self, other)
+        };
+
+        if y == 0.0 {
+            if x.is_sign_positive() {
+                y
+            } else if y.is_sign_positive() {
+                PI
+            } else {
+                -PI
+            }
+        } else if x == 0.0 {
+            if y.is_sign_positive() {
+                FRAC_PI_2
+            } else {
+                -FRAC_PI_2
+            }
+        } else {
+            let mut z = Self::from(TwoFloat::atan2(TwoFloat::from(y), TwoFloat::from(x)));
+            let r = Self::hypot(x, y);
+            let (xx, yy) = (x / r, y / r);
+
+            // The `TwoFloat` result is accurate to around 75 bits, so two
+            // steps are needed to reach full precision
+            for _ in 0..2 {
+                let (s, c) = z.sin_cos();
+                z = if xx.abs() > yy.abs() {
+                    z + (yy - s) / c
+                } else {
+                    z - (xx - c) / s
+                };
+            }
+            z
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::QuadFloat;
+
+    #[test]
+    fn atan2_special_test() {
+        let pi = QuadFloat::from(-1.0).acos();
+        let inf = QuadFloat::INFINITY;
+        assert!((QuadFloat::atan2(inf, inf) - pi / 4.0).abs() < 1e-62);
+        assert_eq!(QuadFloat::atan2(-inf, QuadFloat::from(1.0)), -pi / 2.0);
+        assert_eq!(QuadFloat::atan2(QuadFloat::from(1.0), -inf), pi);
+        assert_eq!(QuadFloat::atan2(QuadFloat::from(-0.0), -inf), -pi);
+        assert!(QuadFloat::atan2(QuadFloat::NAN, inf).hi().is_nan());
+    }
+}
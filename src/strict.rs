@@ -0,0 +1,158 @@
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+use crate::{TwoFloat, TwoFloatError};
+
+/// A `TwoFloat` whose operations check their results in debug builds.
+///
+/// The arithmetic operators and the functions `sqrt`, `exp`, `exp2`, `ln`,
+/// `log2`, `log10`, `powi` and `powf` panic in debug builds if the
+/// corresponding checked operation, such as [`TwoFloat::checked_add`],
+/// returns an error. This catches invalid values where they first appear
+/// rather than after they have propagated through a computation. In release
+/// builds the operations are unchecked and give the same results as for
+/// `TwoFloat`.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{StrictTwoFloat, TwoFloat};
+/// let a = StrictTwoFloat::from(2.0);
+/// let b = (a * a + 1.0).sqrt();
+///
+/// assert_eq!(TwoFloat::from(b), TwoFloat::from(5.0).sqrt());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct StrictTwoFloat(pub TwoFloat);
+
+// Returns the result of the checked operation in debug builds, panicking if
+// it is invalid, and the result of the unchecked operation otherwise
+#[track_caller]
+#[inline]
+fn strict(
+    checked: impl FnOnce() -> Result<TwoFloat, TwoFloatError>,
+    unchecked: impl FnOnce() -> TwoFloat,
+) -> StrictTwoFloat {
+    if cfg!(debug_assertions) {
+        match checked() {
+            Ok(value) => StrictTwoFloat(value),
+            Err(error) => panic!("invalid StrictTwoFloat result: {}", error),
+        }
+    } else {
+        StrictTwoFloat(unchecked())
+    }
+}
+
+// Defines functions that evaluate the `TwoFloat` function of the same name,
+// checking the result using the corresponding checked function
+macro_rules! strict_functions {
+    ($(fn $name:ident($($arg:ident: $t:ty),*) => $checked:ident;)+) => {
+        impl StrictTwoFloat {
+            $(
+                #[doc = concat!(
+                    "Computes [`TwoFloat::", stringify!($name), "`] of the ",
+                    "value, panicking in debug builds if ",
+                    "[`TwoFloat::", stringify!($checked), "`] returns an error."
+                )]
+                #[track_caller]
+                pub fn $name(self $(, $arg: $t)*) -> Self {
+                    strict(
+                        || self.0.$checked($($arg.into()),*),
+                        || self.0.$name($($arg.into()),*),
+                    )
+                }
+            )+
+        }
+    };
+}
+
+strict_functions! {
+    fn sqrt() => checked_sqrt;
+    fn exp() => checked_exp;
+    fn exp2() => checked_exp2;
+    fn ln() => checked_ln;
+    fn log2() => checked_log2;
+    fn log10() => checked_log10;
+    fn powi(n: i32) => checked_powi;
+    fn powf(y: StrictTwoFloat) => checked_powf;
+}
+
+// Defines the operators for `StrictTwoFloat` and `f64` operands using the
+// corresponding checked functions
+macro_rules! strict_ops {
+    ($(fn $trait:ident::$name:ident, $assign:ident::$assign_name:ident => $checked:ident;)+) => {
+        $(
+            binary_ops! {
+                #[track_caller]
+                fn $trait::$name<'a, 'b>(self: &'a StrictTwoFloat, rhs: &'b StrictTwoFloat) -> StrictTwoFloat {
+                    strict(|| self.0.$checked(rhs.0), || self.0.$name(rhs.0))
+                }
+
+                #[track_caller]
+                fn $trait::$name<'a, 'b>(self: &'a StrictTwoFloat, rhs: &'b f64) -> StrictTwoFloat {
+                    let rhs = TwoFloat::from(*rhs);
+                    strict(|| self.0.$checked(rhs), || self.0.$name(rhs))
+                }
+
+                #[track_caller]
+                fn $trait::$name<'a, 'b>(self: &'a f64, rhs: &'b StrictTwoFloat) -> StrictTwoFloat {
+                    let value = TwoFloat::from(*self);
+                    strict(|| value.$checked(rhs.0), || value.$name(rhs.0))
+                }
+            }
+
+            assign_ops! {
+                #[track_caller]
+                fn $assign::$assign_name<'a>(self: &mut StrictTwoFloat, rhs: &'a StrictTwoFloat) {
+                    *self = (*self).$name(rhs);
+                }
+
+                #[track_caller]
+                fn $assign::$assign_name<'a>(self: &mut StrictTwoFloat, rhs: &'a f64) {
+                    *self = (*self).$name(rhs);
+                }
+            }
+        )+
+    };
+}
+
+strict_ops! {
+    fn Add::add, AddAssign::add_assign => checked_add;
+    fn Sub::sub, SubAssign::sub_assign => checked_sub;
+    fn Mul::mul, MulAssign::mul_assign => checked_mul;
+    fn Div::div, DivAssign::div_assign => checked_div;
+    fn Rem::rem, RemAssign::rem_assign => checked_rem;
+}
+
+unary_ops! {
+    fn Neg::neg(self: &StrictTwoFloat) -> StrictTwoFloat {
+        StrictTwoFloat(-self.0)
+    }
+}
+
+impl From<TwoFloat> for StrictTwoFloat {
+    fn from(value: TwoFloat) -> Self {
+        Self(value)
+    }
+}
+
+impl From<f64> for StrictTwoFloat {
+    fn from(value: f64) -> Self {
+        Self(TwoFloat::from(value))
+    }
+}
+
+impl From<StrictTwoFloat> for TwoFloat {
+    fn from(value: StrictTwoFloat) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for StrictTwoFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,527 @@
+use core::{cmp::Ordering, num::FpCategory};
+
+use hexf::hexf32;
+
+use crate::TwoFloat;
+
+mod arithmetic;
+mod convert;
+mod functions;
+mod num_integration;
+
+const EXPONENT_MASK: u32 = 0xff;
+const MANTISSA_MASK: u32 = (1 << 23) - 1;
+
+/// Checks if two `f32` values do not overlap, with the first value being the
+/// more significant. This is the `f32` equivalent of [`no_overlap`].
+///
+/// [`no_overlap`]: crate::no_overlap
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::no_overlap_f32;
+/// let a = no_overlap_f32(1.0, -1e-20);
+/// let b = no_overlap_f32(1e-20, 1.0);
+/// let c = no_overlap_f32(1.0, 0.25);
+///
+/// assert!(a);
+/// assert!(!b);
+/// assert!(!c);
+/// ```
+pub fn no_overlap_f32(a: f32, b: f32) -> bool {
+    match a.classify() {
+        FpCategory::Normal => {
+            if b == 0.0 {
+                return true;
+            }
+            let bits = a.to_bits();
+            let biased_exponent = ((bits >> 23) & EXPONENT_MASK) as i16;
+            let offset = if (bits & MANTISSA_MASK) == 0
+                && libm::copysignf(1.0, a) != libm::copysignf(1.0, b)
+            {
+                152
+            } else {
+                151
+            };
+            let limit = libm::exp2f((biased_exponent - offset) as f32);
+            match libm::fabsf(b).partial_cmp(&limit) {
+                Some(Ordering::Less) => true,
+                Some(Ordering::Equal) => (bits & 1) == 0,
+                _ => false,
+            }
+        }
+        FpCategory::Subnormal | FpCategory::Zero => b == 0.0,
+        _ => false,
+    }
+}
+
+/// Represents a two-word floating point type, represented as the sum of two
+/// non-overlapping `f32` values, giving around 48 bits of precision.
+///
+/// This is intended for targets where `f64` arithmetic is slow or
+/// unavailable. The arithmetic operators use the same algorithms as
+/// `TwoFloat` applied to `f32` words, while the mathematical functions are
+/// evaluated as `TwoFloat` and rounded back to `TwoF32`.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{TwoF32, TwoFloat};
+/// let a = TwoF32::new_div(1.0, 3.0);
+/// let b = a * 3.0 - 1.0;
+///
+/// assert!(b.abs() < 1e-14);
+/// assert!((TwoFloat::from(a) - TwoFloat::from(1.0) / 3.0).abs() < 1e-15);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(C)]
+pub struct TwoF32 {
+    pub(crate) hi: f32,
+    pub(crate) lo: f32,
+}
+
+impl TwoF32 {
+    /// Mantissa size of the double-f32 structure of TwoF32
+    /// aka the number of significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 48;
+
+    /// Smallest finite `TwoF32` value.
+    pub const MIN: Self = Self {
+        hi: f32::MIN,
+        lo: hexf32!("-0x1.fffffep+102"),
+    };
+
+    /// Smallest positive normal `TwoF32` value.
+    pub const MIN_POSITIVE: Self = Self::from_f32(f32::MIN_POSITIVE);
+
+    /// Largest finite `TwoF32` value.
+    pub const MAX: Self = Self {
+        hi: f32::MAX,
+        lo: hexf32!("0x1.fffffep+102"),
+    };
+
+    /// Represents an error value equivalent to `f32::NAN`.
+    pub const NAN: Self = Self {
+        hi: f32::NAN,
+        lo: f32::NAN,
+    };
+
+    /// Represents the difference between 1.0 and the next representable normal value.
+    pub const EPSILON: Self = Self::from_f32(f32::MIN_POSITIVE);
+
+    /// A positive infinite value
+    pub const INFINITY: Self = Self {
+        hi: f32::INFINITY,
+        lo: f32::INFINITY,
+    };
+
+    /// A negative infinite value
+    pub const NEG_INFINITY: Self = Self {
+        hi: f32::NEG_INFINITY,
+        lo: f32::NEG_INFINITY,
+    };
+
+    /// Creates a new TwoF32 from a constant `f32` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// const value: TwoF32 = TwoF32::from_f32(1.0);
+    /// assert_eq!(value.hi(), 1.0);
+    /// ```
+    pub const fn from_f32(value: f32) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+
+    /// Returns the high word of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let value = TwoF32::new_add(1.0, -1.0e-20);
+    /// assert_eq!(value.hi(), 1.0);
+    /// ```
+    pub fn hi(&self) -> f32 {
+        self.hi
+    }
+
+    /// Returns the low word of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let value = TwoF32::new_add(1.0, -1.0e-20);
+    /// assert_eq!(value.lo(), -1.0e-20);
+    /// ```
+    pub fn lo(&self) -> f32 {
+        self.lo
+    }
+
+    /// Returns `true` if `self` is a valid value, where both components are
+    /// finite (not infinity or `NAN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(1.0, 1.0e-30).is_valid();
+    /// let b = TwoF32::new_mul(1.0e30, 1.0e30).is_valid();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.hi.is_finite() && self.lo.is_finite() && no_overlap_f32(self.hi, self.lo)
+    }
+
+    /// Returns the minimum of two numbers. If one of the arguments is `NAN`,
+    /// the other is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(35.2, 1e-14);
+    /// let b = TwoF32::new_add(35.2, -1e-15);
+    ///
+    /// assert_eq!(a.min(b), b);
+    /// ```
+    pub fn min(self, other: Self) -> Self {
+        if !self.is_valid() {
+            other
+        } else if !other.is_valid() || self <= other {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the maximum of two numbers. If one of the arguments is `NAN`,
+    /// the other is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(35.2, 1e-14);
+    /// let b = TwoF32::new_add(35.2, -1e-15);
+    ///
+    /// assert_eq!(a.max(b), a);
+    /// ```
+    pub fn max(self, other: Self) -> Self {
+        if !self.is_valid() {
+            other
+        } else if !other.is_valid() || self >= other {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the absolute value of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(-1.0, 1.0e-20);
+    ///
+    /// assert_eq!(a.abs(), TwoF32::new_add(1.0, -1.0e-20));
+    /// ```
+    pub fn abs(&self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            *self
+        }
+    }
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(0.0f32).is_sign_positive();
+    /// let b = TwoF32::new_add(-1.0, 1.0e-20).is_sign_positive();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    /// ```
+    pub fn is_sign_positive(&self) -> bool {
+        self.hi.is_sign_positive()
+    }
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(-0.0f32).is_sign_negative();
+    /// let b = TwoF32::new_add(1.0, -1.0e-20).is_sign_negative();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    /// ```
+    pub fn is_sign_negative(&self) -> bool {
+        self.hi.is_sign_negative()
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(-1.0, 1.0e-20);
+    /// let b = TwoF32::from(0.3f32);
+    ///
+    /// assert_eq!(a.copysign(&b), -a);
+    /// ```
+    pub fn copysign(&self, sign: &Self) -> Self {
+        if self.is_sign_positive() == sign.is_sign_positive() {
+            *self
+        } else {
+            -self
+        }
+    }
+
+    /// Returns a number that represents the sign of the value.
+    ///
+    /// * `1.0` if the number is positive or `+0.0`
+    /// * `-1.0` if the number is negative or `-0.0`
+    /// * Invalid value otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(3.5f32);
+    /// let b = TwoF32::from(-0.0f32);
+    ///
+    /// assert_eq!(a.signum(), 1.0);
+    /// assert_eq!(b.signum(), -1.0);
+    /// ```
+    pub fn signum(&self) -> Self {
+        if self.is_valid() {
+            if self.is_sign_positive() {
+                Self::from_f32(1.0)
+            } else {
+                Self::from_f32(-1.0)
+            }
+        } else {
+            Self::NAN
+        }
+    }
+
+    /// Converts degrees to radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(90.0f32);
+    /// let b = a.to_radians();
+    ///
+    /// assert!((b - TwoF32::from(twofloat::consts::FRAC_PI_2)).abs() < 1e-13);
+    /// ```
+    pub fn to_radians(self) -> Self {
+        Self::from(TwoFloat::from(self).to_radians())
+    }
+
+    /// Converts radians to degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(twofloat::consts::PI);
+    /// let b = a.to_degrees();
+    ///
+    /// assert!((b - 180.0).abs() < 1e-11);
+    /// ```
+    pub fn to_degrees(self) -> Self {
+        Self::from(TwoFloat::from(self).to_degrees())
+    }
+
+    /// Takes the reciprocal (inverse) of the number, `1/x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(67.2, 5.7e-7);
+    /// let b = a.recip();
+    /// let difference = b.recip() - a;
+    ///
+    /// assert!(difference.abs() < 1e-11);
+    /// ```
+    pub fn recip(self) -> Self {
+        1.0 / self
+    }
+
+    /// Raises the number to an integer power. As for `f64`, any value
+    /// raised to the power of zero is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(2.0f32).powi(3);
+    /// let b = TwoF32::from(0.0f32).powi(0);
+    ///
+    /// assert_eq!(a, 8.0);
+    /// assert_eq!(b, 1.0);
+    /// ```
+    pub fn powi(self, n: i32) -> Self {
+        match n {
+            0 => Self::from_f32(1.0),
+            1 => self,
+            -1 => self.recip(),
+            _ => {
+                let mut result = Self::from_f32(1.0);
+                let mut n_pos = n.unsigned_abs();
+                let mut value = self;
+                while n_pos > 0 {
+                    if (n_pos & 1) != 0 {
+                        result *= &value;
+                    }
+                    value *= value;
+                    n_pos >>= 1;
+                }
+                if n > 0 {
+                    result
+                } else {
+                    result.recip()
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<f32> for TwoF32 {
+    fn eq(&self, other: &f32) -> bool {
+        self.hi.eq(other) && self.lo == 0.0
+    }
+}
+
+impl PartialEq<TwoF32> for f32 {
+    fn eq(&self, other: &TwoF32) -> bool {
+        self.eq(&other.hi) && other.lo == 0.0
+    }
+}
+
+impl PartialEq<TwoF32> for TwoF32 {
+    fn eq(&self, other: &TwoF32) -> bool {
+        if self.is_valid() != other.is_valid()
+            || self.hi.is_nan()
+            || self.lo.is_nan()
+            || other.hi.is_nan()
+            || other.lo.is_nan()
+        {
+            false
+        } else if self.is_valid() {
+            self.hi == other.hi && self.lo == other.lo
+        } else {
+            // all infinities compare equal
+            true
+        }
+    }
+}
+
+impl PartialOrd<f32> for TwoF32 {
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        let hi_cmp = self.hi.partial_cmp(other);
+        if hi_cmp == Some(Ordering::Equal) {
+            self.lo.partial_cmp(&0.0)
+        } else {
+            hi_cmp
+        }
+    }
+}
+
+impl PartialOrd<TwoF32> for f32 {
+    fn partial_cmp(&self, other: &TwoF32) -> Option<Ordering> {
+        let hi_cmp = self.partial_cmp(&other.hi);
+        if hi_cmp == Some(Ordering::Equal) {
+            0.0.partial_cmp(&other.lo)
+        } else {
+            hi_cmp
+        }
+    }
+}
+
+impl PartialOrd<TwoF32> for TwoF32 {
+    fn partial_cmp(&self, other: &TwoF32) -> Option<Ordering> {
+        if self.hi.is_nan() || self.lo.is_nan() || other.hi.is_nan() || other.lo.is_nan() {
+            return None;
+        }
+
+        match (self.is_valid(), other.is_valid()) {
+            (true, true) => {
+                let hi_cmp = self.hi.partial_cmp(&other.hi);
+                if matches!(hi_cmp, Some(Ordering::Equal)) {
+                    self.lo.partial_cmp(&other.lo)
+                } else {
+                    hi_cmp
+                }
+            }
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => Some(Ordering::Equal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hexf::hexf32;
+
+    use super::{no_overlap_f32, TwoF32};
+
+    #[test]
+    fn no_overlap_test() {
+        assert!(!no_overlap_f32(1.0, hexf32!("0x1p-23")));
+        assert!(no_overlap_f32(1.0, hexf32!("0x1p-24")));
+        assert!(!no_overlap_f32(1.0, hexf32!("0x1.000002p-24")));
+        assert!(no_overlap_f32(1.0, hexf32!("-0x1p-25")));
+        assert!(!no_overlap_f32(1.0, hexf32!("-0x1p-24")));
+        assert!(!no_overlap_f32(
+            hexf32!("0x1.000002p+0"),
+            hexf32!("0x1p-24")
+        ));
+        assert!(no_overlap_f32(hexf32!("0x1.000004p+0"), hexf32!("0x1p-24")));
+        assert!(no_overlap_f32(-1.0, -0.0));
+        assert!(!no_overlap_f32(f32::INFINITY, 1.0));
+        assert!(!no_overlap_f32(f32::NAN, 1.0));
+        assert!(!no_overlap_f32(0.0, 1.0));
+        assert!(no_overlap_f32(0.0, 0.0));
+    }
+
+    #[test]
+    fn limits_test() {
+        assert!(TwoF32::MIN.is_valid());
+        assert!(TwoF32::MAX.is_valid());
+        assert_eq!(TwoF32::MIN, -TwoF32::MAX);
+        assert!(!TwoF32::NAN.is_valid());
+    }
+
+    #[test]
+    fn ord_test() {
+        let a = TwoF32::new_add(1.0, 1e-10);
+        let b = TwoF32::new_add(1.0, 2e-10);
+
+        assert!(a < b);
+        assert!(-a > -b);
+        assert!(a > 1.0);
+        assert!(b < TwoF32::INFINITY);
+        assert_eq!(TwoF32::default(), 0.0);
+        assert!(TwoF32::NAN.partial_cmp(&a).is_none());
+    }
+}
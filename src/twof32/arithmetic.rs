@@ -0,0 +1,433 @@
+#![allow(clippy::extra_unused_lifetimes)]
+
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::TwoF32;
+
+// MinGW FMA seems to be inaccurate, use libm even if std is enabled.
+#[cfg(all(feature = "std", not(all(windows, target_env = "gnu"))))]
+#[inline(always)]
+fn fma(x: f32, y: f32, z: f32) -> f32 {
+    f32::mul_add(x, y, z)
+}
+
+#[cfg(not(all(feature = "std", not(all(windows, target_env = "gnu")))))]
+#[inline(always)]
+fn fma(x: f32, y: f32, z: f32) -> f32 {
+    libm::fmaf(x, y, z)
+}
+
+pub(super) fn fast_two_sum(a: f32, b: f32) -> TwoF32 {
+    // Joldes et al. (2017) Algorithm 1
+    let s = a + b;
+    let z = s - a;
+    TwoF32 { hi: s, lo: b - z }
+}
+
+// Multiplies a `TwoF32` by an `f32` using Joldes et al. (2017) Algorithm 7
+fn mul_fp1(x: TwoF32, y: f32) -> TwoF32 {
+    let (ch, cl1) = TwoF32::new_mul(x.hi, y).into();
+    let cl2 = x.lo * y;
+    let (th, tl1) = fast_two_sum(ch, cl2).into();
+    let tl2 = tl1 + cl1;
+    fast_two_sum(th, tl2)
+}
+
+// Checks that both words of a value are finite, without checking that they
+// do not overlap
+fn finite(value: &TwoF32) -> bool {
+    value.hi.is_finite() && value.lo.is_finite()
+}
+
+/// Gives the canonical representation of a value which has no low word, so
+/// that infinities have a zero low word and `NAN` has both words `NAN`.
+#[inline]
+pub(super) fn special_value(value: f32) -> TwoF32 {
+    if value.is_nan() {
+        TwoF32::NAN
+    } else {
+        TwoF32::from_f32(value)
+    }
+}
+
+// Gives the result of an operation following IEEE 754 from an estimate
+// computed from the high words, as for the `TwoFloat` operators
+#[inline(always)]
+fn ieee_result(finite: bool, estimate: f32, compute: impl FnOnce() -> TwoF32) -> TwoF32 {
+    if !finite {
+        return special_value(estimate);
+    }
+
+    let result = compute();
+    if !result.hi.is_finite() || !result.lo.is_finite() {
+        special_value(libm::copysignf(f32::INFINITY, estimate))
+    } else if result.hi == 0.0 {
+        special_value(if estimate == 0.0 { estimate } else { 0.0 })
+    } else {
+        result
+    }
+}
+
+impl TwoF32 {
+    /// Creates a new `TwoF32` by adding two `f32` values using Algorithm 2
+    /// from Joldes et al. (2017).
+    pub fn new_add(a: f32, b: f32) -> Self {
+        let s = a + b;
+        let aa = s - b;
+        let bb = s - aa;
+        let da = a - aa;
+        let db = b - bb;
+        Self { hi: s, lo: da + db }
+    }
+
+    /// Creates a new `TwoF32` by subtracting two `f32` values using
+    /// Algorithm 2 from Joldes et al. (2017) modified for negative right-hand
+    /// side.
+    pub fn new_sub(a: f32, b: f32) -> Self {
+        let s = a - b;
+        let aa = s + b;
+        let bb = s - aa;
+        let da = a - aa;
+        let db = b + bb;
+        Self { hi: s, lo: da - db }
+    }
+
+    /// Creates a new `TwoF32` by multiplying two `f32` values using
+    /// Algorithm 3 from Joldes et al. (2017).
+    pub fn new_mul(a: f32, b: f32) -> Self {
+        let p = a * b;
+        Self {
+            hi: p,
+            lo: fma(a, b, -p),
+        }
+    }
+
+    /// Creates a new `TwoF32` by dividing two `f32` values using Algorithm
+    /// 15 from Joldes et al. (2017) modified for the left-hand-side having a
+    /// zero value in the low word.
+    pub fn new_div(a: f32, b: f32) -> Self {
+        let th = a / b;
+        if !th.is_finite() || th == 0.0 || !b.is_finite() || b == 0.0 {
+            return special_value(th);
+        }
+        let (ph, pl) = Self::new_mul(th, b).into();
+        let dh = a - ph;
+        let d = dh - pl;
+        let tl = d / b;
+        fast_two_sum(th, tl)
+    }
+}
+
+unary_ops! {
+    fn Neg::neg(self: &TwoF32) -> TwoF32 {
+        // A zero low word stays positive, as for `TwoFloat`
+        Self::Output {
+            hi: -self.hi,
+            lo: 0.0 - self.lo,
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 4.
+    fn Add::add<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        ieee_result(finite(self) && rhs.is_finite(), self.hi + rhs, || {
+            let (sh, sl) = TwoF32::new_add(self.hi, *rhs).into();
+            let v = self.lo + sl;
+            fast_two_sum(sh, v)
+        })
+    }
+
+    /// Implements addition of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 4.
+    fn Add::add<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        ieee_result(self.is_finite() && finite(rhs), self + rhs.hi, || {
+            let (sh, sl) = TwoF32::new_add(rhs.hi, *self).into();
+            let v = rhs.lo + sl;
+            fast_two_sum(sh, v)
+        })
+    }
+
+    /// Implements addition of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 6.
+    fn Add::add<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        ieee_result(finite(self) && finite(rhs), self.hi + rhs.hi, || {
+            let (sh, sl) = TwoF32::new_add(self.hi, rhs.hi).into();
+            let (th, tl) = TwoF32::new_add(self.lo, rhs.lo).into();
+            let c = sl + th;
+            let (vh, vl) = fast_two_sum(sh, c).into();
+            let w = tl + vl;
+            fast_two_sum(vh, w)
+        })
+    }
+
+    /// Implements subtraction of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 4 modified for negative right-hand side.
+    fn Sub::sub<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        ieee_result(finite(self) && rhs.is_finite(), self.hi - rhs, || {
+            let (sh, sl) = TwoF32::new_sub(self.hi, *rhs).into();
+            let v = self.lo + sl;
+            fast_two_sum(sh, v)
+        })
+    }
+
+    /// Implements subtraction of `f32` and `TwoF32` using Joldes et al.
+    /// (2017) Algorithm 4 modified for negative left-hand side.
+    fn Sub::sub<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        ieee_result(self.is_finite() && finite(rhs), self - rhs.hi, || {
+            let (sh, sl) = TwoF32::new_sub(*self, rhs.hi).into();
+            let v = sl - rhs.lo;
+            fast_two_sum(sh, v)
+        })
+    }
+
+    /// Implements subtraction of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 6 modified for a negative right-hand side.
+    fn Sub::sub<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        ieee_result(finite(self) && finite(rhs), self.hi - rhs.hi, || {
+            let (sh, sl) = TwoF32::new_sub(self.hi, rhs.hi).into();
+            let (th, tl) = TwoF32::new_sub(self.lo, rhs.lo).into();
+            let c = sl + th;
+            let (vh, vl) = fast_two_sum(sh, c).into();
+            let w = tl + vl;
+            fast_two_sum(vh, w)
+        })
+    }
+
+    /// Implements multiplication of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 9.
+    fn Mul::mul<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        ieee_result(finite(self) && rhs.is_finite(), self.hi * rhs, || {
+            let (ch, cl1) = TwoF32::new_mul(self.hi, *rhs).into();
+            let cl3 = fma(self.lo, *rhs, cl1);
+            fast_two_sum(ch, cl3)
+        })
+    }
+
+    /// Implements multiplication of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 9.
+    fn Mul::mul<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        ieee_result(self.is_finite() && finite(rhs), self * rhs.hi, || {
+            let (ch, cl1) = TwoF32::new_mul(rhs.hi, *self).into();
+            let cl3 = fma(rhs.lo, *self, cl1);
+            fast_two_sum(ch, cl3)
+        })
+    }
+
+    /// Implements multiplication of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 12.
+    fn Mul::mul<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        ieee_result(finite(self) && finite(rhs), self.hi * rhs.hi, || {
+            let (ch, cl1) = TwoF32::new_mul(self.hi, rhs.hi).into();
+            let tl0 = self.lo * rhs.lo;
+            let tl1 = fma(self.hi, rhs.lo, tl0);
+            let cl2 = fma(self.lo, rhs.hi, tl1);
+            let cl3 = cl1 + cl2;
+            fast_two_sum(ch, cl3)
+        })
+    }
+
+    /// Implements division of `TwoF32` and `f32` using Joldes et al. (2017)
+    /// Algorithm 15
+    fn Div::div<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        let divisible = finite(self) && rhs.is_finite() && *rhs != 0.0;
+        ieee_result(divisible, self.hi / rhs, || {
+            let th = self.hi / rhs;
+            let (ph, pl) = TwoF32::new_mul(th, *rhs).into();
+            let dh = self.hi - ph;
+            let dt = dh - pl;
+            let d = dt + self.lo;
+            let tl = d / rhs;
+            fast_two_sum(th, tl)
+        })
+    }
+
+    /// Implements division of `f32` and `TwoF32` using Joldes et al.
+    /// (2017) Algorithm 17 modified for the left-hand side having a zero
+    /// value in the low word.
+    fn Div::div<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        let divisible = self.is_finite() && finite(rhs) && rhs.hi != 0.0;
+        ieee_result(divisible, self / rhs.hi, || {
+            let th = self / rhs.hi;
+            let (rh, rl) = mul_fp1(*rhs, th).into();
+            let ph = self - rh;
+            let d = ph - rl;
+            let tl = d / rhs.hi;
+            fast_two_sum(th, tl)
+        })
+    }
+
+    /// Implements division of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 17.
+    fn Div::div<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        let divisible = finite(self) && finite(rhs) && rhs.hi != 0.0;
+        ieee_result(divisible, self.hi / rhs.hi, || {
+            let th = self.hi / rhs.hi;
+            let (rh, rl) = mul_fp1(*rhs, th).into();
+            let ph = self.hi - rh;
+            let dl = self.lo - rl;
+            let d = ph + dl;
+            let tl = d / rhs.hi;
+            fast_two_sum(th, tl)
+        })
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        let quotient = (self / rhs).trunc();
+        self - quotient * rhs
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        let quotient = (self / rhs).trunc();
+        self - quotient * rhs
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        let quotient = (self / rhs).trunc();
+        self - quotient * rhs
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    /// Implements addition of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 4.
+    fn AddAssign::add_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self + rhs;
+    }
+
+    /// Implements addition of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 6.
+    fn AddAssign::add_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self + rhs;
+    }
+
+    /// Implements subtraction of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 4 modified for negative right-hand side.
+    fn SubAssign::sub_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self - rhs;
+    }
+
+    /// Implements subtraction of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 6 modified for a negative right-hand side.
+    fn SubAssign::sub_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self - rhs;
+    }
+
+    /// Implements multiplication of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 9.
+    fn MulAssign::mul_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self * rhs;
+    }
+
+    /// Implements multiplication of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 12.
+    fn MulAssign::mul_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self * rhs;
+    }
+
+    /// Implements division of `TwoF32` and `f32` using Joldes et al. (2017)
+    /// Algorithm 15
+    fn DivAssign::div_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self / rhs;
+    }
+
+    /// Implements division of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 17.
+    fn DivAssign::div_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self / rhs;
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self % rhs;
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self % rhs;
+    }
+}
+
+impl TwoF32 {
+    /// Calculates Euclidean division, the matching method for `rem_euclid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(9.0f32);
+    /// let b = TwoF32::from(5.0f32);
+    ///
+    /// assert_eq!(a.div_euclid(b), TwoF32::from(1.0f32));
+    /// assert_eq!((-a).div_euclid(b), TwoF32::from(-2.0f32));
+    /// assert_eq!(a.div_euclid(-b), TwoF32::from(-1.0f32));
+    /// assert_eq!((-a).div_euclid(-b), TwoF32::from(2.0f32));
+    /// ```
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let quotient = (self / rhs).trunc();
+        if (self - quotient * rhs) < 0.0 {
+            if rhs > 0.0 {
+                quotient - 1.0
+            } else {
+                quotient + 1.0
+            }
+        } else {
+            quotient
+        }
+    }
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+    ///
+    /// The return value `r` usually satisfies `0.0 <= r < rhs.abs()`,
+    /// although the errors in numerical computation may result in violations
+    /// of this constraint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(9.0f32);
+    /// let b = TwoF32::from(5.0f32);
+    ///
+    /// assert_eq!(a.rem_euclid(b), TwoF32::from(4.0f32));
+    /// assert_eq!((-a).rem_euclid(b), TwoF32::from(1.0f32));
+    /// assert_eq!(a.rem_euclid(-b), TwoF32::from(4.0f32));
+    /// assert_eq!((-a).rem_euclid(-b), TwoF32::from(1.0f32));
+    /// ```
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self % rhs;
+        if remainder < 0.0 {
+            remainder + rhs.abs()
+        } else {
+            remainder
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fast_two_sum;
+    use crate::{TwoF32, TwoFloat};
+
+    #[test]
+    fn error_free_test() {
+        let values = [1.0f32, 3.0, -7.5e-3, 1.0e10, -2.5e-12, 0.1];
+        for &a in &values {
+            for &b in &values {
+                let exact_sum = TwoFloat::new_add(a as f64, b as f64);
+                assert_eq!(TwoFloat::from(TwoF32::new_add(a, b)), exact_sum);
+                assert_eq!(TwoFloat::from(TwoF32::new_sub(a, -b)), exact_sum);
+                assert_eq!(
+                    TwoFloat::from(TwoF32::new_mul(a, b)),
+                    TwoFloat::from(a as f64 * b as f64)
+                );
+
+                let (big, small) = if a.abs() >= b.abs() { (a, b) } else { (b, a) };
+                assert!(fast_two_sum(big, small).is_valid());
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use core::convert::{From, TryFrom};
+
+use super::{arithmetic::fast_two_sum, no_overlap_f32};
+use crate::{arithmetic, TwoF32, TwoFloat, TwoFloatError};
+
+impl From<TwoF32> for TwoFloat {
+    /// Converts the value exactly if the words are within 53 bits of each
+    /// other, otherwise rounds to the nearest `TwoFloat`.
+    fn from(value: TwoF32) -> Self {
+        if !value.hi.is_finite() {
+            return arithmetic::special_value(value.hi as f64);
+        }
+
+        arithmetic::fast_two_sum(value.hi as f64, value.lo as f64)
+    }
+}
+
+impl<'a> From<&'a TwoF32> for TwoFloat {
+    fn from(value: &'a TwoF32) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<TwoFloat> for TwoF32 {
+    /// Rounds the value to a `TwoF32`, where the high word is the nearest
+    /// `f32` to the high word of the input.
+    fn from(value: TwoFloat) -> Self {
+        let hi = value.hi as f32;
+        if !hi.is_finite() {
+            return Self { hi, lo: hi };
+        }
+
+        let remainder = value - hi as f64;
+        fast_two_sum(hi, remainder.hi as f32)
+    }
+}
+
+impl<'a> From<&'a TwoFloat> for TwoF32 {
+    fn from(value: &'a TwoFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<TwoF32> for (f32, f32) {
+    fn from(value: TwoF32) -> Self {
+        (value.hi, value.lo)
+    }
+}
+
+impl TryFrom<(f32, f32)> for TwoF32 {
+    type Error = TwoFloatError;
+
+    fn try_from(value: (f32, f32)) -> Result<Self, Self::Error> {
+        if no_overlap_f32(value.0, value.1) {
+            Ok(Self {
+                hi: value.0,
+                lo: value.1,
+            })
+        } else {
+            Err(Self::Error::ConversionError {})
+        }
+    }
+}
+
+impl From<TwoF32> for [f32; 2] {
+    fn from(value: TwoF32) -> Self {
+        [value.hi, value.lo]
+    }
+}
+
+impl TryFrom<[f32; 2]> for TwoF32 {
+    type Error = TwoFloatError;
+
+    fn try_from(value: [f32; 2]) -> Result<Self, Self::Error> {
+        Self::try_from((value[0], value[1]))
+    }
+}
+
+impl From<f32> for TwoF32 {
+    fn from(value: f32) -> Self {
+        Self::from_f32(value)
+    }
+}
+
+impl From<TwoF32> for f32 {
+    fn from(value: TwoF32) -> Self {
+        value.hi
+    }
+}
+
+impl<'a> From<&'a TwoF32> for f32 {
+    fn from(value: &'a TwoF32) -> Self {
+        value.hi
+    }
+}
+
+impl From<f64> for TwoF32 {
+    /// Rounds the value to the nearest `TwoF32`.
+    fn from(value: f64) -> Self {
+        Self::from(TwoFloat::from(value))
+    }
+}
+
+impl From<TwoF32> for f64 {
+    fn from(value: TwoF32) -> Self {
+        TwoFloat::from(value).hi
+    }
+}
+
+impl<'a> From<&'a TwoF32> for f64 {
+    fn from(value: &'a TwoF32) -> Self {
+        TwoFloat::from(value).hi
+    }
+}
+
+// Integers are converted through `TwoFloat`, which is exact for integers of
+// up to 48 bits
+macro_rules! int_convert {
+    ($($type:tt),+) => {
+        $(
+            impl From<$type> for TwoF32 {
+                fn from(value: $type) -> Self {
+                    Self::from(TwoFloat::from(value))
+                }
+            }
+
+            impl TryFrom<TwoF32> for $type {
+                type Error = TwoFloatError;
+
+                fn try_from(value: TwoF32) -> Result<Self, Self::Error> {
+                    Self::try_from(TwoFloat::from(value))
+                }
+            }
+
+            impl<'a> TryFrom<&'a TwoF32> for $type {
+                type Error = TwoFloatError;
+
+                fn try_from(value: &'a TwoF32) -> Result<Self, Self::Error> {
+                    Self::try_from(TwoFloat::from(value))
+                }
+            }
+        )+
+    };
+}
+
+int_convert!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use crate::{TwoF32, TwoFloat};
+
+    #[test]
+    fn twofloat_test() {
+        let a = TwoF32::new_add(1.0, 1e-10);
+        assert_eq!(TwoF32::from(TwoFloat::from(a)), a);
+
+        let b = TwoFloat::new_add(1.0, 1e-10) + 2f64.powi(-60);
+        let c = TwoF32::from(b);
+        assert!(c.is_valid());
+        assert!((TwoFloat::from(c) - b).abs() < 2f64.powi(-48));
+
+        let d = TwoF32::from(TwoFloat::from(1e300));
+        assert!(d.hi().is_infinite());
+    }
+
+    #[test]
+    fn integer_test() {
+        let value = TwoF32::from(123_456_789_012_345i64);
+        assert!(value.is_valid());
+        assert_eq!(i64::try_from(value), Ok(123_456_789_012_345));
+        assert_eq!(i32::try_from(TwoF32::from(i32::MIN)), Ok(i32::MIN));
+        assert!(u8::try_from(TwoF32::from(-1i32)).is_err());
+        assert_eq!(i32::try_from(TwoF32::new_add(3.0, -1e-10)), Ok(2));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{TwoF32, TwoFloat};
+
+// Defines functions that evaluate the `TwoFloat` function of the same name
+// and round the result. The additional precision of `TwoFloat` means that
+// the results are accurate to within the rounding of the `TwoF32` result.
+macro_rules! twofloat_functions {
+    ($(fn $name:ident($($arg:ident),*);)+) => {
+        impl TwoF32 {
+            $(
+                #[doc = concat!(
+                    "Computes [`TwoFloat::", stringify!($name), "`] of the ",
+                    "value, rounded to the nearest `TwoF32`."
+                )]
+                pub fn $name(self $(, $arg: Self)*) -> Self {
+                    Self::from(TwoFloat::from(self).$name($(TwoFloat::from($arg)),*))
+                }
+            )+
+        }
+    };
+}
+
+twofloat_functions! {
+    fn floor();
+    fn ceil();
+    fn round();
+    fn trunc();
+    fn fract();
+    fn sqrt();
+    fn cbrt();
+    fn hypot(other);
+    fn powf(y);
+    fn exp();
+    fn exp2();
+    fn exp_m1();
+    fn ln();
+    fn ln_1p();
+    fn log(base);
+    fn log2();
+    fn log10();
+    fn sin();
+    fn cos();
+    fn tan();
+    fn asin();
+    fn acos();
+    fn atan();
+    fn atan2(other);
+    fn sinh();
+    fn cosh();
+    fn tanh();
+    fn asinh();
+    fn acosh();
+    fn atanh();
+    fn gamma();
+    fn erf();
+    fn erfc();
+}
+
+impl TwoF32 {
+    /// Simultaneously computes the sine and cosine of the value, as for
+    /// [`TwoFloat::sin_cos`]. Returns a tuple with the sine as the first
+    /// element and the cosine as the second element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(2.5f32);
+    /// let (s, c) = a.sin_cos();
+    ///
+    /// assert!((s * s + c * c - 1.0).abs() < 1e-13);
+    /// ```
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = TwoFloat::from(self).sin_cos();
+        (Self::from(s), Self::from(c))
+    }
+
+    /// Computes `(self * a) + b` using [`TwoFloat::mul_add`], with a single
+    /// rounding of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(1.0, 2f32.powi(-30));
+    /// let b = a.mul_add(a, TwoF32::from(-1.0f32));
+    ///
+    /// assert_eq!(b, TwoF32::new_add(2f32.powi(-29), 2f32.powi(-60)));
+    /// assert_ne!(a * a - 1.0, b);
+    /// ```
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::from(TwoFloat::from(self).mul_add(TwoFloat::from(a), TwoFloat::from(b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TwoF32, TwoFloat};
+
+    #[test]
+    fn rounding_test() {
+        let x = TwoF32::from(0.7f32);
+        let exact = TwoFloat::from(0.7f32).exp();
+        let result = x.exp();
+        assert!(result.is_valid());
+        assert!(((TwoFloat::from(result) - exact) / exact).abs() < 2f64.powi(-47));
+
+        assert_eq!(TwoF32::new_add(2.0, -1e-10).floor(), 1.0);
+        assert!(TwoF32::from(-4.0f32).sqrt().hi().is_nan());
+    }
+}
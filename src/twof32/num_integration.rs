@@ -0,0 +1,15 @@
+use core::{convert::TryFrom, num::FpCategory};
+
+use num_traits::{Inv, Pow};
+
+use crate::{consts, TwoF32, TwoFloatError};
+
+float_traits! {
+    type: TwoF32,
+    float: f32,
+    from_float: TwoF32::from_f32,
+    consts: consts,
+    from_str_radix: crate::parse::from_str_radix_f32,
+    mul_add: TwoF32::mul_add,
+    words: |x: TwoF32| [x.hi, x.lo],
+}
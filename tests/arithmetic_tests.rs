@@ -5,7 +5,7 @@ use core::{convert::TryFrom, fmt::Debug};
 
 use rand::Rng;
 
-use twofloat::{no_overlap, TwoFloat};
+use twofloat::{no_overlap, QuadFloat, TwoFloat};
 
 #[macro_use]
 pub mod common;
@@ -133,6 +133,141 @@ fn div_precision_test() {
     })
 }
 
+// Tests for error bounds
+
+fn relative_error(result: TwoFloat, exact: QuadFloat) -> f64 {
+    TwoFloat::from(((QuadFloat::from(result) - exact) / exact).abs()).hi()
+}
+
+#[test]
+fn error_bound_test() {
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.hi().abs() > 1e-100 && x.hi().abs() < 1e100);
+        let b = get_valid_ddouble(|x| x.hi().abs() > 1e-100 && x.hi().abs() < 1e100);
+        let c = b.hi();
+        let (x, y, z) = (QuadFloat::from(a), QuadFloat::from(b), QuadFloat::from(c));
+
+        let cases = [
+            (a + c, x + z, TwoFloat::ERROR_BOUND_ADD_F64, "+ f64"),
+            (a - c, x - z, TwoFloat::ERROR_BOUND_ADD_F64, "- f64"),
+            (a * c, x * z, TwoFloat::ERROR_BOUND_MUL_F64, "* f64"),
+            (a / c, x / z, TwoFloat::ERROR_BOUND_DIV_F64, "/ f64"),
+            (c / a, z / x, TwoFloat::ERROR_BOUND_DIV, "f64 /"),
+            (a + b, x + y, TwoFloat::ERROR_BOUND_ADD, "+"),
+            (a - b, x - y, TwoFloat::ERROR_BOUND_ADD, "-"),
+            (a * b, x * y, TwoFloat::ERROR_BOUND_MUL, "*"),
+            (a / b, x / y, TwoFloat::ERROR_BOUND_DIV, "/"),
+        ];
+
+        for &(result, exact, bound, op) in cases.iter() {
+            assert!(
+                exact == 0.0 || relative_error(result, exact) <= bound,
+                "Error bound exceeded for {:?} {} {:?}",
+                a,
+                op,
+                b
+            );
+        }
+    });
+}
+
+#[test]
+fn with_bound_test() {
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.hi().abs() < 1e300);
+        let b = get_valid_ddouble(|x| x.hi().abs() < 1e300);
+        let (x, y) = (QuadFloat::from(a), QuadFloat::from(b));
+
+        let cases = [
+            (a.add_with_bound(b), x + y, "+"),
+            (a.sub_with_bound(b), x - y, "-"),
+            (a.mul_with_bound(b), x * y, "*"),
+            (a.div_with_bound(b), x / y, "/"),
+        ];
+
+        for &((result, bound), exact, op) in cases.iter() {
+            if !result.is_valid() {
+                assert_eq!(bound, f64::INFINITY);
+                continue;
+            }
+
+            let error = TwoFloat::from((QuadFloat::from(result) - exact).abs());
+            assert!(
+                error <= bound,
+                "Absolute error {:?} exceeds {} for {:?} {} {:?}",
+                error,
+                bound,
+                a,
+                op,
+                b
+            );
+        }
+    });
+
+    let (result, bound) = TwoFloat::MAX.mul_with_bound(TwoFloat::from(2.0));
+    assert!(!result.is_valid());
+    assert_eq!(bound, f64::INFINITY);
+}
+
+// Tests for fused multiply-add
+
+// Bound on the error of mul_add relative to the exact result
+const MUL_ADD_ERROR: f64 = 2.5e-32;
+
+// Bound on the error of the reference values relative to the magnitude of
+// the terms
+const QUAD_ERROR: f64 = 1e-60;
+
+fn assert_mul_add(x: TwoFloat, y: TwoFloat, z: TwoFloat) {
+    let (p, q, r) = (QuadFloat::from(x), QuadFloat::from(y), QuadFloat::from(z));
+    let exact = p * q + r;
+    let error = (QuadFloat::from(x.mul_add(y, z)) - exact).abs();
+    let bound = exact.abs() * MUL_ADD_ERROR + ((p * q).abs() + r.abs()) * QUAD_ERROR;
+    assert!(
+        error <= bound,
+        "Mismatch in {:?}.mul_add({:?}, {:?})",
+        x,
+        y,
+        z
+    );
+}
+
+#[test]
+fn mul_add_test() {
+    repeated_test(|| {
+        let x = get_valid_ddouble(|x| x.hi().abs() > 1e-100 && x.hi().abs() < 1e100);
+        let y = get_valid_ddouble(|x| x.hi().abs() > 1e-100 && x.hi().abs() < 1e100);
+        let z = get_valid_ddouble(|x| x.hi().abs() > 1e-200 && x.hi().abs() < 1e200);
+        assert_mul_add(x, y, z);
+    });
+}
+
+#[test]
+fn mul_add_cancellation_test() {
+    repeated_test(|| {
+        let x = get_valid_ddouble(|x| x.hi().abs() > 1e-100 && x.hi().abs() < 1e100);
+        let y = get_valid_ddouble(|x| x.hi().abs() > 1e-100 && x.hi().abs() < 1e100);
+        let product = x * y;
+
+        // The error of the product is recovered from the cancellation
+        assert_mul_add(x, y, -product);
+        assert_mul_add(x, y, -product + product.lo() * 0.5);
+        assert_mul_add(x, -y, product);
+    });
+
+    let a = TwoFloat::new_add(1.0, 2f64.powi(-60));
+    let b = TwoFloat::new_add(-1.0, -2f64.powi(-59));
+    assert_eq!(a.mul_add(a, b), 2f64.powi(-120));
+    assert_eq!(a.mul_add(-a, -b), -2f64.powi(-120));
+
+    let c = TwoFloat::new_div(1.0, 3.0);
+    let d = TwoFloat::from(3.0);
+    assert_mul_add(c, d, TwoFloat::from(-1.0));
+    assert_ne!(c.mul_add(d, TwoFloat::from(-1.0)), 0.0);
+    assert_eq!(TwoFloat::from(0.0).mul_add(c, d), d);
+    assert!(TwoFloat::NAN.mul_add(c, d).hi().is_nan());
+}
+
 // Test for negation operator
 
 #[test]
@@ -152,6 +287,23 @@ fn neg_test() {
     });
 }
 
+#[test]
+fn neg_zero_test() {
+    // Negated and absolute zeros keep a positive low word
+    for &(value, negative) in [
+        (-TwoFloat::from(0.0), true),
+        (-TwoFloat::from(-0.0), false),
+        (TwoFloat::from(-0.0).abs(), false),
+        (TwoFloat::from(0.0).abs(), false),
+    ]
+    .iter()
+    {
+        assert_eq!(value.hi(), 0.0);
+        assert_eq!(value.hi().is_sign_negative(), negative, "{:?}", value);
+        assert!(value.lo().is_sign_positive(), "{:?}", value);
+    }
+}
+
 // Helpers for binary operators
 
 enum Operator {
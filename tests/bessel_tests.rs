@@ -0,0 +1,791 @@
+#![allow(clippy::approx_constant)]
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+use core::convert::TryFrom;
+use hexf::hexf64;
+use rand::Rng;
+use twofloat::{consts::FRAC_2_PI, TwoFloat};
+
+// Bessel functions j0, j1, y0 and y1 from 1e-20 to 1000, including arguments
+// next to the first zeros of y0 and j0
+const BESSEL_CASES: [(Pair, Pair, Pair, Pair, Pair); 15] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.16c262777579cp-135"),
+        ),
+        (
+            hexf64!("0x1.79ca10c924223p-68"),
+            hexf64!("-0x1.9b604aaaca625p-204"),
+        ),
+        (
+            hexf64!("-0x1.d642788dc3fb1p+4"),
+            hexf64!("-0x1.82b01ae69dc05p-50"),
+        ),
+        (
+            hexf64!("-0x1.b9be638027fc8p+65"),
+            hexf64!("-0x1.78e68cd114f76p+11"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+        ),
+        (
+            hexf64!("0x1.feb8865590ab3p-1"),
+            hexf64!("0x1.3d645cc8a070fp-56"),
+        ),
+        (
+            hexf64!("0x1.9916952566dfdp-5"),
+            hexf64!("-0x1.015fe3d428355p-60"),
+        ),
+        (
+            hexf64!("-0x1.88c3dd3fcf18cp+0"),
+            hexf64!("-0x1.cba57f3e012f2p-54"),
+        ),
+        (
+            hexf64!("-0x1.9d5f74698d59cp+2"),
+            hexf64!("-0x1.f6763c2366240p-52"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.e07f1d54c3f34p-1"),
+            hexf64!("0x1.a2893ccbca2dbp-55"),
+        ),
+        (
+            hexf64!("0x1.f02a71f4870d7p-3"),
+            hexf64!("-0x1.df59bdcbb59c3p-57"),
+        ),
+        (
+            hexf64!("-0x1.c72feb3b7b8a2p-2"),
+            hexf64!("0x1.1af0f0ddc6aaap-57"),
+        ),
+        (
+            hexf64!("-0x1.78b26a2b7c4dfp+0"),
+            hexf64!("0x1.26edc581c875dp-55"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.c982eb8d417eap-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9ec88df344068p-1"),
+            hexf64!("0x1.11ffde6186d6ep-55"),
+        ),
+        (
+            hexf64!("0x1.9d5767fcc846bp-2"),
+            hexf64!("-0x1.eb125f7181f91p-58"),
+        ),
+        (
+            hexf64!("-0x1.af74bfa0f1304p-56"),
+            hexf64!("-0x1.11721bf3251bdp-110"),
+        ),
+        (
+            hexf64!("-0x1.c24371844b88ap-1"),
+            hexf64!("-0x1.80f5a2bb9d8d6p-57"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.8000000000000p+0"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.060e46ce9651bp-1"),
+            hexf64!("-0x1.1e9e51153032dp-56"),
+        ),
+        (
+            hexf64!("0x1.1da9da9d6fc81p-1"),
+            hexf64!("0x1.598b1190ec723p-57"),
+        ),
+        (
+            hexf64!("0x1.87a0b0d068368p-2"),
+            hexf64!("-0x1.902595cf292e8p-57"),
+        ),
+        (
+            hexf64!("-0x1.a6343b92d8b0fp-2"),
+            hexf64!("0x1.1b62187d94637p-60"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.33d152e971b40p+1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.19b7921f03c8ep-54"),
+            hexf64!("-0x1.ece5cfc3b5051p-111"),
+        ),
+        (
+            hexf64!("0x1.09cdb36551280p-1"),
+            hexf64!("0x1.8483706037fc7p-56"),
+        ),
+        (
+            hexf64!("0x1.0514cf0cb0388p-1"),
+            hexf64!("0x1.e35b3f7978916p-55"),
+        ),
+        (
+            hexf64!("0x1.a4d9b48d8464ap-4"),
+            hexf64!("-0x1.ad8e13e3431fdp-58"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.33d152e971b40p+1"),
+            hexf64!("-0x1.0f539d7da258ep-54"),
+        ),
+        (
+            hexf64!("-0x1.19b7921f03c8ep-55"),
+            hexf64!("-0x1.edc6853b295c5p-109"),
+        ),
+        (
+            hexf64!("0x1.09cdb36551280p-1"),
+            hexf64!("0x1.37673e036b7c4p-55"),
+        ),
+        (
+            hexf64!("0x1.0514cf0cb0389p-1"),
+            hexf64!("-0x1.e4e343129ab01p-55"),
+        ),
+        (
+            hexf64!("0x1.a4d9b48d84648p-4"),
+            hexf64!("-0x1.99c5ac0e83b96p-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.458d0d0bdfc29p+3"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.ff654544ebcd1p-3"),
+            hexf64!("-0x1.052a3a2541c36p-58"),
+        ),
+        (
+            hexf64!("0x1.0212f4e592523p-53"),
+            hexf64!("-0x1.e073372d2a4bdp-108"),
+        ),
+        (
+            hexf64!("0x1.90b87ef5bda94p-7"),
+            hexf64!("-0x1.a8f34a238e40fp-61"),
+        ),
+        (
+            hexf64!("0x1.009dbd953b418p-2"),
+            hexf64!("0x1.4009d86465580p-59"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.6bb7db255cb89p-3"),
+            hexf64!("-0x1.c7707ad643e7fp-57"),
+        ),
+        (
+            hexf64!("-0x1.4f70e7cb3a99dp-2"),
+            hexf64!("0x1.33167815867fbp-57"),
+        ),
+        (
+            hexf64!("-0x1.3bec0b5abc9abp-2"),
+            hexf64!("-0x1.c31c3ce5cb0f4p-56"),
+        ),
+        (
+            hexf64!("0x1.2ed2df29314fbp-3"),
+            hexf64!("0x1.f7bea5985708cp-57"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8800000000000p+3"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9d696a74ed788p-4"),
+            hexf64!("-0x1.a2fbc04945a26p-58"),
+        ),
+        (
+            hexf64!("-0x1.9a54e00165848p-3"),
+            hexf64!("0x1.4f218aa7b830fp-57"),
+        ),
+        (
+            hexf64!("-0x1.a268e41542a8dp-3"),
+            hexf64!("0x1.84c0e1acfa142p-60"),
+        ),
+        (
+            hexf64!("-0x1.bfda5436a988ep-4"),
+            hexf64!("-0x1.29061791b6248p-59"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.e000000000000p+4"),
+            hexf64!("0x1.cd2b297d889bcp-54"),
+        ),
+        (
+            hexf64!("-0x1.61c3650e6eb9cp-4"),
+            hexf64!("-0x1.2732f091e1a6cp-59"),
+        ),
+        (
+            hexf64!("-0x1.e66783a4dbb99p-4"),
+            hexf64!("0x1.f846ed1b2eb2ep-58"),
+        ),
+        (
+            hexf64!("-0x1.e0717d38dfb1dp-4"),
+            hexf64!("-0x1.453690e17ff06p-59"),
+        ),
+        (
+            hexf64!("0x1.59cea08f03724p-4"),
+            hexf64!("0x1.6e513fdc33e16p-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.6400000000000p+5"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.d81592c57ab78p-4"),
+            hexf64!("-0x1.249c12d6a1ee1p-60"),
+        ),
+        (
+            hexf64!("-0x1.f6747839f6391p-6"),
+            hexf64!("0x1.d32259c82848fp-63"),
+        ),
+        (
+            hexf64!("-0x1.05d179c1894efp-5"),
+            hexf64!("-0x1.8d2bbddacbb9fp-60"),
+        ),
+        (
+            hexf64!("-0x1.d995b23787e52p-4"),
+            hexf64!("0x1.f78e0faab1779p-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9000000000000p+5"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.c936ef41b2c50p-5"),
+            hexf64!("-0x1.3da353fa38bb6p-61"),
+        ),
+        (
+            hexf64!("-0x1.8f68900c5532ap-4"),
+            hexf64!("0x1.801ff073f78dcp-58"),
+        ),
+        (
+            hexf64!("-0x1.91ac99c6d2688p-4"),
+            hexf64!("-0x1.738d7c5ac2328p-59"),
+        ),
+        (
+            hexf64!("-0x1.d1452660e7e7dp-5"),
+            hexf64!("0x1.83dd86dcc48eap-61"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9200000000000p+6"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.bdf1c507a77f2p-5"),
+            hexf64!("-0x1.cc2d3b516c119p-59"),
+        ),
+        (
+            hexf64!("-0x1.d96cc5721be26p-5"),
+            hexf64!("0x1.4c2706ad4e06cp-59"),
+        ),
+        (
+            hexf64!("-0x1.dba3383291bf7p-5"),
+            hexf64!("0x1.efece366b72dfp-63"),
+        ),
+        (
+            hexf64!("-0x1.c050f4577ec09p-5"),
+            hexf64!("0x1.698539be1f120p-59"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.f400000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.961ae599a7b3cp-6"),
+            hexf64!("-0x1.91725446cbbf9p-60"),
+        ),
+        (
+            hexf64!("0x1.35dfe90198bdcp-8"),
+            hexf64!("-0x1.46e0e10e6da86p-65"),
+        ),
+        (
+            hexf64!("0x1.350ff97c08bedp-8"),
+            hexf64!("-0x1.10f29b6a526e8p-64"),
+        ),
+        (
+            hexf64!("-0x1.96110516a5f99p-6"),
+            hexf64!("-0x1.23fab880cafd7p-60"),
+        ),
+    ),
+];
+
+// Modified Bessel functions i0, i1, k0 and k1 up to 600, where i0 exceeds
+// 1e258 and k0 is correspondingly small
+const MODIFIED_BESSEL_CASES: [(Pair, Pair, Pair, Pair, Pair); 10] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.16c262777579cp-135"),
+        ),
+        (
+            hexf64!("0x1.79ca10c924223p-68"),
+            hexf64!("0x1.9b604aaaca625p-204"),
+        ),
+        (
+            hexf64!("0x1.7157502acd46ap+5"),
+            hexf64!("0x1.f48fc68a91661p-50"),
+        ),
+        (
+            hexf64!("0x1.5af1d78b58c40p+66"),
+            hexf64!("0x1.56cac40329bf4p+12"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.040401c78e4b2p+0"),
+            hexf64!("-0x1.8554e06db07cdp-54"),
+        ),
+        (
+            hexf64!("0x1.020155c733364p-3"),
+            hexf64!("-0x1.e4dd1598460efp-58"),
+        ),
+        (
+            hexf64!("0x1.8aa02fbb2cb70p+0"),
+            hexf64!("0x1.bafa12692e53fp-54"),
+        ),
+        (
+            hexf64!("0x1.df9e8c10be213p+1"),
+            hexf64!("0x1.3445553451e2ep-53"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.441ce4b386c2dp+0"),
+            hexf64!("-0x1.457bd884199a6p-54"),
+        ),
+        (
+            hexf64!("0x1.215c88b95e67ep-1"),
+            hexf64!("-0x1.108f77a754a54p-57"),
+        ),
+        (
+            hexf64!("0x1.af2107c43e11ap-2"),
+            hexf64!("-0x1.6461577a5300ap-57"),
+        ),
+        (
+            hexf64!("0x1.342d2f39d89c2p-1"),
+            hexf64!("-0x1.7ccf0146f74a2p-59"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.4000000000000p+1"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.a51972f665e07p+1"),
+            hexf64!("-0x1.7eb73b3407d98p-54"),
+        ),
+        (
+            hexf64!("0x1.4223c20769d70p+1"),
+            hexf64!("0x1.e988729d8d36cp-56"),
+        ),
+        (
+            hexf64!("0x1.fec04bbf65eebp-5"),
+            hexf64!("-0x1.b3efcc4603143p-59"),
+        ),
+        (
+            hexf64!("0x1.2ea822fb03577p-4"),
+            hexf64!("-0x1.006cd14c98659p-59"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+3"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.5ff6ee9ed23e4p+11"),
+            hexf64!("0x1.27c75c9f8edbcp-44"),
+        ),
+        (
+            hexf64!("0x1.4ddfa02f156cfp+11"),
+            hexf64!("0x1.9e94ad69e1a20p-45"),
+        ),
+        (
+            hexf64!("0x1.2a4cc9425b782p-16"),
+            hexf64!("0x1.ecb941a64343cp-70"),
+        ),
+        (
+            hexf64!("0x1.38dfdf41990d2p-16"),
+            hexf64!("-0x1.033ba80f511c0p-70"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.e000000000000p+4"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.6bfe996abff48p+39"),
+            hexf64!("-0x1.9defd135d0564p-15"),
+        ),
+        (
+            hexf64!("0x1.65e0288a35ea0p+39"),
+            hexf64!("-0x1.0a62db9b5b0dap-15"),
+        ),
+        (
+            hexf64!("0x1.80272aa30daa7p-46"),
+            hexf64!("0x1.7ea25bdaac55bp-100"),
+        ),
+        (
+            hexf64!("0x1.8680fe5fbaa02p-46"),
+            hexf64!("-0x1.2f4ff3ae7260cp-101"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.6400000000000p+5"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.1a2b2a03bf379p+60"),
+            hexf64!("-0x1.b86a0790aee79p+4"),
+        ),
+        (
+            hexf64!("0x1.16faddf9eeaf3p+60"),
+            hexf64!("-0x1.1c11a44647973p+6"),
+        ),
+        (
+            hexf64!("0x1.4e0e358338204p-67"),
+            hexf64!("-0x1.65e9e6b0f75cep-121"),
+        ),
+        (
+            hexf64!("0x1.51c9ce8c4910dp-67"),
+            hexf64!("-0x1.3154aac07363ep-123"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.e000000000000p+5"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.3807a3acd79fbp+82"),
+            hexf64!("-0x1.40d56f47a3238p+25"),
+        ),
+        (
+            hexf64!("0x1.356b279551026p+82"),
+            hexf64!("0x1.dfe7d84df5d87p+28"),
+        ),
+        (
+            hexf64!("0x1.c0150543676d7p-90"),
+            hexf64!("0x1.75eaba5e97c8ap-150"),
+        ),
+        (
+            hexf64!("0x1.c3cd02c556233p-90"),
+            hexf64!("0x1.f685fdb1dbcddp-147"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9000000000000p+7"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.4ffb24c95a90bp+283"),
+            hexf64!("0x1.c973b34bd2f4ap+229"),
+        ),
+        (
+            hexf64!("0x1.4f23d87fb7a80p+283"),
+            hexf64!("-0x1.803030017bc97p+229"),
+        ),
+        (
+            hexf64!("0x1.f35a063cafc28p-293"),
+            hexf64!("0x1.48ac080439d5ep-348"),
+        ),
+        (
+            hexf64!("0x1.f4993640b84f3p-293"),
+            hexf64!("-0x1.8371cc3b1e558p-347"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.2c00000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9957eb232fba4p+859"),
+            hexf64!("0x1.b212ae5a4a069p+805"),
+        ),
+        (
+            hexf64!("0x1.99008e32a09ddp+859"),
+            hexf64!("-0x1.6ee64c7bc350fp+805"),
+        ),
+        (
+            hexf64!("0x1.113ce7f3ba035p-870"),
+            hexf64!("0x1.7c7eaed178f2bp-924"),
+        ),
+        (
+            hexf64!("0x1.11772c2d91ca5p-870"),
+            hexf64!("-0x1.0a80136bff01ep-932"),
+        ),
+    ),
+];
+
+// Bessel functions jn and yn by argument and order, with orders up to 1000
+// both below and above the argument
+const ORDER_CASES: [(Pair, i32, Pair, Pair); 10] = [
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        2,
+        (
+            hexf64!("0x1.f56a93f863444p-6"),
+            hexf64!("-0x1.e2fa977fc9d2ep-63"),
+        ),
+        (
+            hexf64!("-0x1.5c3f6b77c4955p+2"),
+            hexf64!("0x1.0a9f5b39f6059p-52"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p+1"), hexf64!("0x0.0p+0")),
+        5,
+        (
+            hexf64!("0x1.607d2b130a1e5p-5"),
+            hexf64!("-0x1.bfc86729c3d5cp-61"),
+        ),
+        (
+            hexf64!("-0x1.e7ec12f3a33f0p+0"),
+            hexf64!("0x1.9f4cfb70ccb78p-54"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+1"), hexf64!("0x0.0p+0")),
+        20,
+        (
+            hexf64!("0x1.31356d87c198ap-55"),
+            hexf64!("-0x1.8101d1117731fp-110"),
+        ),
+        (
+            hexf64!("-0x1.b8e6d6a11b8a2p+48"),
+            hexf64!("0x1.df1524e9d2425p-6"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+3"), hexf64!("0x0.0p+0")),
+        3,
+        (
+            hexf64!("0x1.de3e6e9c0cc06p-5"),
+            hexf64!("-0x1.c29ffd187a7c1p-59"),
+        ),
+        (
+            hexf64!("-0x1.016536602934ep-2"),
+            hexf64!("-0x1.c86e1e4653af1p-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9000000000000p+4"), hexf64!("0x0.0p+0")),
+        7,
+        (
+            hexf64!("-0x1.4d30c6f78650bp-7"),
+            hexf64!("-0x1.4597147abf1acp-62"),
+        ),
+        (
+            hexf64!("0x1.4cd8a26e60775p-3"),
+            hexf64!("-0x1.59ea2459f6650p-59"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+5"), hexf64!("0x0.0p+0")),
+        60,
+        (
+            hexf64!("0x1.1929b8dcef3f9p-23"),
+            hexf64!("-0x1.b404f4a79ef22p-77"),
+        ),
+        (
+            hexf64!("-0x1.a8e2c93a49d0dp+15"),
+            hexf64!("-0x1.b14d709950b3fp-41"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.1800000000000p+6"), hexf64!("0x0.0p+0")),
+        4,
+        (
+            hexf64!("0x1.7e2c210d3d660p-4"),
+            hexf64!("-0x1.3f58b834d4f22p-64"),
+        ),
+        (
+            hexf64!("0x1.49285712d9a7cp-6"),
+            hexf64!("0x1.0df9466317718p-60"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.2c00000000000p+7"), hexf64!("0x0.0p+0")),
+        100,
+        (
+            hexf64!("-0x1.f74d0b2e286f6p-7"),
+            hexf64!("0x1.f3cba282f8137p-61"),
+        ),
+        (
+            hexf64!("0x1.2e98acdf44ebbp-4"),
+            hexf64!("0x1.859d9155c88c7p-60"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.86a0000000000p+16"), hexf64!("0x0.0p+0")),
+        300,
+        (
+            hexf64!("-0x1.3431b4ebff345p-9"),
+            hexf64!("0x1.4d85ed61575c9p-63"),
+        ),
+        (
+            hexf64!("0x1.dfc9c423a5d28p-11"),
+            hexf64!("0x1.56cc241723a75p-66"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.e848000000000p+19"), hexf64!("0x0.0p+0")),
+        1000,
+        (
+            hexf64!("0x1.4ecad322be743p-11"),
+            hexf64!("0x1.fce92882f007ep-65"),
+        ),
+        (
+            hexf64!("-0x1.f5a003bf83854p-12"),
+            hexf64!("-0x1.67b513cdc16a9p-66"),
+        ),
+    ),
+];
+#[test]
+fn bessel_reference_test() {
+    for &(source, j0, j1, y0, y1) in BESSEL_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(x.j0(), j0, SPECIAL_RELATIVE_ERROR, &format!("j0({:?})", x));
+        assert_relative(x.j1(), j1, SPECIAL_RELATIVE_ERROR, &format!("j1({:?})", x));
+        assert_relative(x.y0(), y0, SPECIAL_RELATIVE_ERROR, &format!("y0({:?})", x));
+        assert_relative(x.y1(), y1, SPECIAL_RELATIVE_ERROR, &format!("y1({:?})", x));
+        assert_relative(
+            x.jn(0),
+            j0,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("jn({:?}, 0)", x),
+        );
+        assert_relative(
+            x.yn(1),
+            y1,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("yn({:?}, 1)", x),
+        );
+    }
+}
+
+#[test]
+fn modified_bessel_reference_test() {
+    for &(source, i0, i1, k0, k1) in MODIFIED_BESSEL_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(x.i0(), i0, SPECIAL_RELATIVE_ERROR, &format!("i0({:?})", x));
+        assert_relative(x.i1(), i1, SPECIAL_RELATIVE_ERROR, &format!("i1({:?})", x));
+        assert_relative(x.k0(), k0, SPECIAL_RELATIVE_ERROR, &format!("k0({:?})", x));
+        assert_relative(x.k1(), k1, SPECIAL_RELATIVE_ERROR, &format!("k1({:?})", x));
+    }
+}
+
+#[test]
+fn bessel_order_reference_test() {
+    for &(source, n, jn, yn) in ORDER_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(
+            x.jn(n),
+            jn,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("jn({:?}, {})", x, n),
+        );
+        assert_relative(
+            x.yn(n),
+            yn,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("yn({:?}, {})", x, n),
+        );
+    }
+}
+
+#[test]
+fn bessel_special_test() {
+    let zero = TwoFloat::from(0.0);
+    let neg_zero = TwoFloat::from(-0.0);
+
+    assert_eq!(zero.j0(), 1.0);
+    assert_eq!(zero.i0(), 1.0);
+    assert_eq!(zero.j1(), 0.0);
+    assert!(neg_zero.j1().is_sign_negative());
+    assert!(neg_zero.i1().is_sign_negative());
+    assert_eq!(zero.jn(5), 0.0);
+    assert_eq!(TwoFloat::from(1.5).jn(1_000_000), 0.0);
+
+    for f in [TwoFloat::y0, TwoFloat::y1].iter() {
+        assert_eq!(f(zero), TwoFloat::NEG_INFINITY);
+        assert!(f(TwoFloat::from(-1.0)).hi().is_nan());
+    }
+    for f in [TwoFloat::k0, TwoFloat::k1].iter() {
+        assert_eq!(f(zero), TwoFloat::INFINITY);
+        assert!(f(TwoFloat::from(-1.0)).hi().is_nan());
+        assert_eq!(f(TwoFloat::INFINITY), 0.0);
+    }
+    assert_eq!(zero.yn(4), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::from(1.5).yn(1000), TwoFloat::NEG_INFINITY);
+
+    // Orders beyond the limit of the recurrences
+    let large = TwoFloat::from(1e10);
+    assert!(large.jn(i32::MAX).hi().is_nan());
+    assert!(large.yn(i32::MIN).hi().is_nan());
+    assert!(TwoFloat::from(1e20).jn(i32::MAX).is_valid());
+    assert!(TwoFloat::from(1e20).yn(i32::MAX).is_valid());
+
+    for f in [TwoFloat::j0, TwoFloat::j1, TwoFloat::y0, TwoFloat::y1].iter() {
+        assert_eq!(f(TwoFloat::INFINITY), 0.0);
+    }
+    assert_eq!(TwoFloat::NEG_INFINITY.j0(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.i0(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.i1(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::from(1000.0).i0(), TwoFloat::INFINITY);
+
+    for f in [
+        TwoFloat::j0,
+        TwoFloat::j1,
+        TwoFloat::y0,
+        TwoFloat::y1,
+        TwoFloat::i0,
+        TwoFloat::i1,
+        TwoFloat::k0,
+        TwoFloat::k1,
+    ]
+    .iter()
+    {
+        assert!(f(TwoFloat::NAN).hi().is_nan());
+    }
+    assert!(TwoFloat::NAN.jn(3).hi().is_nan());
+    assert!(TwoFloat::NAN.yn(3).hi().is_nan());
+}
+
+#[test]
+fn bessel_symmetry_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(0.0, 100.0).unwrap();
+    repeated_slow_test(|| {
+        let x = TwoFloat::from(rng.sample(dist));
+        assert_eq!((-x).j0(), x.j0(), "j0 is not even at {:?}", x);
+        assert_eq!((-x).j1(), -x.j1(), "j1 is not odd at {:?}", x);
+        assert_eq!((-x).i0(), x.i0(), "i0 is not even at {:?}", x);
+        assert_eq!((-x).i1(), -x.i1(), "i1 is not odd at {:?}", x);
+        assert_eq!((-x).jn(3), -x.jn(3), "j3 is not odd at {:?}", x);
+        assert_eq!(x.jn(-3), -x.jn(3), "j(-3) does not match j3 at {:?}", x);
+        assert_eq!(x.yn(-2), x.yn(2), "y(-2) does not match y2 at {:?}", x);
+    });
+}
+
+#[test]
+fn bessel_wronskian_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(0.0, 100.0).unwrap();
+    repeated_slow_test(|| {
+        let x = TwoFloat::from(rng.sample(dist));
+        if x == 0.0 {
+            return;
+        }
+
+        // j1(x) y0(x) - j0(x) y1(x) = 2/(pi x)
+        let expected = FRAC_2_PI / x;
+        let result = x.j1() * x.y0() - x.j0() * x.y1();
+        assert!(
+            ((result - expected) / expected).abs() < 1e-29,
+            "Bessel functions at {:?} do not satisfy the Wronskian",
+            x
+        );
+
+        // i0(x) k1(x) + i1(x) k0(x) = 1/x
+        let expected = x.recip();
+        let result = x.i0() * x.k1() + x.i1() * x.k0();
+        assert!(
+            ((result - expected) / expected).abs() < 1e-29,
+            "Modified Bessel functions at {:?} do not satisfy the Wronskian",
+            x
+        );
+    });
+}
@@ -0,0 +1,229 @@
+use num_rational::BigRational;
+use num_traits::Signed;
+use rand::Rng;
+
+use twofloat::TwoFloat;
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+
+// Exact arithmetic on large rationals is slow, so use fewer iterations for
+// each exponent in the sweep
+const SWEEP_ITERS: usize = 20;
+
+// Exponents of the results close to the bottom and top of the range of f64
+const LOW_EXPONENTS: core::ops::Range<i32> = -1074..-940;
+const HIGH_EXPONENTS: core::ops::Range<i32> = 940..1024;
+
+// Absolute error allowed for results whose low word is subnormal
+const UNDERFLOW_ERROR: f64 = 4.0 * f64::MIN_POSITIVE * f64::EPSILON;
+
+// Relative error bound of `sqrt`, as stated in its documentation
+const SQRT_ERROR_BOUND: f64 = 1.0 / (1u128 << 102) as f64;
+
+fn sweep() -> impl Iterator<Item = i32> {
+    LOW_EXPONENTS.chain(HIGH_EXPONENTS)
+}
+
+fn random_f64(exponent: i32) -> f64 {
+    let mut rng = rand::rng();
+    let value = rng.random_range(1.0..2.0) * libm::ldexp(1.0, exponent);
+    if rng.random() {
+        value
+    } else {
+        -value
+    }
+}
+
+// Returns a pair of exponents within the range of f64 with the given sum, or
+// difference if `difference` is set
+fn split_exponent(target: i32, difference: bool) -> (i32, i32) {
+    let mut rng = rand::rng();
+    loop {
+        let a = rng.random_range(-1074..1024);
+        let b = if difference { a - target } else { target - a };
+        if (-1074..1024).contains(&b) {
+            return (a, b);
+        }
+    }
+}
+
+fn assert_accurate(result: TwoFloat, exact: &BigRational, bound: f64, description: &str) {
+    let limit = BigRational::from_float(f64::MAX).unwrap();
+    if exact.abs() >= limit {
+        return;
+    }
+
+    let error = (rational(result) - exact).abs();
+    let allowed = exact.abs() * BigRational::from_float(bound).unwrap()
+        + BigRational::from_float(UNDERFLOW_ERROR).unwrap();
+    assert!(
+        result.is_valid() && error <= allowed,
+        "{} gave {:?}",
+        description,
+        result
+    );
+}
+
+#[test]
+fn constructor_boundary_test() {
+    for target in sweep() {
+        for _ in 0..SWEEP_ITERS {
+            let (ea, eb) = split_exponent(target, false);
+            let (a, b) = (random_f64(ea), random_f64(eb));
+            let exact = BigRational::from_float(a).unwrap() * BigRational::from_float(b).unwrap();
+            assert_accurate(
+                TwoFloat::new_mul(a, b),
+                &exact,
+                TwoFloat::ERROR_BOUND_MUL_F64,
+                &format!("new_mul({:?}, {:?})", a, b),
+            );
+
+            let (ea, eb) = split_exponent(target, true);
+            let (a, b) = (random_f64(ea), random_f64(eb));
+            let exact = BigRational::from_float(a).unwrap() / BigRational::from_float(b).unwrap();
+            assert_accurate(
+                TwoFloat::new_div(a, b),
+                &exact,
+                TwoFloat::ERROR_BOUND_DIV_F64,
+                &format!("new_div({:?}, {:?})", a, b),
+            );
+
+            let (a, b) = (random_f64(target), random_f64(target - 1));
+            let exact = BigRational::from_float(a).unwrap() + BigRational::from_float(b).unwrap();
+            assert_accurate(
+                TwoFloat::new_add(a, b),
+                &exact,
+                TwoFloat::ERROR_BOUND_ADD_F64,
+                &format!("new_add({:?}, {:?})", a, b),
+            );
+        }
+    }
+}
+
+#[test]
+fn add_boundary_test() {
+    for target in sweep() {
+        for _ in 0..SWEEP_ITERS {
+            let a = random_twofloat(target, target + 1);
+            let b = random_twofloat(target - 59, target + 1);
+            let (x, y) = (rational(a), rational(b));
+
+            let description = format!("{:?} + {:?}", a, b);
+            assert_accurate(a + b, &(&x + &y), TwoFloat::ERROR_BOUND_ADD, &description);
+            let description = format!("{:?} - {:?}", a, b);
+            assert_accurate(a - b, &(&x - &y), TwoFloat::ERROR_BOUND_ADD, &description);
+            let description = format!("{:?} + {:?}", a, b.hi());
+            assert_accurate(
+                a + b.hi(),
+                &(&x + BigRational::from_float(b.hi()).unwrap()),
+                TwoFloat::ERROR_BOUND_ADD,
+                &description,
+            );
+        }
+    }
+}
+
+#[test]
+fn mul_boundary_test() {
+    for target in sweep() {
+        for _ in 0..SWEEP_ITERS {
+            let (ea, eb) = split_exponent(target, false);
+            let (a, b) = (random_twofloat(ea, ea + 1), random_twofloat(eb, eb + 1));
+            let (x, y) = (rational(a), rational(b));
+
+            let description = format!("{:?} * {:?}", a, b);
+            assert_accurate(a * b, &(&x * &y), TwoFloat::ERROR_BOUND_MUL, &description);
+            let description = format!("{:?} * {:?}", a, b.hi());
+            assert_accurate(
+                a * b.hi(),
+                &(&x * BigRational::from_float(b.hi()).unwrap()),
+                TwoFloat::ERROR_BOUND_MUL,
+                &description,
+            );
+        }
+    }
+}
+
+#[test]
+fn div_boundary_test() {
+    for target in sweep() {
+        for _ in 0..SWEEP_ITERS {
+            let (ea, eb) = split_exponent(target, true);
+            let (a, b) = (random_twofloat(ea, ea + 1), random_twofloat(eb, eb + 1));
+            let (x, y) = (rational(a), rational(b));
+
+            let description = format!("{:?} / {:?}", a, b);
+            assert_accurate(a / b, &(&x / &y), TwoFloat::ERROR_BOUND_DIV, &description);
+
+            let c = b.hi();
+            let z = BigRational::from_float(c).unwrap();
+            let description = format!("{:?} / {:?}", a, c);
+            assert_accurate(a / c, &(&x / &z), TwoFloat::ERROR_BOUND_DIV, &description);
+
+            let c = a.hi();
+            let z = BigRational::from_float(c).unwrap();
+            let description = format!("{:?} / {:?}", c, b);
+            assert_accurate(c / b, &(&z / &y), TwoFloat::ERROR_BOUND_DIV, &description);
+        }
+    }
+}
+
+#[test]
+fn sqrt_boundary_test() {
+    for exponent in -1074..1024 {
+        for _ in 0..SWEEP_ITERS {
+            let a = random_twofloat(exponent, exponent + 1).abs();
+            let x = rational(a);
+            let result = a.sqrt();
+            let square = rational(result) * rational(result);
+            let allowed = &x * BigRational::from_float(2.0 * SQRT_ERROR_BOUND).unwrap();
+            assert!(
+                result.is_valid() && (square - &x).abs() <= allowed,
+                "sqrt({:?}) gave {:?}",
+                a,
+                result
+            );
+        }
+    }
+}
+
+#[test]
+fn max_value_test() {
+    let max = TwoFloat::MAX;
+    let x = rational(max);
+    for &divisor in [3.0, 1.5, 7.0, 1.0 + f64::EPSILON].iter() {
+        let y = BigRational::from_float(divisor).unwrap();
+        let description = format!("MAX / {:?}", divisor);
+        assert_accurate(
+            max / divisor,
+            &(&x / &y),
+            TwoFloat::ERROR_BOUND_DIV,
+            &description,
+        );
+        let description = format!("MAX / TwoFloat({:?})", divisor);
+        assert_accurate(
+            max / TwoFloat::from(divisor),
+            &(&x / &y),
+            TwoFloat::ERROR_BOUND_DIV,
+            &description,
+        );
+        let description = format!("new_div(f64::MAX, {:?})", divisor);
+        assert_accurate(
+            TwoFloat::new_div(f64::MAX, divisor),
+            &(BigRational::from_float(f64::MAX).unwrap() / &y),
+            TwoFloat::ERROR_BOUND_DIV_F64,
+            &description,
+        );
+    }
+
+    let root = max.sqrt();
+    assert!(root.is_valid(), "sqrt(MAX) gave {:?}", root);
+    assert!((root * root - max).abs() <= max * 2.0 * SQRT_ERROR_BOUND);
+    assert_eq!(max * 1.0, max);
+    assert_eq!(max / 1.0, max);
+    assert_eq!(max / TwoFloat::from(1.0), max);
+    assert_eq!(max - max, 0.0);
+}
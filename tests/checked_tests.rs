@@ -0,0 +1,238 @@
+use twofloat::{StrictTwoFloat, TwoFloat, TwoFloatError};
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+
+fn expected(result: TwoFloat) -> Result<TwoFloat, TwoFloatError> {
+    if result.hi().is_finite() {
+        Ok(result)
+    } else {
+        Err(TwoFloatError::Overflow)
+    }
+}
+
+#[test]
+fn checked_arithmetic_test() {
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid());
+        let b = get_valid_ddouble(|x| x.is_valid());
+        assert_eq!(a.checked_add(b), expected(a + b), "{:?} + {:?}", a, b);
+        assert_eq!(a.checked_sub(b), expected(a - b), "{:?} - {:?}", a, b);
+        assert_eq!(a.checked_mul(b), expected(a * b), "{:?} * {:?}", a, b);
+        assert_eq!(a.checked_div(b), expected(a / b), "{:?} / {:?}", a, b);
+        assert_eq!(a.checked_rem(b), Ok(a % b), "{:?} % {:?}", a, b);
+    });
+}
+
+#[test]
+fn checked_function_test() {
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.hi() > 0.0);
+        assert_eq!(a.checked_sqrt(), Ok(a.sqrt()), "sqrt({:?})", a);
+        assert_eq!(a.checked_ln(), Ok(a.ln()), "ln({:?})", a);
+        assert_eq!(a.checked_exp(), expected(a.exp()), "exp({:?})", a);
+    });
+}
+
+#[test]
+fn checked_special_test() {
+    use TwoFloatError::*;
+
+    let zero = TwoFloat::from(0.0);
+    let one = TwoFloat::from(1.0);
+    let two = TwoFloat::from(2.0);
+    let big = TwoFloat::from(1e300);
+    let inf = TwoFloat::INFINITY;
+    let nan = TwoFloat::NAN;
+
+    let cases = [
+        (
+            "MAX + MAX",
+            TwoFloat::MAX.checked_add(TwoFloat::MAX),
+            Err(Overflow),
+        ),
+        ("inf + 1", inf.checked_add(one), Ok(inf)),
+        ("inf - inf", inf.checked_sub(inf), Err(DomainError)),
+        ("NaN - 1", nan.checked_sub(one), Err(NaNInput)),
+        ("1e300 * 1e300", big.checked_mul(big), Err(Overflow)),
+        ("inf * 0", inf.checked_mul(zero), Err(DomainError)),
+        ("1 * NaN", one.checked_mul(nan), Err(NaNInput)),
+        ("1 / 0", one.checked_div(zero), Err(DivisionByZero)),
+        ("-1 / -0", (-one).checked_div(-zero), Err(DivisionByZero)),
+        ("0 / 0", zero.checked_div(zero), Err(DomainError)),
+        ("inf / 0", inf.checked_div(zero), Ok(inf)),
+        ("1 / inf", one.checked_div(inf), Ok(zero)),
+        (
+            "1e300 / 1e-300",
+            big.checked_div(big.recip()),
+            Err(Overflow),
+        ),
+        ("1 % 0", one.checked_rem(zero), Err(DomainError)),
+        ("inf % 1", inf.checked_rem(one), Err(DomainError)),
+        ("sqrt(-1)", (-one).checked_sqrt(), Err(DomainError)),
+        ("sqrt(-0)", (-zero).checked_sqrt(), Ok(-zero)),
+        ("sqrt(inf)", inf.checked_sqrt(), Ok(inf)),
+        ("sqrt(NaN)", nan.checked_sqrt(), Err(NaNInput)),
+        (
+            "exp(1000)",
+            TwoFloat::from(1000.0).checked_exp(),
+            Err(Overflow),
+        ),
+        ("exp(-inf)", (-inf).checked_exp(), Ok(zero)),
+        (
+            "exp(709.5)",
+            TwoFloat::from(709.5).checked_exp(),
+            Ok(TwoFloat::from(709.5).exp()),
+        ),
+        (
+            "exp(-740)",
+            TwoFloat::from(-740.0).checked_exp(),
+            Ok(TwoFloat::from(-740.0).exp()),
+        ),
+        (
+            "exp2(2000)",
+            TwoFloat::from(2000.0).checked_exp2(),
+            Err(Overflow),
+        ),
+        (
+            "exp2(1024)",
+            TwoFloat::from(1024.0).checked_exp2(),
+            Err(Overflow),
+        ),
+        (
+            "exp2(1023.5)",
+            TwoFloat::from(1023.5).checked_exp2(),
+            Ok(TwoFloat::from(1023.5).exp2()),
+        ),
+        (
+            "exp2(-1074)",
+            TwoFloat::from(-1074.0).checked_exp2(),
+            Ok(TwoFloat::from(f64::from_bits(1))),
+        ),
+        ("ln(0)", zero.checked_ln(), Err(DivisionByZero)),
+        ("ln(-1)", (-one).checked_ln(), Err(DomainError)),
+        ("ln(inf)", inf.checked_ln(), Ok(inf)),
+        ("log2(2)", two.checked_log2(), Ok(one)),
+        ("log2(-0)", (-zero).checked_log2(), Err(DivisionByZero)),
+        ("log10(-2)", (-two).checked_log10(), Err(DomainError)),
+        ("0^-1", zero.checked_powi(-1), Err(DivisionByZero)),
+        ("0^0", zero.checked_powi(0), Ok(one)),
+        ("1e300^2", big.checked_powi(2), Err(Overflow)),
+        ("2^3", two.checked_powi(3), Ok(TwoFloat::from(8.0))),
+        (
+            "0^-0.5",
+            zero.checked_powf(-two.recip()),
+            Err(DivisionByZero),
+        ),
+        ("-2^0.5", (-two).checked_powf(two.recip()), Err(DomainError)),
+        ("2^1e300", two.checked_powf(big), Err(Overflow)),
+        ("2^NaN", two.checked_powf(nan), Err(NaNInput)),
+    ];
+
+    for (description, result, expected) in cases.iter() {
+        assert_eq!(result, expected, "{}", description);
+    }
+}
+
+#[test]
+fn error_display_test() {
+    assert_eq!(
+        TwoFloatError::Overflow.to_string(),
+        "TwoFloat operation overflowed"
+    );
+    assert_eq!(
+        TwoFloatError::DomainError.to_string(),
+        "TwoFloat operation outside its domain"
+    );
+    assert_eq!(
+        TwoFloatError::DivisionByZero.to_string(),
+        "TwoFloat division by zero"
+    );
+    assert_eq!(
+        TwoFloatError::NaNInput.to_string(),
+        "NaN operand in TwoFloat operation"
+    );
+}
+
+#[test]
+fn strict_arithmetic_test() {
+    repeated_test(|| {
+        let (a, b) = get_valid_pair(|x, y| x.abs() < 1e150 && y.abs() > 1e-150 && y.abs() < 1e150);
+        let (x, y) = (TwoFloat::from(a), TwoFloat::from(b));
+        let (sx, sy) = (StrictTwoFloat::from(a), StrictTwoFloat(y));
+
+        assert_eq!(sx + sy, StrictTwoFloat(x + y));
+        assert_eq!(sx - b, StrictTwoFloat(x - y));
+        assert_eq!(a * sy, StrictTwoFloat(x * y));
+        assert_eq!(sx / sy, StrictTwoFloat(x / y));
+        assert_eq!(sx % sy, StrictTwoFloat(x % y));
+        assert_eq!(-sx, StrictTwoFloat(-x));
+
+        let mut result = sx;
+        result += sy;
+        result *= b;
+        result /= &sy;
+        result -= a;
+        assert_eq!(TwoFloat::from(result), (x + y) * y / y - x);
+    });
+}
+
+#[test]
+fn strict_function_test() {
+    let a = StrictTwoFloat::from(2.0);
+    let x = TwoFloat::from(2.0);
+
+    assert_eq!(a.sqrt(), StrictTwoFloat(x.sqrt()));
+    assert_eq!(a.exp(), StrictTwoFloat(x.exp()));
+    assert_eq!(a.exp2(), StrictTwoFloat::from(4.0));
+    assert_eq!(a.ln(), StrictTwoFloat(x.ln()));
+    assert_eq!(a.log2(), StrictTwoFloat::from(1.0));
+    assert_eq!(a.log10(), StrictTwoFloat(x.log10()));
+    assert_eq!(a.powi(-2), StrictTwoFloat::from(0.25));
+    assert_eq!(a.powf(a), StrictTwoFloat(x.powf(x)));
+    assert_eq!(a.to_string(), x.to_string());
+
+    let large = TwoFloat::from(709.5);
+    assert!(large.checked_exp().unwrap().hi().is_finite());
+    assert_eq!(StrictTwoFloat(large).exp(), StrictTwoFloat(large.exp()));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "invalid StrictTwoFloat result: TwoFloat operation overflowed")]
+fn strict_overflow_test() {
+    let a = StrictTwoFloat::from(1e300);
+    let _ = a * a;
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "invalid StrictTwoFloat result: TwoFloat division by zero")]
+fn strict_division_test() {
+    let mut a = StrictTwoFloat::from(1.0);
+    a /= 0.0;
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "invalid StrictTwoFloat result: TwoFloat operation outside its domain")]
+fn strict_domain_test() {
+    let _ = StrictTwoFloat::from(-1.0).ln();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "invalid StrictTwoFloat result: NaN operand in TwoFloat operation")]
+fn strict_nan_test() {
+    let _ = 1.0 + StrictTwoFloat(TwoFloat::NAN);
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn strict_release_test() {
+    let a = StrictTwoFloat::from(1e300);
+    assert_eq!(a * a, StrictTwoFloat(TwoFloat::INFINITY));
+    assert!(StrictTwoFloat::from(-1.0).ln().0.hi().is_nan());
+}
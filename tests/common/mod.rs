@@ -1,11 +1,16 @@
 use core::convert::TryFrom;
 
+use num_rational::BigRational;
 use rand::Rng;
 
-use twofloat::{TwoFloat, TwoFloatError};
+use twofloat::{QuadFloat, TwoFloat, TwoFloatError};
 
 const TEST_ITERS: usize = 100_000;
 
+// Iterations of the randomized identity tests of the special functions, each
+// of which evaluates several functions costing thousands of operations
+const SLOW_TEST_ITERS: usize = 1_000;
+
 pub fn random_float() -> f64 {
     let mut engine = rand::rng();
     let mantissa_dist = rand::distr::Uniform::new(0, 1u64 << 52).unwrap();
@@ -33,12 +38,42 @@ pub fn random_ddouble() -> TwoFloat {
     TwoFloat::new_add(x_hi, x_lo)
 }
 
+// Returns a random value with a magnitude in [2^min_exponent, 2^max_exponent)
+// and a low word of up to half an ulp of the high word
+pub fn random_twofloat(min_exponent: i32, max_exponent: i32) -> TwoFloat {
+    let mut rng = rand::rng();
+    let exponent = rng.random_range(min_exponent..max_exponent);
+    let hi = rng.random_range(1.0..2.0) * libm::ldexp(1.0, exponent);
+    let lo = hi * rng.random_range(-1.0..1.0) * f64::EPSILON / 2.0;
+    let value = TwoFloat::new_add(hi, lo);
+    if rng.random() {
+        value
+    } else {
+        -value
+    }
+}
+
+// Returns the exact value as a rational number
+pub fn rational(x: TwoFloat) -> BigRational {
+    BigRational::from_float(x.hi()).unwrap() + BigRational::from_float(x.lo()).unwrap()
+}
+
+pub fn quad(x: TwoFloat) -> QuadFloat {
+    QuadFloat::from(x)
+}
+
 pub fn repeated_test(mut test: impl FnMut()) {
     for _ in 0..TEST_ITERS {
         test();
     }
 }
 
+pub fn repeated_slow_test(mut test: impl FnMut()) {
+    for _ in 0..SLOW_TEST_ITERS {
+        test();
+    }
+}
+
 pub fn repeated_test_enumerate(mut test: impl FnMut(usize)) {
     for i in 0..TEST_ITERS {
         test(i);
@@ -146,6 +181,62 @@ where
     }
 }
 
+// The reference tables of the tests hold values computed using mpmath with
+// far more precision than a `TwoFloat`, written as the words of the rounded
+// result. Each row lists the arguments followed by the function values.
+pub type Pair = (f64, f64);
+
+// Arguments of the reference tables, stored either as an `f64` when they are
+// exactly representable or as the words of a `TwoFloat`
+pub trait Argument: Copy {
+    fn to_twofloat(self) -> TwoFloat;
+}
+
+impl Argument for f64 {
+    fn to_twofloat(self) -> TwoFloat {
+        TwoFloat::from(self)
+    }
+}
+
+impl Argument for Pair {
+    fn to_twofloat(self) -> TwoFloat {
+        TwoFloat::try_from(self).unwrap()
+    }
+}
+
+// Largest relative error accepted for the reference values of the elementary
+// functions
+pub const RELATIVE_ERROR: f64 = 2e-31;
+
+// Largest relative error accepted for the reference values of the special
+// functions
+pub const SPECIAL_RELATIVE_ERROR: f64 = 4e-31;
+
+pub fn assert_relative(result: TwoFloat, expected: Pair, max_error: f64, description: &str) {
+    let expected = TwoFloat::try_from(expected).unwrap();
+    let error = ((result - expected) / expected).abs();
+    assert!(
+        error <= max_error,
+        "Mismatch in {}: {:?} vs {:?}",
+        description,
+        result,
+        expected
+    );
+}
+
+// Checks a function of a single argument against a reference table
+pub fn assert_cases<A: Argument>(
+    cases: &[(A, Pair)],
+    f: impl Fn(TwoFloat) -> TwoFloat,
+    max_error: f64,
+    name: &str,
+) {
+    for &(source, expected) in cases.iter() {
+        let x = source.to_twofloat();
+        assert_relative(f(x), expected, max_error, &format!("{}({:?})", name, x));
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! assert_eq_ulp {
     ($left:expr, $right:expr, $ulp:expr) => ({
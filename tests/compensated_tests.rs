@@ -0,0 +1,227 @@
+use rand::Rng;
+
+use twofloat::{dot, eval_poly, sum_k, QuadFloat, TwoFloat};
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+
+const UNIT_ROUNDOFF: f64 = 1.2e-16;
+
+// Relative error allowed for rounding the result to a `TwoFloat`
+const RESULT_ERROR: f64 = 1e-31;
+
+// The slices are built from many random values, so use fewer iterations
+const SLICE_ITERS: usize = 10_000;
+
+const MAX_LENGTH: usize = 40;
+
+fn random_value() -> f64 {
+    let mut rng = rand::rng();
+    let value = rng.random_range(1.0..2.0) * 2f64.powi(rng.random_range(-60..60));
+    if rng.random() {
+        value
+    } else {
+        -value
+    }
+}
+
+// Returns random values, followed by the words of their negated sum so that
+// the total cancels
+fn cancelling_values() -> Vec<f64> {
+    let mut rng = rand::rng();
+    let length = rng.random_range(1..MAX_LENGTH);
+    let mut values: Vec<f64> = (0..length).map(|_| random_value()).collect();
+    let total: TwoFloat = values.iter().sum();
+    values.push(-total.hi());
+    values.push(-total.lo());
+    if rng.random() {
+        values.push(random_value() * 1e-30);
+    }
+    values.reverse();
+    values
+}
+
+fn assert_close(result: TwoFloat, exact: QuadFloat, bound: QuadFloat, description: &str) {
+    let error = (quad(result) - exact).abs();
+    assert!(
+        error <= exact.abs() * RESULT_ERROR + bound,
+        "{} gave {:?} with error {:?}",
+        description,
+        result,
+        error.words()
+    );
+}
+
+#[test]
+fn dot_f64_test() {
+    let mut rng = rand::rng();
+    for _ in 0..SLICE_ITERS {
+        let length = rng.random_range(1..MAX_LENGTH);
+        let mut x: Vec<f64> = (0..length).map(|_| random_value()).collect();
+        let mut y: Vec<f64> = (0..length).map(|_| random_value()).collect();
+        let total: TwoFloat = x
+            .iter()
+            .zip(y.iter())
+            .map(|(&a, &b)| TwoFloat::new_mul(a, b))
+            .sum();
+        x.extend_from_slice(&[-total.hi(), -total.lo()]);
+        y.extend_from_slice(&[1.0, 1.0]);
+
+        let (exact, magnitude) = x.iter().zip(y.iter()).fold(
+            (QuadFloat::from(0.0), QuadFloat::from(0.0)),
+            |(sum, magnitude), (&a, &b)| {
+                let p = quad(TwoFloat::new_mul(a, b));
+                (sum + p, magnitude + p.abs())
+            },
+        );
+
+        let condition = (x.len() as f64 * UNIT_ROUNDOFF).powi(2) * 2.0;
+        assert_close(
+            dot(&x, &y),
+            exact,
+            magnitude * condition,
+            &format!("dot({:?}, {:?})", x, y),
+        );
+    }
+}
+
+#[test]
+fn dot_twofloat_test() {
+    let mut rng = rand::rng();
+    for _ in 0..SLICE_ITERS {
+        let length = rng.random_range(1..MAX_LENGTH);
+        let mut x: Vec<TwoFloat> = (0..length).map(|_| random_twofloat(-60, 60)).collect();
+        let mut y: Vec<TwoFloat> = (0..length).map(|_| random_twofloat(-60, 60)).collect();
+        let total = x
+            .iter()
+            .zip(y.iter())
+            .fold(QuadFloat::from(0.0), |sum, (&a, &b)| {
+                sum + quad(a) * quad(b)
+            });
+        x.push(-TwoFloat::from(total));
+        y.push(TwoFloat::from(1.0));
+
+        let (exact, magnitude) = x.iter().zip(y.iter()).fold(
+            (QuadFloat::from(0.0), QuadFloat::from(0.0)),
+            |(sum, magnitude), (&a, &b)| {
+                let p = quad(a) * quad(b);
+                (sum + p, magnitude + p.abs())
+            },
+        );
+
+        let condition = (7.0 * x.len() as f64 * UNIT_ROUNDOFF).powi(3) * 2.0;
+        assert_close(
+            dot(&x, &y),
+            exact,
+            magnitude * condition,
+            &format!("dot({:?}, {:?})", x, y),
+        );
+    }
+}
+
+fn assert_sum_k<const K: usize>(values: &[f64]) {
+    let (exact, magnitude) = values.iter().fold(
+        (QuadFloat::from(0.0), QuadFloat::from(0.0)),
+        |(sum, magnitude), &x| (sum + x, magnitude + x.abs()),
+    );
+
+    let condition = (values.len() as f64 * UNIT_ROUNDOFF).powi(K as i32) * 2.0;
+    assert_close(
+        sum_k::<K>(values),
+        exact,
+        magnitude * condition,
+        &format!("sum_k::<{}>({:?})", K, values),
+    );
+}
+
+#[test]
+fn sum_k_test() {
+    for _ in 0..SLICE_ITERS {
+        let values = cancelling_values();
+        assert_sum_k::<2>(&values);
+        assert_sum_k::<3>(&values);
+        assert_sum_k::<4>(&values);
+    }
+}
+
+#[test]
+fn eval_poly_test() {
+    let mut rng = rand::rng();
+    for _ in 0..SLICE_ITERS {
+        // Polynomial with roots clustered around a random point
+        let centre = random_twofloat(-60, 60);
+        let degree = rng.random_range(1..8);
+        let mut coefficients = vec![TwoFloat::from(1.0)];
+        for _ in 0..degree {
+            let root = centre * (1.0 + rng.random_range(-1e-3..1e-3));
+            let mut next = vec![TwoFloat::from(0.0); coefficients.len() + 1];
+            for (i, &c) in coefficients.iter().enumerate() {
+                next[i + 1] += c;
+                next[i] -= c * root;
+            }
+            coefficients = next;
+        }
+
+        let x = centre * (1.0 + rng.random_range(-1e-3..1e-3));
+        let (exact, magnitude) = coefficients.iter().rev().fold(
+            (QuadFloat::from(0.0), QuadFloat::from(0.0)),
+            |(sum, magnitude), &c| {
+                (
+                    sum * quad(x) + quad(c),
+                    magnitude * quad(x).abs() + quad(c).abs(),
+                )
+            },
+        );
+
+        let condition = 4.0 * coefficients.len() as f64 * UNIT_ROUNDOFF.powi(3);
+        assert_close(
+            eval_poly(&coefficients, x),
+            exact,
+            magnitude * condition,
+            &format!("eval_poly({:?}, {:?})", coefficients, x),
+        );
+    }
+}
+
+#[test]
+fn compensated_special_test() {
+    let empty: [f64; 0] = [];
+    let empty_twofloat: [TwoFloat; 0] = [];
+    assert_eq!(dot(&empty, &empty), 0.0);
+    assert_eq!(dot(&empty_twofloat, &empty_twofloat), 0.0);
+    assert_eq!(sum_k::<2>(&empty), 0.0);
+    assert_eq!(eval_poly(&empty_twofloat, TwoFloat::from(2.0)), 0.0);
+
+    let coefficients = [1.0, 2.0, 3.0].map(TwoFloat::from);
+    assert_eq!(eval_poly(&coefficients[..1], TwoFloat::from(5.0)), 1.0);
+    assert_eq!(eval_poly(&coefficients, TwoFloat::from(2.0)), 17.0);
+
+    assert_eq!(sum_k::<3>(&[1.0, f64::INFINITY]).hi(), f64::INFINITY);
+    assert_eq!(sum_k::<2>(&[f64::MAX, f64::MAX]).hi(), f64::INFINITY);
+    assert!(sum_k::<2>(&[f64::INFINITY, f64::NEG_INFINITY])
+        .hi()
+        .is_nan());
+    assert_eq!(dot(&[f64::MAX, 1.0], &[2.0, 1.0]).hi(), f64::INFINITY);
+    assert!(dot(&[f64::NAN], &[1.0]).hi().is_nan());
+}
+
+#[test]
+#[should_panic]
+fn dot_length_test() {
+    dot(&[1.0, 2.0], &[1.0]);
+}
+
+#[test]
+fn sum_k_repeated_test() {
+    // Compensated summation recovers terms far below the precision of the
+    // partial sums
+    repeated_test(|| {
+        let a = get_valid_f64(|x| x.abs() > 1e-100 && x.abs() < 1e100);
+        let b = a * 1e-40;
+        let values = [a, b, -a];
+        assert_eq!(sum_k::<2>(&values), b);
+        assert_eq!(dot(&values, &[1.0, 1.0, 1.0]), b);
+    });
+}
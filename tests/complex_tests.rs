@@ -0,0 +1,622 @@
+#![cfg(feature = "complex")]
+#![allow(clippy::approx_constant)]
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+use core::convert::TryFrom;
+use hexf::hexf64;
+use num_complex::{Complex, Complex64};
+use rand::Rng;
+use twofloat::{
+    consts::{PI, SQRT_2},
+    TwoComplex, TwoFloat,
+};
+
+// Arguments shared by the tables below, given as real and imaginary parts and
+// including one just below the negative real axis and one on the unit circle,
+// each followed by the parts of its exponential
+const EXP_CASES: [(f64, f64, Pair, Pair); 6] = [
+    (
+        0.5,
+        0.25,
+        (
+            hexf64!("0x1.98f390d813322p+0"),
+            hexf64!("0x1.40295e88cc1f8p-55"),
+        ),
+        (
+            hexf64!("0x1.a1b0950a13dbcp-2"),
+            hexf64!("0x1.160d8a75b94b3p-58"),
+        ),
+    ),
+    (
+        -1.5,
+        2.0,
+        (
+            hexf64!("-0x1.7c556e2f1625dp-4"),
+            hexf64!("-0x1.c111ef539fd3ep-59"),
+        ),
+        (
+            hexf64!("0x1.9f85ac61ffb69p-3"),
+            hexf64!("0x1.6de8e20dc2148p-58"),
+        ),
+    ),
+    (
+        3.0,
+        -0.75,
+        (
+            hexf64!("0x1.d6489cb81f389p+3"),
+            hexf64!("-0x1.c8106ea4a639dp-53"),
+        ),
+        (
+            hexf64!("-0x1.b61d54d10695cp+3"),
+            hexf64!("0x1.ca21a94f39e6fp-51"),
+        ),
+    ),
+    (
+        0.6,
+        0.8,
+        (
+            hexf64!("0x1.44fccc52c1d22p+0"),
+            hexf64!("0x1.e11de57b68bf3p-56"),
+        ),
+        (
+            hexf64!("0x1.4e9ea195644a6p+0"),
+            hexf64!("-0x1.a63926636a1c4p-54"),
+        ),
+    ),
+    (
+        -2.0,
+        -1e-10,
+        (
+            hexf64!("0x1.152aaa3bf81ccp-3"),
+            hexf64!("-0x1.8098886ee5704p-57"),
+        ),
+        (
+            hexf64!("-0x1.dc2b2facd3fffp-37"),
+            hexf64!("-0x1.b192c4429efb1p-91"),
+        ),
+    ),
+    (
+        10.0,
+        5.0,
+        (
+            hexf64!("0x1.868134f13fd9cp+12"),
+            hexf64!("0x1.e29685fea46bcp-44"),
+        ),
+        (
+            hexf64!("-0x1.4a06d9d761a59p+14"),
+            hexf64!("0x1.79d9c0ac6c94fp-40"),
+        ),
+    ),
+];
+
+// Reference values for the natural logarithm
+const LN_CASES: [(f64, f64, Pair, Pair); 6] = [
+    (
+        0.5,
+        0.25,
+        (
+            hexf64!("-0x1.29c440606e6abp-1"),
+            hexf64!("0x1.6fd1ad6bef1abp-66"),
+        ),
+        (
+            hexf64!("0x1.dac670561bb4fp-2"),
+            hexf64!("0x1.a2b7f222f65e2p-56"),
+        ),
+    ),
+    (
+        -1.5,
+        2.0,
+        (
+            hexf64!("0x1.d5240f0e0e078p-1"),
+            hexf64!("-0x1.7df5360740fe5p-55"),
+        ),
+        (
+            hexf64!("0x1.1b6e192ebbe44p+1"),
+            hexf64!("0x1.b1b466a88828ep-53"),
+        ),
+    ),
+    (
+        3.0,
+        -0.75,
+        (
+            hexf64!("0x1.210133dad4895p+0"),
+            hexf64!("0x1.8781a5f4d8600p-55"),
+        ),
+        (
+            hexf64!("-0x1.f5b75f92c80ddp-3"),
+            hexf64!("-0x1.8ab6e3cf7afbdp-57"),
+        ),
+    ),
+    (
+        0.6,
+        0.8,
+        (
+            hexf64!("0x1.999999999999ap-56"),
+            hexf64!("-0x1.47ae147ae147bp-111"),
+        ),
+        (
+            hexf64!("0x1.dac670561bb50p-1"),
+            hexf64!("-0x1.875ce886e0109p-56"),
+        ),
+    ),
+    (
+        -2.0,
+        -1e-10,
+        (
+            hexf64!("0x1.62e42fefa39efp-1"),
+            hexf64!("0x1.abcfcadbdb288p-56"),
+        ),
+        (
+            hexf64!("-0x1.921fb5442754ap+1"),
+            hexf64!("-0x1.23ec73c285c09p-53"),
+        ),
+    ),
+    (
+        10.0,
+        5.0,
+        (
+            hexf64!("0x1.3503179f229e7p+1"),
+            hexf64!("-0x1.bf0619910bdeap-53"),
+        ),
+        (
+            hexf64!("0x1.dac670561bb4fp-2"),
+            hexf64!("0x1.a2b7f222f65e2p-56"),
+        ),
+    ),
+];
+
+// Reference values for the square root
+const SQRT_CASES: [(f64, f64, Pair, Pair); 6] = [
+    (
+        0.5,
+        0.25,
+        (
+            hexf64!("0x1.749199c6dc181p-1"),
+            hexf64!("-0x1.a6c34d250fa14p-55"),
+        ),
+        (
+            hexf64!("0x1.5fce63a0fd5edp-3"),
+            hexf64!("0x1.edad2ea479aabp-57"),
+        ),
+    ),
+    (
+        -1.5,
+        2.0,
+        (
+            hexf64!("0x1.6a09e667f3bcdp-1"),
+            hexf64!("-0x1.bdd3413b26456p-55"),
+        ),
+        (
+            hexf64!("0x1.6a09e667f3bcdp+0"),
+            hexf64!("-0x1.bdd3413b26456p-54"),
+        ),
+    ),
+    (
+        3.0,
+        -0.75,
+        (
+            hexf64!("0x1.becdb7c47ebd3p+0"),
+            hexf64!("-0x1.9be265fea096dp-55"),
+        ),
+        (
+            hexf64!("-0x1.b8084372fcdc1p-3"),
+            hexf64!("-0x1.d6ba6157e2423p-57"),
+        ),
+    ),
+    (
+        0.6,
+        0.8,
+        (
+            hexf64!("0x1.c9f25c5bfedd9p-1"),
+            hexf64!("0x1.ab294a33804a6p-56"),
+        ),
+        (
+            hexf64!("0x1.c9f25c5bfeddap-2"),
+            hexf64!("-0x1.6078fe8a40fd4p-56"),
+        ),
+    ),
+    (
+        -2.0,
+        -1e-10,
+        (
+            hexf64!("0x1.36fd255a2213bp-35"),
+            hexf64!("0x1.67f30ce4ab24fp-90"),
+        ),
+        (
+            hexf64!("-0x1.6a09e667f3bcdp+0"),
+            hexf64!("0x1.bdd2bba9904e6p-54"),
+        ),
+    ),
+    (
+        10.0,
+        5.0,
+        (
+            hexf64!("0x1.a08b663afbc3ep+1"),
+            hexf64!("0x1.16f258af7f942p-53"),
+        ),
+        (
+            hexf64!("0x1.8954cfecbad14p-1"),
+            hexf64!("-0x1.3b33c8ee98ed3p-55"),
+        ),
+    ),
+];
+
+// Reference values for the sine
+const SIN_CASES: [(f64, f64, Pair, Pair); 6] = [
+    (
+        0.5,
+        0.25,
+        (
+            hexf64!("0x1.fa5a7b05c7c67p-2"),
+            hexf64!("-0x1.ccbe4092416ffp-57"),
+        ),
+        (
+            hexf64!("0x1.c60471b7762d4p-3"),
+            hexf64!("-0x1.febc470606eb3p-57"),
+        ),
+    ),
+    (
+        -1.5,
+        2.0,
+        (
+            hexf64!("-0x1.e05acfb05cc1ep+1"),
+            hexf64!("-0x1.bfe0c593a2e57p-54"),
+        ),
+        (
+            hexf64!("0x1.06b6148c45ac2p-2"),
+            hexf64!("-0x1.b217c8b64b455p-56"),
+        ),
+    ),
+    (
+        3.0,
+        -0.75,
+        (
+            hexf64!("0x1.762e6a09463bap-3"),
+            hexf64!("0x1.9698a72cca9f9p-57"),
+        ),
+        (
+            hexf64!("0x1.a0d0101e75538p-1"),
+            hexf64!("-0x1.8eae71794ffd6p-55"),
+        ),
+    ),
+    (
+        0.6,
+        0.8,
+        (
+            hexf64!("0x1.82a5fada4160cp-1"),
+            hexf64!("-0x1.3dcb52dc08311p-56"),
+        ),
+        (
+            hexf64!("0x1.7749e0035fc0ep-1"),
+            hexf64!("0x1.a38497f60a7b0p-56"),
+        ),
+    ),
+    (
+        -2.0,
+        -1e-10,
+        (
+            hexf64!("-0x1.d18f6ead1b446p-1"),
+            hexf64!("0x1.028e639622730p-56"),
+        ),
+        (
+            hexf64!("0x1.6e0befd8b1f76p-35"),
+            hexf64!("0x1.76c8adefb1114p-89"),
+        ),
+    ),
+    (
+        10.0,
+        5.0,
+        (
+            hexf64!("-0x1.42f96713c6864p+5"),
+            hexf64!("-0x1.03f4b4cd20015p-49"),
+        ),
+        (
+            hexf64!("-0x1.f2182b4ffad4ap+5"),
+            hexf64!("0x1.fbf0456459361p-49"),
+        ),
+    ),
+];
+
+// Reference values for the cosine
+const COS_CASES: [(f64, f64, Pair, Pair); 6] = [
+    (
+        0.5,
+        0.25,
+        (
+            hexf64!("0x1.cf6fd72d3ef82p-1"),
+            hexf64!("0x1.904428b8691a9p-56"),
+        ),
+        (
+            hexf64!("-0x1.f00fc6e441f79p-4"),
+            hexf64!("-0x1.15da16e02183dp-63"),
+        ),
+    ),
+    (
+        -1.5,
+        2.0,
+        (
+            hexf64!("0x1.1083a5c1caa20p-2"),
+            hexf64!("-0x1.dd76317b63b43p-56"),
+        ),
+        (
+            hexf64!("0x1.cf1340ed6dae0p+1"),
+            hexf64!("-0x1.670f065af7fdbp-53"),
+        ),
+    ),
+    (
+        3.0,
+        -0.75,
+        (
+            hexf64!("-0x1.481f3e53213f3p+0"),
+            hexf64!("0x1.90e917eca1646p-54"),
+        ),
+        (
+            hexf64!("0x1.db525cca3bf5ep-4"),
+            hexf64!("-0x1.e1c9a16426efap-59"),
+        ),
+    ),
+    (
+        0.6,
+        0.8,
+        (
+            hexf64!("0x1.1a94c7882b9f2p+0"),
+            hexf64!("0x1.dfac0d63e5b9ap-54"),
+        ),
+        (
+            hexf64!("-0x1.00bfac9b2c188p-1"),
+            hexf64!("0x1.4622582fe2785p-55"),
+        ),
+    ),
+    (
+        -2.0,
+        -1e-10,
+        (
+            hexf64!("-0x1.aa22657537205p-2"),
+            hexf64!("0x1.6f296e60c728cp-56"),
+        ),
+        (
+            hexf64!("-0x1.8fe9c9efd07c4p-34"),
+            hexf64!("0x1.20fe714250b03p-88"),
+        ),
+    ),
+    (
+        10.0,
+        5.0,
+        (
+            hexf64!("-0x1.f223bf6ff162cp+5"),
+            hexf64!("-0x1.e88197d8d2422p-52"),
+        ),
+        (
+            hexf64!("0x1.42f1e5413ad16p+5"),
+            hexf64!("-0x1.7cfa4c2581495p-51"),
+        ),
+    ),
+];
+
+// Reference values for the argument raised to the power 0.75 - 1.5i
+const POWC_CASES: [(f64, f64, Pair, Pair); 6] = [
+    (
+        0.5,
+        0.25,
+        (
+            hexf64!("0x1.c7ef0db581341p-2"),
+            hexf64!("0x1.ae48202bc8fc9p-56"),
+        ),
+        (
+            hexf64!("0x1.37958b344451bp+0"),
+            hexf64!("0x1.a5479537a1c9bp-56"),
+        ),
+    ),
+    (
+        -1.5,
+        2.0,
+        (
+            hexf64!("0x1.a6a75c40d8fc8p+5"),
+            hexf64!("-0x1.7ed0e8161496bp-50"),
+        ),
+        (
+            hexf64!("0x1.f1ac218a8bd1fp+3"),
+            hexf64!("-0x1.23f7d0b2a47bbp-53"),
+        ),
+    ),
+    (
+        3.0,
+        -0.75,
+        (
+            hexf64!("-0x1.f2a5b046a79d1p-2"),
+            hexf64!("-0x1.f0fd6ed472b46p-59"),
+        ),
+        (
+            hexf64!("-0x1.8a2688e01c89ep+0"),
+            hexf64!("0x1.62b7f60188f66p-55"),
+        ),
+    ),
+    (
+        0.6,
+        0.8,
+        (
+            hexf64!("0x1.8aeb9f4fefbcbp+1"),
+            hexf64!("0x1.c4c006e825f9fp-53"),
+        ),
+        (
+            hexf64!("0x1.49975aa7b373fp+1"),
+            hexf64!("-0x1.e2bfb84494f95p-53"),
+        ),
+    ),
+    (
+        -2.0,
+        -1e-10,
+        (
+            hexf64!("-0x1.df22cd147ef98p-7"),
+            hexf64!("-0x1.bc26407542fb2p-61"),
+        ),
+        (
+            hexf64!("0x1.f23558941eda8p-9"),
+            hexf64!("0x1.4b1deb6ede606p-63"),
+        ),
+    ),
+    (
+        10.0,
+        5.0,
+        (
+            hexf64!("-0x1.84d04441aef58p+3"),
+            hexf64!("0x1.35da44728ab00p-52"),
+        ),
+        (
+            hexf64!("0x1.9cb1a83d64b5cp+0"),
+            hexf64!("-0x1.2e4b8f932033bp-61"),
+        ),
+    ),
+];
+fn complex(re: f64, im: f64) -> TwoComplex {
+    TwoComplex::new(TwoFloat::from(re), TwoFloat::from(im))
+}
+
+fn check_cases(
+    cases: &[(f64, f64, Pair, Pair)],
+    name: &str,
+    function: impl Fn(TwoComplex) -> TwoComplex,
+) {
+    for &(re, im, expected_re, expected_im) in cases.iter() {
+        let z = complex(re, im);
+        let expected = TwoComplex::new(
+            TwoFloat::try_from(expected_re).unwrap(),
+            TwoFloat::try_from(expected_im).unwrap(),
+        );
+        let result = function(z);
+        let error = (result - expected).norm() / expected.norm();
+        assert!(
+            error <= SPECIAL_RELATIVE_ERROR,
+            "Mismatch in {}({:?}): {:?} vs {:?}",
+            name,
+            z,
+            result,
+            expected
+        );
+    }
+}
+
+#[test]
+fn complex_reference_test() {
+    check_cases(&EXP_CASES, "exp", TwoComplex::exp);
+    check_cases(&LN_CASES, "ln", TwoComplex::ln);
+    check_cases(&SQRT_CASES, "sqrt", TwoComplex::sqrt);
+    check_cases(&SIN_CASES, "sin", TwoComplex::sin);
+    check_cases(&COS_CASES, "cos", TwoComplex::cos);
+
+    let exponent = complex(0.75, -1.5);
+    check_cases(&POWC_CASES, "powc", |z| z.powc(exponent));
+}
+
+#[test]
+fn complex_cancellation_test() {
+    // (1 + 2^-60 + i)(1 - 2^-60 + i) = -2^-120 + 2i
+    let epsilon = 2f64.powi(-60);
+    let a = TwoComplex::new(TwoFloat::new_add(1.0, epsilon), TwoFloat::from(1.0));
+    let b = TwoComplex::new(TwoFloat::new_add(1.0, -epsilon), TwoFloat::from(1.0));
+    assert_eq!(a * b, complex(-epsilon * epsilon, 2.0));
+
+    // (-2^-120 + 2i)/(1 - 2^-60 + i) = 1 + 2^-60 + i
+    assert_eq!(complex(-epsilon * epsilon, 2.0) / b, a);
+
+    // ln|z| for |z|^2 = 1 + 2^-80 close to the unit circle
+    let z = complex(1.0, 2f64.powi(-40));
+    let expected = TwoFloat::new_add(2f64.powi(-81), -2f64.powi(-162));
+    let result = z.ln();
+    assert!(((result.re - expected) / expected).abs() < SPECIAL_RELATIVE_ERROR);
+    assert_eq!(result.im, z.im.atan2(z.re));
+}
+
+#[test]
+fn complex_special_test() {
+    let zero = TwoComplex::from(0.0);
+    let one = TwoComplex::from(1.0);
+
+    assert_eq!(TwoComplex::I * TwoComplex::I, -one);
+    assert_eq!(one / TwoComplex::I, -TwoComplex::I);
+    assert_eq!(zero.sqrt(), zero);
+    assert_eq!(complex(-4.0, 0.0).sqrt(), complex(0.0, 2.0));
+    assert_eq!(complex(-4.0, -0.0).sqrt(), complex(0.0, -2.0));
+    assert_eq!(zero.exp(), one);
+    assert_eq!(one.ln(), zero);
+    assert_eq!(complex(1.0, 1.0).powi(-2), complex(0.0, -0.5));
+    assert_eq!(zero.powf(TwoFloat::from(2.0)), zero);
+    assert!(!zero.powc(complex(-1.0, 0.0)).is_valid());
+    assert_eq!(complex(-1.0, 0.0).ln().im, PI);
+
+    // Magnitudes and quotients of large and small values do not overflow
+    let big = 2f64.powi(1000);
+    assert_eq!(complex(3.0 * big, 4.0 * big).norm(), 5.0 * big);
+    assert_eq!(complex(3.0 / big, 4.0 / big).norm(), 5.0 / big);
+    // The low word of the smaller magnitude is subnormal, limiting its
+    // relative precision
+    let underflow_error = hexf64!("0x1p-1072");
+    for &x in [1e300, 1e-300].iter() {
+        let z = complex(x, x);
+        let expected = TwoFloat::from(x) * SQRT_2;
+        assert!(
+            (z.norm() - expected).abs() <= expected * RELATIVE_ERROR + underflow_error,
+            "Incorrect magnitude of {:?}",
+            z
+        );
+        assert_eq!(z.abs(), z.norm());
+    }
+    assert_eq!(complex(big, big) / complex(big, big), one);
+    assert_eq!(one / complex(0.0, 1.0 / big), complex(0.0, -big));
+}
+
+#[test]
+fn complex_conversion_test() {
+    let z = complex(1.5, -2.25);
+    assert_eq!(Complex64::from(z), Complex64::new(1.5, -2.25));
+    assert_eq!(TwoComplex::from(Complex64::new(1.5, -2.25)), z);
+
+    let w = Complex::new(TwoFloat::new_add(1.0, 1e-20), TwoFloat::from(-3.0));
+    assert_eq!(Complex::<TwoFloat>::from(TwoComplex::from(w)), w);
+}
+
+#[test]
+fn complex_identity_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(-10.0, 10.0).unwrap();
+    repeated_slow_test(|| {
+        let z = TwoComplex::new(
+            TwoFloat::new_add(rng.sample(dist), rng.sample(dist) * 1e-17),
+            TwoFloat::new_add(rng.sample(dist), rng.sample(dist) * 1e-17),
+        );
+        let w = complex(rng.sample(dist), rng.sample(dist));
+
+        let result = z * w / w;
+        assert!(
+            (result - z).norm() < z.norm() * 1e-30,
+            "Division of {:?} * {:?} does not recover the value",
+            z,
+            w
+        );
+
+        let root = z.sqrt();
+        assert!(
+            (root * root - z).norm() < z.norm() * 1e-30,
+            "Square root of {:?} is inaccurate",
+            z
+        );
+
+        let result = z.ln().exp();
+        assert!(
+            (result - z).norm() < z.norm() * 1e-29,
+            "exp(ln({:?})) does not recover the value",
+            z
+        );
+
+        let (sin, cos) = (z.sin(), z.cos());
+        let scale = sin.norm_sqr() + cos.norm_sqr();
+        assert!(
+            (sin * sin + cos * cos - 1.0).norm() < scale * 1e-29,
+            "sin^2 + cos^2 is not 1 for {:?}",
+            z
+        );
+    });
+}
@@ -0,0 +1,857 @@
+#![allow(clippy::approx_constant)]
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+use core::convert::TryFrom;
+use hexf::hexf64;
+use rand::Rng;
+use twofloat::{
+    consts::{FRAC_PI_2, PI},
+    TwoFloat,
+};
+
+// Carlson's integral R_F, including a zero and a nearly zero argument and
+// three equal arguments
+const RF_CASES: [(f64, f64, f64, Pair); 7] = [
+    (
+        1.0,
+        2.0,
+        0.0,
+        (
+            hexf64!("0x1.4f9f94f9f50b0p+0"),
+            hexf64!("0x1.b9e61ddaeb023p-54"),
+        ),
+    ),
+    (
+        0.5,
+        1.0,
+        2.0,
+        (
+            hexf64!("0x1.f00e1c3e91a8cp-1"),
+            hexf64!("0x1.05ea8997e39f4p-55"),
+        ),
+    ),
+    (
+        2.0,
+        3.0,
+        4.0,
+        (
+            hexf64!("0x1.2b0ce7fe50f42p-1"),
+            hexf64!("-0x1.4d6d0cdcadb8ap-55"),
+        ),
+    ),
+    (
+        1e-10,
+        0.25,
+        3.5,
+        (
+            hexf64!("0x1.76953b1eba1d4p+0"),
+            hexf64!("-0x1.26cde46119c64p-54"),
+        ),
+    ),
+    (
+        0.0,
+        1e-08,
+        1.0,
+        (
+            hexf64!("0x1.5317a1c62b262p+3"),
+            hexf64!("0x1.5e5301c5f8212p-52"),
+        ),
+    ),
+    (
+        100.0,
+        0.125,
+        7.5,
+        (
+            hexf64!("0x1.09918930229e7p-2"),
+            hexf64!("0x1.e14ea2702104fp-59"),
+        ),
+    ),
+    (
+        1.0,
+        1.0,
+        1.0,
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+    ),
+];
+
+// Reference values for Carlson's degenerate integral R_C
+const RC_CASES: [(f64, f64, Pair); 7] = [
+    (
+        0.0,
+        0.25,
+        (
+            hexf64!("0x1.921fb54442d18p+1"),
+            hexf64!("0x1.1a62633145c07p-53"),
+        ),
+    ),
+    (
+        0.5,
+        2.0,
+        (
+            hexf64!("0x1.b5c6ea0ab6ce0p-1"),
+            hexf64!("0x1.8a44618202ab5p-57"),
+        ),
+    ),
+    (
+        2.0,
+        0.5,
+        (
+            hexf64!("0x1.13464fc5d76e1p+0"),
+            hexf64!("0x1.b7184b61fe87bp-55"),
+        ),
+    ),
+    (
+        1.0,
+        1.0,
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        1e-12,
+        1.0,
+        (
+            hexf64!("0x1.921fa47d4c0dep+0"),
+            hexf64!("0x1.5a6ab5e3231a7p-58"),
+        ),
+    ),
+    (
+        9.0,
+        1.25,
+        (
+            hexf64!("0x1.2e442ae59cf54p-1"),
+            hexf64!("0x1.29ae0d219f7e3p-56"),
+        ),
+    ),
+    (
+        0.0625,
+        0.0625,
+        (hexf64!("0x1.0000000000000p+2"), hexf64!("0x0.0p+0")),
+    ),
+];
+
+// Reference values for Carlson's integral R_D
+const RD_CASES: [(f64, f64, f64, Pair); 5] = [
+    (
+        0.0,
+        2.0,
+        1.0,
+        (
+            hexf64!("0x1.cc15fa4651132p+0"),
+            hexf64!("-0x1.f591174b1db7bp-54"),
+        ),
+    ),
+    (
+        2.0,
+        3.0,
+        4.0,
+        (
+            hexf64!("0x1.5222b69d7c3e7p-3"),
+            hexf64!("-0x1.4e0f62d2c30e5p-57"),
+        ),
+    ),
+    (
+        0.5,
+        0.5,
+        0.25,
+        (
+            hexf64!("0x1.49a0e033378b7p+2"),
+            hexf64!("0x1.61b1acd85d7d7p-53"),
+        ),
+    ),
+    (
+        1e-10,
+        1.0,
+        3.0,
+        (
+            hexf64!("0x1.546e770d0806fp-1"),
+            hexf64!("-0x1.f198a97935d9fp-55"),
+        ),
+    ),
+    (
+        10.0,
+        0.25,
+        5.0,
+        (
+            hexf64!("0x1.039023a04ced6p-3"),
+            hexf64!("0x1.4bce69293a1ffp-57"),
+        ),
+    ),
+];
+
+// Reference values for Carlson's integral R_J
+const RJ_CASES: [(f64, f64, f64, f64, Pair); 6] = [
+    (
+        0.0,
+        1.0,
+        2.0,
+        3.0,
+        (
+            hexf64!("0x1.8dc4087001539p-1"),
+            hexf64!("-0x1.788e60acb89c9p-57"),
+        ),
+    ),
+    (
+        2.0,
+        3.0,
+        4.0,
+        5.0,
+        (
+            hexf64!("0x1.24d07e969767bp-3"),
+            hexf64!("-0x1.d124fd8f2954fp-60"),
+        ),
+    ),
+    (
+        0.5,
+        1.0,
+        1.5,
+        0.25,
+        (
+            hexf64!("0x1.1f722d2c54f78p+1"),
+            hexf64!("0x1.e995074448ae7p-53"),
+        ),
+    ),
+    (
+        1.0,
+        1.0,
+        1.0,
+        1.0,
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        0.0,
+        0.5,
+        4.0,
+        2.5,
+        (
+            hexf64!("0x1.affde22850de9p-1"),
+            hexf64!("-0x1.af84f6aa4ddfdp-56"),
+        ),
+    ),
+    (
+        3.0,
+        0.125,
+        1.0,
+        8.0,
+        (
+            hexf64!("0x1.e7744cbd46ea2p-3"),
+            hexf64!("0x1.38c0bec91b79cp-57"),
+        ),
+    ),
+];
+
+// Complete elliptic integrals of the first and second kind for moduli from
+// 1e-10 to close to one, where K diverges logarithmically
+const COMPLETE_CASES: [(Pair, Pair, Pair); 9] = [
+    (
+        (hexf64!("0x1.b7cdfd9d7bdbbp-34"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.921fb54442d18p+0"),
+            hexf64!("0x1.1a67060d78697p-54"),
+        ),
+        (
+            hexf64!("0x1.921fb54442d18p+0"),
+            hexf64!("0x1.1a5dc05513177p-54"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.999999999999ap-4"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9322866e3cfabp+0"),
+            hexf64!("-0x1.f3afdc72944bbp-55"),
+        ),
+        (
+            hexf64!("0x1.911ddd3e54825p+0"),
+            hexf64!("0x1.6e52d291465fdp-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.98a3548c8ce59p+0"),
+            hexf64!("-0x1.1f3ff50c04b76p-54"),
+        ),
+        (
+            hexf64!("0x1.8bc3dacffca37p+0"),
+            hexf64!("-0x1.bb243f5648210p-55"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.af8d55d323f79p+0"),
+            hexf64!("-0x1.5ffc2b7772464p-57"),
+        ),
+        (
+            hexf64!("0x1.77ab9a753a8f1p+0"),
+            hexf64!("-0x1.14c044e4d990bp-56"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.8000000000000p-1"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.e936a05351becp+0"),
+            hexf64!("-0x1.66fad06c850f7p-55"),
+        ),
+        (
+            hexf64!("0x1.51876358866b7p+0"),
+            hexf64!("0x1.ec58dd8d5faf7p-54"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.ccccccccccccdp-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.23e908bf392ffp+1"),
+            hexf64!("0x1.bdb136e2a5ab4p-58"),
+        ),
+        (
+            hexf64!("0x1.2bf4568a84412p+0"),
+            hexf64!("-0x1.2c45f432a2178p-54"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.fae147ae147aep-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.ada51600c951ap+1"),
+            hexf64!("-0x1.d337a0ded1e6dp-56"),
+        ),
+        (
+            hexf64!("0x1.074a30cc817fbp+0"),
+            hexf64!("0x1.968466aded889p-55"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.ffffe00000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.fe2813d9bbed3p+2"),
+            hexf64!("-0x1.d0aac56554617p-54"),
+        ),
+        (
+            hexf64!("0x1.0000778a02e84p+0"),
+            hexf64!("0x1.b9bc73fcb46e3p-54"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.ffffffffffff8p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.25e4f7b2737fcp+4"),
+            hexf64!("0x1.504955dbf8f01p-50"),
+        ),
+        (
+            hexf64!("0x1.0000000000047p+0"),
+            hexf64!("0x1.e4f7b2737fb2ap-54"),
+        ),
+    ),
+];
+
+// Incomplete elliptic integrals of the first and second kind by modulus and
+// amplitude, with amplitudes beyond π/2 and moduli of zero, one and above one
+const INCOMPLETE_CASES: [(f64, f64, Pair, Pair); 9] = [
+    (
+        0.5,
+        1.0,
+        (
+            hexf64!("0x1.09902bb1b6fd5p+0"),
+            hexf64!("0x1.7661d10c063c5p-55"),
+        ),
+        (
+            hexf64!("0x1.ee04495f87448p-1"),
+            hexf64!("0x1.584cd0a82bf00p-55"),
+        ),
+    ),
+    (
+        0.1,
+        0.25,
+        (
+            hexf64!("0x1.0006be6360877p-2"),
+            hexf64!("0x1.bb9bd7500e9b2p-60"),
+        ),
+        (
+            hexf64!("0x1.fff283dc2b180p-3"),
+            hexf64!("-0x1.db82e8165c825p-58"),
+        ),
+    ),
+    (
+        0.8,
+        -1.25,
+        (
+            hexf64!("-0x1.79b770f04debap+0"),
+            hexf64!("0x1.f55c897d1aa90p-55"),
+        ),
+        (
+            hexf64!("-0x1.140950d9a137dp+0"),
+            hexf64!("-0x1.83307e50c8931p-54"),
+        ),
+    ),
+    (
+        0.99,
+        1.5,
+        (
+            hexf64!("0x1.6fca70dfbf5e8p+1"),
+            hexf64!("-0x1.169f4bc0e35a3p-54"),
+        ),
+        (
+            hexf64!("0x1.04a1b1ce4fb8cp+0"),
+            hexf64!("-0x1.b609471b396e4p-54"),
+        ),
+    ),
+    (
+        0.3,
+        10.0,
+        (
+            hexf64!("0x1.473d2f447b6e4p+3"),
+            hexf64!("-0x1.3fbe7ac5d1dc2p-51"),
+        ),
+        (
+            hexf64!("0x1.3902601c3ae94p+3"),
+            hexf64!("0x1.c7e909965c533p-51"),
+        ),
+    ),
+    (
+        0.9,
+        -4.0,
+        (
+            hexf64!("-0x1.6108c384abe6fp+2"),
+            hexf64!("0x1.6ed9678d79ca3p-52"),
+        ),
+        (
+            hexf64!("-0x1.8f8f4a5f8fa30p+1"),
+            hexf64!("0x1.9ac2fc8c98d85p-55"),
+        ),
+    ),
+    (
+        1.0,
+        0.75,
+        (
+            hexf64!("0x1.aa1202fde78f2p-1"),
+            hexf64!("-0x1.b6dca0b8558b4p-55"),
+        ),
+        (
+            hexf64!("0x1.5cffc16bf8f0dp-1"),
+            hexf64!("0x1.96cb370eb578ap-55"),
+        ),
+    ),
+    (
+        1.5,
+        0.5,
+        (
+            hexf64!("0x1.1edf36835b30cp-1"),
+            hexf64!("-0x1.a7d0412da82afp-55"),
+        ),
+        (
+            hexf64!("0x1.cdd55df6c4dedp-2"),
+            hexf64!("-0x1.412d60e494cdfp-56"),
+        ),
+    ),
+    (
+        0.0,
+        2.0,
+        (hexf64!("0x1.0000000000000p+1"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x1.0000000000000p+1"), hexf64!("0x0.0p+0")),
+    ),
+];
+
+// Jacobi elliptic functions sn, cn and dn by argument and modulus, covering
+// the limiting moduli one and 0.999 as well as a modulus above one
+const JACOBI_CASES: [(f64, f64, Pair, Pair, Pair); 9] = [
+    (
+        1.0,
+        0.5,
+        (
+            hexf64!("0x1.a5307d9130081p-1"),
+            hexf64!("0x1.5fc0914d8d3dbp-55"),
+        ),
+        (
+            hexf64!("0x1.231b79c8ad2edp-1"),
+            hexf64!("0x1.27683f0d2137dp-56"),
+        ),
+        (
+            hexf64!("0x1.d2af1485d4281p-1"),
+            hexf64!("-0x1.79b100cdd0103p-57"),
+        ),
+    ),
+    (
+        0.25,
+        0.1,
+        (
+            hexf64!("0x1.faa1dce264426p-3"),
+            hexf64!("0x1.5766337d0f935p-58"),
+        ),
+        (
+            hexf64!("0x1.f0161f7324183p-1"),
+            hexf64!("0x1.51612842518bcp-55"),
+        ),
+        (
+            hexf64!("0x1.ffd7e360d1dcep-1"),
+            hexf64!("0x1.76a9740ac66a8p-56"),
+        ),
+    ),
+    (
+        -0.75,
+        0.9,
+        (
+            hexf64!("-0x1.49c130450bfd8p-1"),
+            hexf64!("0x1.53b321661d36ep-58"),
+        ),
+        (
+            hexf64!("0x1.87abadd5c67e9p-1"),
+            hexf64!("-0x1.0b3657a2b8a4fp-56"),
+        ),
+        (
+            hexf64!("0x1.a1365a8b4cba5p-1"),
+            hexf64!("-0x1.b15baedc55c22p-55"),
+        ),
+    ),
+    (
+        2.5,
+        0.8,
+        (
+            hexf64!("0x1.e771eebf8f3cfp-1"),
+            hexf64!("-0x1.5796f5bcdfa7fp-58"),
+        ),
+        (
+            hexf64!("-0x1.39503bf18898ap-2"),
+            hexf64!("-0x1.aba68621da811p-56"),
+        ),
+        (
+            hexf64!("0x1.4bc7cd942c6bdp-1"),
+            hexf64!("-0x1.afcb79d324c5cp-56"),
+        ),
+    ),
+    (
+        2.0,
+        0.3,
+        (
+            hexf64!("0x1.dc78289824411p-1"),
+            hexf64!("-0x1.042d9be81515dp-55"),
+        ),
+        (
+            hexf64!("-0x1.76cfc7295ea9fp-2"),
+            hexf64!("0x1.bd4853fba1be0p-56"),
+        ),
+        (
+            hexf64!("0x1.eba45eac5d110p-1"),
+            hexf64!("0x1.b0a1bbb2015ccp-55"),
+        ),
+    ),
+    (
+        -2.75,
+        0.6,
+        (
+            hexf64!("-0x1.55044d991f6c5p-1"),
+            hexf64!("-0x1.f92c0198d84d9p-55"),
+        ),
+        (
+            hexf64!("-0x1.7de7b740f3185p-1"),
+            hexf64!("0x1.d930fcd526498p-55"),
+        ),
+        (
+            hexf64!("0x1.d556ade53a204p-1"),
+            hexf64!("0x1.28f44b92b5d17p-55"),
+        ),
+    ),
+    (
+        1.5,
+        1.0,
+        (
+            hexf64!("0x1.cf6f9786df577p-1"),
+            hexf64!("0x1.834edd51ae31ep-55"),
+        ),
+        (
+            hexf64!("0x1.b34c5ffef20a7p-2"),
+            hexf64!("-0x1.e7c9130cf2b5cp-56"),
+        ),
+        (
+            hexf64!("0x1.b34c5ffef20a7p-2"),
+            hexf64!("-0x1.e7c9130cf2b5cp-56"),
+        ),
+    ),
+    (
+        0.5,
+        2.0,
+        (
+            hexf64!("0x1.a5307d9130081p-2"),
+            hexf64!("0x1.5fc0914d8d3dbp-56"),
+        ),
+        (
+            hexf64!("0x1.d2af1485d4281p-1"),
+            hexf64!("-0x1.79b100cdd0103p-57"),
+        ),
+        (
+            hexf64!("0x1.231b79c8ad2edp-1"),
+            hexf64!("0x1.27683f0d2137dp-56"),
+        ),
+    ),
+    (
+        3.0,
+        0.999,
+        (
+            hexf64!("0x1.fdb57decc49f5p-1"),
+            hexf64!("0x1.6ca55e1492d9dp-55"),
+        ),
+        (
+            hexf64!("0x1.830d9ba3f0f75p-4"),
+            hexf64!("0x1.e0beb3646c081p-58"),
+        ),
+        (
+            hexf64!("0x1.abd76b13de494p-4"),
+            hexf64!("-0x1.ee6ef1d7114cfp-58"),
+        ),
+    ),
+];
+#[test]
+fn carlson_reference_test() {
+    for &(x, y, z, expected) in RF_CASES.iter() {
+        let (x, y, z) = (TwoFloat::from(x), TwoFloat::from(y), TwoFloat::from(z));
+        let description = format!("R_F({:?}, {:?}, {:?})", x, y, z);
+        assert_relative(
+            x.carlson_rf(y, z),
+            expected,
+            SPECIAL_RELATIVE_ERROR,
+            &description,
+        );
+    }
+    for &(x, y, expected) in RC_CASES.iter() {
+        let (x, y) = (TwoFloat::from(x), TwoFloat::from(y));
+        let description = format!("R_C({:?}, {:?})", x, y);
+        assert_relative(
+            x.carlson_rc(y),
+            expected,
+            SPECIAL_RELATIVE_ERROR,
+            &description,
+        );
+    }
+    for &(x, y, z, expected) in RD_CASES.iter() {
+        let (x, y, z) = (TwoFloat::from(x), TwoFloat::from(y), TwoFloat::from(z));
+        let description = format!("R_D({:?}, {:?}, {:?})", x, y, z);
+        assert_relative(
+            x.carlson_rd(y, z),
+            expected,
+            SPECIAL_RELATIVE_ERROR,
+            &description,
+        );
+    }
+    for &(x, y, z, p, expected) in RJ_CASES.iter() {
+        let (x, y, z, p) = (
+            TwoFloat::from(x),
+            TwoFloat::from(y),
+            TwoFloat::from(z),
+            TwoFloat::from(p),
+        );
+        let description = format!("R_J({:?}, {:?}, {:?}, {:?})", x, y, z, p);
+        assert_relative(
+            x.carlson_rj(y, z, p),
+            expected,
+            SPECIAL_RELATIVE_ERROR,
+            &description,
+        );
+    }
+}
+
+#[test]
+fn complete_elliptic_reference_test() {
+    for &(source, k, e) in COMPLETE_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(
+            x.ellint_k(),
+            k,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("K({:?})", x),
+        );
+        assert_relative(
+            x.ellint_e(),
+            e,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("E({:?})", x),
+        );
+        assert_relative(
+            (-x).ellint_k(),
+            k,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("K({:?})", -x),
+        );
+    }
+}
+
+#[test]
+fn incomplete_elliptic_reference_test() {
+    for &(k, phi, f, e) in INCOMPLETE_CASES.iter() {
+        let (k, phi) = (TwoFloat::from(k), TwoFloat::from(phi));
+        let description = format!("F({:?}, {:?})", phi, k);
+        assert_relative(
+            k.ellint_k_incomplete(phi),
+            f,
+            SPECIAL_RELATIVE_ERROR,
+            &description,
+        );
+        let description = format!("E({:?}, {:?})", phi, k);
+        assert_relative(
+            k.ellint_e_incomplete(phi),
+            e,
+            SPECIAL_RELATIVE_ERROR,
+            &description,
+        );
+    }
+}
+
+#[test]
+fn jacobi_reference_test() {
+    for &(u, k, sn, cn, dn) in JACOBI_CASES.iter() {
+        let (u, k) = (TwoFloat::from(u), TwoFloat::from(k));
+        let (sn_result, cn_result, dn_result) = u.jacobi_sn_cn_dn(k);
+        assert_relative(
+            sn_result,
+            sn,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("sn({:?}, {:?})", u, k),
+        );
+        assert_relative(
+            cn_result,
+            cn,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("cn({:?}, {:?})", u, k),
+        );
+        assert_relative(
+            dn_result,
+            dn,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("dn({:?}, {:?})", u, k),
+        );
+        assert_eq!(u.jacobi_sn(k), sn_result);
+        assert_eq!(u.jacobi_cn(k), cn_result);
+        assert_eq!(u.jacobi_dn(k), dn_result);
+    }
+}
+
+#[test]
+fn elliptic_special_test() {
+    let zero = TwoFloat::from(0.0);
+    let one = TwoFloat::from(1.0);
+
+    assert_eq!(zero.ellint_k(), FRAC_PI_2);
+    assert_eq!(zero.ellint_e(), FRAC_PI_2);
+    assert_eq!(one.ellint_k(), TwoFloat::INFINITY);
+    assert_eq!(one.ellint_e(), 1.0);
+    assert!(TwoFloat::from(1.5).ellint_k().hi().is_nan());
+    assert!(TwoFloat::from(-1.5).ellint_e().hi().is_nan());
+
+    assert_eq!(TwoFloat::from(0.5).ellint_k_incomplete(zero), 0.0);
+    assert_eq!(TwoFloat::from(0.5).ellint_e_incomplete(zero), 0.0);
+    assert_eq!(one.ellint_k_incomplete(PI), TwoFloat::INFINITY);
+    assert_eq!(one.ellint_k_incomplete(-PI), TwoFloat::NEG_INFINITY);
+    assert!(TwoFloat::from(2.0)
+        .ellint_k_incomplete(FRAC_PI_2)
+        .hi()
+        .is_nan());
+
+    assert_eq!(zero.carlson_rc(one), FRAC_PI_2);
+    assert!(TwoFloat::from(-1.0).carlson_rf(one, one).hi().is_nan());
+    assert!(zero.carlson_rf(zero, one).hi().is_nan());
+    assert!(one.carlson_rd(one, zero).hi().is_nan());
+    assert!(one.carlson_rj(one, one, zero).hi().is_nan());
+
+    let k = TwoFloat::from(0.5);
+    assert_eq!(zero.jacobi_sn_cn_dn(k), (zero, one, one));
+    assert_eq!(TwoFloat::from(0.25).jacobi_dn(zero), 1.0);
+
+    assert!(TwoFloat::NAN.ellint_k().hi().is_nan());
+    assert!(TwoFloat::NAN.ellint_e().hi().is_nan());
+    assert!(k.ellint_k_incomplete(TwoFloat::NAN).hi().is_nan());
+    assert!(TwoFloat::NAN.ellint_e_incomplete(one).hi().is_nan());
+    assert!(TwoFloat::NAN.carlson_rf(one, one).hi().is_nan());
+    assert!(one.carlson_rj(one, TwoFloat::NAN, one).hi().is_nan());
+    assert!(TwoFloat::NAN.jacobi_sn(k).hi().is_nan());
+    assert!(one.jacobi_cn(TwoFloat::NAN).hi().is_nan());
+}
+
+#[test]
+fn carlson_symmetry_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(0.0, 10.0).unwrap();
+    for _ in 0..1000 {
+        let (x, y, z) = (
+            TwoFloat::from(rng.sample(dist)),
+            TwoFloat::from(rng.sample(dist)),
+            TwoFloat::from(rng.sample(dist)),
+        );
+        let expected = x.carlson_rf(y, z);
+        let result = z.carlson_rf(x, y);
+        assert!(
+            ((result - expected) / expected).abs() < 1e-29,
+            "R_F({:?}, {:?}, {:?}) is not symmetric",
+            x,
+            y,
+            z
+        );
+
+        // R_D(x, y, y) = R_J(x, y, y, y)
+        let expected = x.carlson_rd(y, y);
+        let result = x.carlson_rj(y, y, y);
+        assert!(
+            ((result - expected) / expected).abs() < 1e-29,
+            "R_J({:?}, {:?}, {:?}, {:?}) does not match R_D",
+            x,
+            y,
+            y,
+            y
+        );
+    }
+}
+
+#[test]
+fn legendre_relation_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(0.01, 0.99).unwrap();
+    repeated_slow_test(|| {
+        let k = TwoFloat::from(rng.sample(dist));
+        let k1 = ((1.0 - k) * (1.0 + k)).sqrt();
+
+        // E(k) K(k') + E(k') K(k) - K(k) K(k') = pi/2
+        let result = k.ellint_e() * k1.ellint_k() + k1.ellint_e() * k.ellint_k()
+            - k.ellint_k() * k1.ellint_k();
+        assert!(
+            ((result - FRAC_PI_2) / FRAC_PI_2).abs() < 1e-29,
+            "Complete elliptic integrals at {:?} do not satisfy the Legendre relation",
+            k
+        );
+    });
+}
+
+#[test]
+fn jacobi_identity_test() {
+    let mut rng = rand::rng();
+    let u_dist = rand::distr::Uniform::new(-20.0, 20.0).unwrap();
+    let k_dist = rand::distr::Uniform::new(0.0, 1.0).unwrap();
+    repeated_slow_test(|| {
+        let u = TwoFloat::from(rng.sample(u_dist));
+        let k = TwoFloat::from(rng.sample(k_dist));
+        let (sn, cn, dn) = u.jacobi_sn_cn_dn(k);
+        assert!(
+            (sn * sn + cn * cn - 1.0).abs() < 1e-29,
+            "sn^2 + cn^2 is not 1 for sn_cn_dn({:?}, {:?})",
+            u,
+            k
+        );
+        assert!(
+            (dn * dn + k * k * sn * sn - 1.0).abs() < 1e-29,
+            "dn^2 + k^2 sn^2 is not 1 for sn_cn_dn({:?}, {:?})",
+            u,
+            k
+        );
+
+        // sn(F(phi, k), k) = sin(phi)
+        let phi = u * (PI / 40.0);
+        let result = k.ellint_k_incomplete(phi).jacobi_sn(k);
+        assert!(
+            (result - phi.sin()).abs() < 1e-29,
+            "sn of F({:?}, {:?}) does not match sin({:?})",
+            phi,
+            k,
+            phi
+        );
+    });
+}
@@ -1,10 +1,494 @@
+#![allow(clippy::approx_constant)]
+
 #[macro_use]
 pub mod common;
 
+use core::convert::TryFrom;
+
 use common::*;
+use hexf::hexf64;
 use rand::Rng;
 use twofloat::TwoFloat;
 
+// Natural, base 2 and base 10 logarithms from the smallest subnormal to the
+// largest finite value, including arguments within 1e-18 of one
+const LOG_CASES: [(Pair, Pair, Pair, Pair); 12] = [
+    (
+        (
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+        ),
+        (
+            hexf64!("-0x1.26bb1bbb55516p+1"),
+            hexf64!("0x1.f48ad494ea3e9p-53"),
+        ),
+        (
+            hexf64!("-0x1.a934f0979a371p+1"),
+            hexf64!("-0x1.7f2495fb7fa6ep-53"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("-0x1.bcb7b1526e50ep-110"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.5bf0a8b145769p+1"),
+            hexf64!("0x1.4d57ee2b1013ap-53"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.041c714c12afbp-110"),
+        ),
+        (
+            hexf64!("0x1.71547652b82fep+0"),
+            hexf64!("0x1.777d0ffda0d24p-56"),
+        ),
+        (
+            hexf64!("0x1.bcb7b1526e50ep-2"),
+            hexf64!("0x1.95355baaafad3p-57"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p-60"),
+            hexf64!("-0x1.0000000000000p-121"),
+        ),
+        (
+            hexf64!("0x1.71547652b82fep-60"),
+            hexf64!("0x1.6bf26c4b0b10cp-116"),
+        ),
+        (
+            hexf64!("0x1.bcb7b1526e50ep-62"),
+            hexf64!("0x1.8e427ce565f3fp-117"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p-60"),
+            hexf64!("-0x1.0000000000000p-121"),
+        ),
+        (
+            hexf64!("-0x1.71547652b82fep-60"),
+            hexf64!("-0x1.8307b3b03693cp-116"),
+        ),
+        (
+            hexf64!("-0x1.bcb7b1526e50ep-62"),
+            hexf64!("-0x1.9c283a6ff9667p-117"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.269621134db92p-2"),
+            hexf64!("-0x1.e0efadd9db02bp-56"),
+        ),
+        (
+            hexf64!("-0x1.a8ff971810a5ep-2"),
+            hexf64!("-0x1.817fd3b7d7e5dp-58"),
+        ),
+        (
+            hexf64!("-0x1.ffbfc2bbc7803p-4"),
+            hexf64!("-0x1.d60df12c2e13dp-58"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.8000000000000p+0"),
+            hexf64!("-0x1.0000000000000p-80"),
+        ),
+        (
+            hexf64!("0x1.9f323ecbf984cp-2"),
+            hexf64!("-0x1.a92e56876d4b1p-59"),
+        ),
+        (
+            hexf64!("0x1.2b803473f7ad1p-1"),
+            hexf64!("-0x1.817fd790b9216p-58"),
+        ),
+        (
+            hexf64!("0x1.68a288b60b7fcp-3"),
+            hexf64!("0x1.5b11205eaf8cfp-58"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.fffffffffffffp+1023"),
+            hexf64!("0x1.fffffffffffffp+969"),
+        ),
+        (
+            hexf64!("0x1.62e42fefa39efp+9"),
+            hexf64!("0x1.aac9e3b39803fp-46"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+10"),
+            hexf64!("-0x1.71547652b82ffp-54"),
+        ),
+        (
+            hexf64!("0x1.34413509f79ffp+8"),
+            hexf64!("-0x1.a13b49fbf4aebp-49"),
+        ),
+    ),
+    (
+        (hexf64!("0x0.012688b70e62bp-1022"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.64e69394d9508p+9"),
+            hexf64!("-0x1.35918fe61c196p-47"),
+        ),
+        (
+            hexf64!("-0x1.01730dabca5f6p+10"),
+            hexf64!("0x1.582b4fd59af43p-44"),
+        ),
+        (
+            hexf64!("-0x1.3600000000000p+8"),
+            hexf64!("-0x1.7e6c79a506cedp-50"),
+        ),
+    ),
+    (
+        (hexf64!("0x0.0000000000001p-1022"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.74385446d71c3p+9"),
+            hexf64!("-0x1.8e569fa8ee781p-45"),
+        ),
+        (hexf64!("-0x1.0c80000000000p+10"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.434e6420f4374p+8"),
+            hexf64!("0x1.a0fae8e2e61bep-48"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-1022"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.6232bdd7abcd2p+9"),
+            hexf64!("-0x1.eef3fec1be37fp-46"),
+        ),
+        (hexf64!("-0x1.ff00000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.33a7146f72a42p+8"),
+            hexf64!("0x1.8cf2f9ac032a2p-49"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.7e43c8800759cp+996"),
+            hexf64!("-0x1.698fdc7ace0cap+942"),
+        ),
+        (
+            hexf64!("0x1.5963447f87fb5p+9"),
+            hexf64!("0x1.aada9dc2fafd5p-46"),
+        ),
+        (
+            hexf64!("0x1.f24a09f1a8b89p+9"),
+            hexf64!("-0x1.1e02407a8cd0fp-46"),
+        ),
+        (
+            hexf64!("0x1.2c00000000000p+8"),
+            hexf64!("0x1.8c446d8c82089p-112"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.81cd6c8b43958p+13"),
+            hexf64!("0x1.c25c268497682p-44"),
+        ),
+        (
+            hexf64!("0x1.2d79559791e31p+3"),
+            hexf64!("-0x1.a56866779a25bp-53"),
+        ),
+        (
+            hexf64!("0x1.b2ef5b86e63d0p+3"),
+            hexf64!("0x1.932c0b5d89160p-51"),
+        ),
+        (
+            hexf64!("0x1.05db618083a9ep+2"),
+            hexf64!("-0x1.e54f842c191dcp-52"),
+        ),
+    ),
+];
+
+// exp_m1 and ln_1p from 1e-30 up to 3 and down to -0.95, where ln_1p nears
+// its singularity at -1
+const EXP_M1_LN_1P_CASES: [(Pair, Pair, Pair); 10] = [
+    (
+        (hexf64!("0x1.4484bfeebc2a0p-100"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.4484bfeebc2a0p-100"),
+            hexf64!("0x1.9b604aaaca627p-201"),
+        ),
+        (
+            hexf64!("0x1.4484bfeebc2a0p-100"),
+            hexf64!("-0x1.9b604aaaca627p-201"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.0000000000000p-20"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.fffff00000555p-21"),
+            hexf64!("-0x1.5500000111111p-75"),
+        ),
+        (
+            hexf64!("-0x1.0000080000555p-20"),
+            hexf64!("-0x1.565555622222dp-74"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.999999999999ap-3"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.c56ecf2c56468p-3"),
+            hexf64!("-0x1.08ebb6f671685p-57"),
+        ),
+        (
+            hexf64!("0x1.7565011e49677p-3"),
+            hexf64!("-0x1.c58d9f72fca70p-57"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.3333333333333p-2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.0966f2c7907f6p-2"),
+            hexf64!("-0x1.0a730392f0d98p-59"),
+        ),
+        (
+            hexf64!("-0x1.6d3c324e13f4ep-2"),
+            hexf64!("-0x1.f0207d9d4c9c1p-56"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.0000000000000p-1"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("-0x1.92e9a0720d3ecp-2"),
+            hexf64!("-0x1.3a986f3982169p-63"),
+        ),
+        (
+            hexf64!("-0x1.62e42fefa39efp-1"),
+            hexf64!("-0x1.8bc9e3b39803fp-56"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.1df3b68cfb9efp+0"),
+            hexf64!("0x1.ea61ab771f73cp-54"),
+        ),
+        (
+            hexf64!("0x1.1e85f5e7040d0p-1"),
+            hexf64!("0x1.ef62cd2f9f1e3p-56"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.3333333333333p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.ce04528d3f639p-2"),
+            hexf64!("-0x1.246c2496bf6e4p-64"),
+        ),
+        (
+            hexf64!("-0x1.d5240f0e0e077p-1"),
+            hexf64!("-0x1.041593f17e037p-56"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.bda7fcc9f8a83p+1"),
+            hexf64!("-0x1.4123734e5782cp-53"),
+        ),
+        (
+            hexf64!("0x1.d5240f0e0e078p-1"),
+            hexf64!("-0x1.7df5360740fe5p-55"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.e666666666666p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.39fd14a07b920p-1"),
+            hexf64!("-0x1.125a9cc36f76ep-57"),
+        ),
+        (
+            hexf64!("-0x1.7f7427b73e38fp+1"),
+            hexf64!("-0x1.40ee67e188c2ep-53"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p+1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.315e5bf6fb106p+4"),
+            hexf64!("-0x1.a568407591768p-53"),
+        ),
+        (
+            hexf64!("0x1.62e42fefa39efp+0"),
+            hexf64!("0x1.abc9e3b39803fp-55"),
+        ),
+    ),
+];
+
+// Exponentials close to overflow and across the subnormal range, down to the
+// smallest positive value
+const EXP_LIMIT_CASES: [(Pair, Pair); 12] = [
+    (
+        (hexf64!("0x1.62e3d70a3d70ap+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.fe9ce5c4c52b4p+1023"),
+            hexf64!("0x1.a8a120488d827p+969"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.62c0000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.81e9b4b52d0c9p+1023"),
+            hexf64!("-0x1.40367ff946b15p+964"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.4a40000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.125c1e452b8ccp-953"),
+            hexf64!("0x1.7537528473ff3p-1007"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.5400000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.f48cf6261d064p-982"),
+            hexf64!("-0x0.000058c45952dp-1022"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.5808000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.430e8194c9339p-993"),
+            hexf64!("-0x0.0000008d59798p-1022"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.5b7d333333333p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.4837841623506p-1003"),
+            hexf64!("-0x0.000000003d9e0p-1022"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.5e00000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.14f2b0fb9307fp-1010"),
+            hexf64!("0x0.00000000000acp-1022"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.60a0000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.740accc91284ap-1018"),
+            hexf64!("0x0.0000000000001p-1022"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.6240000000000p+9"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.e6cf6d08897acp-1022"), hexf64!("-0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.6280000000000p+9"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.8bfe55de02338p-1022"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.7200000000000p+9"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.0000000000055p-1022"), hexf64!("-0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.7480000000000p+9"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.0000000000001p-1022"), hexf64!("-0x0.0p+0")),
+    ),
+];
+
+// Powers of two with arguments close to 1024, where the result
+// approaches `f64::MAX` and below -1022 where it is subnormal
+const EXP2_CASES: [(Pair, Pair); 10] = [
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.6a09e667f3bcdp+0"),
+            hexf64!("-0x1.bdd3413b26456p-54"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.d333333333333p+2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9fdf8bcce533ep-8"),
+            hexf64!("0x1.624cc705bfa05p-62"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9100000000000p+6"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.306fe0a31b715p+100"),
+            hexf64!("0x1.6f46ad23182e4p+45"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.ffa0000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.306fe0a31b715p+1023"),
+            hexf64!("0x1.6f46ad23182e4p+968"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.fffffcb923a2ap+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.fff6ea43bd988p+1023"),
+            hexf64!("0x1.8f34030d7f879p+969"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.ffffffffffff8p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.fffffffffe9d2p+1023"),
+            hexf64!("-0x1.0bfbe8c8fd17ep+969"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.ff40000000000p+9"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.b504f333f9de6p-1022"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.0693333333333p+10"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.0000000cfefc6p-1022"), hexf64!("-0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.0c80000000000p+10"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.0000000000001p-1022"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.0ca6666666666p+10"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x0.0000000000001p-1022"), hexf64!("-0x0.0p+0")),
+    ),
+];
+
+// Units in the last place of a double-double value with the given high word
+fn ulp(x: f64) -> f64 {
+    let exponent = ((x.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    2f64.powi(exponent - 105)
+}
+
+fn assert_close(result: TwoFloat, expected: Pair, ulps: f64, description: &str) {
+    let expected = TwoFloat::try_from(expected).unwrap();
+    let difference = (result - expected).abs();
+    assert!(
+        difference <= ulps * ulp(expected.hi()),
+        "Mismatch in {}: {:?} vs {:?}",
+        description,
+        result,
+        expected
+    );
+}
+
 #[test]
 fn exp_test() {
     let mut rng = rand::rng();
@@ -31,6 +515,53 @@ fn exp_test() {
     });
 }
 
+#[test]
+fn exp_limits_test() {
+    // Allowance for the rounding of the subnormal low word, as documented
+    let underflow_error = hexf64!("0x1p-1072");
+    for &(source, expected) in EXP_LIMIT_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        let expected = TwoFloat::try_from(expected).unwrap();
+        let result = x.exp();
+        let bound = hexf64!("0x1p-102") * expected.hi() + underflow_error;
+        assert!(
+            (result - expected).abs() <= bound,
+            "Mismatch in exp({:?}): {:?} vs {:?}",
+            x,
+            result,
+            expected
+        );
+    }
+
+    assert_eq!(TwoFloat::from(709.79).exp(), TwoFloat::INFINITY);
+    assert!(TwoFloat::from(-745.13).exp() > 0.0);
+    assert_eq!(TwoFloat::from(-745.2).exp(), 0.0);
+}
+
+#[test]
+fn exp2_limits_test() {
+    let underflow_error = hexf64!("0x1p-1072");
+    for &(source, expected) in EXP2_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        let expected = TwoFloat::try_from(expected).unwrap();
+        let result = x.exp2();
+        let bound = hexf64!("0x1p-102") * expected.hi() + underflow_error;
+        assert!(
+            (result - expected).abs() <= bound,
+            "Mismatch in exp2({:?}): {:?} vs {:?}",
+            x,
+            result,
+            expected
+        );
+    }
+
+    assert_eq!(TwoFloat::from(1024.0).exp2(), TwoFloat::INFINITY);
+    assert!(TwoFloat::new_add(1024.0, -1e-15).exp2().is_valid());
+    assert_eq!(TwoFloat::from(-1075.0).exp2(), 0.0);
+    assert_eq!(TwoFloat::from(-1076.5).exp2(), 0.0);
+    assert_eq!(TwoFloat::from(-1022.0).exp2(), f64::MIN_POSITIVE);
+}
+
 #[test]
 fn exp_m1_test() {
     let mut rng = rand::rng();
@@ -150,3 +681,98 @@ fn ln_exp_test() {
         );
     });
 }
+
+#[test]
+fn log_reference_test() {
+    for &(source, ln, log2, log10) in LOG_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_close(x.ln(), ln, 2.0, &format!("ln({:?})", x));
+        assert_close(x.log2(), log2, 2.0, &format!("log2({:?})", x));
+        assert_close(x.log10(), log10, 2.0, &format!("log10({:?})", x));
+    }
+}
+
+#[test]
+fn exp_m1_ln_1p_reference_test() {
+    for &(source, exp_m1, ln_1p) in EXP_M1_LN_1P_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_close(x.exp_m1(), exp_m1, 4.0, &format!("exp_m1({:?})", x));
+        assert_close(x.ln_1p(), ln_1p, 2.0, &format!("ln_1p({:?})", x));
+    }
+}
+
+#[test]
+fn log_special_test() {
+    assert_eq!(TwoFloat::from(1.0).ln(), 0.0);
+    assert_eq!(TwoFloat::from(1.0).log2(), 0.0);
+    assert_eq!(TwoFloat::from(1.0).log10(), 0.0);
+
+    for f in [TwoFloat::ln, TwoFloat::log2, TwoFloat::log10].iter() {
+        let zero = f(TwoFloat::from(0.0));
+        assert!(zero.hi().is_infinite() && zero.is_sign_negative());
+        assert!(f(TwoFloat::from(-0.0)).is_sign_negative());
+        assert_eq!(f(TwoFloat::INFINITY), TwoFloat::INFINITY);
+        assert!(f(TwoFloat::NAN).hi().is_nan());
+        assert!(f(TwoFloat::from(-1.0)).hi().is_nan());
+    }
+}
+
+#[test]
+fn log2_exact_test() {
+    let mut rng = rand::rng();
+    repeated_test(|| {
+        let exponent = rng.random_range(-1074..1024);
+        let bits = if exponent < -1022 {
+            1 << (exponent + 1074)
+        } else {
+            ((exponent + 1023) as u64) << 52
+        };
+        let a = TwoFloat::from(f64::from_bits(bits));
+        assert_eq!(
+            a.log2(),
+            exponent as f64,
+            "log2(2^{}) is not exact",
+            exponent
+        );
+    });
+}
+
+#[test]
+fn log10_powers_test() {
+    for exponent in 0..=45 {
+        let a: TwoFloat = format!("1e{}", exponent).parse().unwrap();
+        assert_eq!(
+            a.log10(),
+            exponent as f64,
+            "log10(1e{}) is not exact",
+            exponent
+        );
+
+        let b = TwoFloat::new_add(a.hi(), a.hi() * 2f64.powi(-100)) + a.lo();
+        assert!(b.log10() > exponent as f64, "log10 of {:?}", b);
+    }
+
+    for exponent in [-2, -1, 46, 100] {
+        let a: TwoFloat = format!("1e{}", exponent).parse().unwrap();
+        let expected = (exponent as f64, 0.0);
+        assert_close(a.log10(), expected, 2.0, &format!("log10(1e{})", exponent));
+    }
+}
+
+#[test]
+fn log_scaling_test() {
+    let mut rng = rand::rng();
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid() && x.hi() > 0.0 && x.hi() < 1e300);
+        let exponent = rng.random_range(1..20);
+        let b = a * 2f64.powi(exponent);
+
+        let difference = (b.log2() - a.log2() - exponent as f64).abs();
+        assert!(
+            difference < 1e-28 * (exponent as f64 + a.log2().abs()),
+            "Mismatch in log2 scaling of {:?} by 2^{}",
+            a,
+            exponent
+        );
+    });
+}
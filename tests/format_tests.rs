@@ -0,0 +1,163 @@
+#![allow(clippy::float_cmp)]
+
+use rand::Rng;
+
+use twofloat::TwoFloat;
+
+pub mod common;
+
+use common::*;
+
+// Each conversion works through exact decimal expansions with hundreds of
+// digits, so the property tests use fewer iterations than the arithmetic
+// tests
+const FORMAT_ITERS: usize = 5_000;
+
+fn repeated_format_test(mut test: impl FnMut()) {
+    for _ in 0..FORMAT_ITERS {
+        test();
+    }
+}
+
+#[test]
+fn display_round_trip_test() {
+    repeated_format_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid());
+        let source = format!("{}", a);
+        let result: TwoFloat = source.parse().unwrap();
+        assert_eq!(
+            result.hi(),
+            a.hi(),
+            "Incorrect high word parsing {}",
+            source
+        );
+        assert_eq!(result.lo(), a.lo(), "Incorrect low word parsing {}", source);
+    });
+}
+
+#[test]
+fn display_round_trip_wide_test() {
+    // Values with widely separated high and low words need many digits, so
+    // use fewer iterations
+    for _ in 0..1000 {
+        let a = get_twofloat();
+        let source = format!("{}", a);
+        let result: TwoFloat = source.parse().unwrap();
+        assert_eq!(
+            result.hi(),
+            a.hi(),
+            "Incorrect high word parsing {}",
+            source
+        );
+        assert_eq!(result.lo(), a.lo(), "Incorrect low word parsing {}", source);
+    }
+}
+
+#[test]
+fn display_shortest_test() {
+    repeated_format_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid()).abs();
+        let source = format!("{}", a);
+        let (integer, fraction) = source.split_once('.').unwrap_or((&source, ""));
+        let digits = format!("{}{}", integer, fraction);
+        let significand = digits.trim_start_matches('0').trim_end_matches('0');
+        let trailing_zeros = digits.len() - digits.trim_end_matches('0').len();
+        let exponent = trailing_zeros as i32 - fraction.len() as i32;
+        if significand.len() <= 1 || significand.len() > 38 {
+            return;
+        }
+
+        // Removing the last significant digit must not give the same value,
+        // whether rounding down or up
+        let shorter: u128 = significand[..significand.len() - 1].parse().unwrap();
+        for candidate in [shorter, shorter + 1] {
+            let result: TwoFloat = format!("{}e{}", candidate, exponent + 1).parse().unwrap();
+            assert_ne!(result, a, "Output {} is not the shortest", source);
+        }
+    });
+}
+
+#[test]
+fn display_precision_test() {
+    let mut rng = rand::rng();
+    repeated_format_test(|| {
+        let a = random_float();
+        let precision = rng.random_range(0..40);
+        let expected = format!("{:.*}", precision, a);
+        let result = format!("{:.*}", precision, TwoFloat::from(a));
+        assert_eq!(result, expected, "Formatting mismatch for {:e}", a);
+    });
+}
+
+#[test]
+fn display_options_test() {
+    repeated_format_test(|| {
+        let a = TwoFloat::from(random_float());
+        let expected = format!("{:+.3}", a);
+        assert_eq!(format!("{:+30.3}", a), format!("{:>30}", expected));
+        assert_eq!(format!("{:_<+30.3}", a), format!("{:_<30}", expected));
+        assert_eq!(
+            format!("{:^30.3}", a),
+            format!("{:^30}", format!("{:.3}", a))
+        );
+    });
+}
+
+#[test]
+fn exp_reference_test() {
+    // Exact values of the constants computed using rational arithmetic
+    let cases = [
+        (
+            twofloat::consts::E,
+            "2.7182818284590452353602874713527e0",
+            "2.7182818284590452353602874713526646254744e0",
+        ),
+        (
+            twofloat::consts::LN_2,
+            "6.9314718055994530941723212145818e-1",
+            "6.9314718055994530941723212145817599730466e-1",
+        ),
+        (
+            twofloat::consts::SQRT_2,
+            "1.4142135623730950488016887242097e0",
+            "1.4142135623730950488016887242096939398944e0",
+        ),
+    ];
+
+    for &(value, expected31, expected40) in cases.iter() {
+        assert_eq!(format!("{:.31e}", value), expected31);
+        assert_eq!(format!("{:.40e}", value), expected40);
+        assert_eq!(
+            format!("{:.31E}", -value),
+            format!("-{}", expected31.replace('e', "E"))
+        );
+    }
+}
+
+#[test]
+fn exp_round_trip_test() {
+    repeated_format_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid());
+        let source = format!("{:e}", a);
+        let result: TwoFloat = source.parse().unwrap();
+        assert_eq!(
+            result.hi(),
+            a.hi(),
+            "Incorrect high word parsing {}",
+            source
+        );
+        assert_eq!(result.lo(), a.lo(), "Incorrect low word parsing {}", source);
+    });
+}
+
+#[test]
+fn exp_precision_test() {
+    let mut rng = rand::rng();
+    repeated_format_test(|| {
+        let a = random_float();
+        let precision = rng.random_range(0..40);
+        let expected = format!("{:.*e}", precision, a);
+        let result = format!("{:.*e}", precision, TwoFloat::from(a));
+        assert_eq!(result, expected, "Formatting mismatch for {:e}", a);
+    });
+}
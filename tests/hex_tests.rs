@@ -0,0 +1,174 @@
+#![allow(clippy::float_cmp)]
+
+use hexf::hexf64;
+use num_traits::Num;
+use rand::Rng;
+
+use twofloat::{twofloat, ParseErrorKind, TwoFloat, TwoFloatError};
+
+pub mod common;
+
+use common::*;
+
+#[test]
+fn hex_round_trip_test() {
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid());
+        let source = format!("{:x}", a);
+        let result = TwoFloat::from_hex_str(&source).unwrap();
+        assert_eq!(
+            result.hi(),
+            a.hi(),
+            "Incorrect high word parsing {}",
+            source
+        );
+        assert_eq!(result.lo(), a.lo(), "Incorrect low word parsing {}", source);
+
+        let upper = format!("{:X}", a);
+        assert_eq!(upper, source.to_uppercase());
+        assert_eq!(TwoFloat::from_hex_str(&upper), Ok(a));
+
+        let digits = source.replacen("0x", "", 1);
+        assert_eq!(
+            TwoFloat::from_str_radix(&digits, 16),
+            Ok(a),
+            "Radix 16 parsing disagrees for {}",
+            digits
+        );
+    });
+}
+
+#[test]
+fn hex_round_trip_wide_test() {
+    for _ in 0..1000 {
+        let a = get_twofloat();
+        let source = format!("{:x}", a);
+        let result = TwoFloat::from_hex_str(&source).unwrap();
+        assert_eq!(
+            result.hi(),
+            a.hi(),
+            "Incorrect high word parsing {}",
+            source
+        );
+        assert_eq!(result.lo(), a.lo(), "Incorrect low word parsing {}", source);
+    }
+}
+
+#[test]
+fn hex_f64_test() {
+    let mut rng = rand::rng();
+    repeated_test(|| {
+        let a = f64::from_bits(rng.random_range(0..0x7ff0_0000_0000_0000));
+        let source = format!("{:x}", TwoFloat::from(a));
+        let result = TwoFloat::from_hex_str(&source).unwrap();
+        assert_eq!(result.hi(), a, "Incorrect high word parsing {}", source);
+        assert_eq!(result.lo(), 0.0, "Incorrect low word parsing {}", source);
+    });
+}
+
+#[test]
+fn hex_reference_test() {
+    let cases = [
+        (twofloat::consts::PI, "0x1.921fb54442d18469898cc51701cp+1"),
+        (twofloat::consts::E, "0x1.5bf0a8b1457695355fb8ac404e8p+1"),
+        (
+            TwoFloat::from(hexf64!("0x0.0000000000001p-1022")),
+            "0x1p-1074",
+        ),
+        (
+            TwoFloat::new_add(f64::MAX, -hexf64!("0x1p+969")),
+            "0x1.ffffffffffffecp+1023",
+        ),
+    ];
+
+    for &(value, expected) in cases.iter() {
+        assert_eq!(format!("{:x}", value), expected);
+        assert_eq!(format!("{:x}", -value), format!("-{}", expected));
+    }
+}
+
+#[test]
+fn hex_parse_test() {
+    let cases = [
+        ("0x0", 0.0, 0.0),
+        ("0x1p0", 1.0, 0.0),
+        ("+0X1.8P+1", 3.0, 0.0),
+        ("0x.8", 0.5, 0.0),
+        ("0x1_0000", 65536.0, 0.0),
+        ("0x1.00000000000008p0", 1.0, hexf64!("0x1p-53")),
+        (
+            "0x1.00000000000000000000000000008p0",
+            1.0,
+            hexf64!("0x1p-113"),
+        ),
+        (
+            "0x1.00000000000000000000000000018p0",
+            1.0,
+            hexf64!("0x1.8p-112"),
+        ),
+        (
+            "0x1.00000000000008000000000000000000000000000000000001p0",
+            hexf64!("0x1.0000000000001p0"),
+            hexf64!("-0x1p-53"),
+        ),
+        ("0x1p-1075", 0.0, 0.0),
+        ("0x1.8p-1075", hexf64!("0x1p-1074"), 0.0),
+    ];
+
+    for &(source, hi, lo) in cases.iter() {
+        let result = TwoFloat::from_hex_str(source).unwrap();
+        assert_eq!(result.hi(), hi, "Incorrect high word parsing {}", source);
+        assert_eq!(result.lo(), lo, "Incorrect low word parsing {}", source);
+        assert_eq!(
+            TwoFloat::from_hex_str(&format!("-{}", source.trim_start_matches('+'))),
+            Ok(-result),
+            "Incorrect result parsing -{}",
+            source
+        );
+    }
+
+    assert!(TwoFloat::from_hex_str("0x1p1024")
+        .unwrap()
+        .hi()
+        .is_infinite());
+    assert_eq!(
+        TwoFloat::from_hex_str("0x1p+1024").map(|x| (x.hi(), x.lo())),
+        Ok((f64::INFINITY, 0.0))
+    );
+    assert_eq!(
+        TwoFloat::from_hex_str("-0x1.fffffffffffffffp+1023").map(|x| (x.hi(), x.lo())),
+        Ok((f64::NEG_INFINITY, 0.0))
+    );
+    assert!(TwoFloat::from_hex_str("-inf").unwrap().is_sign_negative());
+    assert!(TwoFloat::from_hex_str("NaN").unwrap().hi().is_nan());
+    assert_eq!(
+        TwoFloat::from_hex_str("0x1.5z"),
+        Err(TwoFloatError::ParseError {
+            kind: ParseErrorKind::InvalidDigit,
+            position: 5
+        })
+    );
+}
+
+#[test]
+fn hex_macro_test() {
+    const PI: TwoFloat = twofloat!("0x1.921fb54442d18469898cc51701b8p+1");
+    assert_eq!(PI, twofloat::consts::PI);
+
+    const LN_2: TwoFloat = twofloat!("0x1.62e42fefa39ef35793c7673007e6p-1");
+    assert_eq!(LN_2, twofloat::consts::LN_2);
+
+    let third = twofloat!("0x0.5555_5555_5555_5555_5555_5555_5555_55");
+    assert_eq!(third.hi(), 1.0 / 3.0);
+    assert_eq!(format!("{:x}", third), "0x1.555555555555555555555555554p-2");
+}
+
+#[test]
+fn hex_options_test() {
+    repeated_test(|| {
+        let a = TwoFloat::from(random_float());
+        let expected = format!("{:+x}", a);
+        assert_eq!(format!("{:+50x}", a), format!("{:>50}", expected));
+        assert_eq!(format!("{:_<+50x}", a), format!("{:_<50}", expected));
+    });
+}
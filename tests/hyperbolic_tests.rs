@@ -3,9 +3,469 @@ pub mod common;
 
 use common::*;
 use core::convert::TryFrom;
+use hexf::hexf64;
 use rand::Rng;
 use twofloat::TwoFloat;
 
+// Hyperbolic sine, cosine and tangent from 1e-20 out to ±710, where sinh and
+// cosh are close to overflow
+const SINH_COSH_TANH_CASES: [(Pair, Pair, Pair, Pair); 12] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.79ca10c924223p-67"),
+            hexf64!("0x1.124031c73196ep-202"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.16c262777579cp-134"),
+        ),
+        (
+            hexf64!("0x1.79ca10c924223p-67"),
+            hexf64!("-0x1.124031c73196ep-201"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.0000000000000p-30"),
+            hexf64!("0x1.0000000000000p-90"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p-30"),
+            hexf64!("0x1.aaaaaaaaaaaabp-91"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.0000000000000p-61"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p-30"),
+            hexf64!("0x1.5555555555555p-90"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+        ),
+        (
+            hexf64!("0x1.9a487337b59b3p-4"),
+            hexf64!("-0x1.473eb1dddb592p-59"),
+        ),
+        (
+            hexf64!("0x1.0147f40224b38p+0"),
+            hexf64!("0x1.b61a83bf14f07p-54"),
+        ),
+        (
+            hexf64!("0x1.983d7795f413ap-4"),
+            hexf64!("-0x1.204a9504e40c7p-59"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.3333333333333p-2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.37d42af54b926p-2"),
+            hexf64!("-0x1.52958e1971272p-57"),
+        ),
+        (
+            hexf64!("0x1.0b9b4e0b6ec4cp+0"),
+            hexf64!("0x1.0ff8cd34b4f0cp-57"),
+        ),
+        (
+            hexf64!("-0x1.2a4dda7d914fap-2"),
+            hexf64!("0x1.dcaef7d34268ap-58"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p-1"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.0acd00fe63b97p-1"),
+            hexf64!("-0x1.9c4979ce24400p-56"),
+        ),
+        (
+            hexf64!("0x1.20ac1862ae8d0p+0"),
+            hexf64!("0x1.93762895be497p-54"),
+        ),
+        (
+            hexf64!("0x1.d9353d7568af3p-2"),
+            hexf64!("0x1.a0df85c043389p-56"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.8000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.a506b2dd3c690p-1"),
+            hexf64!("0x1.a238617081f6ap-57"),
+        ),
+        (
+            hexf64!("0x1.4b705d1e5d6a8p+0"),
+            hexf64!("-0x1.e157485ad04d7p-54"),
+        ),
+        (
+            hexf64!("-0x1.45323e552f228p-1"),
+            hexf64!("-0x1.39d5832bf78fbp-56"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.2cd9fc44eb982p+0"),
+            hexf64!("0x1.6a0092521fc19p-54"),
+        ),
+        (
+            hexf64!("0x1.8b07551d9f550p+0"),
+            hexf64!("0x1.30af4a040065bp-54"),
+        ),
+        (
+            hexf64!("0x1.85efab514f394p-1"),
+            hexf64!("0x1.5618caf8a4f11p-55"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.83368cdb0b6d3p+2"),
+            hexf64!("-0x1.600682dc56987p-53"),
+        ),
+        (
+            hexf64!("0x1.88776e4b30aa3p+2"),
+            hexf64!("0x1.9a72a3151f713p-52"),
+        ),
+        (
+            hexf64!("0x1.f9258260a71c2p-1"),
+            hexf64!("-0x1.c47c0e1b448e9p-56"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.4000000000000p+4"),
+            hexf64!("0x1.cd2b297d889bcp-54"),
+        ),
+        (
+            hexf64!("-0x1.ceb088b68e803p+27"),
+            hexf64!("-0x1.46eed1be40771p-28"),
+        ),
+        (
+            hexf64!("0x1.ceb088b68e803p+27"),
+            hexf64!("0x1.d493046ddc0a4p-28"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("0x1.39792499b1a25p-57"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.6800000000000p+5"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.e4cf766fe49bep+63"),
+            hexf64!("0x1.b408e878b3787p+7"),
+        ),
+        (
+            hexf64!("0x1.e4cf766fe49bep+63"),
+            hexf64!("0x1.b408e878b3787p+7"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.1d8508fa8246ap-129"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.5e40000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.8625c7d4f56c2p+1009"),
+            hexf64!("0x1.cc8f03140c197p+955"),
+        ),
+        (
+            hexf64!("0x1.8625c7d4f56c2p+1009"),
+            hexf64!("0x1.cc8f03140c197p+955"),
+        ),
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.6300000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.3e21a464507f9p+1023"),
+            hexf64!("-0x1.282b80dc02e26p+969"),
+        ),
+        (
+            hexf64!("0x1.3e21a464507f9p+1023"),
+            hexf64!("0x1.282b80dc02e26p+969"),
+        ),
+        (hexf64!("-0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+    ),
+];
+
+// Reference values for the inverse hyperbolic sine
+const ASINH_CASES: [(Pair, Pair); 8] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.79ca10c924223p-67"),
+            hexf64!("-0x1.124031c73196ep-202"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+        ),
+        (
+            hexf64!("0x1.98eb9e7e5fc3ep-4"),
+            hexf64!("0x1.338317fdf1bb7p-60"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.ecc2caec5160ap-2"),
+            hexf64!("0x1.ad07ef7ed5a5dp-56"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.c34366179d427p-1"),
+            hexf64!("-0x1.9f270661722dbp-56"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.8000000000000p+1"),
+            hexf64!("0x1.0000000000000p-55"),
+        ),
+        (
+            hexf64!("-0x1.d185b507edc0ep+0"),
+            hexf64!("0x1.8506bd6e80d1cp-57"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.2a05f20000000p+33"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.7b810429a7c2ap+4"),
+            hexf64!("0x1.7501cd0debab0p-50"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.7e43c8800759cp+996"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.59bbfd8b83e44p+9"),
+            hexf64!("0x1.3ae4268b01a66p-45"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.fffffffffffffp+1023"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.633ce8fb9f87ep+9"),
+            hexf64!("-0x1.3be594e9bd8b0p-45"),
+        ),
+    ),
+];
+
+// Reference values for the inverse hyperbolic cosine
+const ACOSH_CASES: [(Pair, Pair); 7] = [
+    (
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.0000000000000p-80"),
+        ),
+        (
+            hexf64!("0x1.6a09e667f3bcdp-40"),
+            hexf64!("-0x1.bdd34142b124dp-94"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000004p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.6a09e667f3bccp-25"),
+            hexf64!("0x1.7dd38393a8518p-81"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.199999999999ap+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.c636c1a882f2cp-2"),
+            hexf64!("0x1.163019899881dp-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p+1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.5124271980435p+0"),
+            hexf64!("-0x1.9065ed0bf9dcbp-54"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.4000000000000p+3"),
+            hexf64!("0x1.cd2b297d889bcp-54"),
+        ),
+        (
+            hexf64!("0x1.7f21ed1ce05d4p+1"),
+            hexf64!("0x1.164d97b28a595p-53"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.5af1d78b58c40p+66"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.75f57369e9343p+5"),
+            hexf64!("-0x1.7e55f5f2bd9e3p-49"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.7e43c8800759cp+996"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.59bbfd8b83e44p+9"),
+            hexf64!("-0x1.3ae4268b01a66p-45"),
+        ),
+    ),
+];
+
+// Reference values for the inverse hyperbolic tangent
+const ATANH_CASES: [(Pair, Pair); 7] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.79ca10c924223p-67"),
+            hexf64!("0x1.124031c73196ep-201"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+        ),
+        (
+            hexf64!("0x1.9af93cd234412p-4"),
+            hexf64!("0x1.486c9bdd5e657p-62"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.0000000000000p-2"),
+            hexf64!("0x1.0000000000000p-70"),
+        ),
+        (
+            hexf64!("-0x1.058aefa811452p-2"),
+            hexf64!("0x1.6366953863c33p-56"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.193ea7aad030bp-1"),
+            hexf64!("-0x1.a256f99caabebp-55"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.ccccccccccccdp-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.78e360604b32dp+0"),
+            hexf64!("0x1.d0b8ee7108685p-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.fffffffffffffp-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.2b708872320e2p+4"),
+            hexf64!("-0x1.670da7e077bcbp-51"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.0000000000000p-100"),
+        ),
+        (
+            hexf64!("0x1.18080dd3171b7p+5"),
+            hexf64!("-0x1.fe72b2544a0cfp-50"),
+        ),
+    ),
+];
+
+#[test]
+fn sinh_cosh_tanh_reference_test() {
+    for &(source, sinh, cosh, tanh) in SINH_COSH_TANH_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(x.sinh(), sinh, RELATIVE_ERROR, &format!("sinh({:?})", x));
+        assert_relative(x.cosh(), cosh, RELATIVE_ERROR, &format!("cosh({:?})", x));
+        assert_relative(x.tanh(), tanh, RELATIVE_ERROR, &format!("tanh({:?})", x));
+    }
+}
+
+#[test]
+fn inverse_reference_test() {
+    assert_cases(&ASINH_CASES, TwoFloat::asinh, RELATIVE_ERROR, "asinh");
+    assert_cases(&ACOSH_CASES, TwoFloat::acosh, RELATIVE_ERROR, "acosh");
+    assert_cases(&ATANH_CASES, TwoFloat::atanh, RELATIVE_ERROR, "atanh");
+}
+
+#[test]
+fn hyperbolic_symmetry_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(-1.0, 1.0).unwrap();
+    repeated_test(|| {
+        let scale = 2f64.powi(rng.random_range(-60..10));
+        let source = TwoFloat::new_add(rng.sample(dist) * scale, rng.sample(dist) * scale * 1e-17);
+
+        assert_eq!((-source).sinh(), -source.sinh(), "sinh({:?})", source);
+        assert_eq!((-source).cosh(), source.cosh(), "cosh({:?})", source);
+        assert_eq!((-source).tanh(), -source.tanh(), "tanh({:?})", source);
+        assert_eq!((-source).asinh(), -source.asinh(), "asinh({:?})", source);
+        if source.abs() < 1.0 {
+            assert_eq!((-source).atanh(), -source.atanh(), "atanh({:?})", source);
+        }
+    });
+}
+
+#[test]
+fn hyperbolic_special_test() {
+    // Odd functions keep the sign of zero in the high word only
+    for f in [
+        TwoFloat::sinh,
+        TwoFloat::tanh,
+        TwoFloat::asinh,
+        TwoFloat::atanh,
+    ]
+    .iter()
+    {
+        let zero = f(TwoFloat::from(-0.0));
+        assert!(zero.hi() == 0.0 && zero.hi().is_sign_negative());
+        assert!(zero.lo().is_sign_positive(), "{:?}", zero);
+    }
+    assert_eq!(TwoFloat::from(0.0).cosh(), 1.0);
+    assert_eq!(TwoFloat::from(1.0).acosh(), 0.0);
+
+    assert_eq!(TwoFloat::from(1000.0).tanh(), 1.0);
+    assert_eq!(TwoFloat::from(-1000.0).tanh(), -1.0);
+    assert_eq!(TwoFloat::INFINITY.tanh(), 1.0);
+
+    assert!(TwoFloat::from(711.0).cosh().hi().is_infinite());
+    assert!(TwoFloat::from(-711.0).sinh().hi() == f64::NEG_INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.asinh(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.acosh(), TwoFloat::INFINITY);
+
+    assert_eq!(TwoFloat::from(1.0).atanh(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-1.0).atanh(), TwoFloat::NEG_INFINITY);
+    assert!(TwoFloat::from(1.5).atanh().hi().is_nan());
+    assert!(TwoFloat::new_add(1.0, -1e-20).acosh().hi().is_nan());
+
+    for f in [
+        TwoFloat::sinh,
+        TwoFloat::cosh,
+        TwoFloat::tanh,
+        TwoFloat::asinh,
+        TwoFloat::acosh,
+        TwoFloat::atanh,
+    ]
+    .iter()
+    {
+        assert!(f(TwoFloat::NAN).hi().is_nan());
+    }
+}
+
 #[test]
 fn cosh_sinh_test() {
     let mut rng = rand::rng();
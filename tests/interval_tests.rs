@@ -0,0 +1,458 @@
+#[macro_use]
+pub mod common;
+
+use common::*;
+use core::convert::TryFrom;
+use hexf::hexf64;
+use rand::Rng;
+use twofloat::{consts, Interval, QuadFloat, TwoFloat};
+
+// Largest relative width accepted for the bounds of a single operation
+const BOUND_WIDTH: f64 = 1e-29;
+
+// Power of two by which values close to underflow are scaled for comparison
+const UNDERFLOW_SCALE: f64 = hexf64!("0x1p+1000");
+
+const SAMPLE_POINTS: usize = 2;
+
+// The reference values of the functions are slow to compute
+const FUNCTION_ITERS: usize = 10_000;
+
+// Largest relative width accepted for the bounds of the elementary functions,
+// which are widened by their estimated error bounds
+const FUNCTION_WIDTH: f64 = 1e-26;
+
+type Quad = [f64; 4];
+
+// Sines and cosines at the hardest arguments for the reduction modulo π/2.
+// The first is the double closest to a multiple of π/2, so that its cosine is
+// around 2^-61, and the others lie next to π/2 and π, at 1e22, near 2^244
+// and at the largest finite value.
+const REDUCTION_CASES: [(f64, Quad, Quad); 6] = [
+    (
+        hexf64!("0x1.6ac5b262ca1ffp+849"),
+        [
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.2b089ea1e692bp-123"),
+            hexf64!("0x1.b667cc5bcaf8ep-177"),
+            hexf64!("0x1.897f74a572767p-233"),
+        ],
+        [
+            hexf64!("-0x1.14ae72e6ba22fp-61"),
+            hexf64!("0x1.73eef1477d90ep-118"),
+            hexf64!("0x1.4fade1e51055dp-173"),
+            hexf64!("0x1.d4bfea2ab67a2p-231"),
+        ],
+    ),
+    (
+        core::f64::consts::FRAC_PI_2,
+        [
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.377ce858a5d48p-109"),
+            hexf64!("0x1.8ac58c5ec6756p-166"),
+            hexf64!("-0x1.5e9399ae7694cp-222"),
+        ],
+        [
+            hexf64!("0x1.1a62633145c07p-54"),
+            hexf64!("-0x1.f1976b7ed8fbcp-110"),
+            hexf64!("0x1.9fa81376bfe70p-166"),
+            hexf64!("0x1.e82b0c5524bbdp-223"),
+        ],
+    ),
+    (
+        core::f64::consts::PI,
+        [
+            hexf64!("0x1.1a62633145c07p-53"),
+            hexf64!("-0x1.f1976b7ed8fbdp-109"),
+            hexf64!("0x1.b8bb67f5fb7f8p-163"),
+            hexf64!("0x1.4ef715fed9f12p-218"),
+        ],
+        [
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("0x1.377ce858a5d48p-107"),
+            hexf64!("-0x1.8ac58c5ec6757p-164"),
+            hexf64!("0x1.61a37379064dbp-218"),
+        ],
+    ),
+    (
+        hexf64!("0x1.0f0cf064dd592p+73"),
+        [
+            hexf64!("-0x1.b453ab76bf397p-1"),
+            hexf64!("-0x1.f453790772648p-58"),
+            hexf64!("0x1.21f6f48413f44p-112"),
+            hexf64!("-0x1.98fb829b20a51p-166"),
+        ],
+        [
+            hexf64!("0x1.0be2cef01c8f4p-1"),
+            hexf64!("-0x1.b2d1bc8018c4fp-55"),
+            hexf64!("-0x1.614ab5e5d93a4p-109"),
+            hexf64!("-0x1.fe2404f1d9e29p-163"),
+        ],
+    ),
+    (
+        hexf64!("0x1.fffffffffffffp+1023"),
+        [
+            hexf64!("0x1.452fc98b34e97p-8"),
+            hexf64!("-0x1.27bb193d960dfp-62"),
+            hexf64!("0x1.4897bcc9f11cbp-117"),
+            hexf64!("0x1.5f200d980163ap-171"),
+        ],
+        [
+            hexf64!("-0x1.fffe62ecfab75p-1"),
+            hexf64!("-0x1.e038d934070f1p-56"),
+            hexf64!("-0x1.aef38c3e2b357p-111"),
+            hexf64!("0x1.79067d68ea21ap-166"),
+        ],
+    ),
+    (
+        hexf64!("0x1.5fdbbe9bba775p+244"),
+        [
+            hexf64!("-0x1.1ffea0bbde85ap-5"),
+            hexf64!("-0x1.470e89fb3b728p-60"),
+            hexf64!("-0x1.e3583cd1a4c18p-114"),
+            hexf64!("-0x1.3b8ac4bbfeb7bp-169"),
+        ],
+        [
+            hexf64!("-0x1.ffaefa5c71725p-1"),
+            hexf64!("-0x1.5b5ffbd80a95ep-55"),
+            hexf64!("-0x1.9432b2a1a10c6p-109"),
+            hexf64!("-0x1.97e35bc8c29fbp-165"),
+        ],
+    ),
+];
+
+// Exponentials close to overflow, which are reduced by large multiples of
+// ln(2)
+const EXP_CASES: [(f64, Quad); 2] = [
+    (
+        hexf64!("0x1.6240000000000p+9"),
+        [
+            hexf64!("0x1.1bf058bc994adp+1022"),
+            hexf64!("0x1.af2de8b6cbe7fp+967"),
+            hexf64!("0x1.d53e753b76714p+913"),
+            hexf64!("0x1.13efb79611301p+859"),
+        ],
+    ),
+    (
+        hexf64!("0x1.627c000000000p+9"),
+        [
+            hexf64!("0x1.c5bba5ad4d87fp+1022"),
+            hexf64!("0x1.4f71fc88a396ap+968"),
+            hexf64!("-0x1.45744130966a7p+914"),
+            hexf64!("0x1.9ab518e4884fdp+858"),
+        ],
+    ),
+];
+
+// Logarithms next to one, where the result cancels to a single term of the
+// series, and at the boundary √½ of the range to which arguments are reduced
+const LN_CASES: [(f64, Quad); 3] = [
+    (
+        hexf64!("0x1.fffffffffffffp-1"),
+        [
+            hexf64!("-0x1.0000000000000p-53"),
+            hexf64!("-0x1.0000000000000p-107"),
+            hexf64!("-0x1.5555555555556p-161"),
+            hexf64!("0x1.5555555555554p-216"),
+        ],
+    ),
+    (
+        hexf64!("0x1.0000000000001p+0"),
+        [
+            hexf64!("0x1.fffffffffffffp-53"),
+            hexf64!("0x1.5555555555554p-158"),
+            hexf64!("0x1.5555555555559p-212"),
+            hexf64!("-0x1.ddddddddddde9p-266"),
+        ],
+    ),
+    (
+        core::f64::consts::FRAC_1_SQRT_2,
+        [
+            hexf64!("-0x1.62e42fefa39eep-2"),
+            hexf64!("0x1.716fdfdbc882ep-60"),
+            hexf64!("0x1.059d9a92140a7p-114"),
+            hexf64!("-0x1.756f059abf3cfp-168"),
+        ],
+    ),
+];
+
+fn random_interval(min_exponent: i32, max_exponent: i32) -> Interval<TwoFloat> {
+    let a = random_twofloat(min_exponent, max_exponent);
+    let b = random_twofloat(min_exponent, max_exponent);
+    Interval::try_from((a.min(b), a.max(b))).unwrap()
+}
+
+// Returns the bounds of the interval and points spread between them
+fn sample_points(interval: Interval<TwoFloat>) -> Vec<TwoFloat> {
+    let mut rng = rand::rng();
+    let (lo, hi) = (interval.lo(), interval.hi());
+    let mut points = vec![lo, hi];
+    points.extend((0..SAMPLE_POINTS).map(|_| {
+        let t = rng.random_range(0.0..1.0);
+        (lo + (hi - lo) * t).max(lo).min(hi)
+    }));
+    points
+}
+
+fn assert_bounds(down: TwoFloat, up: TwoFloat, exact: QuadFloat, description: &str) {
+    assert!(
+        quad(down) <= exact && exact <= quad(up),
+        "Bounds {:?} and {:?} do not contain {}",
+        down,
+        up,
+        description
+    );
+}
+
+fn assert_encloses(interval: Interval<TwoFloat>, exact: QuadFloat, description: &str) {
+    assert!(
+        interval.is_valid() && quad(interval.lo()) <= exact && exact <= quad(interval.hi()),
+        "Interval {:?} does not contain {}",
+        interval,
+        description
+    );
+}
+
+#[test]
+fn directed_arithmetic_test() {
+    repeated_test(|| {
+        let a = random_ddouble();
+        let b = random_ddouble();
+        let (x, y) = (quad(a), quad(b));
+
+        let cases = [
+            (a.add_down(b), a.add_up(b), x + y, "+"),
+            (a.sub_down(b), a.sub_up(b), x - y, "-"),
+            (a.mul_down(b), a.mul_up(b), x * y, "*"),
+            (a.div_down(b), a.div_up(b), x / y, "/"),
+        ];
+
+        for &(down, up, exact, op) in cases.iter() {
+            let description = format!("{:?} {} {:?}", a, op, b);
+            if !exact.is_valid() || exact.abs() > f64::MAX / 2.0 {
+                continue;
+            }
+
+            assert_bounds(down, up, exact, &description);
+            assert!(
+                quad(up) - quad(down) <= exact.abs() * BOUND_WIDTH + 1e-300,
+                "Bounds {:?} and {:?} too wide for {}",
+                down,
+                up,
+                description
+            );
+        }
+    });
+}
+
+#[test]
+fn directed_underflow_test() {
+    repeated_test(|| {
+        let a = random_twofloat(-1074, -900);
+        let b = random_twofloat(-100, 100);
+        let (x, y) = (quad(a), quad(b));
+
+        assert_bounds(a.add_down(b), a.add_up(b), x + y, "sum");
+        assert_bounds(a.mul_down(b), a.mul_up(b), x * y, "product");
+        // The reference quotient loses precision when it is close to
+        // underflow, so the bounds are compared after exact scaling
+        assert_bounds(
+            a.div_down(b) * UNDERFLOW_SCALE,
+            a.div_up(b) * UNDERFLOW_SCALE,
+            quad(a * UNDERFLOW_SCALE) / y,
+            "quotient",
+        );
+        assert_bounds(
+            a.sub_down(a * 0.75),
+            a.sub_up(a * 0.75),
+            x - quad(a * 0.75),
+            "difference",
+        );
+    });
+}
+
+#[test]
+fn directed_special_test() {
+    let one = TwoFloat::from(1.0);
+
+    let down = TwoFloat::MAX.add_down(TwoFloat::MAX);
+    assert!(down.is_valid() && down > 1e308);
+    assert_eq!(TwoFloat::MAX.add_up(TwoFloat::MAX), TwoFloat::INFINITY);
+
+    let up = TwoFloat::MIN.mul_up(TwoFloat::from(2.0));
+    assert!(up.is_valid() && up < -1e308);
+    assert_eq!(
+        TwoFloat::MIN.mul_down(TwoFloat::from(2.0)),
+        TwoFloat::NEG_INFINITY
+    );
+
+    assert_eq!(TwoFloat::INFINITY.add_down(one), TwoFloat::INFINITY);
+    assert_eq!(one.div_up(TwoFloat::from(0.0)), TwoFloat::INFINITY);
+    assert!(TwoFloat::INFINITY.sub_up(TwoFloat::INFINITY).hi().is_nan());
+    assert!(TwoFloat::NAN.mul_down(one).hi().is_nan());
+
+    assert!(TwoFloat::from(0.0).add_down(TwoFloat::from(0.0)) <= 0.0);
+    assert!(TwoFloat::from(0.0).add_up(TwoFloat::from(0.0)) >= 0.0);
+}
+
+#[test]
+fn interval_arithmetic_test() {
+    repeated_test(|| {
+        let a = random_interval(-30, 30);
+        let b = random_interval(-30, 30);
+        let (sum, difference, product, quotient) = (a + b, a - b, a * b, a / b);
+
+        for &x in sample_points(a).iter() {
+            for &y in sample_points(b).iter() {
+                let (p, q) = (quad(x), quad(y));
+                assert_encloses(sum, p + q, "sum");
+                assert_encloses(difference, p - q, "difference");
+                assert_encloses(product, p * q, "product");
+                assert_encloses(quotient, p / q, "quotient");
+            }
+        }
+
+        assert_encloses(-a, -quad(a.lo()), "negation");
+    });
+}
+
+#[test]
+fn interval_function_test() {
+    for _ in 0..FUNCTION_ITERS {
+        let a = random_interval(-10, 9);
+        let positive = Interval::try_from((
+            a.lo().abs().min(a.hi().abs()),
+            a.hi().abs().max(a.lo().abs()),
+        ))
+        .unwrap();
+        let (sin, cos, exp) = (a.sin(), a.cos(), a.exp());
+        let (sqrt, ln) = (positive.sqrt(), positive.ln());
+
+        for &x in sample_points(a).iter() {
+            let p = quad(x);
+            assert_encloses(sin, p.sin(), &format!("sin({:?})", x));
+            assert_encloses(cos, p.cos(), &format!("cos({:?})", x));
+            assert_encloses(exp, p.exp(), &format!("exp({:?})", x));
+        }
+
+        for &x in sample_points(positive).iter() {
+            let p = quad(x);
+            assert_encloses(sqrt, p.sqrt(), &format!("sqrt({:?})", x));
+            assert_encloses(ln, p.ln(), &format!("ln({:?})", x));
+        }
+    }
+}
+
+#[test]
+fn interval_extreme_test() {
+    for _ in 0..FUNCTION_ITERS {
+        let x = random_twofloat(-1074, 1000).abs();
+        let point = Interval::from(x);
+        assert_encloses(point.sqrt(), quad(x).sqrt(), &format!("sqrt({:?})", x));
+        assert_encloses(point.ln(), quad(x).ln(), &format!("ln({:?})", x));
+
+        let y = random_twofloat(-60, 60) * 1e5;
+        assert_encloses(
+            Interval::from(y).sin(),
+            quad(y).sin(),
+            &format!("sin({:?})", y),
+        );
+        assert_encloses(
+            Interval::from(y).cos(),
+            quad(y).cos(),
+            &format!("cos({:?})", y),
+        );
+    }
+}
+
+// Checks that the interval encloses the reference value without being much
+// wider than the estimated error bounds allow
+fn assert_reference(interval: Interval<TwoFloat>, expected: Quad, description: &str) {
+    let exact = QuadFloat::try_from(expected).unwrap();
+    assert_encloses(interval, exact, description);
+    assert!(
+        quad(interval.hi()) - quad(interval.lo()) <= exact.abs() * FUNCTION_WIDTH,
+        "Interval {:?} too wide for {}",
+        interval,
+        description
+    );
+}
+
+#[test]
+fn interval_reference_test() {
+    for &(x, sin, cos) in REDUCTION_CASES.iter() {
+        let point = Interval::from(TwoFloat::from(x));
+        assert_reference(point.sin(), sin, &format!("sin({:e})", x));
+        assert_reference(point.cos(), cos, &format!("cos({:e})", x));
+        assert_reference((-point).sin(), sin.map(|w| -w), &format!("sin({:e})", -x));
+    }
+
+    for &(x, exp) in EXP_CASES.iter() {
+        let point = Interval::from(TwoFloat::from(x));
+        assert_reference(point.exp(), exp, &format!("exp({:e})", x));
+    }
+
+    for &(x, ln) in LN_CASES.iter() {
+        let point = Interval::from(TwoFloat::from(x));
+        assert_reference(point.ln(), ln, &format!("ln({:e})", x));
+    }
+}
+
+#[test]
+fn interval_special_test() {
+    let zero = Interval::from(0.0);
+    let unit = Interval::try_from((TwoFloat::from(-1.0), TwoFloat::from(1.0))).unwrap();
+    let positive = Interval::try_from((TwoFloat::from(1.0), TwoFloat::from(2.0))).unwrap();
+
+    assert_eq!(positive / unit, Interval::ENTIRE);
+
+    let upper_half = Interval::try_from((TwoFloat::from(0.0), TwoFloat::from(2.0))).unwrap();
+    let half = TwoFloat::from(0.5);
+    for (quotient, sign) in [
+        (positive / upper_half, 1.0),
+        (-positive / -upper_half, 1.0),
+        (positive / -upper_half, -1.0),
+        (-positive / upper_half, -1.0),
+    ] {
+        let (near, far) = if sign > 0.0 {
+            (quotient.lo(), quotient.hi())
+        } else {
+            (-quotient.hi(), -quotient.lo())
+        };
+        assert!(near <= half && half - near < 1e-30, "{:?}", quotient);
+        assert_eq!(far, TwoFloat::INFINITY);
+    }
+    assert_eq!(zero / upper_half, zero);
+    assert_eq!(unit / upper_half, Interval::ENTIRE);
+    let non_negative = Interval::try_from((TwoFloat::from(0.0), TwoFloat::from(1.0))).unwrap();
+    assert_eq!((non_negative / upper_half).lo(), 0.0);
+    assert_eq!((non_negative / upper_half).hi(), TwoFloat::INFINITY);
+    assert!(!(positive / zero).is_valid());
+    assert_eq!(Interval::ENTIRE * zero, zero);
+    assert_eq!(Interval::ENTIRE.sin(), unit);
+    assert!(Interval::try_from((TwoFloat::from(2.0), TwoFloat::from(1.0))).is_err());
+
+    assert!(!(-positive).sqrt().is_valid());
+    assert_eq!(unit.sqrt().lo(), 0.0);
+    assert_eq!(unit.ln().lo(), TwoFloat::NEG_INFINITY);
+    assert!(!(-positive).ln().is_valid());
+
+    let exp = Interval::try_from((TwoFloat::NEG_INFINITY, TwoFloat::from(0.0)))
+        .unwrap()
+        .exp();
+    assert_eq!(exp.lo(), 0.0);
+    assert!(exp.contains(TwoFloat::from(1.0)));
+
+    let large = Interval::try_from((TwoFloat::from(709.5), TwoFloat::from(710.0))).unwrap();
+    assert_eq!(large.exp().hi(), TwoFloat::INFINITY);
+    assert!(large.exp().lo().is_valid());
+
+    let small = Interval::try_from((TwoFloat::from(-800.0), TwoFloat::from(-750.0))).unwrap();
+    assert_eq!(small.exp().lo(), 0.0);
+    assert!(small.exp().hi() > 0.0 && small.exp().hi() < 1e-300);
+
+    let around_pi = Interval::try_from((consts::FRAC_PI_2, consts::PI)).unwrap();
+    assert_eq!(around_pi.sin().hi(), 1.0);
+    assert!(around_pi.sin().lo() < 0.0 && around_pi.sin().lo() > -1e-30);
+    assert_eq!(around_pi.cos().lo(), -1.0);
+}
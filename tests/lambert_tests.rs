@@ -0,0 +1,192 @@
+#![allow(clippy::approx_constant)]
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+use hexf::hexf64;
+use rand::Rng;
+use twofloat::{consts::E, TwoFloat};
+
+// Principal branch of the Lambert W function from next to the branch point
+// -1/e up to 1e300, where W is around 684
+const W0_CASES: [(f64, Pair); 10] = [
+    (
+        1.0,
+        (
+            hexf64!("0x1.22609af8e9657p-1"),
+            hexf64!("0x1.2f57eed531437p-55"),
+        ),
+    ),
+    (
+        2.718281828459045,
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.ea8556644e4cdp-56"),
+        ),
+    ),
+    (
+        1e-10,
+        (
+            hexf64!("0x1.b7cdfd9cbef6ap-34"),
+            hexf64!("0x1.e6f9e82a4bb65p-88"),
+        ),
+    ),
+    (
+        -0.3,
+        (
+            hexf64!("-0x1.f525db815078bp-2"),
+            hexf64!("-0x1.bb8d80576584fp-57"),
+        ),
+    ),
+    (
+        -0.36787944117,
+        (
+            hexf64!("-0x1.ffffa20a269ebp-1"),
+            hexf64!("0x1.594bd21d78075p-55"),
+        ),
+    ),
+    (
+        -0.2,
+        (
+            hexf64!("-0x1.09642639865b6p-2"),
+            hexf64!("0x1.0ab2c2e82c577p-58"),
+        ),
+    ),
+    (
+        10.0,
+        (
+            hexf64!("0x1.bedaec5606044p+0"),
+            hexf64!("-0x1.1a2406e98de7bp-55"),
+        ),
+    ),
+    (
+        0.5,
+        (
+            hexf64!("0x1.682ce1cadd300p-2"),
+            hexf64!("-0x1.4e148abadf1dcp-57"),
+        ),
+    ),
+    (
+        1e+100,
+        (
+            hexf64!("0x1.c1afaba5e1a96p+7"),
+            hexf64!("-0x1.cdc14699fe12cp-48"),
+        ),
+    ),
+    (
+        1e+300,
+        (
+            hexf64!("0x1.561fa4884a0e5p+9"),
+            hexf64!("0x1.e3bd643836affp-51"),
+        ),
+    ),
+];
+
+// Reference values for the lower branch of the Lambert W function
+const WM1_CASES: [(f64, Pair); 6] = [
+    (
+        -0.1,
+        (
+            hexf64!("-0x1.c9e01e6bc1fbap+1"),
+            hexf64!("-0x1.4b1d17f26dc91p-56"),
+        ),
+    ),
+    (
+        -0.3,
+        (
+            hexf64!("-0x1.c805b402bff7fp+0"),
+            hexf64!("0x1.33da72baa3c85p-62"),
+        ),
+    ),
+    (
+        -0.36787944117,
+        (
+            hexf64!("-0x1.00002efaf2702p+0"),
+            hexf64!("0x1.fb3de5aa329d9p-54"),
+        ),
+    ),
+    (
+        -1e-10,
+        (
+            hexf64!("-0x1.a4b94c5712cdap+4"),
+            hexf64!("0x1.918186e09baa8p-54"),
+        ),
+    ),
+    (
+        -1e-300,
+        (
+            hexf64!("-0x1.5ca950bbd0767p+9"),
+            hexf64!("-0x1.14219931c72bfp-45"),
+        ),
+    ),
+    (
+        -0.25,
+        (
+            hexf64!("-0x1.139f158d4a4d1p+1"),
+            hexf64!("0x1.d9d1d5a9abfaep-53"),
+        ),
+    ),
+];
+
+#[test]
+fn lambert_reference_test() {
+    assert_cases(
+        &W0_CASES,
+        TwoFloat::lambert_w0,
+        SPECIAL_RELATIVE_ERROR,
+        "W0",
+    );
+    assert_cases(
+        &WM1_CASES,
+        TwoFloat::lambert_wm1,
+        SPECIAL_RELATIVE_ERROR,
+        "W-1",
+    );
+}
+
+#[test]
+fn lambert_special_test() {
+    let zero = TwoFloat::from(0.0);
+    let branch = -E.recip();
+
+    assert_eq!(zero.lambert_w0(), 0.0);
+    assert_eq!(branch.lambert_w0(), -1.0);
+    assert_eq!(branch.lambert_wm1(), -1.0);
+    assert_eq!(zero.lambert_wm1(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.lambert_w0(), TwoFloat::INFINITY);
+
+    assert!(TwoFloat::from(-0.5).lambert_w0().hi().is_nan());
+    assert!(TwoFloat::from(-0.5).lambert_wm1().hi().is_nan());
+    assert!(TwoFloat::from(0.5).lambert_wm1().hi().is_nan());
+    assert!(TwoFloat::NAN.lambert_w0().hi().is_nan());
+    assert!(TwoFloat::NAN.lambert_wm1().hi().is_nan());
+}
+
+#[test]
+fn lambert_identity_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(-0.36, 20.0).unwrap();
+    for _ in 0..10000 {
+        let x = TwoFloat::from(rng.sample(dist));
+        let w = x.lambert_w0();
+        let result = w * w.exp();
+        assert!(
+            ((result - x) / x).abs() < 1e-29,
+            "W0({:?}) = {:?} does not satisfy w exp(w) = x",
+            x,
+            w
+        );
+
+        if x < 0.0 {
+            let w = x.lambert_wm1();
+            let result = w * w.exp();
+            assert!(
+                ((result - x) / x).abs() < 1e-29,
+                "W-1({:?}) = {:?} does not satisfy w exp(w) = x",
+                x,
+                w
+            );
+        }
+    }
+}
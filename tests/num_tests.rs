@@ -1,5 +1,5 @@
-use num_traits::{Float, One};
-use twofloat::TwoFloat;
+use num_traits::{float::FloatCore, Float, FloatConst, One};
+use twofloat::{QuadFloat, TwoF32, TwoFloat};
 
 pub mod common;
 
@@ -10,3 +10,48 @@ fn tan_test() {
     let actual = Float::tan(value);
     assert_eq!(expected, actual);
 }
+
+// Checks that the `Float` and `FloatCore` implementations agree
+fn check_traits<T: Float + FloatCore + FloatConst + core::fmt::Debug>() {
+    let values = [
+        T::zero(),
+        <T as Float>::neg_zero(),
+        T::one(),
+        <T as Float>::max_value(),
+        <T as Float>::infinity(),
+        <T as Float>::neg_infinity(),
+        <T as Float>::nan(),
+        T::PI(),
+    ];
+    for &value in values.iter() {
+        assert_eq!(
+            Float::is_infinite(value),
+            FloatCore::is_infinite(value),
+            "{:?}",
+            value
+        );
+        assert_eq!(
+            Float::is_nan(value),
+            FloatCore::is_nan(value),
+            "{:?}",
+            value
+        );
+        assert_eq!(
+            Float::classify(value),
+            FloatCore::classify(value),
+            "{:?}",
+            value
+        );
+    }
+
+    assert!(Float::is_infinite(<T as Float>::infinity()));
+    assert!(!Float::is_infinite(<T as Float>::nan()));
+    assert!(Float::is_sign_negative(<T as Float>::neg_zero()));
+}
+
+#[test]
+fn trait_consistency_test() {
+    check_traits::<TwoFloat>();
+    check_traits::<TwoF32>();
+    check_traits::<QuadFloat>();
+}
@@ -0,0 +1,138 @@
+use core::{cmp::Ordering, convert::TryFrom, hash::BuildHasher};
+use std::collections::{hash_map::RandomState, BTreeMap, HashSet};
+
+use twofloat::{NotNanTwoFloat, OrderedTwoFloat, TwoFloat, TwoFloatError};
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+
+#[test]
+fn total_cmp_test() {
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid());
+        let b = get_valid_ddouble(|x| x.is_valid());
+        assert_eq!(
+            Some(a.total_cmp(&b)),
+            a.partial_cmp(&b),
+            "total_cmp({:?}, {:?})",
+            a,
+            b
+        );
+        assert_eq!(a.total_cmp(&a), Ordering::Equal);
+    });
+}
+
+#[test]
+fn total_cmp_special_test() {
+    let values = [
+        -TwoFloat::NAN,
+        TwoFloat::NEG_INFINITY,
+        TwoFloat::MIN,
+        TwoFloat::from(-1.0),
+        TwoFloat::from(-0.0),
+        TwoFloat::from(0.0),
+        TwoFloat::new_add(1.0, 1e-30),
+        TwoFloat::MAX,
+        TwoFloat::INFINITY,
+        TwoFloat::NAN,
+    ];
+
+    for (i, a) in values.iter().enumerate() {
+        for (j, b) in values.iter().enumerate() {
+            assert_eq!(a.total_cmp(b), i.cmp(&j), "total_cmp({:?}, {:?})", a, b);
+        }
+    }
+}
+
+#[test]
+fn ordered_test() {
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid());
+        let b = get_valid_ddouble(|x| x.is_valid());
+        let (oa, ob) = (OrderedTwoFloat(a), OrderedTwoFloat(b));
+        assert_eq!(Some(oa.cmp(&ob)), a.partial_cmp(&b), "{:?}, {:?}", a, b);
+        assert_eq!(oa == ob, a == b, "{:?}, {:?}", a, b);
+    });
+}
+
+#[test]
+fn ordered_special_test() {
+    let state = RandomState::new();
+
+    let zero = OrderedTwoFloat::from(0.0);
+    let negative_zero = OrderedTwoFloat::from(-0.0);
+    assert_eq!(zero, negative_zero);
+    assert_eq!(state.hash_one(zero), state.hash_one(negative_zero));
+
+    let nan = OrderedTwoFloat(TwoFloat::NAN);
+    let negative_nan = OrderedTwoFloat(-TwoFloat::NAN);
+    assert_eq!(nan, negative_nan);
+    assert_eq!(state.hash_one(nan), state.hash_one(negative_nan));
+    assert!(nan > OrderedTwoFloat(TwoFloat::INFINITY));
+
+    let set: HashSet<_> = [
+        zero,
+        negative_zero,
+        nan,
+        negative_nan,
+        OrderedTwoFloat::from(1.0),
+    ]
+    .iter()
+    .copied()
+    .collect();
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn ordered_map_test() {
+    let mut map = BTreeMap::new();
+    map.insert(OrderedTwoFloat(TwoFloat::new_div(2.0, 3.0)), "two thirds");
+    map.insert(OrderedTwoFloat(TwoFloat::new_div(1.0, 3.0)), "one third");
+    map.insert(OrderedTwoFloat(TwoFloat::NAN), "nan");
+    map.insert(OrderedTwoFloat(TwoFloat::NEG_INFINITY), "negative infinity");
+
+    let values: Vec<_> = map.values().copied().collect();
+    assert_eq!(
+        values,
+        ["negative infinity", "one third", "two thirds", "nan"]
+    );
+    assert_eq!(
+        map[&OrderedTwoFloat(TwoFloat::from(1.0) / 3.0)],
+        "one third"
+    );
+}
+
+#[test]
+fn not_nan_test() {
+    let state = RandomState::new();
+
+    assert_eq!(
+        NotNanTwoFloat::new(TwoFloat::NAN),
+        Err(TwoFloatError::NaNInput)
+    );
+    assert_eq!(
+        NotNanTwoFloat::try_from(-TwoFloat::NAN),
+        Err(TwoFloatError::NaNInput)
+    );
+
+    let infinity = NotNanTwoFloat::new(TwoFloat::INFINITY).unwrap();
+    assert_eq!(infinity.into_inner(), TwoFloat::INFINITY);
+
+    let zero = NotNanTwoFloat::new(TwoFloat::from(0.0)).unwrap();
+    let negative_zero = NotNanTwoFloat::new(TwoFloat::from(-0.0)).unwrap();
+    assert_eq!(zero, negative_zero);
+    assert_eq!(state.hash_one(zero), state.hash_one(negative_zero));
+
+    repeated_test(|| {
+        let a = get_valid_ddouble(|x| x.is_valid());
+        let b = get_valid_ddouble(|x| x.is_valid());
+        let (na, nb) = (
+            NotNanTwoFloat::new(a).unwrap(),
+            NotNanTwoFloat::new(b).unwrap(),
+        );
+        assert_eq!(Some(na.cmp(&nb)), a.partial_cmp(&b), "{:?}, {:?}", a, b);
+        assert_eq!(TwoFloat::from(na), a);
+    });
+}
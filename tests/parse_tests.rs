@@ -0,0 +1,248 @@
+#![allow(clippy::float_cmp)]
+#![allow(clippy::approx_constant)]
+
+use hexf::hexf64;
+use num_traits::Num;
+use rand::Rng;
+
+use twofloat::{ParseErrorKind, TwoFloat, TwoFloatError};
+
+pub mod common;
+
+use common::repeated_test;
+
+// Reference values computed using exact rational arithmetic
+const DECIMAL_CASES: [(&str, f64, f64); 13] = [
+    (
+        "3.14159265358979323846264338327950288",
+        hexf64!("0x1.921fb54442d18p+1"),
+        hexf64!("0x1.1a62633145c07p-53"),
+    ),
+    (
+        "0.1",
+        hexf64!("0x1.999999999999ap-4"),
+        hexf64!("-0x1.999999999999ap-58"),
+    ),
+    (
+        "1e-5",
+        hexf64!("0x1.4f8b588e368f1p-17"),
+        hexf64!("-0x1.ee78183f91e64p-71"),
+    ),
+    (
+        "2.718281828459045235360287471352662497757",
+        hexf64!("0x1.5bf0a8b145769p+1"),
+        hexf64!("0x1.4d57ee2b1013ap-53"),
+    ),
+    (
+        "1.7976931348623157e308",
+        hexf64!("0x1.fffffffffffffp+1023"),
+        hexf64!("-0x1.4e53663a912b6p+966"),
+    ),
+    ("1e-320", 1e-320, 0.0),
+    ("4.9e-324", hexf64!("0x0.0000000000001p-1022"), 0.0),
+    (
+        "123456789012345678901234567890123456789",
+        hexf64!("0x1.7383a69580580p+126"),
+        hexf64!("-0x1.3a55205cd751cp+72"),
+    ),
+    (
+        "1.000000000000000000000000000000000000000001",
+        1.0,
+        hexf64!("0x1.64cfda3281e39p-140"),
+    ),
+    (
+        "6.02214076e23",
+        hexf64!("0x1.fe185ca57c517p+78"),
+        hexf64!("0x1.8c00000000000p+23"),
+    ),
+    (
+        "0.3333333333333333333333333333333333333333333333",
+        hexf64!("0x1.5555555555555p-2"),
+        hexf64!("0x1.5555555555555p-56"),
+    ),
+    (
+        "1e300",
+        hexf64!("0x1.7e43c8800759cp+996"),
+        hexf64!("-0x1.698fdc7ace0cap+942"),
+    ),
+    (
+        "9.9999999999999999999999999999999999e-300",
+        hexf64!("0x1.ac9a7b3b7302fp-994"),
+        hexf64!("0x0.0000000fa1259p-1022"),
+    ),
+];
+
+#[test]
+fn parse_reference_test() {
+    for &(source, hi, lo) in DECIMAL_CASES.iter() {
+        let result: TwoFloat = source.parse().unwrap();
+        assert_eq!(result.hi(), hi, "Incorrect high word parsing {}", source);
+        assert_eq!(result.lo(), lo, "Incorrect low word parsing {}", source);
+        assert!(result.is_valid(), "Invalid result parsing {}", source);
+
+        let negative: TwoFloat = format!("-{}", source).parse().unwrap();
+        assert_eq!(negative, -result, "Incorrect result parsing -{}", source);
+    }
+}
+
+#[test]
+fn parse_f64_test() {
+    let mut rng = rand::rng();
+    repeated_test(|| {
+        let a = f64::from_bits(rng.random_range(0..0x7ff0_0000_0000_0000));
+        let result: TwoFloat = format!("{:e}", a).parse().unwrap();
+        assert_eq!(result.hi(), a, "Incorrect result parsing {:e}", a);
+    });
+}
+
+#[test]
+fn parse_exact_test() {
+    let mut rng = rand::rng();
+    repeated_test(|| {
+        // Every f64 has an exact decimal representation of at most 767
+        // significant digits
+        let a = f64::from_bits(rng.random_range(0..0x7ff0_0000_0000_0000));
+        let source = format!("{:.767e}", a);
+        let result: TwoFloat = source.parse().unwrap();
+        assert_eq!(result.hi(), a, "Incorrect high word parsing {}", source);
+        assert_eq!(result.lo(), 0.0, "Incorrect low word parsing {}", source);
+    });
+}
+
+#[test]
+fn parse_format_test() {
+    let cases = [
+        ("0", 0.0),
+        ("-0", -0.0),
+        ("+12", 12.0),
+        ("1_000_000", 1e6),
+        ("1.5", 1.5),
+        ("1.", 1.0),
+        (".25", 0.25),
+        ("00.125", 0.125),
+        ("2.5E3", 2500.0),
+        ("2.5e+3", 2500.0),
+        ("25e-1", 2.5),
+        ("1e1_0", 1e10),
+        ("0e99999999999", 0.0),
+        ("1e-99999999999", 0.0),
+    ];
+
+    for &(source, expected) in cases.iter() {
+        let result: TwoFloat = source.parse().unwrap();
+        assert_eq!(result, expected, "Incorrect result parsing {}", source);
+    }
+
+    let negative_zero = "-0".parse::<TwoFloat>().unwrap();
+    assert!(negative_zero.hi().is_sign_negative());
+    assert!(negative_zero.lo().is_sign_positive());
+}
+
+#[test]
+fn parse_special_test() {
+    for source in ["inf", "+Infinity", "INF"].iter() {
+        let result: TwoFloat = source.parse().unwrap();
+        assert_eq!(
+            result,
+            TwoFloat::INFINITY,
+            "Incorrect result parsing {}",
+            source
+        );
+    }
+
+    for source in ["-inf", "-infinity"].iter() {
+        let result: TwoFloat = source.parse().unwrap();
+        assert!(
+            result.hi().is_infinite() && result.is_sign_negative(),
+            "Incorrect result parsing {}",
+            source
+        );
+    }
+
+    for source in ["NaN", "nan", "-nan"].iter() {
+        let result: TwoFloat = source.parse().unwrap();
+        assert!(result.hi().is_nan(), "Incorrect result parsing {}", source);
+    }
+
+    let result: TwoFloat = "1e309".parse().unwrap();
+    assert!(
+        result.hi().is_infinite(),
+        "Overflow did not return infinity"
+    );
+}
+
+#[test]
+fn parse_error_test() {
+    let cases = [
+        ("", ParseErrorKind::Empty, 0),
+        ("+", ParseErrorKind::MissingDigits, 1),
+        ("1.5x", ParseErrorKind::InvalidDigit, 3),
+        ("1e5.0", ParseErrorKind::InvalidDigit, 3),
+        ("1e-", ParseErrorKind::MissingDigits, 3),
+        ("infinite", ParseErrorKind::InvalidDigit, 0),
+    ];
+
+    for &(source, kind, position) in cases.iter() {
+        let result = source.parse::<TwoFloat>();
+        assert_eq!(
+            result,
+            Err(TwoFloatError::ParseError { kind, position }),
+            "Unexpected result parsing {:?}",
+            source
+        );
+    }
+}
+
+#[test]
+fn from_str_radix_test() {
+    let cases = [
+        ("101.11", 2, 5.75),
+        ("-z", 36, -35.0),
+        ("ff.8", 16, 255.5),
+        ("1.8p3", 16, 12.0),
+        ("1P-1", 16, 0.5),
+        ("12.1", 3, 5.0 + 1.0 / 3.0),
+        ("777", 8, 511.0),
+        ("2.5e2", 10, 250.0),
+    ];
+
+    for &(source, radix, expected) in cases.iter() {
+        let result = TwoFloat::from_str_radix(source, radix).unwrap();
+        assert_eq!(
+            result.hi(),
+            expected,
+            "Incorrect result parsing {} in radix {}",
+            source,
+            radix
+        );
+    }
+
+    let third = TwoFloat::from_str_radix("0.1", 3).unwrap();
+    assert_eq!(third.hi(), hexf64!("0x1.5555555555555p-2"));
+    assert_eq!(third.lo(), hexf64!("0x1.5555555555555p-56"));
+
+    let pi = TwoFloat::from_str_radix("3.243f6a8885a308d313198a2e03707344a", 16).unwrap();
+    assert_eq!(pi, twofloat::consts::PI);
+
+    assert_eq!(
+        TwoFloat::from_str_radix("1", 37),
+        Err(TwoFloatError::ParseError {
+            kind: ParseErrorKind::InvalidRadix,
+            position: 0
+        })
+    );
+    assert_eq!(
+        TwoFloat::from_str_radix("12", 2),
+        Err(TwoFloatError::ParseError {
+            kind: ParseErrorKind::InvalidDigit,
+            position: 1
+        })
+    );
+    assert_eq!(
+        TwoFloat::from_str_radix("1e2", 8),
+        Err(TwoFloatError::ParseError {
+            kind: ParseErrorKind::InvalidDigit,
+            position: 1
+        })
+    );
+}
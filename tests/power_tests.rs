@@ -87,7 +87,7 @@ fn cbrt_test() {
 #[test]
 fn powi_0_test() {
     repeated_test(|| {
-        let source = get_valid_twofloat(|x, _| x != 0.0);
+        let source = get_twofloat();
         let expected = TwoFloat::from(1.0);
         let result = source.powi(0);
 
@@ -179,7 +179,9 @@ fn zero_powf_test() {
         let result = TwoFloat::from(0.0).powf(source);
 
         if source == 0.0 {
-            assert!(!result.is_valid(), "0^0 returned valid result");
+            assert_eq!(result, 1.0, "0^0 did not return 1");
+        } else if source < 0.0 {
+            assert_eq!(result, f64::INFINITY, "0^{source} did not return infinity");
         } else {
             assert!(result.is_valid(), "0^{} produced invalid value", source);
             assert_eq!(result, 0.0, "0^{source} did not return 0");
@@ -193,12 +195,8 @@ fn powf_zero_test() {
         let source = get_twofloat();
         let result = source.powf(TwoFloat::from(0.0));
 
-        if source == 0.0 {
-            assert!(!result.is_valid(), "0^0 returned valid result");
-        } else {
-            assert!(result.is_valid(), "{}^0 returned invalid value", source);
-            assert_eq!(result, 1.0, "{source}^0 did not return 1");
-        }
+        assert!(result.is_valid(), "{}^0 returned invalid value", source);
+        assert_eq!(result, 1.0, "{source}^0 did not return 1");
     });
 }
 
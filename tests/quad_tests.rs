@@ -0,0 +1,1014 @@
+#![allow(clippy::approx_constant)]
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+use core::convert::TryFrom;
+use hexf::hexf64;
+use num_rational::BigRational;
+use num_traits::{Float, FloatConst, Num, Signed};
+use rand::Rng;
+use twofloat::{QuadFloat, TwoFloat};
+
+type Quad = [f64; 4];
+
+// Largest relative error accepted for the reference values
+const RELATIVE_ERROR: f64 = 1e-62;
+
+const IDENTITY_ITERS: usize = 1000;
+
+// Sums, products and quotients of pairs of inputs, including operands of
+// very different magnitudes and a sum that cancels down to the lower words
+const ARITHMETIC_CASES: [(Quad, Quad, Quad, Quad, Quad); 4] = [
+    (
+        [
+            hexf64!("0x1.5555555555555p-2"),
+            hexf64!("0x1.5555555555555p-56"),
+            hexf64!("0x1.5555555555555p-110"),
+            hexf64!("0x1.5555555555555p-164"),
+        ],
+        [
+            hexf64!("0x1.2492492492492p-2"),
+            hexf64!("0x1.2492492492492p-56"),
+            hexf64!("0x1.2492492492492p-110"),
+            hexf64!("0x1.2492492492492p-164"),
+        ],
+        [
+            hexf64!("0x1.3cf3cf3cf3cf4p-1"),
+            hexf64!("-0x1.8618618618618p-56"),
+            hexf64!("-0x1.8618618618618p-110"),
+            hexf64!("-0x1.8618618618619p-164"),
+        ],
+        [
+            hexf64!("0x1.8618618618618p-4"),
+            hexf64!("0x1.8618618618618p-58"),
+            hexf64!("0x1.8618618618618p-112"),
+            hexf64!("0x1.8618618618618p-166"),
+        ],
+        [
+            hexf64!("0x1.2aaaaaaaaaaabp+0"),
+            hexf64!("-0x1.5555555555555p-54"),
+            hexf64!("-0x1.5555555555555p-108"),
+            hexf64!("-0x1.5555555555555p-162"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.921fb54442d18p+1"),
+            hexf64!("-0x1.1a62633145c07p-53"),
+            hexf64!("0x1.f1976b7ed8fbcp-109"),
+            hexf64!("-0x1.4cf98e804177dp-163"),
+        ],
+        [
+            hexf64!("0x1.b91102745ea8cp-99"),
+            hexf64!("0x1.28f24eb447d26p-153"),
+            hexf64!("-0x1.06cf42760f7c5p-207"),
+            hexf64!("0x1.11f1235d8917ep-262"),
+        ],
+        [
+            hexf64!("-0x1.921fb54442d18p+1"),
+            hexf64!("-0x1.1a62633145b99p-53"),
+            hexf64!("0x1.8d684f3e5f039p-107"),
+            hexf64!("0x1.3e20a14f83fbep-162"),
+        ],
+        [
+            hexf64!("-0x1.5a69ab9c558e6p-97"),
+            hexf64!("-0x1.adc554ccdf9cap-152"),
+            hexf64!("0x1.d687937fccdc2p-208"),
+            hexf64!("-0x1.96d1d2507a418p-262"),
+        ],
+        [
+            hexf64!("-0x1.d2cb683a86c56p+99"),
+            hexf64!("0x1.3d5de07e357a7p+45"),
+            hexf64!("-0x1.02a301279ff8ap-10"),
+            hexf64!("-0x1.2f5774160760dp-65"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.9734041fbce37p+133"),
+            hexf64!("-0x1.62acba2eb1d49p+77"),
+            hexf64!("0x1.942da06b9918fp+21"),
+            hexf64!("-0x1.6ec49c3b2c9bfp-34"),
+        ],
+        [
+            hexf64!("-0x1.3ab2d6db2e7adp-39"),
+            hexf64!("0x1.b432ad8cccda6p-96"),
+            hexf64!("-0x1.0e4d55c839dd0p-154"),
+            hexf64!("-0x1.1bbd08585a5edp-212"),
+        ],
+        [
+            hexf64!("0x1.9734041fbce37p+133"),
+            hexf64!("-0x1.62acba2eb1d49p+77"),
+            hexf64!("0x1.942da06b9918fp+21"),
+            hexf64!("-0x1.789a32f2060fcp-34"),
+        ],
+        [
+            hexf64!("-0x1.f49244fbeaa11p+94"),
+            hexf64!("0x1.07196811522d1p+40"),
+            hexf64!("-0x1.68b14e258c65ep-16"),
+            hexf64!("0x1.9d7275fcd3b38p-70"),
+        ],
+        [
+            hexf64!("-0x1.4b4016762a617p+172"),
+            hexf64!("-0x1.b332d3e3664f7p+118"),
+            hexf64!("-0x1.430bbe0a37ef4p+64"),
+            hexf64!("0x1.7258c98376ca0p+8"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.0000000000000p-150"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("-0x1.0000000000000p-151"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("0x1.0000000000000p-151"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("-0x1.8000000000000p-150"),
+            hexf64!("-0x1.0000000000000p-301"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("-0x1.0000000000000p-151"),
+            hexf64!("0x1.0000000000000p-302"),
+            hexf64!("-0x1.0000000000000p-453"),
+        ],
+    ),
+];
+
+// Exponentials and logarithms of magnitudes for inputs from 1e-21 to 123,
+// most with all four words nonzero
+const EXP_LN_CASES: [(Quad, Quad, Quad); 9] = [
+    (
+        [
+            hexf64!("0x1.afc25c5397028p-70"),
+            hexf64!("0x1.aa9767465983ep-124"),
+            hexf64!("-0x1.93c88ed70c918p-178"),
+            hexf64!("0x1.1fa78aa758528p-232"),
+        ],
+        [
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("0x1.afc25c5397028p-70"),
+            hexf64!("0x1.aa98d35e5cbcap-124"),
+            hexf64!("-0x1.89acd6f6c41adp-179"),
+        ],
+        [
+            hexf64!("-0x1.7ffb1c0143d2ep+5"),
+            hexf64!("0x1.871f9c90f72ffp-49"),
+            hexf64!("-0x1.e1a1a325fb8f1p-109"),
+            hexf64!("-0x1.7f42907b89da6p-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+            hexf64!("0x1.999999999999ap-112"),
+            hexf64!("-0x1.999999999999ap-166"),
+        ],
+        [
+            hexf64!("0x1.1aec7b35a00d4p+0"),
+            hexf64!("-0x1.941f71cfd9ea5p-54"),
+            hexf64!("-0x1.4e7e39e81fa38p-108"),
+            hexf64!("0x1.d994791bb2ef0p-162"),
+        ],
+        [
+            hexf64!("-0x1.26bb1bbb55516p+1"),
+            hexf64!("0x1.f48ad494ea3e9p-53"),
+            hexf64!("0x1.9ebae3ae0260cp-107"),
+            hexf64!("0x1.2d10378be1cf1p-161"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.5555555555555p-2"),
+            hexf64!("-0x1.5555555555555p-56"),
+            hexf64!("-0x1.5555555555555p-110"),
+            hexf64!("-0x1.5555555555555p-164"),
+        ],
+        [
+            hexf64!("0x1.6edd3122f2ea5p-1"),
+            hexf64!("-0x1.763a67b363af1p-56"),
+            hexf64!("-0x1.06cd95e578052p-110"),
+            hexf64!("0x1.60e62121fe126p-164"),
+        ],
+        [
+            hexf64!("-0x1.193ea7aad030bp+0"),
+            hexf64!("0x1.a256f99caabebp-54"),
+            hexf64!("0x1.20d2907aef499p-110"),
+            hexf64!("0x1.03ee41f84d020p-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.8000000000000p-1"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("0x1.0ef9db467dcf8p+1"),
+            hexf64!("-0x1.0acf2a4470462p-53"),
+            hexf64!("-0x1.de0da9df72a55p-108"),
+            hexf64!("-0x1.5ef7f0fff2e47p-162"),
+        ],
+        [
+            hexf64!("-0x1.269621134db92p-2"),
+            hexf64!("-0x1.e0efadd9db02bp-56"),
+            hexf64!("0x1.63d5cf0b6f233p-110"),
+            hexf64!("0x1.2af2195297dc0p-164"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.6a09e667f3bcdp+0"),
+            hexf64!("0x1.bdd3413b26456p-54"),
+            hexf64!("-0x1.57d3e3adec175p-108"),
+            hexf64!("-0x1.2775099da2f59p-164"),
+        ],
+        [
+            hexf64!("0x1.f1e72fbc0ac98p-3"),
+            hexf64!("-0x1.7d41d70ef6a4dp-57"),
+            hexf64!("0x1.7d0f18075ba1ep-111"),
+            hexf64!("0x1.d3eea7062e644p-165"),
+        ],
+        [
+            hexf64!("0x1.62e42fefa39efp-2"),
+            hexf64!("0x1.abc9e3b39803fp-57"),
+            hexf64!("0x1.7b57a079a1934p-112"),
+            hexf64!("-0x1.ace93a4ebe5d1p-166"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.0c152382d7366p+2"),
+            hexf64!("-0x1.ee6913347c2a6p-52"),
+            hexf64!("-0x1.4bba47a9e5fd2p-108"),
+            hexf64!("-0x1.ccaef65529b02p-162"),
+        ],
+        [
+            hexf64!("0x1.07c598ab5e2bdp+6"),
+            hexf64!("-0x1.cec99a8b5e986p-48"),
+            hexf64!("-0x1.53e6fdd66d5dfp-102"),
+            hexf64!("-0x1.20ded6e8b0963p-157"),
+        ],
+        [
+            hexf64!("0x1.6eb28cd34d8a2p+0"),
+            hexf64!("-0x1.586c2f2e6e9e4p-54"),
+            hexf64!("-0x1.645beddbfe099p-108"),
+            hexf64!("-0x1.ebcc126f5fd92p-164"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.8c00000000000p+3"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("0x1.ce83697f78bfbp+17"),
+            hexf64!("0x1.bcd86539c6f79p-37"),
+            hexf64!("0x1.78e9a56f57902p-92"),
+            hexf64!("0x1.808559e910318p-146"),
+        ],
+        [
+            hexf64!("0x1.4201bf2f08ee3p+1"),
+            hexf64!("-0x1.8f44f0034481ep-55"),
+            hexf64!("0x1.0270bbbd6a9cep-110"),
+            hexf64!("-0x1.3eaab8b3b85b3p-166"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.415e5bf6fb106p+4"),
+            hexf64!("0x1.a568407591768p-53"),
+            hexf64!("-0x1.d1c82c2c1cb94p-107"),
+            hexf64!("-0x1.5521424712f22p-161"),
+        ],
+        [
+            hexf64!("0x1.040f1e87ad0c4p-29"),
+            hexf64!("0x1.cb31ca2bfb587p-83"),
+            hexf64!("0x1.8f5cceb17b3b5p-137"),
+            hexf64!("0x1.9b83ed644d7a3p-191"),
+        ],
+        [
+            hexf64!("0x1.8000000000000p+1"),
+            hexf64!("0x1.637b6adefc856p-219"),
+            hexf64!("-0x1.fd5ec3f9bc59fp-273"),
+            hexf64!("-0x1.15a3fb13db60ep-327"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.edd2f1a9fbe77p+6"),
+            hexf64!("-0x1.ba5e353f7ced9p-49"),
+            hexf64!("-0x1.6872b020c49bap-105"),
+            hexf64!("-0x1.78d4fdf3b645ap-159"),
+        ],
+        [
+            hexf64!("0x1.1428e1cbc0134p+178"),
+            hexf64!("0x1.545064bcaf122p+123"),
+            hexf64!("-0x1.09e3b21a226b1p+69"),
+            hexf64!("0x1.04eb9acd8e76cp+12"),
+        ],
+        [
+            hexf64!("0x1.343774f3e2362p+2"),
+            hexf64!("0x1.c11a7517b51b1p-54"),
+            hexf64!("0x1.c6bb381ad6965p-108"),
+            hexf64!("0x1.bff77804578f3p-164"),
+        ],
+    ),
+];
+
+// Square and cube roots of the magnitudes of the same inputs
+const ROOT_CASES: [(Quad, Quad, Quad); 9] = [
+    (
+        [
+            hexf64!("0x1.afc25c5397028p-70"),
+            hexf64!("0x1.aa9767465983ep-124"),
+            hexf64!("-0x1.93c88ed70c918p-178"),
+            hexf64!("0x1.1fa78aa758528p-232"),
+        ],
+        [
+            hexf64!("0x1.4c7608720b692p-35"),
+            hexf64!("0x1.3ed6281226b57p-91"),
+            hexf64!("0x1.297b8a40df049p-146"),
+            hexf64!("0x1.ba9bb6f3d617bp-205"),
+        ],
+        [
+            hexf64!("0x1.e3b84397821a8p-24"),
+            hexf64!("-0x1.52d373ecc024bp-79"),
+            hexf64!("0x1.d2e39f920634bp-133"),
+            hexf64!("-0x1.af13547206d3cp-191"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+            hexf64!("0x1.999999999999ap-112"),
+            hexf64!("-0x1.999999999999ap-166"),
+        ],
+        [
+            hexf64!("0x1.43d136248490fp-2"),
+            hexf64!("-0x1.2648bb4986143p-57"),
+            hexf64!("0x1.ffdd55d808959p-111"),
+            hexf64!("0x1.c000b56232ecfp-166"),
+        ],
+        [
+            hexf64!("0x1.db4c7760bcff2p-2"),
+            hexf64!("0x1.e2d50ee141632p-56"),
+            hexf64!("0x1.1e22f8dbdd027p-111"),
+            hexf64!("-0x1.bf43ca0c9936dp-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.5555555555555p-2"),
+            hexf64!("-0x1.5555555555555p-56"),
+            hexf64!("-0x1.5555555555555p-110"),
+            hexf64!("-0x1.5555555555555p-164"),
+        ],
+        [
+            hexf64!("0x1.279a74590331cp-1"),
+            hexf64!("0x1.34863e0792bedp-55"),
+            hexf64!("-0x1.a82f9e6c53222p-109"),
+            hexf64!("-0x1.cb0f41134253ap-163"),
+        ],
+        [
+            hexf64!("0x1.63003fbb4c375p-1"),
+            hexf64!("0x1.9f05b3003aac6p-55"),
+            hexf64!("0x1.efeebb6341cdep-111"),
+            hexf64!("-0x1.8b48869ec5a5bp-166"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.8000000000000p-1"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("0x1.bb67ae8584caap-1"),
+            hexf64!("0x1.cec95d0b5c1e3p-55"),
+            hexf64!("-0x1.f11db689f2ccfp-111"),
+            hexf64!("0x1.3da4798c720a6p-165"),
+        ],
+        [
+            hexf64!("0x1.d12ed0af1a27fp-1"),
+            hexf64!("-0x1.84a8a3d967a4cp-58"),
+            hexf64!("0x1.7f8b80da999fdp-112"),
+            hexf64!("0x1.919f070a73522p-166"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.6a09e667f3bcdp+0"),
+            hexf64!("0x1.bdd3413b26456p-54"),
+            hexf64!("-0x1.57d3e3adec175p-108"),
+            hexf64!("-0x1.2775099da2f59p-164"),
+        ],
+        [
+            hexf64!("0x1.306fe0a31b715p+0"),
+            hexf64!("0x1.6f46ad23182e4p-55"),
+            hexf64!("0x1.7b7b2f09cd0d9p-110"),
+            hexf64!("-0x1.60afd0e50e934p-164"),
+        ],
+        [
+            hexf64!("0x1.1f59ac3c7d6c0p+0"),
+            hexf64!("-0x1.4a0f1c3f06a03p-55"),
+            hexf64!("0x1.5491c0902afc5p-111"),
+            hexf64!("0x1.897da0e5005f3p-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.0c152382d7366p+2"),
+            hexf64!("-0x1.ee6913347c2a6p-52"),
+            hexf64!("-0x1.4bba47a9e5fd2p-108"),
+            hexf64!("-0x1.ccaef65529b02p-162"),
+        ],
+        [
+            hexf64!("0x1.05f8bd37c0e62p+1"),
+            hexf64!("-0x1.c550bf8a35e59p-56"),
+            hexf64!("-0x1.141b5b4e4dc93p-110"),
+            hexf64!("-0x1.310683dca0d6cp-164"),
+        ],
+        [
+            hexf64!("0x1.9cab8133ea76ap+0"),
+            hexf64!("0x1.964ed168f6b8ep-54"),
+            hexf64!("-0x1.362a0188e5d54p-111"),
+            hexf64!("0x1.76af7751181fep-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.8c00000000000p+3"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("0x1.c247a85fe81fap+1"),
+            hexf64!("0x1.9d8eef6854220p-54"),
+            hexf64!("-0x1.85cd1c5cb2764p-108"),
+            hexf64!("0x1.4c3526444a472p-163"),
+        ],
+        [
+            hexf64!("0x1.281172f8e7074p+1"),
+            hexf64!("-0x1.e7765bc31332bp-53"),
+            hexf64!("-0x1.6280d407d624dp-108"),
+            hexf64!("-0x1.93f52ca8aba0ep-162"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.415e5bf6fb106p+4"),
+            hexf64!("0x1.a568407591768p-53"),
+            hexf64!("-0x1.d1c82c2c1cb94p-107"),
+            hexf64!("-0x1.5521424712f22p-161"),
+        ],
+        [
+            hexf64!("0x1.1ed3fe64fc541p+2"),
+            hexf64!("0x1.5f6e4658d43eap-52"),
+            hexf64!("-0x1.0de17ce1b17b9p-107"),
+            hexf64!("-0x1.2ba999bdf9a2bp-161"),
+        ],
+        [
+            hexf64!("0x1.5bf0a8b145769p+1"),
+            hexf64!("0x1.4d57ee2b1013ap-53"),
+            hexf64!("-0x1.618713a31d3e2p-109"),
+            hexf64!("0x1.c5a6d2b53c26dp-163"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.edd2f1a9fbe77p+6"),
+            hexf64!("-0x1.ba5e353f7ced9p-49"),
+            hexf64!("-0x1.6872b020c49bap-105"),
+            hexf64!("-0x1.78d4fdf3b645ap-159"),
+        ],
+        [
+            hexf64!("0x1.638dee52c80edp+3"),
+            hexf64!("-0x1.62cc052fd785fp-51"),
+            hexf64!("-0x1.84b0e4b52d329p-105"),
+            hexf64!("-0x1.23304bb925a69p-159"),
+        ],
+        [
+            hexf64!("0x1.3ead4f488ee35p+2"),
+            hexf64!("0x1.c62a25c2ccd89p-52"),
+            hexf64!("0x1.91a3ecb91418bp-106"),
+            hexf64!("0x1.91eb5862b599bp-160"),
+        ],
+    ),
+];
+
+// Sines, cosines and arctangents of the same inputs
+const TRIG_CASES: [(Quad, Quad, Quad, Quad); 9] = [
+    (
+        [
+            hexf64!("0x1.afc25c5397028p-70"),
+            hexf64!("0x1.aa9767465983ep-124"),
+            hexf64!("-0x1.93c88ed70c918p-178"),
+            hexf64!("0x1.1fa78aa758528p-232"),
+        ],
+        [
+            hexf64!("0x1.afc25c5397028p-70"),
+            hexf64!("0x1.aa9767465983ep-124"),
+            hexf64!("-0x1.93c88ed7d941cp-178"),
+            hexf64!("-0x1.1ee393bf15328p-232"),
+        ],
+        [
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.6c180338c33bdp-140"),
+            hexf64!("0x1.dca5222c375bfp-194"),
+            hexf64!("-0x1.ac6f6c3477777p-249"),
+        ],
+        [
+            hexf64!("0x1.afc25c5397028p-70"),
+            hexf64!("0x1.aa9767465983ep-124"),
+            hexf64!("-0x1.93c88ed8a5f21p-178"),
+            hexf64!("0x1.45229bb4fa910p-233"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+            hexf64!("0x1.999999999999ap-112"),
+            hexf64!("-0x1.999999999999ap-166"),
+        ],
+        [
+            hexf64!("0x1.98eaecb8bcb2cp-4"),
+            hexf64!("-0x1.6893d0d94a9bep-59"),
+            hexf64!("0x1.14fe318a676fcp-114"),
+            hexf64!("0x1.c6dc0468aca9ep-168"),
+        ],
+        [
+            hexf64!("0x1.fd712f9a817c1p-1"),
+            hexf64!("-0x1.f65e29e2f9a4ep-55"),
+            hexf64!("0x1.2a54f82c9eb37p-110"),
+            hexf64!("-0x1.ce984e8382a35p-165"),
+        ],
+        [
+            hexf64!("0x1.983e282e2cc4cp-4"),
+            hexf64!("0x1.d6ecdfbe5cb85p-59"),
+            hexf64!("-0x1.e005f9513b107p-116"),
+            hexf64!("-0x1.f00e714b09199p-175"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.5555555555555p-2"),
+            hexf64!("-0x1.5555555555555p-56"),
+            hexf64!("-0x1.5555555555555p-110"),
+            hexf64!("-0x1.5555555555555p-164"),
+        ],
+        [
+            hexf64!("-0x1.4f0c2068a80c7p-2"),
+            hexf64!("0x1.4ebb3b7b386e2p-56"),
+            hexf64!("0x1.7b7801c8ce5e0p-110"),
+            hexf64!("-0x1.31a8e80ef242ap-165"),
+        ],
+        [
+            hexf64!("0x1.e3d165991982dp-1"),
+            hexf64!("-0x1.4a4b33934d978p-55"),
+            hexf64!("-0x1.f2775dd8b12f2p-109"),
+            hexf64!("-0x1.9c0716d122df5p-167"),
+        ],
+        [
+            hexf64!("-0x1.4978fa3269ee1p-2"),
+            hexf64!("-0x1.2419a87f2a458p-57"),
+            hexf64!("0x1.29b08e07bcbb1p-112"),
+            hexf64!("-0x1.d6573ce4290ccp-166"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.8000000000000p-1"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("0x1.5cffc16bf8f0dp-1"),
+            hexf64!("0x1.96cb370eb578ap-55"),
+            hexf64!("0x1.49108ece8f84dp-113"),
+            hexf64!("-0x1.cded01c566056p-167"),
+        ],
+        [
+            hexf64!("0x1.769fec655211fp-1"),
+            hexf64!("-0x1.827d5cf8c68c5p-57"),
+            hexf64!("0x1.93b4e0bfb8f21p-112"),
+            hexf64!("-0x1.81bb191b5117cp-168"),
+        ],
+        [
+            hexf64!("0x1.4978fa3269ee1p-1"),
+            hexf64!("0x1.2419a87f2a458p-56"),
+            hexf64!("-0x1.29b08e07bcbb1p-111"),
+            hexf64!("0x1.d6573ce4290cdp-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.6a09e667f3bcdp+0"),
+            hexf64!("0x1.bdd3413b26456p-54"),
+            hexf64!("-0x1.57d3e3adec175p-108"),
+            hexf64!("-0x1.2775099da2f59p-164"),
+        ],
+        [
+            hexf64!("-0x1.f9bc75444858ap-1"),
+            hexf64!("-0x1.921d2eea296c9p-55"),
+            hexf64!("-0x1.028628048a754p-110"),
+            hexf64!("0x1.c534d69d871d2p-164"),
+        ],
+        [
+            hexf64!("0x1.3f5f6868470b7p-3"),
+            hexf64!("-0x1.d6326001c496dp-58"),
+            hexf64!("0x1.bc2583d9d760ep-113"),
+            hexf64!("-0x1.0fe1d686bd1fap-171"),
+        ],
+        [
+            hexf64!("-0x1.e91f42805715dp-1"),
+            hexf64!("0x1.6ed0c200507f4p-56"),
+            hexf64!("0x1.b4c1774c642e2p-110"),
+            hexf64!("-0x1.4b75e94e2792fp-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.0c152382d7366p+2"),
+            hexf64!("-0x1.ee6913347c2a6p-52"),
+            hexf64!("-0x1.4bba47a9e5fd2p-108"),
+            hexf64!("-0x1.ccaef65529b02p-162"),
+        ],
+        [
+            hexf64!("-0x1.bb67ae8584caap-1"),
+            hexf64!("-0x1.cec95d0b5c1e3p-55"),
+            hexf64!("0x1.f11db689f2ccfp-111"),
+            hexf64!("-0x1.3da4798c720a5p-165"),
+        ],
+        [
+            hexf64!("-0x1.0000000000000p-1"),
+            hexf64!("-0x1.6129240cfe247p-217"),
+            hexf64!("0x1.7f06e5d7bc5a1p-272"),
+            hexf64!("0x1.bbf8b6e84660ap-326"),
+        ],
+        [
+            hexf64!("0x1.56219a568d94cp+0"),
+            hexf64!("-0x1.cdcf5a6ae377fp-56"),
+            hexf64!("0x1.a32c0c97734c6p-110"),
+            hexf64!("-0x1.28c44323ec40fp-164"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.8c00000000000p+3"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+            hexf64!("0x0.0p+0"),
+        ],
+        [
+            hexf64!("-0x1.858a06294dcb3p-3"),
+            hexf64!("0x1.3e39c55582a0dp-58"),
+            hexf64!("0x1.22c3f94f28686p-112"),
+            hexf64!("0x1.2619a29451165p-166"),
+        ],
+        [
+            hexf64!("0x1.f6a735ba49af5p-1"),
+            hexf64!("0x1.0e7fd325e0cc2p-55"),
+            hexf64!("0x1.1663fae7d1d58p-109"),
+            hexf64!("-0x1.0d97b41df46f2p-163"),
+        ],
+        [
+            hexf64!("0x1.7d7b5a1718148p+0"),
+            hexf64!("0x1.00655b0b0e0b2p-57"),
+            hexf64!("0x1.704ac9b06cac9p-111"),
+            hexf64!("-0x1.fcf346a4cbd7fp-166"),
+        ],
+    ),
+    (
+        [
+            hexf64!("-0x1.415e5bf6fb106p+4"),
+            hexf64!("0x1.a568407591768p-53"),
+            hexf64!("-0x1.d1c82c2c1cb94p-107"),
+            hexf64!("-0x1.5521424712f22p-161"),
+        ],
+        [
+            hexf64!("-0x1.e391b43eb78f9p-1"),
+            hexf64!("-0x1.3ffc45ac4e20ep-57"),
+            hexf64!("-0x1.4077da0efb244p-115"),
+            hexf64!("-0x1.b367a1d4df085p-169"),
+        ],
+        [
+            hexf64!("0x1.507b24c0e11eep-2"),
+            hexf64!("0x1.6848a8e1e50a0p-56"),
+            hexf64!("-0x1.bec1a6dfe5e0dp-111"),
+            hexf64!("-0x1.0456b54c905f5p-165"),
+        ],
+        [
+            hexf64!("-0x1.85638dffe93d3p+0"),
+            hexf64!("-0x1.4908cb4a82e65p-54"),
+            hexf64!("-0x1.6e48ec75ab61bp-111"),
+            hexf64!("-0x1.4da4aee463b8dp-165"),
+        ],
+    ),
+    (
+        [
+            hexf64!("0x1.edd2f1a9fbe77p+6"),
+            hexf64!("-0x1.ba5e353f7ced9p-49"),
+            hexf64!("-0x1.6872b020c49bap-105"),
+            hexf64!("-0x1.78d4fdf3b645ap-159"),
+        ],
+        [
+            hexf64!("-0x1.9b9dadc41aea5p-1"),
+            hexf64!("-0x1.67fc3b870795ap-55"),
+            hexf64!("0x1.8ce8f808a7fa2p-109"),
+            hexf64!("0x1.f10ec33f5257dp-163"),
+        ],
+        [
+            hexf64!("-0x1.307e5980a156fp-1"),
+            hexf64!("0x1.d2ec1c7c41fb0p-55"),
+            hexf64!("0x1.ceaa8c8e5a2c7p-110"),
+            hexf64!("-0x1.1ff56435d9c6dp-165"),
+        ],
+        [
+            hexf64!("0x1.900cdfeb560b4p+0"),
+            hexf64!("-0x1.7c87ea17377f0p-55"),
+            hexf64!("0x1.5c525bd28c241p-109"),
+            hexf64!("-0x1.2d8631618610cp-166"),
+        ],
+    ),
+];
+
+fn assert_relative(result: QuadFloat, expected: Quad, description: &str) {
+    let expected = QuadFloat::try_from(expected).unwrap();
+    let error = ((result - expected) / expected).abs();
+    assert!(
+        error <= RELATIVE_ERROR,
+        "Mismatch in {}: {:?} vs {:?}",
+        description,
+        result,
+        expected
+    );
+}
+
+fn random_quad() -> QuadFloat {
+    let mut rng = rand::rng();
+    let mut value = QuadFloat::from(rng.random_range(-100.0..100.0));
+    for k in 1..4 {
+        value += rng.random_range(-1.0..1.0) * 2f64.powi(-53 * k);
+    }
+    value
+}
+
+#[test]
+fn arithmetic_reference_test() {
+    for &(a, b, sum, product, quotient) in ARITHMETIC_CASES.iter() {
+        let a = QuadFloat::try_from(a).unwrap();
+        let b = QuadFloat::try_from(b).unwrap();
+        assert_relative(a + b, sum, &format!("{:?} + {:?}", a, b));
+        assert_relative(a * b, product, &format!("{:?} * {:?}", a, b));
+        assert_relative(a / b, quotient, &format!("{:?} / {:?}", a, b));
+        assert_relative(a - -b, sum, &format!("{:?} - {:?}", a, -b));
+    }
+}
+
+#[test]
+fn exp_ln_reference_test() {
+    for &(source, exp, ln) in EXP_LN_CASES.iter() {
+        let x = QuadFloat::try_from(source).unwrap();
+        assert_relative(x.exp(), exp, &format!("exp({:?})", x));
+        assert_relative(x.abs().ln(), ln, &format!("ln({:?})", x));
+    }
+}
+
+#[test]
+fn root_reference_test() {
+    for &(source, sqrt, cbrt) in ROOT_CASES.iter() {
+        let x = QuadFloat::try_from(source).unwrap().abs();
+        assert_relative(x.sqrt(), sqrt, &format!("sqrt({:?})", x));
+        assert_relative(x.cbrt(), cbrt, &format!("cbrt({:?})", x));
+    }
+}
+
+#[test]
+fn trig_reference_test() {
+    for &(source, sin, cos, atan) in TRIG_CASES.iter() {
+        let x = QuadFloat::try_from(source).unwrap();
+        assert_relative(x.sin(), sin, &format!("sin({:?})", x));
+        assert_relative(x.cos(), cos, &format!("cos({:?})", x));
+        assert_relative(x.atan(), atan, &format!("atan({:?})", x));
+    }
+}
+
+#[test]
+fn identity_test() {
+    for _ in 0..IDENTITY_ITERS {
+        let x = random_quad();
+        let (s, c) = x.sin_cos();
+        assert!((s * s + c * c - 1.0).abs() < 1e-61, "sin_cos({:?})", x);
+
+        let y = x.abs().sqrt();
+        assert!(((y * y - x.abs()) / x.abs()).abs() < 1e-62, "sqrt({:?})", x);
+
+        let e = (x / 8.0).exp();
+        assert!(((e.ln() - x / 8.0) / x).abs() < 1e-62, "ln(exp({:?}))", x);
+
+        let r = x.recip();
+        assert!((r * x - 1.0).abs() < 1e-62, "recip({:?})", x);
+    }
+}
+
+fn rational(x: QuadFloat) -> BigRational {
+    <[f64; 4]>::from(x)
+        .iter()
+        .map(|&w| BigRational::from_float(w).unwrap())
+        .sum()
+}
+
+#[test]
+fn mul_add_test() {
+    let tolerance = BigRational::from_float(2f64.powi(-205)).unwrap();
+    for _ in 0..IDENTITY_ITERS {
+        let (x, a) = (random_quad(), random_quad());
+        for b in [
+            random_quad(),
+            -(x * a),
+            -(x * a) + random_quad() * 2f64.powi(-200),
+        ] {
+            let result = x.mul_add(a, b);
+            let exact = rational(x) * rational(a) + rational(b);
+            let error = (rational(result) - &exact).abs();
+            assert!(
+                error <= exact.abs() * &tolerance,
+                "Mismatch in {:?} * {:?} + {:?}: {:?}",
+                x,
+                a,
+                b,
+                result
+            );
+        }
+    }
+
+    assert_eq!(
+        <QuadFloat as Float>::mul_add(QuadFloat::from(2.0), QuadFloat::from(3.0), 1.0.into()),
+        7.0
+    );
+    assert!(QuadFloat::INFINITY
+        .mul_add(QuadFloat::from(0.0), QuadFloat::from(1.0))
+        .hi()
+        .is_nan());
+}
+
+#[test]
+fn twofloat_conversion_test() {
+    repeated_test(|| {
+        let a = random_ddouble();
+        if !a.is_valid() {
+            return;
+        }
+
+        let q = QuadFloat::from(a);
+        assert!(q.is_valid());
+        assert_eq!(TwoFloat::from(q), a);
+        assert_eq!(q.hi(), a.hi());
+    });
+
+    let third = QuadFloat::from(1.0) / 3.0;
+    assert_eq!(TwoFloat::from(third), TwoFloat::from(1.0) / 3.0);
+}
+
+#[test]
+fn special_test() {
+    assert!(QuadFloat::NAN.sqrt().hi().is_nan());
+    assert!(QuadFloat::from(-1.0).sqrt().hi().is_nan());
+    assert!(QuadFloat::INFINITY.sin().hi().is_nan());
+    assert_eq!(QuadFloat::from(1.0) / 0.0, QuadFloat::INFINITY);
+    assert_eq!(QuadFloat::INFINITY.exp(), QuadFloat::INFINITY);
+    assert_eq!(QuadFloat::NEG_INFINITY.exp(), 0.0);
+    assert_eq!(QuadFloat::from(0.0).ln(), QuadFloat::NEG_INFINITY);
+    assert!(QuadFloat::from(-0.0).sin().is_sign_negative());
+
+    // Infinite results have zero lower words, so they are identical to the
+    // constants
+    let one = QuadFloat::from(1.0);
+    for &(result, expected) in [
+        (QuadFloat::INFINITY + 1.0, QuadFloat::INFINITY),
+        (one - QuadFloat::INFINITY, QuadFloat::NEG_INFINITY),
+        (QuadFloat::NEG_INFINITY * 2.0, QuadFloat::NEG_INFINITY),
+        (QuadFloat::MAX * 2.0, QuadFloat::INFINITY),
+        (QuadFloat::MAX + QuadFloat::MAX, QuadFloat::INFINITY),
+        (QuadFloat::from(1e300) * 1e300, QuadFloat::INFINITY),
+        (-one / 0.0, QuadFloat::NEG_INFINITY),
+    ]
+    .iter()
+    {
+        assert_eq!(result, expected);
+        assert_eq!(<[f64; 4]>::from(result), <[f64; 4]>::from(expected));
+    }
+    assert_eq!(
+        <[f64; 4]>::from(QuadFloat::INFINITY),
+        [f64::INFINITY, 0.0, 0.0, 0.0]
+    );
+}
+
+#[test]
+fn display_test() {
+    let a: QuadFloat = "0.1".parse().unwrap();
+    assert_eq!(format!("{}", a), "0.1");
+    assert_eq!(format!("{}", QuadFloat::from(-2.5)), "-2.5");
+    assert_eq!(format!("{:+.3}", QuadFloat::from(1.0) / 3.0), "+0.333");
+    assert_eq!(format!("{}", QuadFloat::NEG_INFINITY), "-inf");
+    assert_eq!(format!("{}", QuadFloat::NAN), "NaN");
+    let pi = format!("{}", QuadFloat::PI());
+    assert_eq!(
+        pi,
+        "3.1415926535897932384626433832795028841971693993751058209749445923"
+    );
+    assert_eq!(pi.parse::<QuadFloat>().unwrap(), QuadFloat::PI());
+
+    repeated_test(|| {
+        let value = random_quad();
+        let formatted = format!("{}", value);
+        assert_eq!(
+            <[f64; 4]>::from(formatted.parse::<QuadFloat>().unwrap()),
+            <[f64; 4]>::from(value),
+            "Round trip of {:?} through {}",
+            value,
+            formatted
+        );
+    });
+}
+
+#[test]
+fn parse_test() {
+    let a: QuadFloat =
+        "3.141592653589793238462643383279502884197169399375105820974944592307816406286"
+            .parse()
+            .unwrap();
+    assert_eq!(a, QuadFloat::PI());
+
+    let b = QuadFloat::from_str_radix("-0.8", 16).unwrap();
+    assert_eq!(b, QuadFloat::from(-0.5));
+
+    assert!("1.2.3".parse::<QuadFloat>().is_err());
+    assert_eq!("-inf".parse::<QuadFloat>(), Ok(QuadFloat::NEG_INFINITY));
+
+    // Infinities have zero lower words, and NaN has all words NaN
+    for &(source, expected) in [
+        ("inf", f64::INFINITY),
+        ("-inf", f64::NEG_INFINITY),
+        ("1e400", f64::INFINITY),
+    ]
+    .iter()
+    {
+        let value: QuadFloat = source.parse().unwrap();
+        assert_eq!(
+            <[f64; 4]>::from(value),
+            [expected, 0.0, 0.0, 0.0],
+            "Incorrect words parsing {}",
+            source
+        );
+    }
+    let nan: QuadFloat = "nan".parse().unwrap();
+    assert!(<[f64; 4]>::from(nan).iter().all(|w| w.is_nan()));
+}
+
+#[test]
+fn num_traits_test() {
+    fn hypotenuse<T: Float>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    let c = hypotenuse(QuadFloat::from(3.0), QuadFloat::from(4.0));
+    assert!((c - 5.0).abs() < 1e-62);
+
+    assert_eq!(<QuadFloat as Float>::floor(QuadFloat::from(2.5)), 2.0);
+    assert_eq!(
+        <QuadFloat as num_traits::ToPrimitive>::to_i64(&QuadFloat::from(-7.0)),
+        Some(-7)
+    );
+    assert_eq!(
+        <QuadFloat as num_traits::NumCast>::from(u128::MAX),
+        Some(QuadFloat::from(u128::MAX))
+    );
+    assert!((QuadFloat::TAU() - QuadFloat::PI() * 2.0).abs() < 1e-62);
+}
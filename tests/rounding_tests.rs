@@ -0,0 +1,185 @@
+use num_rational::BigRational;
+use rand::Rng;
+
+use twofloat::{Round, TwoFloat};
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+
+// Exact arithmetic on large rationals is slow, so use fewer iterations
+const ROUNDING_ITERS: usize = 10_000;
+
+const ROUNDINGS: [Round; 3] = [Round::Nearest, Round::Down, Round::Up];
+
+fn assert_rounded(result: TwoFloat, exact: &BigRational, round: Round, description: &str) {
+    let correct = result.is_valid()
+        && match round {
+            Round::Nearest => true,
+            Round::Down => rational(result) <= *exact,
+            Round::Up => rational(result) >= *exact,
+        };
+    assert!(
+        correct,
+        "{} rounded {:?} gave {:?}",
+        description, round, result
+    );
+}
+
+fn check_arithmetic(a: TwoFloat, b: TwoFloat) {
+    if !a.is_valid() || !b.is_valid() {
+        return;
+    }
+
+    let (x, y) = (rational(a), rational(b));
+    let mut cases = vec![
+        (
+            TwoFloat::add_rounded as fn(_, _, _) -> _,
+            &x + &y,
+            a + b,
+            "+",
+        ),
+        (TwoFloat::sub_rounded, &x - &y, a - b, "-"),
+        (TwoFloat::mul_rounded, &x * &y, a * b, "*"),
+    ];
+    if b != 0.0 {
+        cases.push((TwoFloat::div_rounded, &x / &y, a / b, "/"));
+    }
+
+    for (op, exact, nearest, symbol) in cases.iter() {
+        if !nearest.is_valid() || nearest.hi().abs() > f64::MAX / 2.0 {
+            continue;
+        }
+
+        let description = format!("{:?} {} {:?}", a, symbol, b);
+        for &round in ROUNDINGS.iter() {
+            assert_rounded(op(a, b, round), exact, round, &description);
+        }
+        assert_eq!(op(a, b, Round::Nearest), *nearest, "{}", description);
+    }
+}
+
+fn check_sqrt(a: TwoFloat) {
+    let x = rational(a);
+    let description = format!("sqrt({:?})", a);
+
+    let down = a.sqrt_rounded(Round::Down);
+    let up = a.sqrt_rounded(Round::Up);
+    assert!(
+        down >= 0.0 && down.is_valid() && up.is_valid(),
+        "Invalid bounds {:?} and {:?} for {}",
+        down,
+        up,
+        description
+    );
+    assert!(
+        rational(down) * rational(down) <= x,
+        "{} rounded down gave {:?}",
+        description,
+        down
+    );
+    assert!(
+        rational(up) * rational(up) >= x,
+        "{} rounded up gave {:?}",
+        description,
+        up
+    );
+    assert_eq!(a.sqrt_rounded(Round::Nearest), a.sqrt(), "{}", description);
+}
+
+#[test]
+fn rounded_arithmetic_test() {
+    for _ in 0..ROUNDING_ITERS {
+        check_arithmetic(random_ddouble(), random_ddouble());
+    }
+}
+
+#[test]
+fn rounded_cancellation_test() {
+    let mut rng = rand::rng();
+    for _ in 0..ROUNDING_ITERS {
+        let a = random_twofloat(-100, 100);
+        let b = -a * (1.0 + rng.random_range(-1e-20..1e-20));
+        check_arithmetic(a, b);
+    }
+}
+
+#[test]
+fn rounded_underflow_test() {
+    for _ in 0..ROUNDING_ITERS {
+        let a = random_twofloat(-1074, -900);
+        let b = random_twofloat(-100, 100);
+        check_arithmetic(a, b);
+        check_arithmetic(b, a);
+    }
+}
+
+#[test]
+fn rounded_sqrt_test() {
+    for _ in 0..ROUNDING_ITERS {
+        check_sqrt(random_twofloat(-1074, 1000).abs());
+        check_sqrt(random_twofloat(-10, 10).abs());
+    }
+
+    check_sqrt(TwoFloat::MAX);
+    check_sqrt(TwoFloat::from(f64::MIN_POSITIVE));
+    check_sqrt(TwoFloat::from(f64::from_bits(1)));
+}
+
+#[test]
+fn rounded_sqrt_boundary_test() {
+    // Subnormal arguments, where the low word carries no precision
+    for bits in [1u64, 2, 3, 0x7, 0xfffff, 0xf_ffff_ffff_ffff] {
+        check_sqrt(TwoFloat::from(f64::from_bits(bits)));
+    }
+    check_sqrt(TwoFloat::new_add(f64::MIN_POSITIVE, f64::from_bits(1)));
+
+    // Arguments close to the largest finite value
+    check_sqrt(TwoFloat::from(f64::MAX));
+    check_sqrt(TwoFloat::new_add(f64::MAX, -f64::MAX * f64::EPSILON / 4.0));
+    check_sqrt(TwoFloat::from(f64::from_bits(f64::MAX.to_bits() - 1)));
+
+    for _ in 0..ROUNDING_ITERS {
+        check_sqrt(random_twofloat(-1074, -1022).abs());
+        let a = random_twofloat(1020, 1024).abs();
+        if a.is_valid() {
+            check_sqrt(a);
+        }
+    }
+}
+
+#[test]
+fn rounded_special_test() {
+    let one = TwoFloat::from(1.0);
+    let zero = TwoFloat::from(0.0);
+
+    for &round in ROUNDINGS.iter() {
+        assert!(TwoFloat::NAN.mul_rounded(one, round).hi().is_nan());
+        assert!((-one).sqrt_rounded(round).hi().is_nan());
+        assert_eq!(zero.sqrt_rounded(round), 0.0);
+    }
+
+    for &round in ROUNDINGS.iter() {
+        let infinity = TwoFloat::INFINITY.sqrt_rounded(round);
+        assert_eq!(infinity, TwoFloat::INFINITY);
+        assert_eq!(one.div_rounded(zero, round), TwoFloat::INFINITY);
+        assert_eq!((-one).div_rounded(zero, round), TwoFloat::NEG_INFINITY);
+    }
+
+    assert_eq!(one.add_rounded(one, Round::Nearest), 2.0);
+    assert!(one.add_rounded(one, Round::Down) <= 2.0);
+    assert!(one.add_rounded(one, Round::Up) >= 2.0);
+
+    assert_eq!(
+        TwoFloat::MAX.add_rounded(TwoFloat::MAX, Round::Up),
+        TwoFloat::INFINITY
+    );
+    assert!(TwoFloat::MAX
+        .add_rounded(TwoFloat::MAX, Round::Down)
+        .is_valid());
+
+    let four = TwoFloat::from(4.0);
+    assert!(four.sqrt_rounded(Round::Down) <= 2.0);
+    assert!(four.sqrt_rounded(Round::Up) >= 2.0);
+}
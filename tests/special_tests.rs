@@ -0,0 +1,794 @@
+#![allow(clippy::approx_constant)]
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+use core::convert::TryFrom;
+use hexf::hexf64;
+use rand::Rng;
+use twofloat::TwoFloat;
+
+// Gamma, log-gamma and digamma from tiny arguments to the edge of overflow,
+// including negative arguments handled by the reflection formula
+const GAMMA_CASES: [(Pair, Pair, Pair, Pair); 16] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.5af1d78b58c40p+66"),
+            hexf64!("0x1.56c187bcab877p+12"),
+        ),
+        (
+            hexf64!("0x1.7069e2aa2aa5bp+5"),
+            hexf64!("-0x1.a7190e275d945p-51"),
+        ),
+        (
+            hexf64!("-0x1.5af1d78b58c40p+66"),
+            hexf64!("-0x1.56d40049a7f72p+12"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.999999999999ap-4"),
+            hexf64!("-0x1.999999999999ap-58"),
+        ),
+        (
+            hexf64!("0x1.306ea7b280d88p+3"),
+            hexf64!("-0x1.2db835e53d394p-51"),
+        ),
+        (
+            hexf64!("0x1.2058e35f3deeep+1"),
+            hexf64!("-0x1.bffd9b8485cd8p-56"),
+        ),
+        (
+            hexf64!("-0x1.4d8f668552b03p+3"),
+            hexf64!("0x1.e77368e05bbffp-51"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.c5bf891b4ef6bp+0"),
+            hexf64!("-0x1.618f13eb7ca89p-54"),
+        ),
+        (
+            hexf64!("0x1.250d048e7a1bdp-1"),
+            hexf64!("0x1.7abf2ad8d5088p-58"),
+        ),
+        (
+            hexf64!("-0x1.f6a897d3214fcp+0"),
+            hexf64!("0x1.40e69e9653bdbp-54"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.4000000000000p+0"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.d013fc47eeeeap-1"),
+            hexf64!("0x1.e381b62b7d025p-56"),
+        ),
+        (
+            hexf64!("-0x1.92857d38caf41p-4"),
+            hexf64!("-0x1.e0816975a994cp-58"),
+        ),
+        (
+            hexf64!("-0x1.d1d32879af85cp-3"),
+            hexf64!("-0x1.fd2167039b250p-57"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.c5bf891b4ef6bp-1"),
+            hexf64!("-0x1.618f13eb7ca89p-55"),
+        ),
+        (
+            hexf64!("-0x1.eeb95b094c191p-4"),
+            hexf64!("-0x1.346863f58b075p-58"),
+        ),
+        (
+            hexf64!("0x1.2aed059bd608ap-5"),
+            hexf64!("0x1.cd3d2ca77b63ap-63"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.4000000000000p+1"),
+            hexf64!("-0x1.0000000000000p-55"),
+        ),
+        (
+            hexf64!("0x1.544fa6d47b390p+0"),
+            hexf64!("-0x1.01a160da0e817p-55"),
+        ),
+        (
+            hexf64!("0x1.2383e809a67e7p-2"),
+            hexf64!("0x1.15bbf72d47899p-56"),
+        ),
+        (
+            hexf64!("0x1.680425af12b5ep-1"),
+            hexf64!("-0x1.4ccb06f79b14ep-56"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p+1"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x1.0000000000000p+1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.62e42fefa39efp-1"),
+            hexf64!("0x1.abc9e3b39803fp-56"),
+        ),
+        (
+            hexf64!("0x1.d8773039049e7p-1"),
+            hexf64!("0x1.6cb90701fbfabp-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.d000000000000p+2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.20d86288356b5p+10"),
+            hexf64!("-0x1.30bc9c0cddf37p-44"),
+        ),
+        (
+            hexf64!("0x1.c35701a50ff06p+2"),
+            hexf64!("-0x1.85af1ab8d6b92p-54"),
+        ),
+        (
+            hexf64!("0x1.e9137b7a7e563p+0"),
+            hexf64!("0x1.c23d18186ceb8p-54"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.4800000000000p+4"),
+            hexf64!("0x1.203af9ee75616p-50"),
+        ),
+        (
+            hexf64!("0x1.e02bbbd549cd4p+58"),
+            hexf64!("0x1.061ee5eff14a3p+2"),
+        ),
+        (
+            hexf64!("0x1.46a6e9fba19d9p+5"),
+            hexf64!("-0x1.fb61d59a4a0ddp-49"),
+        ),
+        (
+            hexf64!("0x1.7f779125b7cb4p+1"),
+            hexf64!("-0x1.cf9b6ee46631ep-54"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9000000000000p+5"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.7a88e4484be3bp+208"),
+            hexf64!("0x1.ae4bacbea71b0p+154"),
+        ),
+        (
+            hexf64!("0x1.2121a930c6ec3p+7"),
+            hexf64!("-0x1.4be6e03cc18b1p-47"),
+        ),
+        (
+            hexf64!("0x1.f37465ca59ec1p+1"),
+            hexf64!("0x1.59ee10341b037p-53"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9200000000000p+6"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.5b98374db8c0bp+521"),
+            hexf64!("0x1.0ab4d9bb81acbp+467"),
+        ),
+        (
+            hexf64!("0x1.696f7f9481308p+8"),
+            hexf64!("-0x1.c0b2dfee4ea66p-48"),
+        ),
+        (
+            hexf64!("0x1.26bb2d352de35p+2"),
+            hexf64!("-0x1.7ad71922b90d5p-53"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.5500000000000p+7"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9589f849167a8p+1015"),
+            hexf64!("-0x1.60b3dbca76556p+961"),
+        ),
+        (
+            hexf64!("0x1.6000911686cd6p+9"),
+            hexf64!("-0x1.a82e55e0e61c0p-47"),
+        ),
+        (
+            hexf64!("0x1.48b0f1f98e564p+2"),
+            hexf64!("0x1.e0f6c8a50e384p-53"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.c5bf891b4ef6bp+1"),
+            hexf64!("0x1.618f13eb7ca89p-53"),
+        ),
+        (
+            hexf64!("0x1.43f89a3f0edd6p+0"),
+            hexf64!("0x1.053cd734e6a31p-55"),
+        ),
+        (
+            hexf64!("0x1.2aed059bd608ap-5"),
+            hexf64!("0x1.cd3d2ca77b63ap-63"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.4000000000000p+1"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("-0x1.e3ff812e32183p-1"),
+            hexf64!("0x1.47f94b3cde7d2p-56"),
+        ),
+        (
+            hexf64!("-0x1.ccbf9f5ed0f15p-5"),
+            hexf64!("-0x1.967c9e6c76143p-59"),
+        ),
+        (
+            hexf64!("0x1.1a68793defc15p+0"),
+            hexf64!("0x1.ab5313205ad4fp-54"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.499999999999ap+3"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.1a855ea0eea1ap-21"),
+            hexf64!("0x1.de7edf9923a8cp-76"),
+        ),
+        (
+            hexf64!("-0x1.cea3f76b8a357p+3"),
+            hexf64!("-0x1.2a41f878184ffp-52"),
+        ),
+        (
+            hexf64!("0x1.2a64d19f6c3ccp+2"),
+            hexf64!("0x1.d4a822f029e73p-52"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.2c66666666666p+7"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.149c500d81f36p-872"),
+            hexf64!("-0x1.4bbfb06591c87p-927"),
+        ),
+        (
+            hexf64!("-0x1.2e2c677c9a637p+9"),
+            hexf64!("-0x1.2b2261a6a4e20p-45"),
+        ),
+        (
+            hexf64!("0x1.2adbbe9266f3dp+3"),
+            hexf64!("-0x1.9a99e03cf03c3p-54"),
+        ),
+    ),
+];
+
+// Reference values for the logarithm of the gamma function of large arguments
+const LN_GAMMA_CASES: [(Pair, Pair); 4] = [
+    (
+        (hexf64!("0x1.f400000000000p+9"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.711386da7cab6p+12"),
+            hexf64!("0x1.c69e3fae4f6b3p-42"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.2a05f20040000p+33"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9a43710fa2969p+37"),
+            hexf64!("-0x1.4c8c4b6ac136ep-17"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.249ad2594c37dp+332"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.060a2246099aep+340"),
+            hexf64!("0x1.48c13b52a403dp+286"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.7e43c8800759cp+996"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.017f38e7a1ab5p+1006"),
+            hexf64!("-0x1.3d874269f16b8p+947"),
+        ),
+    ),
+];
+
+// Reference values for tiny negative arguments, where the product in the
+// reflection formula underflows, listing the input followed by its gamma
+// function and the logarithm of the absolute value of the gamma function
+const SMALL_NEGATIVE_GAMMA_CASES: [(Pair, Pair, Pair); 2] = [
+    (
+        (hexf64!("-0x1.1297872d9cbb5p-515"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.dd55745cbb7edp+514"),
+            hexf64!("0x1.71876b56d137ap+459"),
+        ),
+        (
+            hexf64!("0x1.64e69394d9508p+8"),
+            hexf64!("0x1.8cef1adecd9e9p-49"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.67e9c127b6e74p-532"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.6c2d4256ffcc3p+531"),
+            hexf64!("-0x1.fb823cdf79d1ep+473"),
+        ),
+        (
+            hexf64!("0x1.7069e2aa2aa5bp+8"),
+            hexf64!("-0x1.c5e488fe69c88p-48"),
+        ),
+    ),
+];
+
+// Reference values for small negative arguments, where the terms of the
+// reflection formula cancel, listing the input followed by its digamma
+// function
+const SMALL_NEGATIVE_DIGAMMA_CASES: [(Pair, Pair); 3] = [
+    (
+        (hexf64!("-0x1.56e1fc2f8f359p-997"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.7e43c8800759bp+996"),
+            hexf64!("0x1.e9dfd69be7022p+942"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.5af1d78b58c40p+66"),
+            hexf64!("0x1.56c187bcab877p+12"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.999999999999ap-3"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.023d4cb568c98p+2"),
+            hexf64!("0x1.0969d1e29a77bp-52"),
+        ),
+    ),
+];
+
+// Error function, its complement and the scaled complement on both sides of
+// zero, out to 20 where erfc falls below 1e-175
+const ERF_CASES: [(Pair, Pair, Pair, Pair); 11] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.aa4a230244ae0p-67"),
+            hexf64!("-0x1.cd2718f1a1f9fp-124"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.aa4a230244ae0p-67"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.aa4a230244ae0p-67"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.999999999999ap-4"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.cca5ea24fb334p-4"),
+            hexf64!("-0x1.112c8facdc19cp-60"),
+        ),
+        (
+            hexf64!("0x1.1cca5ea24fb33p+0"),
+            hexf64!("0x1.0444b23eb3706p-54"),
+        ),
+        (
+            hexf64!("0x1.1fa71742a741ep+0"),
+            hexf64!("0x1.1a7f913522d4cp-56"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p-2"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.1af54e232d609p-2"),
+            hexf64!("-0x1.adf3509a11f47p-56"),
+        ),
+        (
+            hexf64!("0x1.728558ee694fcp-1"),
+            hexf64!("-0x1.290657b2f705cp-55"),
+        ),
+        (
+            hexf64!("0x1.8a6adcda2ea92p-1"),
+            hexf64!("-0x1.cbae40b0518d8p-57"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0a7ef5c18edd2p-1"),
+            hexf64!("0x1.5e809f1a31a28p-56"),
+        ),
+        (
+            hexf64!("0x1.eb02147ce245cp-2"),
+            hexf64!("-0x1.5e809f1a31a28p-56"),
+        ),
+        (
+            hexf64!("0x1.3b3bc3c98b0f3p-1"),
+            hexf64!("-0x1.aa856b121880fp-56"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.8000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.6c1c9759d0e5fp-1"),
+            hexf64!("-0x1.b1432f2cbc455p-55"),
+        ),
+        (
+            hexf64!("0x1.b60e4bace8730p+0"),
+            hexf64!("-0x1.275e6869a1dd5p-54"),
+        ),
+        (
+            hexf64!("0x1.8067edd7c6e39p+1"),
+            hexf64!("0x1.8bd2f67b02ea2p-53"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.af767a741088bp-1"),
+            hexf64!("-0x1.c97f778122797p-56"),
+        ),
+        (
+            hexf64!("0x1.4226162fbddd5p-3"),
+            hexf64!("-0x1.b40443f6ec34ap-59"),
+        ),
+        (
+            hexf64!("0x1.b5d8780f956b2p-2"),
+            hexf64!("0x1.825447f231a67p-58"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.4000000000000p+1"),
+            hexf64!("0x1.70ef54646d497p-57"),
+        ),
+        (
+            hexf64!("0x1.ffcaa8f4c9beap-1"),
+            hexf64!("0x1.b102504ba6758p-55"),
+        ),
+        (
+            hexf64!("0x1.aab859b20ac9ep-12"),
+            hexf64!("-0x1.2825d33ac1683p-70"),
+        ),
+        (
+            hexf64!("0x1.afbb3f3b7343bp-3"),
+            hexf64!("-0x1.d61c8db750ec7p-58"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.0000000000000p+2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.ffffff7b91176p-1"),
+            hexf64!("-0x1.0b2865615db40p-56"),
+        ),
+        (
+            hexf64!("0x1.ffffffbdc88bbp+0"),
+            hexf64!("0x1.0b2865615db40p-56"),
+        ),
+        (
+            hexf64!("0x1.0f2ebce76d9c8p+24"),
+            hexf64!("0x1.a018523af1d59p-30"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p+2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.8cf81557d20b6p-56"),
+        ),
+        (
+            hexf64!("0x1.8cf81557d20b6p-56"),
+            hexf64!("0x1.a7fff0cc732c0p-112"),
+        ),
+        (
+            hexf64!("0x1.7c0348489d721p-4"),
+            hexf64!("0x1.ca9cebb9a1c44p-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+3"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.7d8a7f2a8a2d0p-149"),
+        ),
+        (
+            hexf64!("0x1.7d8a7f2a8a2d0p-149"),
+            hexf64!("-0x1.8b231dcfa8e27p-203"),
+        ),
+        (
+            hexf64!("0x1.cbe831f997124p-5"),
+            hexf64!("-0x1.ed815e254e295p-60"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4000000000000p+4"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.b54f000000000p-583"),
+        ),
+        (
+            hexf64!("0x1.b54f244df93dfp-583"),
+            hexf64!("-0x1.6cb7adc235f07p-637"),
+        ),
+        (
+            hexf64!("0x1.cd9bc89b73548p-6"),
+            hexf64!("-0x1.c826601a2f019p-65"),
+        ),
+    ),
+];
+
+// Reference values for the scaled complementary error function of large
+// arguments
+const ERFC_SCALED_CASES: [(Pair, Pair); 5] = [
+    (
+        (hexf64!("0x1.e000000000000p+4"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.33f3abfd60d6fp-6"),
+            hexf64!("0x1.060d74c72796bp-60"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.86a0000000000p+16"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.7a9f084b432a7p-18"),
+            hexf64!("-0x1.ec0de390c8391p-72"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.2a05f20000000p+33"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.f044332d68161p-35"),
+            hexf64!("-0x1.10d08a6cb5cb0p-91"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4e718d7d7625ap+664"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.ba394ce53f796p-666"),
+            hexf64!("0x1.fc0f93f608dc5p-720"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.4000000000000p+4"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0e398d7d01704p+578"),
+            hexf64!("-0x1.1b8649000ffedp+524"),
+        ),
+    ),
+];
+
+#[test]
+fn gamma_reference_test() {
+    for &(source, gamma, ln_gamma, digamma) in GAMMA_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(
+            x.gamma(),
+            gamma,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("gamma({:?})", x),
+        );
+        assert_relative(
+            x.ln_gamma().0,
+            ln_gamma,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("ln_gamma({:?})", x),
+        );
+        assert_relative(
+            x.digamma(),
+            digamma,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("digamma({:?})", x),
+        );
+
+        let sign = if gamma.0 > 0.0 { 1 } else { -1 };
+        assert_eq!(x.ln_gamma().1, sign, "Incorrect sign of ln_gamma({:?})", x);
+    }
+
+    assert_cases(
+        &LN_GAMMA_CASES,
+        |x| x.ln_gamma().0,
+        SPECIAL_RELATIVE_ERROR,
+        "ln_gamma",
+    );
+
+    for &(source, gamma, ln_gamma) in SMALL_NEGATIVE_GAMMA_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(
+            x.gamma(),
+            gamma,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("gamma({:?})", x),
+        );
+        assert_relative(
+            x.ln_gamma().0,
+            ln_gamma,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("ln_gamma({:?})", x),
+        );
+        assert_eq!(x.ln_gamma().1, -1, "Incorrect sign of ln_gamma({:?})", x);
+    }
+
+    assert_cases(
+        &SMALL_NEGATIVE_DIGAMMA_CASES,
+        TwoFloat::digamma,
+        SPECIAL_RELATIVE_ERROR,
+        "digamma",
+    );
+}
+
+#[test]
+fn erf_reference_test() {
+    for &(source, erf, erfc, erfc_scaled) in ERF_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(
+            x.erf(),
+            erf,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("erf({:?})", x),
+        );
+        assert_relative(
+            x.erfc(),
+            erfc,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("erfc({:?})", x),
+        );
+        assert_relative(
+            x.erfc_scaled(),
+            erfc_scaled,
+            SPECIAL_RELATIVE_ERROR,
+            &format!("erfc_scaled({:?})", x),
+        );
+    }
+
+    assert_cases(
+        &ERFC_SCALED_CASES,
+        TwoFloat::erfc_scaled,
+        SPECIAL_RELATIVE_ERROR,
+        "erfc_scaled",
+    );
+}
+
+#[test]
+fn gamma_special_test() {
+    for n in 1..20 {
+        let expected = (1..n).fold(TwoFloat::from(1.0), |acc, k| acc * k as f64);
+        let x = TwoFloat::from(n as f64);
+        assert_eq!(x.gamma(), expected, "gamma({}) is not exact", n);
+    }
+
+    assert_eq!(TwoFloat::from(1.0).ln_gamma(), (TwoFloat::from(0.0), 1));
+    assert_eq!(TwoFloat::from(2.0).ln_gamma(), (TwoFloat::from(0.0), 1));
+
+    assert_eq!(TwoFloat::from(0.0).gamma(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-0.0).gamma(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::from(172.0).gamma(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::INFINITY.gamma(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-200.5).gamma(), 0.0);
+    assert!(!TwoFloat::from(-3.0).gamma().is_valid());
+    assert!(!TwoFloat::NEG_INFINITY.gamma().is_valid());
+
+    assert_eq!(TwoFloat::from(0.0).ln_gamma(), (TwoFloat::INFINITY, 1));
+    assert_eq!(TwoFloat::from(-0.0).ln_gamma(), (TwoFloat::INFINITY, -1));
+    assert_eq!(TwoFloat::from(-4.0).ln_gamma().0, TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.ln_gamma().0, TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-1.5).ln_gamma().1, 1);
+    assert_eq!(TwoFloat::from(-2.5).ln_gamma().1, -1);
+
+    assert_eq!(TwoFloat::from(0.0).digamma(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::from(-0.0).digamma(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::INFINITY.digamma(), TwoFloat::INFINITY);
+    assert!(!TwoFloat::from(-2.0).digamma().is_valid());
+
+    for f in [TwoFloat::gamma, TwoFloat::digamma].iter() {
+        assert!(f(TwoFloat::NAN).hi().is_nan());
+    }
+    assert!(TwoFloat::NAN.ln_gamma().0.hi().is_nan());
+}
+
+#[test]
+fn erf_special_test() {
+    assert_eq!(TwoFloat::from(0.0).erf(), 0.0);
+    let negative_zero = TwoFloat::from(-0.0).erf();
+    assert!(negative_zero.hi() == 0.0 && negative_zero.hi().is_sign_negative());
+    assert!(negative_zero.lo().is_sign_positive());
+    assert_eq!(TwoFloat::from(0.0).erfc(), 1.0);
+    assert_eq!(TwoFloat::from(0.0).erfc_scaled(), 1.0);
+
+    assert_eq!(TwoFloat::INFINITY.erf(), 1.0);
+    assert_eq!(TwoFloat::NEG_INFINITY.erf(), -1.0);
+    assert_eq!(TwoFloat::INFINITY.erfc(), 0.0);
+    assert_eq!(TwoFloat::NEG_INFINITY.erfc(), 2.0);
+    assert_eq!(TwoFloat::from(30.0).erfc(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.erfc_scaled(), 0.0);
+    assert_eq!(TwoFloat::from(-30.0).erfc_scaled(), TwoFloat::INFINITY);
+
+    for f in [TwoFloat::erf, TwoFloat::erfc, TwoFloat::erfc_scaled].iter() {
+        assert!(f(TwoFloat::NAN).hi().is_nan());
+    }
+}
+
+#[test]
+fn gamma_recurrence_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(-50.0, 50.0).unwrap();
+    repeated_test(|| {
+        let source = TwoFloat::from(rng.sample(dist));
+        let gamma = source.gamma();
+        let next = (source + 1.0).gamma();
+        assert!(
+            ((next - gamma * source) / next).abs() < 1e-28,
+            "gamma({:?}) does not satisfy the recurrence relation",
+            source
+        );
+
+        let (ln_gamma, sign) = source.ln_gamma();
+        let expected = gamma.abs().ln();
+        assert!(
+            (ln_gamma - expected).abs() < 1e-28 * expected.abs().max(TwoFloat::from(1.0)),
+            "ln_gamma({:?}) does not match logarithm of gamma",
+            source
+        );
+        assert_eq!(
+            sign,
+            if gamma > 0.0 { 1 } else { -1 },
+            "Incorrect sign of ln_gamma({:?})",
+            source
+        );
+
+        if source.abs() > 1e-10 {
+            let digamma = source.digamma();
+            let next = (source + 1.0).digamma();
+            assert!(
+                (next - digamma - source.recip()).abs()
+                    < 1e-26 * next.abs().max(source.recip().abs()),
+                "digamma({:?}) does not satisfy the recurrence relation",
+                source
+            );
+        }
+    });
+}
+
+#[test]
+fn erf_erfc_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new(-6.0, 6.0).unwrap();
+    repeated_test(|| {
+        let source = loop {
+            let a = rng.sample(dist);
+            let b = random_float();
+            if let Ok(result) = TwoFloat::try_from((a, b)) {
+                break result;
+            }
+        };
+
+        let erf = source.erf();
+        let erfc = source.erfc();
+        assert!(
+            (erf + erfc - 1.0).abs() < 1e-30,
+            "erf({:?}) + erfc({:?}) is not equal to 1",
+            source,
+            source
+        );
+        assert_eq!((-source).erf(), -erf, "erf({:?}) is not odd", source);
+
+        let scaled = source.erfc_scaled();
+        let expected = erfc * (source * source).exp();
+        assert!(
+            ((scaled - expected) / scaled).abs() < 1e-28,
+            "erfc_scaled({:?}) does not match scaled erfc",
+            source
+        );
+    });
+}
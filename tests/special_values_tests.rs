@@ -0,0 +1,519 @@
+#![allow(clippy::float_cmp)]
+
+use core::cmp::Ordering;
+
+use num_traits::Float;
+use twofloat::{consts, QuadFloat, TwoF32, TwoFloat};
+
+const NAN: f64 = f64::NAN;
+const INF: f64 = f64::INFINITY;
+
+// Special values, together with finite values for which some functions give
+// special results
+const INPUTS: [f64; 13] = [
+    NAN, INF, -INF, 0.0, -0.0, 1.0, -1.0, 2.0, -2.0, 0.5, -0.5, 1e300, -1e300,
+];
+
+type Function = fn(TwoFloat) -> TwoFloat;
+type Reference = fn(f64) -> f64;
+type BinaryReference = fn(f64, f64) -> f64;
+
+// Relative difference allowed between finite results and the `f64` reference
+const TOLERANCE: f64 = 1e-14;
+
+// Checks that the result follows the `f64` result, where special values
+// must be equal in their canonical representation, including the sign of
+// zero
+fn assert_follows(result: TwoFloat, expected: f64, description: &str) {
+    let correct = if expected.is_nan() {
+        result.hi().is_nan() && result.lo().is_nan()
+    } else if expected == 0.0 || expected.is_infinite() {
+        result.hi().to_bits() == expected.to_bits() && result.lo() == 0.0
+    } else {
+        result.is_valid() && (result.hi() - expected).abs() <= expected.abs() * TOLERANCE
+    };
+
+    assert!(
+        correct,
+        "{} gave {:?} instead of {:?}",
+        description, result, expected
+    );
+}
+
+macro_rules! unary_table {
+    ($($name:ident),* $(,)?) => {
+        [$((stringify!($name), TwoFloat::$name as fn(TwoFloat) -> TwoFloat, f64::$name as fn(f64) -> f64)),*]
+    };
+}
+
+macro_rules! binary_table {
+    ($($name:ident),* $(,)?) => {
+        [$((
+            stringify!($name),
+            TwoFloat::$name as fn(TwoFloat, TwoFloat) -> TwoFloat,
+            f64::$name as fn(f64, f64) -> f64,
+        )),*]
+    };
+}
+
+#[test]
+fn arithmetic_special_test() {
+    let ops: [(&str, BinaryReference); 5] = [
+        ("+", |a, b| a + b),
+        ("-", |a, b| a - b),
+        ("*", |a, b| a * b),
+        ("/", |a, b| a / b),
+        ("%", |a, b| a % b),
+    ];
+
+    for &a in INPUTS.iter() {
+        for &b in INPUTS.iter() {
+            let (x, y) = (TwoFloat::from(a), TwoFloat::from(b));
+            let results = [
+                [
+                    x + y,
+                    x + b,
+                    a + y,
+                    {
+                        let mut z = x;
+                        z += y;
+                        z
+                    },
+                    {
+                        let mut z = x;
+                        z += b;
+                        z
+                    },
+                ],
+                [
+                    x - y,
+                    x - b,
+                    a - y,
+                    {
+                        let mut z = x;
+                        z -= y;
+                        z
+                    },
+                    {
+                        let mut z = x;
+                        z -= b;
+                        z
+                    },
+                ],
+                [
+                    x * y,
+                    x * b,
+                    a * y,
+                    {
+                        let mut z = x;
+                        z *= y;
+                        z
+                    },
+                    {
+                        let mut z = x;
+                        z *= b;
+                        z
+                    },
+                ],
+                [
+                    x / y,
+                    x / b,
+                    a / y,
+                    {
+                        let mut z = x;
+                        z /= y;
+                        z
+                    },
+                    {
+                        let mut z = x;
+                        z /= b;
+                        z
+                    },
+                ],
+                [
+                    x % y,
+                    x % b,
+                    a % y,
+                    {
+                        let mut z = x;
+                        z %= y;
+                        z
+                    },
+                    {
+                        let mut z = x;
+                        z %= b;
+                        z
+                    },
+                ],
+            ];
+
+            for ((symbol, op), values) in ops.iter().zip(results.iter()) {
+                for result in values.iter() {
+                    assert_follows(*result, op(a, b), &format!("{:?} {} {:?}", a, symbol, b));
+                }
+            }
+        }
+
+        let x = TwoFloat::from(a);
+        assert_follows(-x, -a, &format!("-{:?}", a));
+    }
+}
+
+#[test]
+fn constructor_special_test() {
+    for &a in INPUTS.iter() {
+        for &b in INPUTS.iter() {
+            let description = format!("({:?}, {:?})", a, b);
+            assert_follows(TwoFloat::new_add(a, b), a + b, &description);
+            assert_follows(TwoFloat::new_sub(a, b), a - b, &description);
+            assert_follows(TwoFloat::new_mul(a, b), a * b, &description);
+            assert_follows(TwoFloat::new_div(a, b), a / b, &description);
+        }
+    }
+}
+
+#[test]
+fn mul_add_special_test() {
+    for &a in INPUTS.iter() {
+        for &b in INPUTS.iter() {
+            for &c in INPUTS.iter() {
+                let (x, y, z) = (TwoFloat::from(a), TwoFloat::from(b), TwoFloat::from(c));
+                assert_follows(
+                    x.mul_add(y, z),
+                    a.mul_add(b, c),
+                    &format!("{:?}.mul_add({:?}, {:?})", a, b, c),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn unary_special_test() {
+    let table = unary_table![
+        sqrt, cbrt, exp, exp_m1, exp2, ln, ln_1p, log2, log10, sin, cos, tan, asin, acos, atan,
+        sinh, cosh, tanh, asinh, acosh, atanh, trunc, floor, ceil, round, fract, recip, to_degrees,
+        to_radians,
+    ];
+
+    for &(name, function, reference) in table.iter() {
+        for &a in INPUTS.iter() {
+            assert_follows(
+                function(TwoFloat::from(a)),
+                reference(a),
+                &format!("{}({:?})", name, a),
+            );
+        }
+    }
+
+    for &a in INPUTS.iter() {
+        let x = TwoFloat::from(a);
+        assert_follows(x.abs(), a.abs(), &format!("abs({:?})", a));
+        assert_follows(x.signum(), a.signum(), &format!("signum({:?})", a));
+        assert_follows(x.sin_cos().0, a.sin(), &format!("sin_cos({:?})", a));
+        assert_follows(x.sin_cos().1, a.cos(), &format!("sin_cos({:?})", a));
+        for n in -3..=3 {
+            assert_follows(x.powi(n), a.powi(n), &format!("powi({:?}, {})", a, n));
+        }
+    }
+}
+
+#[test]
+fn binary_special_test() {
+    let table = binary_table![atan2, hypot, powf, log, min, max, div_euclid, rem_euclid];
+
+    for &(name, function, reference) in table.iter() {
+        for &a in INPUTS.iter() {
+            for &b in INPUTS.iter() {
+                assert_follows(
+                    function(TwoFloat::from(a), TwoFloat::from(b)),
+                    reference(a, b),
+                    &format!("{}({:?}, {:?})", name, a, b),
+                );
+            }
+        }
+    }
+
+    for &a in INPUTS.iter() {
+        for &b in INPUTS.iter() {
+            let (x, y) = (TwoFloat::from(a), TwoFloat::from(b));
+            let atan2 = a.atan2(b);
+            let description = format!("({:?}, {:?})", a, b);
+            assert_follows(TwoFloat::copysign(&x, &y), a.copysign(b), &description);
+            assert_follows(x.atan2_pi(y), atan2 / core::f64::consts::PI, &description);
+            assert_follows(x.atan2d(y), atan2.to_degrees(), &description);
+        }
+    }
+}
+
+// Functions without an `f64` equivalent, with their results for NaN, positive
+// and negative infinity, positive and negative zero
+#[test]
+fn function_special_test() {
+    let pi = consts::PI.hi();
+    let half_pi = consts::FRAC_PI_2.hi();
+    let table: [(&str, Function, [f64; 5]); 33] = [
+        ("gamma", TwoFloat::gamma, [NAN, INF, NAN, INF, -INF]),
+        ("ln_gamma", |x| x.ln_gamma().0, [NAN, INF, INF, INF, INF]),
+        ("digamma", TwoFloat::digamma, [NAN, INF, NAN, -INF, INF]),
+        ("erf", TwoFloat::erf, [NAN, 1.0, -1.0, 0.0, -0.0]),
+        ("erfc", TwoFloat::erfc, [NAN, 0.0, 2.0, 1.0, 1.0]),
+        (
+            "erfc_scaled",
+            TwoFloat::erfc_scaled,
+            [NAN, 0.0, INF, 1.0, 1.0],
+        ),
+        ("j0", TwoFloat::j0, [NAN, 0.0, 0.0, 1.0, 1.0]),
+        ("j1", TwoFloat::j1, [NAN, 0.0, -0.0, 0.0, -0.0]),
+        ("jn(2)", |x| x.jn(2), [NAN, 0.0, 0.0, 0.0, 0.0]),
+        ("jn(3)", |x| x.jn(3), [NAN, 0.0, -0.0, 0.0, -0.0]),
+        ("y0", TwoFloat::y0, [NAN, 0.0, NAN, -INF, -INF]),
+        ("y1", TwoFloat::y1, [NAN, 0.0, NAN, -INF, -INF]),
+        ("yn(2)", |x| x.yn(2), [NAN, 0.0, NAN, -INF, -INF]),
+        ("yn(3)", |x| x.yn(3), [NAN, 0.0, NAN, -INF, -INF]),
+        ("i0", TwoFloat::i0, [NAN, INF, INF, 1.0, 1.0]),
+        ("i1", TwoFloat::i1, [NAN, INF, -INF, 0.0, -0.0]),
+        ("k0", TwoFloat::k0, [NAN, 0.0, NAN, INF, INF]),
+        ("k1", TwoFloat::k1, [NAN, 0.0, NAN, INF, INF]),
+        (
+            "lambert_w0",
+            TwoFloat::lambert_w0,
+            [NAN, INF, NAN, 0.0, -0.0],
+        ),
+        (
+            "lambert_wm1",
+            TwoFloat::lambert_wm1,
+            [NAN, NAN, NAN, -INF, -INF],
+        ),
+        ("zeta", TwoFloat::zeta, [NAN, 1.0, NAN, -0.5, -0.5]),
+        (
+            "ellint_k",
+            TwoFloat::ellint_k,
+            [NAN, NAN, NAN, half_pi, half_pi],
+        ),
+        (
+            "ellint_e",
+            TwoFloat::ellint_e,
+            [NAN, NAN, NAN, half_pi, half_pi],
+        ),
+        ("sin_pi", TwoFloat::sin_pi, [NAN, NAN, NAN, 0.0, -0.0]),
+        ("cos_pi", TwoFloat::cos_pi, [NAN, NAN, NAN, 1.0, 1.0]),
+        ("tan_pi", TwoFloat::tan_pi, [NAN, NAN, NAN, 0.0, -0.0]),
+        ("asin_pi", TwoFloat::asin_pi, [NAN, NAN, NAN, 0.0, -0.0]),
+        ("sind", TwoFloat::sind, [NAN, NAN, NAN, 0.0, -0.0]),
+        ("cosd", TwoFloat::cosd, [NAN, NAN, NAN, 1.0, 1.0]),
+        ("tand", TwoFloat::tand, [NAN, NAN, NAN, 0.0, -0.0]),
+        ("asind", TwoFloat::asind, [NAN, NAN, NAN, 0.0, -0.0]),
+        ("sincos_pi", |x| x.sincos_pi().0, [NAN, NAN, NAN, 0.0, -0.0]),
+        ("sincosd", |x| x.sincosd().1, [NAN, NAN, NAN, 1.0, 1.0]),
+    ];
+
+    for &(name, function, expected) in table.iter() {
+        for (&a, &value) in INPUTS[..5].iter().zip(expected.iter()) {
+            assert_follows(
+                function(TwoFloat::from(a)),
+                value,
+                &format!("{}({:?})", name, a),
+            );
+        }
+    }
+
+    assert_follows(TwoFloat::from(1.0).ellint_k(), INF, "ellint_k(1)");
+    assert_follows(TwoFloat::from(2.0).ellint_k(), NAN, "ellint_k(2)");
+    assert_follows(TwoFloat::from(1.0).zeta(), NAN, "zeta(1)");
+    assert_follows(TwoFloat::from(-1.0).gamma(), NAN, "gamma(-1)");
+    assert_follows(TwoFloat::from(-1.0).ln_gamma().0, INF, "ln_gamma(-1)");
+    assert_follows(
+        TwoFloat::from(0.0).atan2_pi(TwoFloat::from(-1.0)),
+        1.0,
+        "atan2_pi",
+    );
+    assert_follows(TwoFloat::from(INF).atan(), half_pi, "atan(inf)");
+    assert_follows(
+        TwoFloat::from(-INF).atan2(TwoFloat::from(-INF)),
+        -0.75 * pi,
+        "atan2",
+    );
+}
+
+// Functions with an equivalent in `libm`, which is followed for finite
+// arguments where the result may be special
+#[test]
+fn libm_special_test() {
+    let table: [(&str, Function, Reference); 8] = [
+        ("gamma", TwoFloat::gamma, libm::tgamma),
+        ("ln_gamma", |x| x.ln_gamma().0, libm::lgamma),
+        ("erf", TwoFloat::erf, libm::erf),
+        ("erfc", TwoFloat::erfc, libm::erfc),
+        ("j0", TwoFloat::j0, libm::j0),
+        ("j1", TwoFloat::j1, libm::j1),
+        ("y0", TwoFloat::y0, libm::y0),
+        ("y1", TwoFloat::y1, libm::y1),
+    ];
+
+    for &(name, function, reference) in table.iter() {
+        for &a in INPUTS[5..].iter() {
+            assert_follows(
+                function(TwoFloat::from(a)),
+                reference(a),
+                &format!("{}({:?})", name, a),
+            );
+        }
+    }
+
+    for &a in INPUTS[5..].iter() {
+        let x = TwoFloat::from(a);
+        assert_follows(x.jn(3), libm::jn(3, a), &format!("jn(3, {:?})", a));
+        assert_follows(x.yn(3), libm::yn(3, a), &format!("yn(3, {:?})", a));
+    }
+}
+
+#[test]
+fn multiple_argument_special_test() {
+    let one = TwoFloat::from(1.0);
+    let half = TwoFloat::from(0.5);
+    let nan = TwoFloat::NAN;
+    let inf = TwoFloat::INFINITY;
+
+    assert_follows(nan.polylog(half), NAN, "polylog(NaN, 0.5)");
+    assert_follows(one.polylog(nan), NAN, "polylog(1, NaN)");
+    assert_follows(
+        TwoFloat::from(2.0).polylog(TwoFloat::from(0.0)),
+        0.0,
+        "polylog(2, 0)",
+    );
+    assert_follows(
+        TwoFloat::from(2.0).polylog(TwoFloat::from(-0.0)),
+        -0.0,
+        "polylog(2, -0)",
+    );
+
+    assert_follows(one.carlson_rf(one, nan), NAN, "carlson_rf");
+    assert_follows(one.carlson_rf(one, inf), 0.0, "carlson_rf");
+    assert_follows(one.carlson_rc(nan), NAN, "carlson_rc");
+    assert_follows(one.carlson_rc(inf), 0.0, "carlson_rc");
+    assert_follows(one.carlson_rd(one, nan), NAN, "carlson_rd");
+    assert_follows(one.carlson_rd(one, inf), 0.0, "carlson_rd");
+    assert_follows(one.carlson_rj(one, one, nan), NAN, "carlson_rj");
+    assert_follows(one.carlson_rj(one, one, inf), 0.0, "carlson_rj");
+
+    assert_follows(half.ellint_k_incomplete(nan), NAN, "ellint_k_incomplete");
+    assert_follows(nan.ellint_e_incomplete(one), NAN, "ellint_e_incomplete");
+    assert_follows(
+        half.ellint_k_incomplete(TwoFloat::from(-0.0)),
+        -0.0,
+        "ellint_k_incomplete",
+    );
+    assert_follows(
+        half.ellint_e_incomplete(TwoFloat::from(-0.0)),
+        -0.0,
+        "ellint_e_incomplete",
+    );
+
+    assert_follows(nan.jacobi_sn(half), NAN, "jacobi_sn");
+    assert_follows(inf.jacobi_cn(half), NAN, "jacobi_cn");
+    assert_follows(one.jacobi_dn(nan), NAN, "jacobi_dn");
+    assert_follows(TwoFloat::from(-0.0).jacobi_sn(half), -0.0, "jacobi_sn");
+}
+
+#[test]
+fn directed_special_test() {
+    let one = TwoFloat::from(1.0);
+    let inf = TwoFloat::INFINITY;
+
+    assert_follows(inf.add_down(one), INF, "add_down");
+    assert_follows(inf.sub_up(inf), NAN, "sub_up");
+    assert_follows(one.div_down(TwoFloat::from(-0.0)), -INF, "div_down");
+    assert_follows(TwoFloat::NAN.mul_up(one), NAN, "mul_up");
+    assert_follows(TwoFloat::MAX.mul_up(TwoFloat::MAX), INF, "mul_up");
+    assert_follows(inf.sqrt_down(), INF, "sqrt_down");
+    assert_follows(TwoFloat::from(-0.0).sqrt_up(), -0.0, "sqrt_up");
+}
+
+#[test]
+fn representation_test() {
+    assert_eq!(TwoFloat::INFINITY.lo(), 0.0);
+    assert_eq!(TwoFloat::NEG_INFINITY.lo(), 0.0);
+    assert!(TwoFloat::NAN.hi().is_nan() && TwoFloat::NAN.lo().is_nan());
+
+    assert_follows(TwoFloat::from(INF), INF, "from(inf)");
+    assert_follows(TwoFloat::from(-0.0), -0.0, "from(-0)");
+    assert_follows(TwoFloat::from(NAN), NAN, "from(NaN)");
+    assert_follows(TwoFloat::from(f32::NEG_INFINITY), -INF, "from(-inf)");
+    assert_follows(TwoFloat::from_f64(NAN), NAN, "from_f64(NaN)");
+    assert_follows(TwoFloat::from(TwoF32::INFINITY), INF, "from(TwoF32 inf)");
+    assert_follows(
+        TwoFloat::from(QuadFloat::NEG_INFINITY),
+        -INF,
+        "from(QuadFloat -inf)",
+    );
+    assert_follows(TwoFloat::from(TwoF32::NAN), NAN, "from(TwoF32 NaN)");
+    assert_follows(TwoFloat::from(QuadFloat::NAN), NAN, "from(QuadFloat NaN)");
+
+    // Functions which overflow give the same representation as the constants
+    assert_eq!(TwoFloat::from(1000.0).exp(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(2000.0).exp2(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::MAX * 2.0, TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::new_mul(1e300, 1e300), TwoFloat::INFINITY);
+    assert_eq!(f64::from(TwoFloat::NEG_INFINITY), -INF);
+
+    for value in [TwoFloat::INFINITY, TwoFloat::from(1000.0).exp()].iter() {
+        assert!(value.is_infinite());
+        assert!(!value.is_finite() && !value.is_valid() && !value.is_nan());
+    }
+    assert!(TwoFloat::NAN.is_nan() && !TwoFloat::NAN.is_infinite());
+}
+
+#[test]
+fn comparison_special_test() {
+    let values = [
+        TwoFloat::NEG_INFINITY,
+        TwoFloat::MIN,
+        TwoFloat::from(-1.0),
+        TwoFloat::from(-0.0),
+        TwoFloat::from(1.0),
+        TwoFloat::MAX,
+        TwoFloat::INFINITY,
+    ];
+
+    for (i, a) in values.iter().enumerate() {
+        for (j, b) in values.iter().enumerate() {
+            assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)), "{:?} <=> {:?}", a, b);
+            assert_eq!(a == b, i == j, "{:?} == {:?}", a, b);
+        }
+
+        assert_eq!(a.partial_cmp(&TwoFloat::NAN), None);
+        assert_eq!(TwoFloat::NAN.partial_cmp(a), None);
+        assert!(*a != TwoFloat::NAN);
+    }
+
+    assert_eq!(TwoFloat::from(0.0), TwoFloat::from(-0.0));
+    assert_eq!(TwoFloat::INFINITY, INF);
+    assert_eq!(-INF, TwoFloat::NEG_INFINITY);
+    assert!(TwoFloat::INFINITY > f64::MAX);
+    assert_eq!(
+        TwoFloat::NEG_INFINITY.partial_cmp(&f64::MIN),
+        Some(Ordering::Less)
+    );
+    assert!(TwoFloat::NAN != TwoFloat::NAN);
+
+    assert_eq!(TwoFloat::INFINITY.min(TwoFloat::from(1.0)), 1.0);
+    assert_eq!(
+        TwoFloat::NAN.max(TwoFloat::NEG_INFINITY),
+        TwoFloat::NEG_INFINITY
+    );
+}
+
+#[test]
+fn format_special_test() {
+    assert_eq!(format!("{}", TwoFloat::INFINITY), format!("{}", INF));
+    assert_eq!(format!("{}", TwoFloat::NEG_INFINITY), format!("{}", -INF));
+    assert_eq!(format!("{}", TwoFloat::NAN), format!("{}", NAN));
+    assert_eq!(format!("{}", TwoFloat::from(-0.0)), format!("{}", -0.0));
+    assert_eq!(format!("{:e}", TwoFloat::INFINITY), format!("{:e}", INF));
+
+    let parsed: TwoFloat = "inf".parse().unwrap();
+    assert_follows(parsed, INF, "parse(inf)");
+    let parsed: TwoFloat = "-0".parse().unwrap();
+    assert_follows(parsed, -0.0, "parse(-0)");
+}
@@ -1,11 +1,578 @@
+#![allow(clippy::approx_constant)]
+
 #[macro_use]
 pub mod common;
 
 use common::*;
 use core::convert::TryFrom;
+use hexf::hexf64;
 use rand::Rng;
 use twofloat::TwoFloat;
 
+// Sine, cosine and tangent next to π/2 and at large arguments up to the
+// largest finite value, which need the full Payne-Hanek reduction
+const LARGE_CASES: [(Pair, Pair, Pair, Pair); 10] = [
+    (
+        (hexf64!("0x1.921fb54442d18p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.377ce858a5d48p-109"),
+        ),
+        (
+            hexf64!("0x1.1a62633145c07p-54"),
+            hexf64!("-0x1.f1976b7ed8fbcp-110"),
+        ),
+        (
+            hexf64!("0x1.d02967c31cdb5p+53"),
+            hexf64!("-0x1.f3c72fe49aa2ap-3"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.921fb54442d18p+0"),
+            hexf64!("0x1.1a62633145c07p-54"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.e396a47941a45p-220"),
+        ),
+        (
+            hexf64!("-0x1.f1976b7ed8fbcp-110"),
+            hexf64!("0x1.4cf98e804177dp-164"),
+        ),
+        (
+            hexf64!("-0x1.0769b1bb38249p+109"),
+            hexf64!("0x1.ae96910fa0b1cp+54"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0f0cf064dd592p+73"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.b453ab76bf397p-1"),
+            hexf64!("-0x1.f453790772648p-58"),
+        ),
+        (
+            hexf64!("0x1.0be2cef01c8f4p-1"),
+            hexf64!("-0x1.b2d1bc8018c4fp-55"),
+        ),
+        (
+            hexf64!("-0x1.a0f79c1b6b257p+0"),
+            hexf64!("-0x1.d27810f5737ddp-54"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.0f0cf064dd592p+73"),
+            hexf64!("0x1.0000000000000p+20"),
+        ),
+        (
+            hexf64!("0x1.f457e961a1ac1p-1"),
+            hexf64!("-0x1.13ee1370a743ep-55"),
+        ),
+        (
+            hexf64!("0x1.b2851f16d331ep-3"),
+            hexf64!("0x1.1b65a65c3339ap-58"),
+        ),
+        (
+            hexf64!("0x1.26c7bdd2e67f1p+2"),
+            hexf64!("-0x1.6c124aa0ce291p-52"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.6ac5b262ca1ffp+849"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.2b089ea1e692bp-123"),
+        ),
+        (
+            hexf64!("-0x1.14ae72e6ba22fp-61"),
+            hexf64!("0x1.73eef1477d90ep-118"),
+        ),
+        (
+            hexf64!("-0x1.d9ba9a7975636p+60"),
+            hexf64!("0x1.714cf36c65449p+6"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p+1000"),
+            hexf64!("-0x1.0000000000000p+940"),
+        ),
+        (
+            hexf64!("0x1.351cf84eecf72p-3"),
+            hexf64!("0x1.09508f92ab91bp-57"),
+        ),
+        (
+            hexf64!("-0x1.fa226a4bf29b5p-1"),
+            hexf64!("-0x1.d63df85cd4793p-58"),
+        ),
+        (
+            hexf64!("-0x1.38b209ae14648p-3"),
+            hexf64!("0x1.28adda361f95ap-62"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.7e43c8800759cp+996"),
+            hexf64!("0x1.137367c236c65p+940"),
+        ),
+        (
+            hexf64!("0x1.63d7537be5001p-1"),
+            hexf64!("0x1.569aa495b9f66p-55"),
+        ),
+        (
+            hexf64!("-0x1.7021c47c1b644p-1"),
+            hexf64!("0x1.3b155bbe8193dp-58"),
+        ),
+        (
+            hexf64!("-0x1.eee7e932f3e5fp-1"),
+            hexf64!("0x1.93b6038bfbda4p-55"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.fffffffffffffp+1023"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.452fc98b34e97p-8"),
+            hexf64!("-0x1.27bb193d960dfp-62"),
+        ),
+        (
+            hexf64!("-0x1.fffe62ecfab75p-1"),
+            hexf64!("-0x1.e038d934070f1p-56"),
+        ),
+        (
+            hexf64!("-0x1.4530cfe729484p-8"),
+            hexf64!("0x1.1c97823bf87a5p-62"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.6ac5b262ca1ffp+849"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.2b089ea1e692bp-123"),
+        ),
+        (
+            hexf64!("-0x1.14ae72e6ba22fp-61"),
+            hexf64!("0x1.73eef1477d90ep-118"),
+        ),
+        (
+            hexf64!("-0x1.d9ba9a7975636p+60"),
+            hexf64!("0x1.714cf36c65449p+6"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.d6f3454000000p+26"),
+            hexf64!("0x1.12e0be826d695p-30"),
+        ),
+        (
+            hexf64!("0x1.faf0521dc234fp-1"),
+            hexf64!("-0x1.7dade92360708p-56"),
+        ),
+        (
+            hexf64!("0x1.1f4077a71065bp-3"),
+            hexf64!("0x1.763928c48cf9dp-57"),
+        ),
+        (
+            hexf64!("0x1.c3c92fdcb67ffp+2"),
+            hexf64!("-0x1.24aa81774638dp-52"),
+        ),
+    ),
+];
+
+// Sine, cosine and tangent of arguments in half turns, including a multiple
+// of a quarter turn and arguments beyond 2^53 with a fractional low word
+const SIN_COS_PI_CASES: [(Pair, Pair, Pair, Pair); 9] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.28b70caa3e9f8p-65"),
+            hexf64!("0x1.4c4504627079fp-120"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.57e7d02cccd6dp-131"),
+        ),
+        (
+            hexf64!("0x1.28b70caa3e9f8p-65"),
+            hexf64!("0x1.4c4504627079fp-120"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.999999999999ap-4"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.3c6ef372fe950p-2"),
+            hexf64!("-0x1.8623eec4d2746p-57"),
+        ),
+        (
+            hexf64!("0x1.e6f0e134454ffp-1"),
+            hexf64!("0x1.47d950bf4284ep-55"),
+        ),
+        (
+            hexf64!("0x1.4cb7bfb4961afp-2"),
+            hexf64!("0x1.4b18a7d41ef60p-58"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.5555555555555p-2"),
+            hexf64!("0x1.5555555555555p-56"),
+        ),
+        (
+            hexf64!("0x1.bb67ae8584caap-1"),
+            hexf64!("0x1.cec95d0b5c1e3p-55"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p-1"),
+            hexf64!("0x1.d05527b6e43d2p-109"),
+        ),
+        (
+            hexf64!("0x1.bb67ae8584caap+0"),
+            hexf64!("0x1.cec95d0b5c1e2p-54"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.0000000000000p-2"),
+            hexf64!("0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("-0x1.6a09e667f3bcdp-1"),
+            hexf64!("0x1.cf98c45603a84p-55"),
+        ),
+        (
+            hexf64!("0x1.6a09e667f3bcdp-1"),
+            hexf64!("-0x1.ac0dbe2048e27p-55"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("0x1.921fb54442d18p-58"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.8000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.6a09e667f3bcdp-1"),
+            hexf64!("-0x1.bdd3413b26456p-55"),
+        ),
+        (
+            hexf64!("-0x1.6a09e667f3bcdp-1"),
+            hexf64!("0x1.bdd3413b26456p-55"),
+        ),
+        (hexf64!("-0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.0000000000000p-60"),
+        ),
+        (
+            hexf64!("0x1.921fb54442d18p-59"),
+            hexf64!("0x1.1a62633145c07p-113"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("0x1.3bd3cc9be45dep-118"),
+        ),
+        (
+            hexf64!("-0x1.921fb54442d18p-59"),
+            hexf64!("-0x1.1a62633145c07p-113"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.4000000000000p+1"),
+            hexf64!("0x1.70ef54646d497p-57"),
+        ),
+        (
+            hexf64!("-0x1.0000000000000p+0"),
+            hexf64!("0x1.47f94f03f989ap-111"),
+        ),
+        (
+            hexf64!("0x1.21c2c25e4127dp-55"),
+            hexf64!("-0x1.40c8eefe2e974p-114"),
+        ),
+        (
+            hexf64!("-0x1.c4587645cabcep+54"),
+            hexf64!("-0x1.c0c6019185d0cp-1"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.c6bf526340001p+49"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.87de2a6aea963p-2"),
+            hexf64!("-0x1.72cedd3d5a610p-57"),
+        ),
+        (
+            hexf64!("0x1.d906bcf328d46p-1"),
+            hexf64!("0x1.457e610231ac2p-56"),
+        ),
+        (
+            hexf64!("0x1.a827999fcef32p-2"),
+            hexf64!("0x1.08b2fb1366ea9p-56"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.0000000000000p+60"),
+            hexf64!("0x1.8000000000000p-2"),
+        ),
+        (
+            hexf64!("0x1.d906bcf328d46p-1"),
+            hexf64!("0x1.457e610231ac2p-56"),
+        ),
+        (
+            hexf64!("0x1.87de2a6aea963p-2"),
+            hexf64!("-0x1.72cedd3d5a610p-57"),
+        ),
+        (
+            hexf64!("0x1.3504f333f9de6p+1"),
+            hexf64!("0x1.21165f626cdd5p-53"),
+        ),
+    ),
+];
+
+// The same functions of arguments in degrees, out to 1e200
+const SIN_COS_DEG_CASES: [(Pair, Pair, Pair, Pair); 7] = [
+    (
+        (hexf64!("0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.a5fea5ec6fd1dp-73"),
+            hexf64!("-0x1.4c9bb89d8de23p-127"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p+0"),
+            hexf64!("-0x1.5bcfc584aa404p-146"),
+        ),
+        (
+            hexf64!("0x1.a5fea5ec6fd1dp-73"),
+            hexf64!("-0x1.4c9bb89d8de23p-127"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.1df0b2b89dd1ep-6"),
+            hexf64!("0x1.5834d68148788p-60"),
+        ),
+        (
+            hexf64!("0x1.ffec097f5af8ap-1"),
+            hexf64!("-0x1.18945ff801a15p-55"),
+        ),
+        (
+            hexf64!("0x1.1dfbd9410a422p-6"),
+            hexf64!("0x1.8c721220dd6f1p-62"),
+        ),
+    ),
+    (
+        (
+            hexf64!("0x1.e000000000000p+4"),
+            hexf64!("0x1.0000000000000p-50"),
+        ),
+        (
+            hexf64!("0x1.0000000000000p-1"),
+            hexf64!("0x1.ef49c3f648c9cp-57"),
+        ),
+        (
+            hexf64!("0x1.bb67ae8584caap-1"),
+            hexf64!("0x1.874c428211a95p-55"),
+        ),
+        (
+            hexf64!("0x1.279a74590331cp-1"),
+            hexf64!("0x1.f3292f75aea13p-55"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.6c00000000000p+5"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.6d2f29b1f2f46p-1"),
+            hexf64!("-0x1.7637392556014p-57"),
+        ),
+        (
+            hexf64!("0x1.66dd943f43372p-1"),
+            hexf64!("0x1.25aaf095b418dp-59"),
+        ),
+        (
+            hexf64!("-0x1.0481eb08fbac5p+0"),
+            hexf64!("-0x1.5ee71322a5041p-55"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.9000000000000p+6"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.f838b8c811c17p-1"),
+            hexf64!("0x1.682ec6bde69d5p-55"),
+        ),
+        (
+            hexf64!("-0x1.63a1a7e0b738ap-3"),
+            hexf64!("0x1.744603e3937c7p-57"),
+        ),
+        (
+            hexf64!("-0x1.6af648056a136p+2"),
+            hexf64!("-0x1.27c9baa1ca563p-52"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.6600000000000p+7"),
+            hexf64!("-0x1.203af9ee75616p-50"),
+        ),
+        (
+            hexf64!("-0x1.1df0b2b89dd19p-6"),
+            hexf64!("-0x1.39b0308ade174p-60"),
+        ),
+        (
+            hexf64!("-0x1.ffec097f5af8ap-1"),
+            hexf64!("0x1.15c5276287ffep-55"),
+        ),
+        (
+            hexf64!("0x1.1dfbd9410a41dp-6"),
+            hexf64!("0x1.08f4ea9fc681ap-62"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.4e718d7d7625ap+664"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.9376253f463d1p-1"),
+            hexf64!("0x1.eda014796a4e9p-55"),
+        ),
+        (
+            hexf64!("-0x1.3b37fb1bdc939p-1"),
+            hexf64!("0x1.bbf07ed3a7b9ep-57"),
+        ),
+        (
+            hexf64!("-0x1.47aa413b0ee1ep+0"),
+            hexf64!("-0x1.57aa696dd6277p-57"),
+        ),
+    ),
+];
+
+// Arcsines in half turns and in degrees, up to an argument just below one
+const ASIN_PI_CASES: [(Pair, Pair, Pair); 5] = [
+    (
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.5555555555555p-3"),
+            hexf64!("0x1.5555555555555p-57"),
+        ),
+        (hexf64!("0x1.e000000000000p+4"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.6666666666666p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.f97b07373b692p-3"),
+            hexf64!("-0x1.c05f95c05fa0ep-60"),
+        ),
+        (
+            hexf64!("-0x1.636a8112d5c5fp+5"),
+            hexf64!("0x1.3897995697986p-49"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.3333333333333p-2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.8d41e8c042c2cp-4"),
+            hexf64!("0x1.5b248687f1312p-58"),
+        ),
+        (
+            hexf64!("0x1.175257a72ef0fp+4"),
+            hexf64!("0x1.682b5d2f2b2d1p-51"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.fffffca501acbp-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.ffdaaee280c91p-2"),
+            hexf64!("0x1.1c98509e6af83p-59"),
+        ),
+        (
+            hexf64!("0x1.67e5c2f7428d6p+6"),
+            hexf64!("-0x1.6fc9cea139930p-52"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.79ca10c924223p-67"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.e1042c3d96d7fp-69"),
+            hexf64!("0x1.3781ba9a1acb7p-123"),
+        ),
+        (
+            hexf64!("-0x1.5236ef1b4e0fdp-61"),
+            hexf64!("-0x1.53e3232e94a3ep-117"),
+        ),
+    ),
+];
+
+// Four quadrant arctangents of (y, x) in half turns and in degrees, including
+// coordinates close to the limits of the range of `f64`
+const ATAN2_PI_CASES: [(Pair, Pair, Pair, Pair); 6] = [
+    (
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x1.8000000000000p+1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.a37f5c4c419efp-4"),
+            hexf64!("0x1.9a97709251caep-59"),
+        ),
+        (
+            hexf64!("0x1.26f58ce59e23cp+4"),
+            hexf64!("0x1.80b27b26e182bp-51"),
+        ),
+    ),
+    (
+        (hexf64!("-0x1.0000000000000p+1"), hexf64!("0x0.0p+0")),
+        (hexf64!("-0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.27ece16d7b8e8p-1"),
+            hexf64!("0x1.7220bc0c35f6ep-55"),
+        ),
+        (
+            hexf64!("-0x1.a0251d01f5c06p+6"),
+            hexf64!("0x1.087e08712be33p-48"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.7e43c8800759cp+996"), hexf64!("0x0.0p+0")),
+        (hexf64!("-0x1.0b95d9266b8bap+996"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.6388695898fa1p-1"),
+            hexf64!("-0x1.53fe17da597e0p-55"),
+        ),
+        (
+            hexf64!("0x1.f3f7d424971fap+6"),
+            hexf64!("-0x1.f0ea8c586ec98p-51"),
+        ),
+    ),
+    (
+        (hexf64!("0x0.05c0ab9347ed7p-1022"), hexf64!("0x0.0p+0")),
+        (hexf64!("-0x0.03739a252b281p-1022"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("0x1.58131e9284718p-1"),
+            hexf64!("0x1.7220bc0c35f6ep-55"),
+        ),
+        (
+            hexf64!("0x1.e3dae2fe0a3fap+6"),
+            hexf64!("0x1.087e08712be33p-48"),
+        ),
+    ),
+    (
+        (hexf64!("0x1.0000000000000p+0"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x1.87e92154ef7acp-665"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x1.0000000000000p-1"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x1.6800000000000p+6"), hexf64!("0x0.0p+0")),
+    ),
+    (
+        (hexf64!("-0x1.999999999999ap-4"), hexf64!("0x0.0p+0")),
+        (hexf64!("0x1.c000000000000p+2"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.29fd9401af82dp-8"),
+            hexf64!("0x1.f98f293644028p-62"),
+        ),
+        (
+            hexf64!("-0x1.a30c98225ecffp-1"),
+            hexf64!("0x1.a6f151f44fa38p-55"),
+        ),
+    ),
+];
+
 #[test]
 fn sin_cos_test() {
     let mut rng = rand::rng();
@@ -146,3 +713,229 @@ fn sin_cos_atan2_test() {
         );
     });
 }
+
+#[test]
+fn large_argument_reference_test() {
+    for &(source, sin, cos, tan) in LARGE_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(x.sin(), sin, RELATIVE_ERROR, &format!("sin({:?})", x));
+        assert_relative(x.cos(), cos, RELATIVE_ERROR, &format!("cos({:?})", x));
+        assert_relative(x.tan(), tan, RELATIVE_ERROR, &format!("tan({:?})", x));
+
+        let (s, c) = x.sin_cos();
+        assert_eq!(s, x.sin(), "sin_cos({:?}).0 not equal to sin", x);
+        assert_eq!(c, x.cos(), "sin_cos({:?}).1 not equal to cos", x);
+    }
+}
+
+#[test]
+fn large_argument_test() {
+    repeated_test(|| {
+        let source = get_valid_ddouble(|x| x.is_valid() && x.abs() > 1e10);
+
+        let (sin, cos) = source.sin_cos();
+        assert!(
+            sin.is_valid() && cos.is_valid(),
+            "sin_cos({:?}) returned invalid result",
+            source
+        );
+
+        let difference = (1.0 - (cos * cos + sin * sin)).abs();
+        assert!(
+            difference < 1e-30,
+            "cos^2 + sin^2 for {:?} returned value different from 1",
+            source
+        );
+
+        assert_eq!((-source).sin(), -sin, "sin({:?}) is not odd", source);
+        assert_eq!((-source).cos(), cos, "cos({:?}) is not even", source);
+    });
+}
+
+#[test]
+fn half_turn_reference_test() {
+    for &(source, sin, cos, tan) in SIN_COS_PI_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(x.sin_pi(), sin, RELATIVE_ERROR, &format!("sin_pi({:?})", x));
+        assert_relative(x.cos_pi(), cos, RELATIVE_ERROR, &format!("cos_pi({:?})", x));
+        assert_relative(x.tan_pi(), tan, RELATIVE_ERROR, &format!("tan_pi({:?})", x));
+
+        let (s, c) = x.sincos_pi();
+        assert_eq!(s, x.sin_pi(), "sincos_pi({:?}).0 not equal to sin_pi", x);
+        assert_eq!(c, x.cos_pi(), "sincos_pi({:?}).1 not equal to cos_pi", x);
+    }
+}
+
+#[test]
+fn degree_reference_test() {
+    for &(source, sin, cos, tan) in SIN_COS_DEG_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(x.sind(), sin, RELATIVE_ERROR, &format!("sind({:?})", x));
+        assert_relative(x.cosd(), cos, RELATIVE_ERROR, &format!("cosd({:?})", x));
+        assert_relative(x.tand(), tan, RELATIVE_ERROR, &format!("tand({:?})", x));
+
+        let (s, c) = x.sincosd();
+        assert_eq!(s, x.sind(), "sincosd({:?}).0 not equal to sind", x);
+        assert_eq!(c, x.cosd(), "sincosd({:?}).1 not equal to cosd", x);
+    }
+}
+
+#[test]
+fn half_turn_exact_test() {
+    for n in -8i32..=8 {
+        let x = TwoFloat::from(n as f64);
+        let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+        assert_eq!(x.sin_pi(), 0.0, "sin_pi({}) is not zero", n);
+        assert_eq!(x.cos_pi(), sign, "cos_pi({}) is not {}", n, sign);
+        assert_eq!(x.tan_pi(), 0.0, "tan_pi({}) is not zero", n);
+        assert_eq!((x + 0.5).sin_pi(), sign, "sin_pi({}.5) is not {}", n, sign);
+        assert_eq!((x + 0.5).cos_pi(), 0.0, "cos_pi({}.5) is not zero", n);
+
+        let degrees = x * 90.0;
+        let expected = [(0.0, 1.0), (1.0, 0.0), (0.0, -1.0), (-1.0, 0.0)][n.rem_euclid(4) as usize];
+        assert_eq!(
+            degrees.sincosd(),
+            (TwoFloat::from(expected.0), TwoFloat::from(expected.1)),
+            "sincosd({:?}) is not exact",
+            degrees
+        );
+    }
+
+    assert_eq!(TwoFloat::from(0.5).tan_pi(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-0.5).tan_pi(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::from(1e300).sin_pi(), 0.0);
+    assert_eq!(TwoFloat::new_add(1e300, 0.5).cos_pi(), 0.0);
+    assert!(!TwoFloat::INFINITY.sin_pi().is_valid());
+    assert!(!TwoFloat::NAN.cosd().is_valid());
+}
+
+#[test]
+fn half_turn_signed_zero_test() {
+    let is_zero = |x: TwoFloat, negative: bool| {
+        x.hi() == 0.0 && x.lo() == 0.0 && x.hi().is_sign_negative() == negative
+    };
+
+    for n in -8i32..=8 {
+        if n == 0 {
+            continue;
+        }
+        let x = TwoFloat::from(n as f64);
+        let negative = n < 0;
+        assert!(
+            is_zero(x.sin_pi(), negative),
+            "sin_pi({}) has the wrong sign",
+            n
+        );
+        assert!(
+            is_zero(x.sincos_pi().0, negative),
+            "sincos_pi({}) has the wrong sign",
+            n
+        );
+        assert!(
+            is_zero((x * 180.0).sind(), negative),
+            "sind({}) has the wrong sign",
+            n * 180
+        );
+        assert!(
+            is_zero(x.tan_pi(), negative == (n % 2 == 0)),
+            "tan_pi({}) has the wrong sign",
+            n
+        );
+        assert!(
+            is_zero((x + 0.5).cos_pi(), false),
+            "cos_pi({}.5) is not +0",
+            n
+        );
+        assert!(
+            is_zero((x * 180.0 + 90.0).cosd(), false),
+            "cosd({}) is not +0",
+            n * 180 + 90
+        );
+    }
+}
+
+#[test]
+fn inverse_half_turn_test() {
+    assert_eq!(TwoFloat::from(1.0).asin_pi(), 0.5);
+    assert_eq!(TwoFloat::from(-1.0).asind(), -90.0);
+    assert!(!TwoFloat::from(1.5).asin_pi().is_valid());
+
+    let one = TwoFloat::from(1.0);
+    let zero = TwoFloat::from(0.0);
+    assert_eq!(TwoFloat::atan2_pi(zero, -one), 1.0);
+    assert_eq!(TwoFloat::atan2_pi(-one, zero), -0.5);
+    assert_eq!(TwoFloat::atan2d(one, one), 45.0);
+    assert_eq!(TwoFloat::atan2d(-one, -one), -135.0);
+}
+
+#[test]
+fn inverse_half_turn_reference_test() {
+    for &(source, turns, degrees) in ASIN_PI_CASES.iter() {
+        let x = TwoFloat::try_from(source).unwrap();
+        assert_relative(
+            x.asin_pi(),
+            turns,
+            RELATIVE_ERROR,
+            &format!("asin_pi({:?})", x),
+        );
+        assert_relative(
+            x.asind(),
+            degrees,
+            RELATIVE_ERROR,
+            &format!("asind({:?})", x),
+        );
+    }
+
+    for &(y, x, turns, degrees) in ATAN2_PI_CASES.iter() {
+        let (y, x) = (
+            TwoFloat::try_from(y).unwrap(),
+            TwoFloat::try_from(x).unwrap(),
+        );
+        let description = format!("({:?}, {:?})", y, x);
+        assert_relative(
+            y.atan2_pi(x),
+            turns,
+            RELATIVE_ERROR,
+            &format!("atan2_pi{}", description),
+        );
+        assert_relative(
+            y.atan2d(x),
+            degrees,
+            RELATIVE_ERROR,
+            &format!("atan2d{}", description),
+        );
+    }
+}
+
+#[test]
+fn half_turn_test() {
+    let mut rng = rand::rng();
+    let dist = rand::distr::Uniform::new_inclusive(-4.0, 4.0).unwrap();
+    repeated_test(|| {
+        let source = TwoFloat::from(rng.sample(dist));
+        let expected = (source * twofloat::consts::PI).sin_cos();
+        let (s, c) = source.sincos_pi();
+        assert!(
+            (s - expected.0).abs() < 1e-30 && (c - expected.1).abs() < 1e-30,
+            "sincos_pi({:?}) does not match sin_cos of angle in radians",
+            source
+        );
+
+        let degrees = source * 180.0;
+        let (sd, cd) = degrees.sincosd();
+        assert!(
+            (sd - s).abs() < 1e-30 && (cd - c).abs() < 1e-30,
+            "sincosd({:?}) does not match sincos_pi",
+            degrees
+        );
+
+        let turns = source.rem_euclid(TwoFloat::from(2.0));
+        let expected_angle = if turns > 1.0 { turns - 2.0 } else { turns };
+        let angle = TwoFloat::atan2_pi(s, c);
+        assert!(
+            (angle - expected_angle).abs() < 1e-30,
+            "atan2_pi of sincos_pi({:?}) does not return the same angle",
+            source
+        );
+    });
+}
@@ -0,0 +1,296 @@
+#[macro_use]
+pub mod common;
+
+use common::*;
+use core::convert::TryFrom;
+use num_traits::{Float, FloatConst, Num};
+use rand::Rng;
+use twofloat::{no_overlap_f32, TwoF32, TwoFloat};
+
+// Bound on the relative error of the arithmetic operations, around ten units
+// of 2^-48
+const ARITHMETIC_ERROR: f64 = 4e-14;
+
+// Bound on the relative error of functions evaluated through TwoFloat
+const FUNCTION_ERROR: f64 = 8e-15;
+
+fn random_twof32() -> TwoF32 {
+    let mut rng = rand::rng();
+    let hi = rng.random_range(1.0f32..2.0) * 2f32.powi(rng.random_range(-30..30));
+    let lo = hi * rng.random_range(-1.0f32..1.0) * 2f32.powi(-24);
+    let value = TwoF32::new_add(hi, lo);
+    if rng.random() {
+        value
+    } else {
+        -value
+    }
+}
+
+fn assert_relative(result: TwoF32, expected: TwoFloat, bound: f64, description: &str) {
+    let error = ((TwoFloat::from(result) - expected) / expected).abs();
+    assert!(
+        result.is_valid() && error <= bound,
+        "Mismatch in {}: {:?} vs {:?}",
+        description,
+        result,
+        expected
+    );
+}
+
+#[test]
+fn arithmetic_test() {
+    repeated_test(|| {
+        let a = random_twof32();
+        let b = random_twof32();
+        let (x, y) = (TwoFloat::from(a), TwoFloat::from(b));
+
+        assert_relative(
+            a + b,
+            x + y,
+            ARITHMETIC_ERROR,
+            &format!("{:?} + {:?}", a, b),
+        );
+        assert_relative(
+            a - b,
+            x - y,
+            ARITHMETIC_ERROR,
+            &format!("{:?} - {:?}", a, b),
+        );
+        assert_relative(
+            a * b,
+            x * y,
+            ARITHMETIC_ERROR,
+            &format!("{:?} * {:?}", a, b),
+        );
+        assert_relative(
+            a / b,
+            x / y,
+            ARITHMETIC_ERROR,
+            &format!("{:?} / {:?}", a, b),
+        );
+
+        let c = b.hi();
+        let z = TwoFloat::from(c);
+        assert_relative(
+            a + c,
+            x + z,
+            ARITHMETIC_ERROR,
+            &format!("{:?} + {:?}", a, c),
+        );
+        assert_relative(
+            a * c,
+            x * z,
+            ARITHMETIC_ERROR,
+            &format!("{:?} * {:?}", a, c),
+        );
+        assert_relative(
+            a / c,
+            x / z,
+            ARITHMETIC_ERROR,
+            &format!("{:?} / {:?}", a, c),
+        );
+        assert_relative(
+            c / a,
+            z / x,
+            ARITHMETIC_ERROR,
+            &format!("{:?} / {:?}", c, a),
+        );
+    });
+}
+
+#[test]
+fn new_ops_test() {
+    repeated_test(|| {
+        let a = random_twof32().hi();
+        let b = random_twof32().hi();
+        let (x, y) = (a as f64, b as f64);
+
+        let sum = TwoF32::new_add(a, b);
+        assert!(no_overlap_f32(sum.hi(), sum.lo()));
+        assert_eq!(TwoFloat::from(sum), TwoFloat::new_add(x, y));
+        assert_eq!(
+            TwoFloat::from(TwoF32::new_sub(a, b)),
+            TwoFloat::new_sub(x, y)
+        );
+        assert_eq!(TwoFloat::from(TwoF32::new_mul(a, b)), TwoFloat::from(x * y));
+
+        let quotient = TwoF32::new_div(a, b);
+        assert_relative(
+            quotient,
+            TwoFloat::new_div(x, y),
+            ARITHMETIC_ERROR,
+            &format!("{:?} / {:?}", a, b),
+        );
+    });
+}
+
+#[test]
+fn conversion_test() {
+    repeated_test(|| {
+        let a = random_twof32();
+        let x = TwoFloat::from(a);
+        assert_eq!(TwoF32::from(x), a);
+        assert_eq!(f32::from(a), a.hi());
+        assert_eq!(TwoF32::try_from((a.hi(), a.lo())), Ok(a));
+    });
+
+    let third = TwoF32::from(TwoFloat::from(1.0) / 3.0);
+    assert_eq!(third, TwoF32::from(1.0f32) / 3.0);
+    assert!(TwoF32::try_from([1.0f32, 0.5]).is_err());
+    assert_eq!(TwoF32::from(0.1), "0.1".parse::<TwoF32>().unwrap());
+}
+
+#[test]
+fn function_test() {
+    repeated_test(|| {
+        let a = random_twof32();
+        let x = TwoFloat::from(a);
+        let small = a / a.hi().abs().max(1.0);
+        let s = TwoFloat::from(small);
+
+        assert_relative(a.abs().sqrt(), x.abs().sqrt(), FUNCTION_ERROR, "sqrt");
+        assert_relative(a.cbrt(), x.cbrt(), FUNCTION_ERROR, "cbrt");
+        assert_relative(small.exp(), s.exp(), FUNCTION_ERROR, "exp");
+        assert_relative(a.abs().ln(), x.abs().ln(), FUNCTION_ERROR, "ln");
+        assert_relative(small.sin(), s.sin(), FUNCTION_ERROR, "sin");
+        assert_relative(a.atan(), x.atan(), FUNCTION_ERROR, "atan");
+    });
+}
+
+#[test]
+fn format_round_trip_test() {
+    repeated_test(|| {
+        let a = random_twof32();
+        let fixed = format!("{}", a);
+        assert_eq!(fixed.parse::<TwoF32>(), Ok(a), "Round trip of {}", fixed);
+
+        let scientific = format!("{:e}", a);
+        assert_eq!(
+            scientific.parse::<TwoF32>(),
+            Ok(a),
+            "Round trip of {}",
+            scientific
+        );
+    });
+}
+
+#[test]
+fn parse_test() {
+    let a: TwoF32 = "3.14159265358979323846".parse().unwrap();
+    assert_eq!(a, TwoF32::PI());
+    assert_eq!(format!("{}", a), "3.1415926535898");
+
+    let b = TwoF32::from_str_radix("-0.8", 16).unwrap();
+    assert_eq!(b, TwoF32::from(-0.5f32));
+
+    let c: TwoF32 = "1e-45".parse().unwrap();
+    assert_eq!(c.hi(), f32::from_bits(1));
+    assert_eq!(c.lo(), 0.0);
+
+    assert!("1.2.3".parse::<TwoF32>().is_err());
+    assert_eq!("1e39".parse::<TwoF32>(), Ok(TwoF32::INFINITY));
+    assert_eq!("-inf".parse::<TwoF32>(), Ok(TwoF32::NEG_INFINITY));
+
+    // Infinities have a zero low word, and NaN has both words NaN
+    for &(source, expected) in [
+        ("inf", f32::INFINITY),
+        ("-inf", f32::NEG_INFINITY),
+        ("1e400", f32::INFINITY),
+    ]
+    .iter()
+    {
+        let value: TwoF32 = source.parse().unwrap();
+        assert_eq!(
+            (value.hi(), value.lo()),
+            (expected, 0.0),
+            "Incorrect words parsing {}",
+            source
+        );
+    }
+    let nan: TwoF32 = "nan".parse().unwrap();
+    assert!(nan.hi().is_nan() && nan.lo().is_nan());
+}
+
+#[test]
+fn special_test() {
+    assert!(TwoF32::NAN.sqrt().hi().is_nan());
+    assert!(!(TwoF32::from(1.0f32) / 0.0).is_valid());
+    assert_eq!(TwoF32::INFINITY.exp(), TwoF32::INFINITY);
+    assert_eq!(TwoF32::from(0.0f32).ln(), TwoF32::NEG_INFINITY);
+    assert_eq!(format!("{}", TwoF32::NAN), "NaN");
+    assert_eq!(format!("{:+}", TwoF32::INFINITY), "+inf");
+    assert_eq!(format!("{}", TwoF32::from(-0.0f32)), "-0");
+    assert!(!(TwoF32::MAX * 2.0).is_valid());
+}
+
+#[test]
+fn ieee_arithmetic_test() {
+    let one = TwoF32::from(1.0f32);
+    let zero = TwoF32::from(0.0f32);
+    let inf = f32::INFINITY;
+    let cases = [
+        (TwoF32::INFINITY + 1.0, inf),
+        (1.0 + TwoF32::INFINITY, inf),
+        (TwoF32::INFINITY + one, inf),
+        (TwoF32::NEG_INFINITY - 1.0, -inf),
+        (1.0 - TwoF32::INFINITY, -inf),
+        (one - TwoF32::INFINITY, -inf),
+        (TwoF32::INFINITY * 1.0, inf),
+        (-1.0 * TwoF32::INFINITY, -inf),
+        (TwoF32::INFINITY * -one, -inf),
+        (one / 0.0, inf),
+        (1.0 / zero, inf),
+        (-one / zero, -inf),
+        (one / -zero, -inf),
+        (TwoF32::MAX * 2.0, inf),
+        (TwoF32::MIN * TwoF32::MAX, -inf),
+        (TwoF32::MAX + TwoF32::MAX, inf),
+        (TwoF32::MAX / 0.5, inf),
+        (one / TwoF32::INFINITY, 0.0),
+        (-1.0 / TwoF32::INFINITY, -0.0),
+    ];
+
+    for (i, &(result, expected)) in cases.iter().enumerate() {
+        assert_eq!(result.hi(), expected, "Incorrect high word in case {}", i);
+        assert_eq!(
+            result.hi().is_sign_negative(),
+            expected.is_sign_negative(),
+            "Incorrect sign in case {}",
+            i
+        );
+        assert_eq!(result.lo(), 0.0, "Incorrect low word in case {}", i);
+    }
+
+    for &result in [
+        TwoF32::INFINITY - TwoF32::INFINITY,
+        TwoF32::INFINITY * 0.0,
+        zero / zero,
+        TwoF32::INFINITY / TwoF32::INFINITY,
+        TwoF32::NAN + 1.0,
+    ]
+    .iter()
+    {
+        assert!(result.hi().is_nan() && result.lo().is_nan());
+    }
+}
+
+#[test]
+fn num_traits_test() {
+    fn hypotenuse<T: Float>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    let c = hypotenuse(TwoF32::from(3.0f32), TwoF32::from(4.0f32));
+    assert_eq!(c, 5.0);
+
+    assert_eq!(<TwoF32 as Float>::floor(TwoF32::from(2.5f32)), 2.0);
+    assert_eq!(
+        <TwoF32 as num_traits::ToPrimitive>::to_i64(&TwoF32::from(-7.0f32)),
+        Some(-7)
+    );
+    assert_eq!(
+        <TwoF32 as num_traits::NumCast>::from(1u64 << 40),
+        Some(TwoF32::from(1u64 << 40))
+    );
+    assert!((TwoF32::TAU() - TwoF32::PI() * 2.0).abs() < 1e-14);
+}
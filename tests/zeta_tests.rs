@@ -0,0 +1,329 @@
+#![allow(clippy::approx_constant)]
+
+#[macro_use]
+pub mod common;
+
+use common::*;
+use hexf::hexf64;
+use rand::Rng;
+use twofloat::{
+    consts::{LN_2, PI},
+    TwoFloat,
+};
+
+// Riemann zeta function on both sides of the pole at one, from -20.5 up to 50
+// where it differs from one by less than 1e-15
+const ZETA_CASES: [(f64, Pair); 12] = [
+    (
+        2.0,
+        (
+            hexf64!("0x1.a51a6625307d3p+0"),
+            hexf64!("0x1.1873d8912200cp-55"),
+        ),
+    ),
+    (
+        3.0,
+        (
+            hexf64!("0x1.33ba004f00621p+0"),
+            hexf64!("0x1.c1b8b8ae2cf35p-55"),
+        ),
+    ),
+    (
+        0.5,
+        (
+            hexf64!("-0x1.75d9cb07e73ffp+0"),
+            hexf64!("0x1.c817cb1bdd435p-56"),
+        ),
+    ),
+    (
+        0.25,
+        (
+            hexf64!("-0x1.a06606f24924dp-1"),
+            hexf64!("0x1.32aad2dc774e0p-61"),
+        ),
+    ),
+    (
+        1.5,
+        (
+            hexf64!("0x1.4e6250bfbd89dp+1"),
+            hexf64!("0x1.b8a11796b87f8p-53"),
+        ),
+    ),
+    (
+        10.5,
+        (
+            hexf64!("0x1.002dee3039d48p+0"),
+            hexf64!("0x1.35f044b7e9acep-54"),
+        ),
+    ),
+    (
+        -1.5,
+        (
+            hexf64!("-0x1.a18caf2985c7bp-6"),
+            hexf64!("0x1.7a00f9187df13p-63"),
+        ),
+    ),
+    (
+        -3.0,
+        (
+            hexf64!("0x1.1111111111111p-7"),
+            hexf64!("0x1.1111111111111p-63"),
+        ),
+    ),
+    (
+        -20.5,
+        (
+            hexf64!("-0x1.b0deb1c8248e3p+6"),
+            hexf64!("0x1.429b4a82d6b34p-48"),
+        ),
+    ),
+    (0.0, (hexf64!("-0x1.0000000000000p-1"), hexf64!("0x0.0p+0"))),
+    (
+        -0.75,
+        (
+            hexf64!("-0x1.11b34d8c6a28cp-3"),
+            hexf64!("0x1.09e741ff9aebcp-58"),
+        ),
+    ),
+    (
+        50.0,
+        (
+            hexf64!("0x1.0000000000004p+0"),
+            hexf64!("0x1.af195f6991412p-80"),
+        ),
+    ),
+];
+
+// Riemann zeta function of small negative arguments, where the reflection
+// formula would lose the low bits of the argument
+const SMALL_NEGATIVE_ZETA_CASES: [(Pair, Pair); 3] = [
+    (
+        (hexf64!("-0x1.19799812dea11p-40"), hexf64!("0x0.0p+0")),
+        (
+            hexf64!("-0x1.fffffffffbf56p-2"),
+            hexf64!("0x1.ffc412d8671d1p-58"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.49da7e361ce4cp-32"),
+            hexf64!("0x1.87400be8ff322p-90"),
+        ),
+        (
+            hexf64!("-0x1.fffffffb438a3p-2"),
+            hexf64!("0x1.ecc8941b77607p-56"),
+        ),
+    ),
+    (
+        (
+            hexf64!("-0x1.0000000000000p-4"),
+            hexf64!("-0x1.0624dd2f1a9fcp-62"),
+        ),
+        (
+            hexf64!("-0x1.c8f7145d3abf1p-2"),
+            hexf64!("0x1.3365195af0f4ap-56"),
+        ),
+    ),
+];
+
+// Polylogarithm by order and argument, with negative and non-integer orders
+// and arguments down to -1e10
+const POLYLOG_CASES: [(f64, f64, Pair); 16] = [
+    (
+        2.0,
+        0.5,
+        (
+            hexf64!("0x1.2a1b6e272566fp-1"),
+            hexf64!("0x1.e3cad5982236fp-55"),
+        ),
+    ),
+    (
+        3.0,
+        -0.75,
+        (
+            hexf64!("-0x1.6226f988c071dp-1"),
+            hexf64!("0x1.3190ffa32cc83p-56"),
+        ),
+    ),
+    (
+        2.0,
+        -5.0,
+        (
+            hexf64!("-0x1.5fe860df00d96p+1"),
+            hexf64!("0x1.c5f4ca7587c15p-53"),
+        ),
+    ),
+    (
+        5.0,
+        0.9,
+        (
+            hexf64!("0x1.dbc8e8f3f6603p-1"),
+            hexf64!("-0x1.c2c67eeb03af1p-55"),
+        ),
+    ),
+    (
+        1.5,
+        -10.0,
+        (
+            hexf64!("-0x1.a4914bc74e50dp+1"),
+            hexf64!("0x1.d02b8af81d6dep-56"),
+        ),
+    ),
+    (
+        0.5,
+        -0.7,
+        (
+            hexf64!("-0x1.e8ed43a1dc42bp-2"),
+            hexf64!("0x1.5c1a91d8fa1fcp-56"),
+        ),
+    ),
+    (
+        2.5,
+        0.8,
+        (
+            hexf64!("0x1.f180e5be5629bp-1"),
+            hexf64!("0x1.4d9e0f3de35d5p-60"),
+        ),
+    ),
+    (
+        -1.5,
+        0.9,
+        (
+            hexf64!("0x1.70e6ca74c54ebp+8"),
+            hexf64!("0x1.993f5d40bda2ep-46"),
+        ),
+    ),
+    (
+        -1.0,
+        -100000.0,
+        (
+            hexf64!("-0x1.4f89a0c1e9489p-17"),
+            hexf64!("0x1.9e2dc694235d5p-71"),
+        ),
+    ),
+    (
+        0.5,
+        -10000000000.0,
+        (
+            hexf64!("-0x1.5a42b5935b3bep+2"),
+            hexf64!("-0x1.8e487db16fccdp-54"),
+        ),
+    ),
+    (
+        1.5,
+        -7.25,
+        (
+            hexf64!("-0x1.65e29604b3cb4p+1"),
+            hexf64!("0x1.f7883b1c44fb1p-53"),
+        ),
+    ),
+    (
+        5.5,
+        -3.0,
+        (
+            hexf64!("-0x1.6be9d6b8dd70ep+1"),
+            hexf64!("-0x1.eea3295a7c582p-55"),
+        ),
+    ),
+    (
+        -0.5,
+        -3.0,
+        (
+            hexf64!("-0x1.c90029bc699dfp-2"),
+            hexf64!("0x1.86e39f2ff65dap-56"),
+        ),
+    ),
+    (
+        30.5,
+        -0.9,
+        (
+            hexf64!("-0x1.ccccccc837ccap-1"),
+            hexf64!("0x1.25e92a3438b57p-55"),
+        ),
+    ),
+    (
+        2.5,
+        0.3,
+        (
+            hexf64!("0x1.45946a26d347ap-2"),
+            hexf64!("-0x1.667697ed4aac4p-59"),
+        ),
+    ),
+    (
+        0.75,
+        0.6,
+        (
+            hexf64!("0x1.01267a4c98832p+0"),
+            hexf64!("0x1.30d47b9421b09p-56"),
+        ),
+    ),
+];
+
+#[test]
+fn zeta_reference_test() {
+    assert_cases(&ZETA_CASES, TwoFloat::zeta, SPECIAL_RELATIVE_ERROR, "zeta");
+    assert_cases(
+        &SMALL_NEGATIVE_ZETA_CASES,
+        TwoFloat::zeta,
+        SPECIAL_RELATIVE_ERROR,
+        "zeta",
+    );
+}
+
+#[test]
+fn polylog_reference_test() {
+    for &(s, x, expected) in POLYLOG_CASES.iter() {
+        let (s, x) = (TwoFloat::from(s), TwoFloat::from(x));
+        let description = format!("Li_{:?}({:?})", s, x);
+        assert_relative(s.polylog(x), expected, SPECIAL_RELATIVE_ERROR, &description);
+    }
+}
+
+#[test]
+fn zeta_special_test() {
+    let one = TwoFloat::from(1.0);
+    let two = TwoFloat::from(2.0);
+
+    assert!(one.zeta().hi().is_nan());
+    assert_eq!(TwoFloat::from(-2.0).zeta(), 0.0);
+    assert_eq!(TwoFloat::from(-10.0).zeta(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.zeta(), 1.0);
+    assert!(TwoFloat::NEG_INFINITY.zeta().hi().is_nan());
+    assert!(TwoFloat::NAN.zeta().hi().is_nan());
+
+    assert_eq!(two.polylog(one), two.zeta());
+    assert_eq!(TwoFloat::from(0.5).polylog(one), TwoFloat::INFINITY);
+    assert_eq!(two.polylog(TwoFloat::from(0.0)), 0.0);
+    assert!(two.polylog(TwoFloat::from(1.5)).hi().is_nan());
+    assert!(two.polylog(TwoFloat::NAN).hi().is_nan());
+    assert!(TwoFloat::NAN.polylog(TwoFloat::from(0.5)).hi().is_nan());
+
+    // Li_2(-1) = -pi^2/12
+    let expected = -PI * PI / 12.0;
+    let result = two.polylog(-one);
+    assert!(((result - expected) / expected).abs() < 1e-30);
+}
+
+#[test]
+fn polylog_identity_test() {
+    let mut rng = rand::rng();
+    let fraction_dist = rand::distr::Uniform::new(0.1, 0.9).unwrap();
+    let x_dist = rand::distr::Uniform::new(-1.0, 1.0).unwrap();
+    repeated_slow_test(|| {
+        // Orders close to an integer are excluded, as the accuracy is reduced
+        let n = f64::from(rng.random_range(-2..5));
+        let s = TwoFloat::from(n + rng.sample(fraction_dist));
+        let x = TwoFloat::from(rng.sample(x_dist));
+
+        // Li_s(x) + Li_s(-x) = 2^(1 - s) Li_s(x^2)
+        let a = s.polylog(x);
+        let b = s.polylog(-x);
+        let result = ((1.0 - s) * LN_2).exp() * s.polylog(x * x);
+        assert!(
+            (a + b - result).abs() < (a.abs() + b.abs()) * 1e-29,
+            "Li_{:?}({:?}) does not satisfy the duplication formula",
+            s,
+            x
+        );
+    });
+}
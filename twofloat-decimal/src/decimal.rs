@@ -0,0 +1,838 @@
+use core::cmp::Ordering;
+
+// Large enough to hold the exact decimal expansion of any dyadic value
+// between 2^-1076 and 2^1024, which covers every `TwoFloat` together with the
+// rounding boundaries either side of it.
+const MAX_DIGITS: usize = 1400;
+
+const MAX_SHIFT: usize = 60;
+const DECIMAL_POINT_RANGE: i32 = 4096;
+
+// Number of bits that can be shifted while keeping at most one integer digit,
+// indexed by the position of the decimal point.
+const POWERS: [u8; 19] = [
+    0, 3, 6, 9, 13, 16, 19, 23, 26, 29, 33, 36, 39, 43, 46, 49, 53, 56, 59,
+];
+
+/// The parameters of a binary floating-point format used for the words of a
+/// multi-word value.
+#[derive(Debug, Clone, Copy)]
+pub struct WordFormat {
+    /// Number of bits in the significand, including the implicit bit
+    pub mantissa_digits: i32,
+    /// Exponent of the least significant bit of the subnormal values
+    pub min_exponent: i32,
+    /// Values of `2^max_exponent` and above overflow
+    pub max_exponent: i32,
+}
+
+impl WordFormat {
+    pub const F64: Self = Self {
+        mantissa_digits: 53,
+        min_exponent: -1074,
+        max_exponent: 1024,
+    };
+
+    pub const F32: Self = Self {
+        mantissa_digits: 24,
+        min_exponent: -149,
+        max_exponent: 128,
+    };
+
+    /// Creates the value `mantissa * 2^exponent` as an `f64`, which must be
+    /// representable in this format unless it overflows.
+    fn make_word(&self, mantissa: u64, exponent: i32) -> f64 {
+        if mantissa != 0 && 64 - mantissa.leading_zeros() as i32 + exponent > self.max_exponent {
+            return f64::INFINITY;
+        }
+
+        // Split the scaling so that each factor is a normal `f64`
+        let half = exponent / 2;
+        mantissa as f64 * pow2(half) * pow2(exponent - half)
+    }
+}
+
+fn pow2(n: i32) -> f64 {
+    f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+fn get_shift(n: usize) -> usize {
+    if n < POWERS.len() {
+        POWERS[n] as usize
+    } else {
+        MAX_SHIFT
+    }
+}
+
+/// Arbitrary-precision decimal number used for exact conversions, following
+/// the "simple decimal conversion" algorithm used by the Go and Rust standard
+/// libraries.
+///
+/// The represented value is `0.d[0]d[1]... * 10^decimal_point`. If
+/// `truncated` is set, nonzero digits beyond the capacity were discarded, so
+/// the stored value is slightly smaller than the exact value.
+#[derive(Clone)]
+pub struct Decimal {
+    pub num_digits: usize,
+    pub decimal_point: i32,
+    pub truncated: bool,
+    pub digits: [u8; MAX_DIGITS],
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Self {
+            num_digits: 0,
+            decimal_point: 0,
+            truncated: false,
+            digits: [0; MAX_DIGITS],
+        }
+    }
+}
+
+impl Decimal {
+    /// Creates the exact decimal representation of `mantissa * 2^exponent`.
+    pub fn from_dyadic(mantissa: u64, exponent: i32) -> Self {
+        let mut result = Self::default();
+        let mut buffer = [0u8; 20];
+        let mut n = mantissa;
+        let mut len = 0;
+        while n > 0 {
+            buffer[len] = (n % 10) as u8;
+            n /= 10;
+            len += 1;
+        }
+        for (i, &digit) in buffer[..len].iter().rev().enumerate() {
+            result.digits[i] = digit;
+        }
+        result.num_digits = len;
+        result.decimal_point = len as i32;
+        result.trim();
+        result.shift(exponent);
+        result
+    }
+
+    /// Creates the exact decimal representation of the magnitude of a finite
+    /// `f64` value.
+    pub fn from_f64(x: f64) -> Self {
+        let bits = x.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        if biased_exponent == 0 {
+            Self::from_dyadic(fraction, -1074)
+        } else {
+            Self::from_dyadic(fraction | (1 << 52), biased_exponent - 1075)
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num_digits == 0
+    }
+
+    /// Appends a digit after the existing digits.
+    pub fn push_digit(&mut self, digit: u8) {
+        if self.num_digits < MAX_DIGITS {
+            self.digits[self.num_digits] = digit;
+            self.num_digits += 1;
+        } else if digit != 0 {
+            self.truncated = true;
+        }
+    }
+
+    /// Removes trailing zeros.
+    pub fn trim(&mut self) {
+        while self.num_digits != 0 && self.digits[self.num_digits - 1] == 0 {
+            self.num_digits -= 1;
+        }
+        if self.num_digits == 0 {
+            self.decimal_point = 0;
+        }
+    }
+
+    /// Multiplies the value by `2^shift`, or divides it by `2^-shift` if
+    /// `shift` is negative.
+    pub fn shift(&mut self, mut shift: i32) {
+        while shift > 0 {
+            let n = (shift as usize).min(MAX_SHIFT);
+            self.left_shift(n);
+            shift -= n as i32;
+        }
+        while shift < 0 {
+            let n = ((-shift) as usize).min(MAX_SHIFT);
+            self.right_shift(n);
+            shift += n as i32;
+        }
+    }
+
+    fn left_shift(&mut self, shift: usize) {
+        if self.num_digits == 0 {
+            return;
+        }
+
+        // Determine the number of new digits from the final carry
+        let mut n = 0u64;
+        for &digit in self.digits[..self.num_digits].iter().rev() {
+            n = (n + ((digit as u64) << shift)) / 10;
+        }
+        let mut num_new_digits = 0;
+        while n > 0 {
+            n /= 10;
+            num_new_digits += 1;
+        }
+
+        let mut read_index = self.num_digits;
+        let mut write_index = self.num_digits + num_new_digits;
+        let mut n = 0u64;
+        while read_index != 0 {
+            read_index -= 1;
+            write_index -= 1;
+            n += (self.digits[read_index] as u64) << shift;
+            self.store_digit(write_index, (n % 10) as u8);
+            n /= 10;
+        }
+        while n > 0 {
+            write_index -= 1;
+            self.store_digit(write_index, (n % 10) as u8);
+            n /= 10;
+        }
+
+        self.num_digits = (self.num_digits + num_new_digits).min(MAX_DIGITS);
+        self.decimal_point += num_new_digits as i32;
+        self.trim();
+    }
+
+    fn store_digit(&mut self, index: usize, digit: u8) {
+        if index < MAX_DIGITS {
+            self.digits[index] = digit;
+        } else if digit != 0 {
+            self.truncated = true;
+        }
+    }
+
+    fn right_shift(&mut self, shift: usize) {
+        let mut read_index = 0;
+        let mut write_index = 0;
+        let mut n = 0u64;
+        while (n >> shift) == 0 {
+            if read_index < self.num_digits {
+                n = 10 * n + self.digits[read_index] as u64;
+                read_index += 1;
+            } else if n == 0 {
+                return;
+            } else {
+                while (n >> shift) == 0 {
+                    n *= 10;
+                    read_index += 1;
+                }
+                break;
+            }
+        }
+
+        self.decimal_point -= read_index as i32 - 1;
+        if self.decimal_point < -DECIMAL_POINT_RANGE {
+            *self = Self::default();
+            return;
+        }
+
+        let mask = (1u64 << shift) - 1;
+        while read_index < self.num_digits {
+            let new_digit = (n >> shift) as u8;
+            n = 10 * (n & mask) + self.digits[read_index] as u64;
+            read_index += 1;
+            self.digits[write_index] = new_digit;
+            write_index += 1;
+        }
+        while n > 0 {
+            let new_digit = (n >> shift) as u8;
+            n = 10 * (n & mask);
+            if write_index < MAX_DIGITS {
+                self.digits[write_index] = new_digit;
+                write_index += 1;
+            } else if new_digit > 0 {
+                self.truncated = true;
+            }
+        }
+
+        self.num_digits = write_index;
+        self.trim();
+    }
+
+    /// Computes `self * multiplier + addend` for an integer-valued decimal.
+    pub fn mul_add_small(&mut self, multiplier: u32, addend: u32) {
+        if self.num_digits == 0 {
+            if addend != 0 {
+                *self = Self::from_dyadic(addend as u64, 0);
+            }
+            return;
+        }
+
+        // Materialize trailing zeros up to the units position
+        while (self.num_digits as i32) < self.decimal_point && self.num_digits < MAX_DIGITS {
+            self.digits[self.num_digits] = 0;
+            self.num_digits += 1;
+        }
+
+        let mut carry = if self.num_digits as i32 == self.decimal_point {
+            addend as u64
+        } else {
+            self.truncated |= addend != 0;
+            0
+        };
+
+        for digit in self.digits[..self.num_digits].iter_mut().rev() {
+            let n = (*digit as u64) * (multiplier as u64) + carry;
+            *digit = (n % 10) as u8;
+            carry = n / 10;
+        }
+
+        let mut carry_digits = [0u8; 20];
+        let mut num_carry_digits = 0;
+        while carry > 0 {
+            carry_digits[num_carry_digits] = (carry % 10) as u8;
+            carry /= 10;
+            num_carry_digits += 1;
+        }
+
+        if num_carry_digits > 0 {
+            let new_len = (self.num_digits + num_carry_digits).min(MAX_DIGITS);
+            for i in (num_carry_digits..self.num_digits + num_carry_digits).rev() {
+                let digit = self.digits[i - num_carry_digits];
+                self.store_digit(i, digit);
+            }
+            for i in 0..num_carry_digits {
+                self.digits[i] = carry_digits[num_carry_digits - 1 - i];
+            }
+            self.num_digits = new_len;
+            self.decimal_point += num_carry_digits as i32;
+        }
+        self.trim();
+    }
+
+    /// Divides the value by `divisor`, which must be less than `2^32`.
+    pub fn div_small(&mut self, divisor: u64) {
+        let mut read_index = 0;
+        let mut write_index = 0;
+        let mut n = 0u64;
+        while n < divisor {
+            if read_index < self.num_digits {
+                n = 10 * n + self.digits[read_index] as u64;
+                read_index += 1;
+            } else if n == 0 {
+                return;
+            } else {
+                while n < divisor {
+                    n *= 10;
+                    read_index += 1;
+                }
+                break;
+            }
+        }
+
+        self.decimal_point -= read_index as i32 - 1;
+        if self.decimal_point < -DECIMAL_POINT_RANGE {
+            *self = Self::default();
+            return;
+        }
+
+        while read_index < self.num_digits {
+            let new_digit = (n / divisor) as u8;
+            n = 10 * (n % divisor) + self.digits[read_index] as u64;
+            read_index += 1;
+            self.digits[write_index] = new_digit;
+            write_index += 1;
+        }
+        while n > 0 {
+            if write_index == MAX_DIGITS {
+                self.truncated = true;
+                break;
+            }
+            self.digits[write_index] = (n / divisor) as u8;
+            write_index += 1;
+            n = 10 * (n % divisor);
+        }
+
+        self.num_digits = write_index;
+        self.trim();
+    }
+
+    /// Compares the magnitudes of two values, ignoring truncation.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+
+        self.decimal_point
+            .cmp(&other.decimal_point)
+            .then_with(|| self.digits[..self.num_digits].cmp(&other.digits[..other.num_digits]))
+    }
+
+    /// Returns the digit with the given power of ten as its weight.
+    fn digit_at(&self, weight: i32) -> u8 {
+        let index = self.decimal_point - 1 - weight;
+        if index >= 0 && (index as usize) < self.num_digits {
+            self.digits[index as usize]
+        } else {
+            0
+        }
+    }
+
+    /// Returns the range of weights spanned by the digits of both values,
+    /// allowing for a carry into the leading position.
+    fn weight_range(&self, other: &Self) -> (i32, i32) {
+        let top = self.decimal_point.max(other.decimal_point) + 1;
+        let bottom = (self.decimal_point - self.num_digits as i32)
+            .min(other.decimal_point - other.num_digits as i32);
+        (top, bottom)
+    }
+
+    /// Removes leading zeros, adjusting the decimal point to compensate.
+    fn remove_leading_zeros(&mut self) {
+        let leading_zeros = self.digits[..self.num_digits]
+            .iter()
+            .take_while(|&&d| d == 0)
+            .count();
+        self.digits.copy_within(leading_zeros..self.num_digits, 0);
+        self.num_digits -= leading_zeros;
+        self.decimal_point -= leading_zeros as i32;
+        self.trim();
+    }
+
+    /// Adds another non-negative value to this one.
+    pub fn add(&mut self, other: &Self) {
+        if other.is_zero() {
+            return;
+        }
+        if self.is_zero() {
+            self.clone_from(other);
+            return;
+        }
+
+        let (top, bottom) = self.weight_range(other);
+        let len = ((top - bottom) as usize).min(MAX_DIGITS);
+        let mut result = Self {
+            num_digits: len,
+            decimal_point: top,
+            truncated: self.truncated || other.truncated || len < (top - bottom) as usize,
+            digits: [0; MAX_DIGITS],
+        };
+
+        let mut carry = 0;
+        for (i, digit) in result.digits[..len].iter_mut().enumerate().rev() {
+            let weight = top - 1 - i as i32;
+            let n = self.digit_at(weight) + other.digit_at(weight) + carry;
+            *digit = n % 10;
+            carry = n / 10;
+        }
+
+        result.remove_leading_zeros();
+        *self = result;
+    }
+
+    /// Subtracts a non-negative value no greater than this one.
+    pub fn sub(&mut self, other: &Self) {
+        if other.is_zero() {
+            return;
+        }
+
+        let (top, bottom) = self.weight_range(other);
+        let len = ((top - bottom) as usize).min(MAX_DIGITS);
+        let mut result = Self {
+            num_digits: len,
+            decimal_point: top,
+            truncated: self.truncated || other.truncated || len < (top - bottom) as usize,
+            digits: [0; MAX_DIGITS],
+        };
+
+        let mut borrow = 0;
+        for (i, digit) in result.digits[..len].iter_mut().enumerate().rev() {
+            let weight = top - 1 - i as i32;
+            let subtrahend = other.digit_at(weight) + borrow;
+            let minuend = self.digit_at(weight);
+            if minuend >= subtrahend {
+                *digit = minuend - subtrahend;
+                borrow = 0;
+            } else {
+                *digit = minuend + 10 - subtrahend;
+                borrow = 1;
+            }
+        }
+        debug_assert!(borrow == 0);
+
+        result.remove_leading_zeros();
+        *self = result;
+    }
+
+    /// Returns whether rounding to `n` significant digits should round up,
+    /// with ties rounding to even.
+    pub fn should_round_up(&self, n: usize) -> bool {
+        if n >= self.num_digits {
+            return false;
+        }
+
+        match self.digits[n].cmp(&5) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => {
+                n + 1 < self.num_digits || self.truncated || (n > 0 && self.digits[n - 1] & 1 != 0)
+            }
+        }
+    }
+
+    /// Rounds to `n` significant digits, with ties rounding to even.
+    pub fn round(&mut self, n: usize) {
+        if self.should_round_up(n) {
+            self.round_up(n);
+        } else {
+            self.round_down(n);
+        }
+    }
+
+    /// Truncates to `n` significant digits.
+    pub fn round_down(&mut self, n: usize) {
+        if n < self.num_digits {
+            self.num_digits = n;
+            self.truncated = false;
+            self.trim();
+        }
+    }
+
+    /// Rounds up to `n` significant digits.
+    pub fn round_up(&mut self, n: usize) {
+        if n >= self.num_digits {
+            return;
+        }
+
+        self.truncated = false;
+        for i in (0..n).rev() {
+            if self.digits[i] < 9 {
+                self.digits[i] += 1;
+                self.num_digits = i + 1;
+                return;
+            }
+        }
+
+        // All the retained digits were nines
+        self.digits[0] = 1;
+        self.num_digits = 1;
+        self.decimal_point += 1;
+    }
+
+    /// Removes the integer part of the value, which must be less than
+    /// `10^19`, and returns it.
+    fn split_integer(&mut self) -> u64 {
+        if self.decimal_point <= 0 {
+            return 0;
+        }
+
+        let dp = self.decimal_point as usize;
+        let mut n = 0u64;
+        for i in 0..dp {
+            n *= 10;
+            if i < self.num_digits {
+                n += self.digits[i] as u64;
+            }
+        }
+
+        if dp >= self.num_digits {
+            self.num_digits = 0;
+            self.decimal_point = 0;
+        } else {
+            let leading_zeros = self.digits[dp..self.num_digits]
+                .iter()
+                .take_while(|&&d| d == 0)
+                .count();
+            let start = dp + leading_zeros;
+            self.digits.copy_within(start..self.num_digits, 0);
+            self.num_digits -= start;
+            self.decimal_point = -(leading_zeros as i32);
+        }
+        n
+    }
+
+    /// Compares a value less than 1 with 1/2.
+    fn cmp_half(&self) -> Ordering {
+        if self.num_digits == 0 || self.decimal_point < 0 {
+            Ordering::Less
+        } else if self.digits[0] != 5 {
+            self.digits[0].cmp(&5)
+        } else if self.num_digits > 1 || self.truncated {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Replaces a value `x` in the range (0, 1) with `1 - x`.
+    fn complement(&mut self) {
+        debug_assert!(self.decimal_point == 0 && self.num_digits > 0);
+        let last = self.num_digits - 1;
+        for digit in &mut self.digits[..last] {
+            *digit = 9 - *digit;
+        }
+        // A truncated value lies strictly between the stored value and the
+        // next unit in the last place, so the complement does as well.
+        self.digits[last] = if self.truncated {
+            9 - self.digits[last]
+        } else {
+            10 - self.digits[last]
+        };
+        self.remove_leading_zeros();
+    }
+
+    /// Rounds `self * 2^exponent` to the nearest value in the given format,
+    /// leaving the magnitude of the rounding error in `self` in units of
+    /// `2^q`.
+    ///
+    /// Returns the rounded significand `m` and exponent `q`, such that the
+    /// result is `m * 2^q`, and whether the error is negative.
+    fn round_split(&mut self, mut exponent: i32, format: &WordFormat) -> (u64, i32, bool) {
+        let zero = (0, format.min_exponent, false);
+        let overflow = (1 << format.mantissa_digits, format.max_exponent, false);
+
+        if self.num_digits == 0 {
+            return zero;
+        }
+
+        // Bounds from 2^(3 * (dp - 1)) <= 10^(dp - 1) <= value < 10^dp <= 2^(3 * dp)
+        let dp = self.decimal_point;
+        if dp <= 0 && 3 * dp + exponent < format.min_exponent - 2 {
+            *self = Self::default();
+            return zero;
+        }
+        if dp > 0 && 3 * (dp - 1) + exponent >= format.max_exponent {
+            return overflow;
+        }
+
+        // Normalize to the range [1/2, 1)
+        while self.decimal_point > 0 {
+            let shift = get_shift(self.decimal_point as usize);
+            self.right_shift(shift);
+            exponent += shift as i32;
+        }
+        while self.decimal_point < 0 || self.digits[0] < 5 {
+            let shift = if self.decimal_point == 0 {
+                if self.digits[0] < 2 {
+                    2
+                } else {
+                    1
+                }
+            } else {
+                get_shift((-self.decimal_point) as usize)
+            };
+            self.left_shift(shift);
+            exponent -= shift as i32;
+        }
+
+        if exponent > format.max_exponent {
+            return overflow;
+        }
+
+        let q = (exponent - format.mantissa_digits).max(format.min_exponent);
+        self.shift(exponent - q);
+        let mut mantissa = self.split_integer();
+        let round_up = match self.cmp_half() {
+            Ordering::Greater => true,
+            Ordering::Equal => mantissa & 1 != 0,
+            Ordering::Less => false,
+        };
+        if round_up {
+            mantissa += 1;
+            self.complement();
+        }
+        (mantissa, q, round_up)
+    }
+
+    /// Converts the value to `N` non-overlapping words of the given format,
+    /// widened to `f64`, where each is the remaining error of the previous
+    /// words rounded to nearest.
+    ///
+    /// Overflow is indicated by returning infinite values.
+    pub fn round_words<const N: usize>(
+        &mut self,
+        mut exponent: i32,
+        format: &WordFormat,
+    ) -> [f64; N] {
+        let mut words = [0.0; N];
+        let mut negative = false;
+        for (i, word) in words.iter_mut().enumerate() {
+            let (mantissa, q, round_up) = self.round_split(exponent, format);
+            let value = format.make_word(mantissa, q);
+            if i == 0 && value.is_infinite() {
+                return [f64::INFINITY; N];
+            }
+
+            *word = if negative { -value } else { value };
+            negative ^= round_up;
+            exponent = q;
+        }
+        words
+    }
+}
+
+/// Creates the `f64` value `mantissa * 2^exponent`, where `mantissa <= 2^53`
+/// and `exponent >= -1074`.
+pub const fn make_f64(mut mantissa: u64, mut exponent: i32) -> f64 {
+    if mantissa == 1 << 53 {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+
+    if mantissa < 1 << 52 {
+        // Subnormal or zero, exponent must be at the minimum
+        f64::from_bits(mantissa)
+    } else {
+        let biased_exponent = exponent + 52 + 1023;
+        if biased_exponent >= 2047 {
+            f64::INFINITY
+        } else {
+            f64::from_bits(((biased_exponent as u64) << 52) | (mantissa & ((1 << 52) - 1)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use super::{Decimal, WordFormat};
+
+    fn digits(d: &Decimal) -> &[u8] {
+        &d.digits[..d.num_digits]
+    }
+
+    #[test]
+    fn from_dyadic_test() {
+        let d = Decimal::from_dyadic(3, -3);
+        assert_eq!(digits(&d), &[3, 7, 5]);
+        assert_eq!(d.decimal_point, 0);
+
+        let d = Decimal::from_dyadic(25, 4);
+        assert_eq!(digits(&d), &[4]);
+        assert_eq!(d.decimal_point, 3);
+    }
+
+    #[test]
+    fn mul_add_small_test() {
+        let mut d = Decimal::default();
+        d.mul_add_small(16, 15);
+        d.mul_add_small(16, 15);
+        d.mul_add_small(16, 0);
+        assert_eq!(digits(&d), &[4, 0, 8]);
+        assert_eq!(d.decimal_point, 4);
+        assert!(!d.truncated);
+    }
+
+    #[test]
+    fn div_small_test() {
+        let mut d = Decimal::from_dyadic(1, 0);
+        d.div_small(8);
+        assert_eq!(digits(&d), &[1, 2, 5]);
+        assert!(!d.truncated);
+
+        let mut d = Decimal::from_dyadic(1, 0);
+        d.div_small(3);
+        assert!(d.truncated);
+        assert!(digits(&d).iter().all(|&x| x == 3));
+    }
+
+    #[test]
+    fn add_sub_test() {
+        let mut d = Decimal::from_f64(0.75);
+        d.add(&Decimal::from_f64(99.5));
+        assert_eq!(digits(&d), &[1, 0, 0, 2, 5]);
+        assert_eq!(d.decimal_point, 3);
+
+        d.sub(&Decimal::from_f64(100.0));
+        assert_eq!(digits(&d), &[2, 5]);
+        assert_eq!(d.decimal_point, 0);
+
+        d.sub(&Decimal::from_f64(0.25));
+        assert!(d.is_zero());
+    }
+
+    #[test]
+    fn compare_test() {
+        let a = Decimal::from_f64(0.75);
+        let b = Decimal::from_f64(0.7);
+        let c = Decimal::from_f64(7.0);
+        assert_eq!(a.compare(&b), Ordering::Greater);
+        assert_eq!(b.compare(&c), Ordering::Less);
+        assert_eq!(a.compare(&a.clone()), Ordering::Equal);
+        assert_eq!(Decimal::default().compare(&b), Ordering::Less);
+    }
+
+    #[test]
+    fn round_test() {
+        let mut d = Decimal::from_f64(0.125);
+        d.round(2);
+        assert_eq!(digits(&d), &[1, 2]);
+
+        let mut d = Decimal::from_f64(0.375);
+        d.round(2);
+        assert_eq!(digits(&d), &[3, 8]);
+
+        let mut d = Decimal::from_f64(99.5);
+        d.round(2);
+        assert_eq!(digits(&d), &[1]);
+        assert_eq!(d.decimal_point, 3);
+
+        let mut d = Decimal::from_f64(0.5);
+        d.round(0);
+        assert!(d.is_zero());
+
+        let mut d = Decimal::from_f64(0.75);
+        d.round(0);
+        assert_eq!(digits(&d), &[1]);
+        assert_eq!(d.decimal_point, 1);
+    }
+
+    #[test]
+    fn round_words_test() {
+        assert_eq!(
+            Decimal::from_dyadic(1, 0).round_words(0, &WordFormat::F64),
+            [1.0, 0.0]
+        );
+        assert_eq!(
+            Decimal::from_dyadic(1, -1075).round_words(0, &WordFormat::F64),
+            [0.0, 0.0]
+        );
+        assert_eq!(
+            Decimal::from_dyadic(3, -1075).round_words(0, &WordFormat::F64),
+            [f64::from_bits(2), 0.0]
+        );
+
+        let [hi, lo] = Decimal::from_dyadic((1 << 53) + 1, 0).round_words(0, &WordFormat::F64);
+        assert_eq!(hi, 9007199254740992.0);
+        assert_eq!(lo, 1.0);
+
+        let [hi, lo] = Decimal::from_dyadic((1 << 53) + 3, 0).round_words(0, &WordFormat::F64);
+        assert_eq!(hi, 9007199254740996.0);
+        assert_eq!(lo, -1.0);
+
+        let [hi, lo] = Decimal::from_dyadic(1, 1024).round_words(0, &WordFormat::F64);
+        assert!(hi.is_infinite() && lo.is_infinite());
+
+        let words = Decimal::from_dyadic((1 << 53) + 3, 0).round_words(0, &WordFormat::F64);
+        assert_eq!(words, [9007199254740996.0, -1.0, 0.0]);
+    }
+
+    #[test]
+    fn round_words_f32_test() {
+        let f32_words = |mantissa, exponent| {
+            Decimal::from_dyadic(mantissa, exponent).round_words(0, &WordFormat::F32)
+        };
+
+        assert_eq!(f32_words((1 << 24) + 1, 0), [16777216.0, 1.0]);
+        assert_eq!(f32_words((1 << 24) + 3, 0), [16777220.0, -1.0]);
+        assert_eq!(f32_words(1, -150), [0.0, 0.0]);
+        assert_eq!(f32_words(3, -150), [f32::from_bits(2) as f64, 0.0]);
+        assert_eq!(f32_words(1, 128), [f64::INFINITY; 2]);
+
+        let [hi, lo] = f32_words((1 << 40) + 1, -40);
+        assert_eq!(hi, 1.0);
+        assert_eq!(lo, 2f64.powi(-40));
+    }
+}
@@ -0,0 +1,19 @@
+/*!
+# twofloat-decimal
+
+Exact decimal arithmetic and number parsing shared by the `twofloat` and
+`twofloat-macros` crates, so that values computed at compile time are
+identical to those parsed at runtime. This crate is an implementation detail
+and should not be used directly.
+*/
+
+#![no_std]
+#![forbid(unsafe_code)]
+
+mod decimal;
+mod parse;
+
+pub use decimal::{make_f64, Decimal, WordFormat};
+pub use parse::{
+    digit_value, parse_decimal, parse_radix, parse_sign, scan_number, Number, ParseError,
+};
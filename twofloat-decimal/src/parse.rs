@@ -0,0 +1,244 @@
+use crate::{Decimal, WordFormat};
+
+// Exponents beyond this magnitude always overflow or underflow, clamping
+// avoids integer overflow when accumulating the digits.
+const EXPONENT_LIMIT: i32 = 0x10000;
+
+/// The reason a string could not be parsed, together with the position in
+/// the string at which the error was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input contained an unexpected character
+    InvalidDigit(usize),
+    /// The input ended before the digits of the mantissa or exponent
+    MissingDigits(usize),
+}
+
+const fn missing_digits(bytes: &[u8], position: usize) -> ParseError {
+    if position < bytes.len() {
+        ParseError::InvalidDigit(position)
+    } else {
+        ParseError::MissingDigits(position)
+    }
+}
+
+// Applies the sign to the words, leaving zero words after the first positive
+// so that "-0" parses to the same words as the conversion of -0.0
+fn signed_words<const N: usize>(words: [f64; N], negative: bool) -> [f64; N] {
+    if negative {
+        let mut signed = words.map(|w| 0.0 - w);
+        signed[0] = -words[0];
+        signed
+    } else {
+        words
+    }
+}
+
+/// Reads an optional leading sign, returning whether it is negative.
+pub const fn parse_sign(bytes: &[u8], position: &mut usize) -> bool {
+    if *position < bytes.len() && (bytes[*position] == b'+' || bytes[*position] == b'-') {
+        *position += 1;
+        bytes[*position - 1] == b'-'
+    } else {
+        false
+    }
+}
+
+/// Returns the value of the digit in the given radix, which may be up to 36.
+pub const fn digit_value(c: u8, radix: u32) -> Option<u32> {
+    (c as char).to_digit(radix)
+}
+
+/// Reads a run of digits, allowing underscores as separators after the
+/// first digit, and returns the number of digits read.
+const fn skip_digits(bytes: &[u8], position: &mut usize, radix: u32) -> usize {
+    let mut count = 0;
+    while *position < bytes.len() {
+        let c = bytes[*position];
+        if digit_value(c, radix).is_some() {
+            count += 1;
+        } else if c != b'_' || count == 0 {
+            break;
+        }
+        *position += 1;
+    }
+    count
+}
+
+const fn parse_exponent(bytes: &[u8], position: &mut usize) -> Result<i32, ParseError> {
+    let negative = parse_sign(bytes, position);
+
+    let start = *position;
+    if skip_digits(bytes, position, 10) == 0 {
+        return Err(missing_digits(bytes, *position));
+    }
+
+    let mut exponent = 0i32;
+    let mut index = start;
+    while index < *position {
+        if let Some(digit) = digit_value(bytes[index], 10) {
+            if exponent < EXPONENT_LIMIT {
+                exponent = 10 * exponent + digit as i32;
+            }
+        }
+        index += 1;
+    }
+
+    Ok(if negative { -exponent } else { exponent })
+}
+
+/// The location of the significand of a number and its exponent, as found
+/// by [`scan_number`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    /// Index of the first character of the significand
+    pub start: usize,
+    /// Index after the last character of the significand, which may include
+    /// a point and underscores between the digits
+    pub end: usize,
+    /// Number of digits after the point
+    pub fraction_digits: usize,
+    /// The exponent introduced by `e` for radix 10 or by `p` for radix 16,
+    /// or zero if there is none
+    pub exponent: i32,
+}
+
+/// Checks that the bytes from `position` onwards form an unsigned number in
+/// the given radix, with an optional point and exponent, and returns the
+/// location of its digits. The exponent is a power of ten introduced by `e`
+/// for radix 10 and a power of two introduced by `p` for radix 16.
+pub const fn scan_number(
+    bytes: &[u8],
+    mut position: usize,
+    radix: u32,
+) -> Result<Number, ParseError> {
+    let start = position;
+    let mut digit_count = skip_digits(bytes, &mut position, radix);
+    let mut fraction_digits = 0;
+    if position < bytes.len() && bytes[position] == b'.' {
+        position += 1;
+        fraction_digits = skip_digits(bytes, &mut position, radix);
+        digit_count += fraction_digits;
+    }
+
+    if digit_count == 0 {
+        return Err(missing_digits(bytes, position));
+    }
+
+    let end = position;
+    let marker = match radix {
+        10 => b'e',
+        16 => b'p',
+        _ => 0,
+    };
+
+    let mut exponent = 0;
+    if marker != 0 && position < bytes.len() && bytes[position].to_ascii_lowercase() == marker {
+        position += 1;
+        exponent = match parse_exponent(bytes, &mut position) {
+            Ok(exponent) => exponent,
+            Err(e) => return Err(e),
+        };
+    }
+
+    if position < bytes.len() {
+        return Err(ParseError::InvalidDigit(position));
+    }
+
+    Ok(Number {
+        start,
+        end,
+        fraction_digits,
+        exponent,
+    })
+}
+
+/// Parses a decimal number with an optional sign and an optional exponent
+/// introduced by `e`, returning `N` non-overlapping words of the given
+/// format widened to `f64`, where each word is the remaining error of the
+/// previous words rounded to nearest.
+pub fn parse_decimal<const N: usize>(s: &str, format: &WordFormat) -> Result<[f64; N], ParseError> {
+    let bytes = s.as_bytes();
+    let mut position = 0;
+    let negative = parse_sign(bytes, &mut position);
+    let number = scan_number(bytes, position, 10)?;
+
+    let mut d = Decimal::default();
+    let mut after_point = false;
+    for &c in &bytes[number.start..number.end] {
+        match digit_value(c, 10) {
+            Some(0) if d.is_zero() => {
+                if after_point {
+                    d.decimal_point -= 1;
+                }
+            }
+            Some(digit) => {
+                d.push_digit(digit as u8);
+                if !after_point {
+                    d.decimal_point += 1;
+                }
+            }
+            None => after_point |= c == b'.',
+        }
+    }
+
+    d.decimal_point = d.decimal_point.saturating_add(number.exponent);
+    d.trim();
+    Ok(signed_words(d.round_words(0, format), negative))
+}
+
+/// Parses a number in the given radix with an optional sign, returning the
+/// words as for [`parse_decimal`], which is used for radix 10. A binary
+/// exponent introduced by `p` is accepted for radix 16.
+pub fn parse_radix<const N: usize>(
+    s: &str,
+    radix: u32,
+    format: &WordFormat,
+) -> Result<[f64; N], ParseError> {
+    if radix == 10 {
+        return parse_decimal(s, format);
+    }
+
+    let bytes = s.as_bytes();
+    let mut position = 0;
+    let negative = parse_sign(bytes, &mut position);
+    let number = scan_number(bytes, position, radix)?;
+
+    // All digits are accumulated into an integer which is then divided by
+    // the radix raised to the number of fractional digits.
+    let mut d = Decimal::default();
+    for &c in &bytes[number.start..number.end] {
+        if let Some(digit) = digit_value(c, radix) {
+            d.mul_add_small(radix, digit);
+        }
+    }
+
+    let fraction_digits = number.fraction_digits;
+    let mut exponent = number.exponent;
+    if radix.is_power_of_two() {
+        let bits = radix.trailing_zeros() as usize;
+        let shift = bits.saturating_mul(fraction_digits).min(i32::MAX as usize) as i32;
+        exponent = exponent.saturating_sub(shift);
+    } else {
+        // Divide by the largest power of the radix that fits in 32 bits
+        let mut chunk = 1u64;
+        let mut chunk_digits = 0;
+        while chunk * (radix as u64) <= u32::MAX as u64 {
+            chunk *= radix as u64;
+            chunk_digits += 1;
+        }
+
+        let mut remaining = fraction_digits;
+        while remaining > 0 && !d.is_zero() {
+            if remaining >= chunk_digits {
+                d.div_small(chunk);
+                remaining -= chunk_digits;
+            } else {
+                d.div_small((radix as u64).pow(remaining as u32));
+                remaining = 0;
+            }
+        }
+    }
+
+    Ok(signed_words(d.round_words(exponent, format), negative))
+}
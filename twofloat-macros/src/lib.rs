@@ -0,0 +1,108 @@
+/*!
+# twofloat-macros
+
+Procedural macros for the `twofloat` crate. This crate should not be used
+directly, instead enable the `macros` feature of `twofloat` and use the
+re-exported macros.
+*/
+
+#![forbid(unsafe_code)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Lit, Token,
+};
+use twofloat_decimal::{parse_decimal, WordFormat};
+
+/// A numeric or string literal, optionally preceded by a minus sign.
+struct Input {
+    negative: bool,
+    source: String,
+    literal: Lit,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let negative = input.parse::<Option<Token![-]>>()?.is_some();
+        let literal: Lit = input.parse()?;
+        let (source, suffix) = match &literal {
+            Lit::Float(value) => (value.base10_digits().to_string(), value.suffix()),
+            Lit::Int(value) => (value.base10_digits().to_string(), value.suffix()),
+            Lit::Str(value) => (value.value(), value.suffix()),
+            _ => {
+                return Err(syn::Error::new(
+                    literal.span(),
+                    "expected a numeric or string literal",
+                ))
+            }
+        };
+
+        if !suffix.is_empty() && suffix != "f64" {
+            return Err(syn::Error::new(
+                literal.span(),
+                format!("unsupported literal suffix `{}`", suffix),
+            ));
+        }
+
+        Ok(Self {
+            negative,
+            source,
+            literal,
+        })
+    }
+}
+
+/// Creates a `TwoFloat` constant from a decimal literal, computing the
+/// correctly rounded high and low words at compile time.
+///
+/// The argument may be a numeric literal, optionally preceded by a minus
+/// sign, or a string literal containing a decimal number. Numeric literals
+/// are parsed from their source text rather than converted through `f64`,
+/// so every digit is significant. The expansion is a constant expression, so
+/// it can be used to initialize `const` items.
+///
+/// # Examples
+///
+/// ```
+/// use twofloat::{tf, TwoFloat};
+///
+/// const TENTH: TwoFloat = tf!(0.1);
+/// assert_eq!(TENTH, "0.1".parse::<TwoFloat>().unwrap());
+///
+/// const PI: TwoFloat = tf!(3.14159265358979323846264338327950288);
+/// assert_eq!(PI, twofloat::consts::PI);
+///
+/// const PLANCK: TwoFloat = tf!("6.62607015e-34");
+/// assert_eq!(PLANCK, "6.62607015e-34".parse::<TwoFloat>().unwrap());
+/// assert_eq!(tf!(-1.5), TwoFloat::from(-1.5));
+/// ```
+#[proc_macro]
+pub fn tf(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    let span = input.literal.span();
+    let [hi, lo] = match parse_decimal(&input.source, &WordFormat::F64) {
+        Ok(words) => words,
+        Err(_) => {
+            return syn::Error::new(span, "invalid decimal literal")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    if hi.is_infinite() {
+        return syn::Error::new(span, "literal out of range for `TwoFloat`")
+            .to_compile_error()
+            .into();
+    }
+
+    let (hi, lo) = if input.negative { (-hi, -lo) } else { (hi, lo) };
+    let hi_bits = hi.to_bits();
+    let lo_bits = lo.to_bits();
+    quote!({
+        const VALUE: ::twofloat::TwoFloat = ::twofloat::TwoFloat::__from_bits(#hi_bits, #lo_bits);
+        VALUE
+    })
+    .into()
+}
@@ -0,0 +1,67 @@
+#![allow(clippy::float_cmp)]
+
+use twofloat::{tf, TwoFloat};
+
+#[test]
+fn tf_parse_test() {
+    let cases = [
+        (tf!(0.1), "0.1"),
+        (tf!(1e-5), "1e-5"),
+        (tf!(-2.5E3), "-2.5e3"),
+        (tf!(1_000_000), "1000000"),
+        (tf!(0x10), "16"),
+        (tf!(1.5f64), "1.5"),
+        (tf!(0), "0"),
+        (tf!(-0.0), "-0"),
+        (tf!(1.7976931348623157e308), "1.7976931348623157e308"),
+        (tf!(4.9e-324), "4.9e-324"),
+        (
+            tf!("3.14159265358979323846264338327950288"),
+            "3.14159265358979323846264338327950288",
+        ),
+        (
+            tf!("-1.2345678901234567890123456789e-5"),
+            "-1.2345678901234567890123456789e-5",
+        ),
+        (tf!("+.25"), "0.25"),
+        (tf!("6.022_140_76e+23"), "6.02214076e23"),
+    ];
+
+    for &(value, source) in cases.iter() {
+        let expected: TwoFloat = source.parse().unwrap();
+        assert_eq!(
+            value.hi(),
+            expected.hi(),
+            "Incorrect high word for {}",
+            source
+        );
+        assert_eq!(
+            value.lo(),
+            expected.lo(),
+            "Incorrect low word for {}",
+            source
+        );
+        assert_eq!(
+            value.is_sign_negative(),
+            expected.is_sign_negative(),
+            "Incorrect sign for {}",
+            source
+        );
+    }
+}
+
+#[test]
+fn tf_const_test() {
+    const E: TwoFloat = tf!("2.718281828459045235360287471352662497757");
+    const PI: TwoFloat = tf!("3.141592653589793238462643383279502884197");
+    const LN_2: TwoFloat = tf!("0.693147180559945309417232121458176568076");
+
+    assert_eq!(E, twofloat::consts::E);
+    assert_eq!(PI, twofloat::consts::PI);
+    assert_eq!(LN_2, twofloat::consts::LN_2);
+
+    const TABLE: [TwoFloat; 3] = [tf!(0.1), tf!(0.2), tf!(0.3)];
+    for (value, source) in TABLE.iter().zip(["0.1", "0.2", "0.3"].iter()) {
+        assert_eq!(*value, source.parse::<TwoFloat>().unwrap());
+    }
+}